@@ -0,0 +1,36 @@
+use multiversx_sc_scenario::*;
+
+/// The contracts deployed by the multisig are not registered,
+/// so they run from their compiled wasm, in the embedded engine.
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/examples/multisig");
+
+    blockchain.register_partial_contract::<multisig::AbiProvider, _>(
+        "file:output/multisig.wasm",
+        multisig::ContractBuilder,
+        "multisig",
+    );
+    blockchain.register_partial_contract::<multisig::AbiProvider, _>(
+        "file:output/multisig-view.wasm",
+        multisig::ContractBuilder,
+        "multisig-view",
+    );
+
+    blockchain
+}
+
+#[test]
+fn deployadder_then_call_wasm() {
+    multiversx_sc_scenario::run_rs("scenarios/deployAdder_then_call.scen.json", world());
+}
+
+#[test]
+fn deployfactorial_wasm() {
+    multiversx_sc_scenario::run_rs("scenarios/deployFactorial.scen.json", world());
+}
+
+#[test]
+fn upgrade_wasm() {
+    multiversx_sc_scenario::run_rs("scenarios/upgrade.scen.json", world());
+}
@@ -0,0 +1,1330 @@
+// Code generated by the multiversx-sc-meta TypeScript generator. DO NOT EDIT.
+// Contract: AbiTester
+
+export class NestedEncoder {
+    private readonly data: number[] = [];
+
+    write(bytes: Uint8Array): void {
+        bytes.forEach((b) => this.data.push(b));
+    }
+
+    writeU8(value: number): void {
+        this.data.push(value & 0xff);
+    }
+
+    toBytes(): Uint8Array {
+        return Uint8Array.from(this.data);
+    }
+}
+
+export class NestedDecoder {
+    private offset = 0;
+
+    constructor(private readonly bytes: Uint8Array) {}
+
+    read(length: number): Uint8Array {
+        if (this.offset + length > this.bytes.length) {
+            throw new Error("input too short");
+        }
+        const result = this.bytes.slice(this.offset, this.offset + length);
+        this.offset += length;
+        return result;
+    }
+
+    readU8(): number {
+        return this.read(1)[0];
+    }
+
+    isDepleted(): boolean {
+        return this.offset === this.bytes.length;
+    }
+}
+
+export interface Codec<T> {
+    encodeNested(value: T, out: NestedEncoder): void;
+    decodeNested(input: NestedDecoder): T;
+    encodeTop(value: T): Uint8Array;
+    decodeTop(bytes: Uint8Array): T;
+}
+
+export type NestedCodec<T> = Pick<Codec<T>, "encodeNested" | "decodeNested">;
+
+export function encodeNestedToBytes<T>(codec: NestedCodec<T>, value: T): Uint8Array {
+    const out = new NestedEncoder();
+    codec.encodeNested(value, out);
+    return out.toBytes();
+}
+
+export function decodeNestedFromBytes<T>(codec: NestedCodec<T>, bytes: Uint8Array): T {
+    const input = new NestedDecoder(bytes);
+    const value = codec.decodeNested(input);
+    if (!input.isDepleted()) {
+        throw new Error("input too long");
+    }
+    return value;
+}
+
+/** For types whose top encoding is the same as their nested encoding, e.g. structs. */
+export function nestedCodec<T>(nested: NestedCodec<T>): Codec<T> {
+    return {
+        encodeNested: (value, out) => nested.encodeNested(value, out),
+        decodeNested: (input) => nested.decodeNested(input),
+        encodeTop: (value) => encodeNestedToBytes(nested, value),
+        decodeTop: (bytes) => decodeNestedFromBytes(nested, bytes),
+    };
+}
+
+/** Enums top-encode as their nested encoding, except for a fieldless first variant, which is empty. */
+export function enumCodec<T>(nested: NestedCodec<T>, firstVariantFieldless: boolean): Codec<T> {
+    return {
+        encodeNested: (value, out) => nested.encodeNested(value, out),
+        decodeNested: (input) => nested.decodeNested(input),
+        encodeTop: (value) => {
+            const bytes = encodeNestedToBytes(nested, value);
+            if (firstVariantFieldless && bytes.length === 1 && bytes[0] === 0) {
+                return new Uint8Array(0);
+            }
+            return bytes;
+        },
+        decodeTop: (bytes) => {
+            if (firstVariantFieldless && bytes.length === 0) {
+                return decodeNestedFromBytes(nested, Uint8Array.of(0));
+            }
+            return decodeNestedFromBytes(nested, bytes);
+        },
+    };
+}
+
+export function mapCodec<A, B>(codec: Codec<A>, to: (value: B) => A, from: (value: A) => B): Codec<B> {
+    return {
+        encodeNested: (value, out) => codec.encodeNested(to(value), out),
+        decodeNested: (input) => from(codec.decodeNested(input)),
+        encodeTop: (value) => codec.encodeTop(to(value)),
+        decodeTop: (bytes) => from(codec.decodeTop(bytes)),
+    };
+}
+
+function fitsInBytes(value: bigint, size: number, signed: boolean): boolean {
+    const bits = BigInt(size * 8);
+    if (signed) {
+        const bound = 1n << (bits - 1n);
+        return value >= -bound && value < bound;
+    }
+    return value >= 0n && value < 1n << bits;
+}
+
+function bigintToBytes(value: bigint, size: number): Uint8Array {
+    const result = new Uint8Array(size);
+    let remaining = BigInt.asUintN(size * 8, value);
+    for (let i = size - 1; i >= 0; i--) {
+        result[i] = Number(remaining & 0xffn);
+        remaining >>= 8n;
+    }
+    return result;
+}
+
+function bytesToBigint(bytes: Uint8Array, signed: boolean): bigint {
+    let value = 0n;
+    bytes.forEach((b) => {
+        value = (value << 8n) | BigInt(b);
+    });
+    if (signed && bytes.length > 0 && bytes[0] >= 0x80) {
+        value -= 1n << BigInt(bytes.length * 8);
+    }
+    return value;
+}
+
+/** Strips the leading bytes that don't change the value, zero becomes empty. */
+function minimalBytes(bytes: Uint8Array, signed: boolean): Uint8Array {
+    let start = 0;
+    while (start < bytes.length) {
+        const next = start + 1 < bytes.length ? bytes[start + 1] : 0;
+        const redundantZero = bytes[start] === 0 && (!signed || next < 0x80);
+        const redundantSign = signed && bytes[start] === 0xff && start + 1 < bytes.length && next >= 0x80;
+        if (!redundantZero && !redundantSign) {
+            break;
+        }
+        start++;
+    }
+    return bytes.slice(start);
+}
+
+function checkFits(value: bigint, size: number, signed: boolean): void {
+    if (!fitsInBytes(value, size, signed)) {
+        throw new Error(`value ${value} out of range`);
+    }
+}
+
+function intCodec(size: number, signed: boolean): Codec<bigint> {
+    return {
+        encodeNested: (value, out) => {
+            checkFits(value, size, signed);
+            out.write(bigintToBytes(value, size));
+        },
+        decodeNested: (input) => bytesToBigint(input.read(size), signed),
+        encodeTop: (value) => {
+            checkFits(value, size, signed);
+            return minimalBytes(bigintToBytes(value, size), signed);
+        },
+        decodeTop: (bytes) => {
+            if (bytes.length > size) {
+                throw new Error("input too long");
+            }
+            return bytesToBigint(bytes, signed);
+        },
+    };
+}
+
+function numberCodec(size: number, signed: boolean): Codec<number> {
+    return mapCodec(intCodec(size, signed), (value: number) => BigInt(value), Number);
+}
+
+export const U8Codec = numberCodec(1, false);
+export const U16Codec = numberCodec(2, false);
+export const U32Codec = numberCodec(4, false);
+export const U64Codec = intCodec(8, false);
+export const I8Codec = numberCodec(1, true);
+export const I16Codec = numberCodec(2, true);
+export const I32Codec = numberCodec(4, true);
+export const I64Codec = intCodec(8, true);
+
+function bigToBytes(value: bigint, signed: boolean): Uint8Array {
+    if (!signed && value < 0n) {
+        throw new Error(`value ${value} out of range`);
+    }
+    let size = 1;
+    while (!fitsInBytes(value, size, signed)) {
+        size++;
+    }
+    return minimalBytes(bigintToBytes(value, size), signed);
+}
+
+function bigNumberCodec(signed: boolean): Codec<bigint> {
+    return {
+        encodeNested: (value, out) => {
+            const bytes = bigToBytes(value, signed);
+            U32Codec.encodeNested(bytes.length, out);
+            out.write(bytes);
+        },
+        decodeNested: (input) => bytesToBigint(input.read(U32Codec.decodeNested(input)), signed),
+        encodeTop: (value) => bigToBytes(value, signed),
+        decodeTop: (bytes) => bytesToBigint(bytes, signed),
+    };
+}
+
+export const BigUintCodec = bigNumberCodec(false);
+export const BigIntCodec = bigNumberCodec(true);
+
+export const BoolCodec: Codec<boolean> = {
+    encodeNested: (value, out) => out.writeU8(value ? 1 : 0),
+    decodeNested: (input) => decodeBool(input.readU8()),
+    encodeTop: (value) => (value ? Uint8Array.of(1) : new Uint8Array(0)),
+    decodeTop: (bytes) => decodeBool(U8Codec.decodeTop(bytes)),
+};
+
+function decodeBool(value: number): boolean {
+    switch (value) {
+        case 0:
+            return false;
+        case 1:
+            return true;
+        default:
+            throw new Error(`invalid bool value: ${value}`);
+    }
+}
+
+export const BytesCodec: Codec<Uint8Array> = {
+    encodeNested: (value, out) => {
+        U32Codec.encodeNested(value.length, out);
+        out.write(value);
+    },
+    decodeNested: (input) => input.read(U32Codec.decodeNested(input)),
+    encodeTop: (value) => value.slice(),
+    decodeTop: (bytes) => bytes.slice(),
+};
+
+export const Utf8StringCodec: Codec<string> = mapCodec(
+    BytesCodec,
+    (value: string) => new TextEncoder().encode(value),
+    (bytes) => new TextDecoder().decode(bytes),
+);
+
+function fixedBytesCodec(size: number): Codec<Uint8Array> {
+    const checkSize = (bytes: Uint8Array) => {
+        if (bytes.length !== size) {
+            throw new Error(`expected ${size} bytes, got ${bytes.length}`);
+        }
+        return bytes.slice();
+    };
+    return {
+        encodeNested: (value, out) => out.write(checkSize(value)),
+        decodeNested: (input) => input.read(size),
+        encodeTop: checkSize,
+        decodeTop: checkSize,
+    };
+}
+
+export const AddressCodec = fixedBytesCodec(32);
+export const H256Codec = fixedBytesCodec(32);
+export const CodeMetadataCodec = nestedCodec(U16Codec);
+
+export const UnitCodec: Codec<null> = nestedCodec({
+    encodeNested: () => {},
+    decodeNested: () => null,
+});
+
+/** Stands in for the types the generator doesn't know, which can only be passed around top-encoded. */
+export const RawBytesCodec: Codec<Uint8Array> = {
+    encodeNested: () => {
+        throw new Error("raw bytes cannot be nested-encoded");
+    },
+    decodeNested: () => {
+        throw new Error("raw bytes cannot be nested-decoded");
+    },
+    encodeTop: (value) => value.slice(),
+    decodeTop: (bytes) => bytes.slice(),
+};
+
+export function ListCodec<T>(item: Codec<T>): Codec<Array<T>> {
+    return {
+        encodeNested: (value, out) => {
+            U32Codec.encodeNested(value.length, out);
+            value.forEach((v) => item.encodeNested(v, out));
+        },
+        decodeNested: (input) => {
+            const length = U32Codec.decodeNested(input);
+            const result: Array<T> = [];
+            for (let i = 0; i < length; i++) {
+                result.push(item.decodeNested(input));
+            }
+            return result;
+        },
+        encodeTop: (value) => {
+            const out = new NestedEncoder();
+            value.forEach((v) => item.encodeNested(v, out));
+            return out.toBytes();
+        },
+        decodeTop: (bytes) => {
+            const input = new NestedDecoder(bytes);
+            const result: Array<T> = [];
+            while (!input.isDepleted()) {
+                result.push(item.decodeNested(input));
+            }
+            return result;
+        },
+    };
+}
+
+export function OptionCodec<T>(item: Codec<T>): Codec<T | null> {
+    const nested: NestedCodec<T | null> = {
+        encodeNested: (value, out) => {
+            if (value === null) {
+                out.writeU8(0);
+            } else {
+                out.writeU8(1);
+                item.encodeNested(value, out);
+            }
+        },
+        decodeNested: (input) => {
+            const flag = input.readU8();
+            switch (flag) {
+                case 0:
+                    return null;
+                case 1:
+                    return item.decodeNested(input);
+                default:
+                    throw new Error(`invalid Option flag: ${flag}`);
+            }
+        },
+    };
+    return {
+        ...nested,
+        encodeTop: (value) => (value === null ? new Uint8Array(0) : encodeNestedToBytes(nested, value)),
+        decodeTop: (bytes) => {
+            if (bytes.length === 0) {
+                return null;
+            }
+            if (bytes[0] !== 1) {
+                throw new Error(`invalid Option flag: ${bytes[0]}`);
+            }
+            return decodeNestedFromBytes(nested, bytes);
+        },
+    };
+}
+
+export function TupleCodec<T extends unknown[]>(...items: Codec<unknown>[]): Codec<T> {
+    return nestedCodec<T>({
+        encodeNested: (value, out) => items.forEach((item, i) => item.encodeNested(value[i], out)),
+        decodeNested: (input) => items.map((item) => item.decodeNested(input)) as T,
+    });
+}
+
+export function ArrayCodec<T>(item: Codec<T>, length: number): Codec<Array<T>> {
+    return nestedCodec<Array<T>>({
+        encodeNested: (value, out) => {
+            if (value.length !== length) {
+                throw new Error(`expected ${length} items, got ${value.length}`);
+            }
+            value.forEach((v) => item.encodeNested(v, out));
+        },
+        decodeNested: (input) => {
+            const result: Array<T> = [];
+            for (let i = 0; i < length; i++) {
+                result.push(item.decodeNested(input));
+            }
+            return result;
+        },
+    });
+}
+
+export class ArgReader {
+    private index = 0;
+
+    constructor(private readonly args: Uint8Array[]) {}
+
+    next(): Uint8Array {
+        if (!this.hasNext()) {
+            throw new Error("not enough arguments");
+        }
+        return this.args[this.index++];
+    }
+
+    hasNext(): boolean {
+        return this.index < this.args.length;
+    }
+}
+
+/** Encodes values as a sequence of top-encoded arguments or results. */
+export interface MultiCodec<T> {
+    encodeMulti(value: T, args: Uint8Array[]): void;
+    decodeMulti(reader: ArgReader): T;
+}
+
+export function SingleValue<T>(codec: Codec<T>): MultiCodec<T> {
+    return {
+        encodeMulti: (value, args) => {
+            args.push(codec.encodeTop(value));
+        },
+        decodeMulti: (reader) => codec.decodeTop(reader.next()),
+    };
+}
+
+/** The unit type is a multi-value of no arguments. */
+export const UnitValue: MultiCodec<null> = {
+    encodeMulti: () => {},
+    decodeMulti: () => null,
+};
+
+export function VariadicValue<T>(item: MultiCodec<T>): MultiCodec<Array<T>> {
+    return {
+        encodeMulti: (value, args) => value.forEach((v) => item.encodeMulti(v, args)),
+        decodeMulti: (reader) => {
+            const result: Array<T> = [];
+            while (reader.hasNext()) {
+                result.push(item.decodeMulti(reader));
+            }
+            return result;
+        },
+    };
+}
+
+export function CountedVariadicValue<T>(item: MultiCodec<T>): MultiCodec<Array<T>> {
+    return {
+        encodeMulti: (value, args) => {
+            args.push(U32Codec.encodeTop(value.length));
+            value.forEach((v) => item.encodeMulti(v, args));
+        },
+        decodeMulti: (reader) => {
+            const count = U32Codec.decodeTop(reader.next());
+            const result: Array<T> = [];
+            for (let i = 0; i < count; i++) {
+                result.push(item.decodeMulti(reader));
+            }
+            return result;
+        },
+    };
+}
+
+export function OptionalValue<T>(item: MultiCodec<T>): MultiCodec<T | undefined> {
+    return {
+        encodeMulti: (value, args) => {
+            if (value !== undefined) {
+                item.encodeMulti(value, args);
+            }
+        },
+        decodeMulti: (reader) => (reader.hasNext() ? item.decodeMulti(reader) : undefined),
+    };
+}
+
+export function MultiValue<T extends unknown[]>(...items: MultiCodec<unknown>[]): MultiCodec<T> {
+    return {
+        encodeMulti: (value, args) => items.forEach((item, i) => item.encodeMulti(value[i], args)),
+        decodeMulti: (reader) => items.map((item) => item.decodeMulti(reader)) as T,
+    };
+}
+
+export function decodeResults<T>(returnData: Uint8Array[], codec: MultiCodec<T>): T {
+    const reader = new ArgReader(returnData);
+    const value = codec.decodeMulti(reader);
+    if (reader.hasNext()) {
+        throw new Error("too many results");
+    }
+    return value;
+}
+
+export interface ContractCall {
+    func: string;
+    args: Uint8Array[];
+}
+
+export type QueryRunner = (call: ContractCall) => Promise<Uint8Array[]>;
+
+/**
+ * Its only purpose is to test that the ABI generator works fine.
+ */
+export type AbiEnum =
+    | { kind: "Nothing" }
+    | { kind: "Something"; fields: [number] }
+    | { kind: "SomethingMore"; fields: [number, OnlyShowsUpAsNested08] }
+    | { kind: "SomeStruct"; fields: { a: number; b: OnlyShowsUpAsNested09 } };
+
+export const AbiEnumCodec: Codec<AbiEnum> = enumCodec<AbiEnum>(
+    {
+        encodeNested(value, out) {
+            switch (value.kind) {
+                case "Nothing":
+                    out.writeU8(0);
+                    break;
+                case "Something":
+                    out.writeU8(1);
+                    I32Codec.encodeNested(value.fields[0], out);
+                    break;
+                case "SomethingMore":
+                    out.writeU8(2);
+                    U8Codec.encodeNested(value.fields[0], out);
+                    OnlyShowsUpAsNested08Codec.encodeNested(value.fields[1], out);
+                    break;
+                case "SomeStruct":
+                    out.writeU8(3);
+                    U16Codec.encodeNested(value.fields.a, out);
+                    OnlyShowsUpAsNested09Codec.encodeNested(value.fields.b, out);
+                    break;
+            }
+        },
+        decodeNested(input) {
+            const discriminant = input.readU8();
+            switch (discriminant) {
+                case 0:
+                    return { kind: "Nothing" };
+                case 1:
+                    return { kind: "Something", fields: [I32Codec.decodeNested(input)] };
+                case 2:
+                    return { kind: "SomethingMore", fields: [U8Codec.decodeNested(input), OnlyShowsUpAsNested08Codec.decodeNested(input)] };
+                case 3:
+                    return { kind: "SomeStruct", fields: { a: U16Codec.decodeNested(input), b: OnlyShowsUpAsNested09Codec.decodeNested(input) } };
+                default:
+                    throw new Error(`invalid AbiEnum discriminant: ${discriminant}`);
+            }
+        },
+    },
+    true,
+);
+
+/**
+ * Its only purpose is to test that the ABI generator works fine.
+ */
+export interface AbiManagedType {
+    big_uint: bigint;
+    integer: number;
+    managed_buffer: Uint8Array;
+}
+
+export const AbiManagedTypeCodec: Codec<AbiManagedType> = nestedCodec<AbiManagedType>({
+    encodeNested(value, out) {
+        BigUintCodec.encodeNested(value.big_uint, out);
+        I32Codec.encodeNested(value.integer, out);
+        BytesCodec.encodeNested(value.managed_buffer, out);
+    },
+    decodeNested(input) {
+        return {
+            big_uint: BigUintCodec.decodeNested(input),
+            integer: I32Codec.decodeNested(input),
+            managed_buffer: BytesCodec.decodeNested(input),
+        };
+    },
+});
+
+/**
+ * Its only purpose is to test that the ABI generator works fine.
+ */
+export interface AbiManagedVecItem {
+    value1: number;
+    value2: number;
+}
+
+export const AbiManagedVecItemCodec: Codec<AbiManagedVecItem> = nestedCodec<AbiManagedVecItem>({
+    encodeNested(value, out) {
+        U32Codec.encodeNested(value.value1, out);
+        U32Codec.encodeNested(value.value2, out);
+    },
+    decodeNested(input) {
+        return {
+            value1: U32Codec.decodeNested(input),
+            value2: U32Codec.decodeNested(input),
+        };
+    },
+});
+
+/**
+ * Its only purpose is to test that the ABI generator works fine.
+ */
+export interface AbiTestType {
+    /**
+     * This type should only appear here.
+     */
+    nested: OnlyShowsUpAsNested01;
+    /**
+     * Tests that recursive types will not send the ABI generator into an infinite loop.
+     */
+    next: AbiTestType | null;
+    /**
+     * Tests that tuples tell the ABI of their component types even if they appear nowhere else.
+     * Also, just like above, recursive types need to work even when nested into a tuple.
+     */
+    tuple_madness: [OnlyShowsUpAsNested02, AbiTestType | null];
+}
+
+export const AbiTestTypeCodec: Codec<AbiTestType> = nestedCodec<AbiTestType>({
+    encodeNested(value, out) {
+        OnlyShowsUpAsNested01Codec.encodeNested(value.nested, out);
+        OptionCodec(AbiTestTypeCodec).encodeNested(value.next, out);
+        TupleCodec<[OnlyShowsUpAsNested02, AbiTestType | null]>(OnlyShowsUpAsNested02Codec, OptionCodec(AbiTestTypeCodec)).encodeNested(value.tuple_madness, out);
+    },
+    decodeNested(input) {
+        return {
+            nested: OnlyShowsUpAsNested01Codec.decodeNested(input),
+            next: OptionCodec(AbiTestTypeCodec).decodeNested(input),
+            tuple_madness: TupleCodec<[OnlyShowsUpAsNested02, AbiTestType | null]>(OnlyShowsUpAsNested02Codec, OptionCodec(AbiTestTypeCodec)).decodeNested(input),
+        };
+    },
+});
+
+export type EsdtLocalRole =
+    | "None"
+    | "Mint"
+    | "Burn"
+    | "NftCreate"
+    | "NftAddQuantity"
+    | "NftBurn"
+    | "NftAddUri"
+    | "NftUpdateAttributes"
+    | "Transfer";
+
+export const EsdtLocalRoleCodec: Codec<EsdtLocalRole> = enumCodec<EsdtLocalRole>(
+    {
+        encodeNested(value, out) {
+            switch (value) {
+                case "None":
+                    out.writeU8(0);
+                    break;
+                case "Mint":
+                    out.writeU8(1);
+                    break;
+                case "Burn":
+                    out.writeU8(2);
+                    break;
+                case "NftCreate":
+                    out.writeU8(3);
+                    break;
+                case "NftAddQuantity":
+                    out.writeU8(4);
+                    break;
+                case "NftBurn":
+                    out.writeU8(5);
+                    break;
+                case "NftAddUri":
+                    out.writeU8(6);
+                    break;
+                case "NftUpdateAttributes":
+                    out.writeU8(7);
+                    break;
+                case "Transfer":
+                    out.writeU8(8);
+                    break;
+            }
+        },
+        decodeNested(input) {
+            const discriminant = input.readU8();
+            switch (discriminant) {
+                case 0:
+                    return "None";
+                case 1:
+                    return "Mint";
+                case 2:
+                    return "Burn";
+                case 3:
+                    return "NftCreate";
+                case 4:
+                    return "NftAddQuantity";
+                case 5:
+                    return "NftBurn";
+                case 6:
+                    return "NftAddUri";
+                case 7:
+                    return "NftUpdateAttributes";
+                case 8:
+                    return "Transfer";
+                default:
+                    throw new Error(`invalid EsdtLocalRole discriminant: ${discriminant}`);
+            }
+        },
+    },
+    true,
+);
+
+export interface EsdtTokenData {
+    token_type: EsdtTokenType;
+    amount: bigint;
+    frozen: boolean;
+    hash: Uint8Array;
+    name: Uint8Array;
+    attributes: Uint8Array;
+    creator: Uint8Array;
+    royalties: bigint;
+    uris: Array<Uint8Array>;
+}
+
+export const EsdtTokenDataCodec: Codec<EsdtTokenData> = nestedCodec<EsdtTokenData>({
+    encodeNested(value, out) {
+        EsdtTokenTypeCodec.encodeNested(value.token_type, out);
+        BigUintCodec.encodeNested(value.amount, out);
+        BoolCodec.encodeNested(value.frozen, out);
+        BytesCodec.encodeNested(value.hash, out);
+        BytesCodec.encodeNested(value.name, out);
+        BytesCodec.encodeNested(value.attributes, out);
+        AddressCodec.encodeNested(value.creator, out);
+        BigUintCodec.encodeNested(value.royalties, out);
+        ListCodec(BytesCodec).encodeNested(value.uris, out);
+    },
+    decodeNested(input) {
+        return {
+            token_type: EsdtTokenTypeCodec.decodeNested(input),
+            amount: BigUintCodec.decodeNested(input),
+            frozen: BoolCodec.decodeNested(input),
+            hash: BytesCodec.decodeNested(input),
+            name: BytesCodec.decodeNested(input),
+            attributes: BytesCodec.decodeNested(input),
+            creator: AddressCodec.decodeNested(input),
+            royalties: BigUintCodec.decodeNested(input),
+            uris: ListCodec(BytesCodec).decodeNested(input),
+        };
+    },
+});
+
+export interface EsdtTokenPayment {
+    token_identifier: string;
+    token_nonce: bigint;
+    amount: bigint;
+}
+
+export const EsdtTokenPaymentCodec: Codec<EsdtTokenPayment> = nestedCodec<EsdtTokenPayment>({
+    encodeNested(value, out) {
+        Utf8StringCodec.encodeNested(value.token_identifier, out);
+        U64Codec.encodeNested(value.token_nonce, out);
+        BigUintCodec.encodeNested(value.amount, out);
+    },
+    decodeNested(input) {
+        return {
+            token_identifier: Utf8StringCodec.decodeNested(input),
+            token_nonce: U64Codec.decodeNested(input),
+            amount: BigUintCodec.decodeNested(input),
+        };
+    },
+});
+
+export type EsdtTokenType =
+    | "Fungible"
+    | "NonFungible"
+    | "SemiFungible"
+    | "Meta"
+    | "Invalid";
+
+export const EsdtTokenTypeCodec: Codec<EsdtTokenType> = enumCodec<EsdtTokenType>(
+    {
+        encodeNested(value, out) {
+            switch (value) {
+                case "Fungible":
+                    out.writeU8(0);
+                    break;
+                case "NonFungible":
+                    out.writeU8(1);
+                    break;
+                case "SemiFungible":
+                    out.writeU8(2);
+                    break;
+                case "Meta":
+                    out.writeU8(3);
+                    break;
+                case "Invalid":
+                    out.writeU8(4);
+                    break;
+            }
+        },
+        decodeNested(input) {
+            const discriminant = input.readU8();
+            switch (discriminant) {
+                case 0:
+                    return "Fungible";
+                case 1:
+                    return "NonFungible";
+                case 2:
+                    return "SemiFungible";
+                case 3:
+                    return "Meta";
+                case 4:
+                    return "Invalid";
+                default:
+                    throw new Error(`invalid EsdtTokenType discriminant: ${discriminant}`);
+            }
+        },
+    },
+    true,
+);
+
+/**
+ * Tests that the ABI generator also fetches types that only appear as fields.
+ */
+export interface OnlyShowsUpAsNested01 {}
+
+export const OnlyShowsUpAsNested01Codec: Codec<OnlyShowsUpAsNested01> = nestedCodec<OnlyShowsUpAsNested01>({
+    encodeNested() {},
+    decodeNested() {
+        return {};
+    },
+});
+
+/**
+ * Tests that the ABI generator also fetches types that only appear as fields.
+ */
+export interface OnlyShowsUpAsNested02 {
+    something: Array<number>;
+}
+
+export const OnlyShowsUpAsNested02Codec: Codec<OnlyShowsUpAsNested02> = nestedCodec<OnlyShowsUpAsNested02>({
+    encodeNested(value, out) {
+        ArrayCodec(U8Codec, 0).encodeNested(value.something, out);
+    },
+    decodeNested(input) {
+        return {
+            something: ArrayCodec(U8Codec, 0).decodeNested(input),
+        };
+    },
+});
+
+/**
+ * Tests that the ABI generator also fetches types that only appear as fields.
+ */
+export interface OnlyShowsUpAsNested03 {}
+
+export const OnlyShowsUpAsNested03Codec: Codec<OnlyShowsUpAsNested03> = nestedCodec<OnlyShowsUpAsNested03>({
+    encodeNested() {},
+    decodeNested() {
+        return {};
+    },
+});
+
+/**
+ * Tests that the ABI generator also fetches types that only appear as fields.
+ */
+export interface OnlyShowsUpAsNested04 {}
+
+export const OnlyShowsUpAsNested04Codec: Codec<OnlyShowsUpAsNested04> = nestedCodec<OnlyShowsUpAsNested04>({
+    encodeNested() {},
+    decodeNested() {
+        return {};
+    },
+});
+
+/**
+ * Tests that the ABI generator also fetches types that only appear as fields.
+ */
+export interface OnlyShowsUpAsNested05 {}
+
+export const OnlyShowsUpAsNested05Codec: Codec<OnlyShowsUpAsNested05> = nestedCodec<OnlyShowsUpAsNested05>({
+    encodeNested() {},
+    decodeNested() {
+        return {};
+    },
+});
+
+/**
+ * Tests that the ABI generator also fetches types that only appear as fields.
+ */
+export interface OnlyShowsUpAsNested06 {}
+
+export const OnlyShowsUpAsNested06Codec: Codec<OnlyShowsUpAsNested06> = nestedCodec<OnlyShowsUpAsNested06>({
+    encodeNested() {},
+    decodeNested() {
+        return {};
+    },
+});
+
+/**
+ * Tests that the ABI generator also fetches types that only appear as fields.
+ */
+export interface OnlyShowsUpAsNested07 {}
+
+export const OnlyShowsUpAsNested07Codec: Codec<OnlyShowsUpAsNested07> = nestedCodec<OnlyShowsUpAsNested07>({
+    encodeNested() {},
+    decodeNested() {
+        return {};
+    },
+});
+
+/**
+ * Tests that the ABI generator also fetches types that only appear as fields.
+ */
+export interface OnlyShowsUpAsNested08 {}
+
+export const OnlyShowsUpAsNested08Codec: Codec<OnlyShowsUpAsNested08> = nestedCodec<OnlyShowsUpAsNested08>({
+    encodeNested() {},
+    decodeNested() {
+        return {};
+    },
+});
+
+/**
+ * Tests that the ABI generator also fetches types that only appear as fields.
+ */
+export interface OnlyShowsUpAsNested09 {}
+
+export const OnlyShowsUpAsNested09Codec: Codec<OnlyShowsUpAsNested09> = nestedCodec<OnlyShowsUpAsNested09>({
+    encodeNested() {},
+    decodeNested() {
+        return {};
+    },
+});
+
+/**
+ * Tests that the ABI generator also fetches types that only appear as fields.
+ */
+export interface OnlyShowsUpAsNestedInArray {}
+
+export const OnlyShowsUpAsNestedInArrayCodec: Codec<OnlyShowsUpAsNestedInArray> = nestedCodec<OnlyShowsUpAsNestedInArray>({
+    encodeNested() {},
+    decodeNested() {
+        return {};
+    },
+});
+
+/**
+ * Tests that the ABI generator also fetches types that only appear as fields.
+ */
+export interface OnlyShowsUpAsNestedInArrayVec {}
+
+export const OnlyShowsUpAsNestedInArrayVecCodec: Codec<OnlyShowsUpAsNestedInArrayVec> = nestedCodec<OnlyShowsUpAsNestedInArrayVec>({
+    encodeNested() {},
+    decodeNested() {
+        return {};
+    },
+});
+
+/**
+ * Tests that the ABI generator also fetches types that only appear as fields.
+ */
+export interface OnlyShowsUpAsNestedInBox {}
+
+export const OnlyShowsUpAsNestedInBoxCodec: Codec<OnlyShowsUpAsNestedInBox> = nestedCodec<OnlyShowsUpAsNestedInBox>({
+    encodeNested() {},
+    decodeNested() {
+        return {};
+    },
+});
+
+/**
+ * Tests that the ABI generator also fetches types that only appear as fields.
+ */
+export interface OnlyShowsUpAsNestedInBoxedSlice {}
+
+export const OnlyShowsUpAsNestedInBoxedSliceCodec: Codec<OnlyShowsUpAsNestedInBoxedSlice> = nestedCodec<OnlyShowsUpAsNestedInBoxedSlice>({
+    encodeNested() {},
+    decodeNested() {
+        return {};
+    },
+});
+
+/**
+ * Tests that the ABI generator also fetches types that only appear as fields.
+ */
+export interface OnlyShowsUpAsNestedInOption {}
+
+export const OnlyShowsUpAsNestedInOptionCodec: Codec<OnlyShowsUpAsNestedInOption> = nestedCodec<OnlyShowsUpAsNestedInOption>({
+    encodeNested() {},
+    decodeNested() {
+        return {};
+    },
+});
+
+/**
+ * Tests that the ABI generator also fetches types that only appear as fields.
+ */
+export interface OnlyShowsUpAsNestedInRef {}
+
+export const OnlyShowsUpAsNestedInRefCodec: Codec<OnlyShowsUpAsNestedInRef> = nestedCodec<OnlyShowsUpAsNestedInRef>({
+    encodeNested() {},
+    decodeNested() {
+        return {};
+    },
+});
+
+/**
+ * Tests that the ABI generator also fetches types that only appear as fields.
+ */
+export interface OnlyShowsUpAsNestedInSingleValueMapper {}
+
+export const OnlyShowsUpAsNestedInSingleValueMapperCodec: Codec<OnlyShowsUpAsNestedInSingleValueMapper> = nestedCodec<OnlyShowsUpAsNestedInSingleValueMapper>({
+    encodeNested() {},
+    decodeNested() {
+        return {};
+    },
+});
+
+/**
+ * Tests that the ABI generator also fetches types that only appear as fields.
+ */
+export interface OnlyShowsUpAsNestedInSlice {}
+
+export const OnlyShowsUpAsNestedInSliceCodec: Codec<OnlyShowsUpAsNestedInSlice> = nestedCodec<OnlyShowsUpAsNestedInSlice>({
+    encodeNested() {},
+    decodeNested() {
+        return {};
+    },
+});
+
+/**
+ * Tests that the ABI generator also fetches types that only appear as fields.
+ */
+export interface OnlyShowsUpAsNestedInVec {}
+
+export const OnlyShowsUpAsNestedInVecCodec: Codec<OnlyShowsUpAsNestedInVec> = nestedCodec<OnlyShowsUpAsNestedInVec>({
+    encodeNested() {},
+    decodeNested() {
+        return {};
+    },
+});
+
+/**
+ * Tests that the ABI generator also fetches types that only appear as fields.
+ */
+export interface OnlyShowsUpInConstructor {
+    something: null;
+}
+
+export const OnlyShowsUpInConstructorCodec: Codec<OnlyShowsUpInConstructor> = nestedCodec<OnlyShowsUpInConstructor>({
+    encodeNested(value, out) {
+        UnitCodec.encodeNested(value.something, out);
+    },
+    decodeNested(input) {
+        return {
+            something: UnitCodec.decodeNested(input),
+        };
+    },
+});
+
+export const AbiTesterCalls = {
+    /**
+     * Contract constructor.
+     */
+    init(_constructor_arg_1: number, _constructor_arg_2: OnlyShowsUpInConstructor): Uint8Array[] {
+        const args: Uint8Array[] = [];
+        SingleValue(I32Codec).encodeMulti(_constructor_arg_1, args);
+        SingleValue(OnlyShowsUpInConstructorCodec).encodeMulti(_constructor_arg_2, args);
+        return args;
+    },
+
+    /**
+     * Example endpoint docs.
+     */
+    echoAbiTestType(att: AbiTestType): ContractCall {
+        const args: Uint8Array[] = [];
+        SingleValue(AbiTestTypeCodec).encodeMulti(att, args);
+        return { func: "echo_abi_test_type", args };
+    },
+
+    echoEnum(e: AbiEnum): ContractCall {
+        const args: Uint8Array[] = [];
+        SingleValue(AbiEnumCodec).encodeMulti(e, args);
+        return { func: "echo_enum", args };
+    },
+
+    takeManagedType(_arg: AbiManagedType): ContractCall {
+        const args: Uint8Array[] = [];
+        SingleValue(AbiManagedTypeCodec).encodeMulti(_arg, args);
+        return { func: "take_managed_type", args };
+    },
+
+    multiResult3(): ContractCall {
+        const args: Uint8Array[] = [];
+        return { func: "multi_result_3", args };
+    },
+
+    multiResult4(): ContractCall {
+        const args: Uint8Array[] = [];
+        return { func: "multi_result_4", args };
+    },
+
+    varArgs(_simple_arg: number, _var_args: Array<[OnlyShowsUpAsNested04, number]>): ContractCall {
+        const args: Uint8Array[] = [];
+        SingleValue(U32Codec).encodeMulti(_simple_arg, args);
+        VariadicValue(MultiValue<[OnlyShowsUpAsNested04, number]>(SingleValue(OnlyShowsUpAsNested04Codec), SingleValue(I32Codec))).encodeMulti(_var_args, args);
+        return { func: "var_args", args };
+    },
+
+    multiResultVec(): ContractCall {
+        const args: Uint8Array[] = [];
+        return { func: "multi_result_vec", args };
+    },
+
+    optionalArg(_simple_arg: number, _opt_args: OnlyShowsUpAsNested06 | undefined): ContractCall {
+        const args: Uint8Array[] = [];
+        SingleValue(U32Codec).encodeMulti(_simple_arg, args);
+        OptionalValue(SingleValue(OnlyShowsUpAsNested06Codec)).encodeMulti(_opt_args, args);
+        return { func: "optional_arg", args };
+    },
+
+    optionalResult(): ContractCall {
+        const args: Uint8Array[] = [];
+        return { func: "optional_result", args };
+    },
+
+    addressVsH256(address: Uint8Array, h256: Uint8Array): ContractCall {
+        const args: Uint8Array[] = [];
+        SingleValue(AddressCodec).encodeMulti(address, args);
+        SingleValue(H256Codec).encodeMulti(h256, args);
+        return { func: "address_vs_h256", args };
+    },
+
+    managedAddressVsByteArray(address: Uint8Array, byte_array: Array<number>): ContractCall {
+        const args: Uint8Array[] = [];
+        SingleValue(AddressCodec).encodeMulti(address, args);
+        SingleValue(ArrayCodec(U8Codec, 32)).encodeMulti(byte_array, args);
+        return { func: "managed_address_vs_byte_array", args };
+    },
+
+    esdtLocalRole(): ContractCall {
+        const args: Uint8Array[] = [];
+        return { func: "esdt_local_role", args };
+    },
+
+    esdtTokenPayment(): ContractCall {
+        const args: Uint8Array[] = [];
+        return { func: "esdt_token_payment", args };
+    },
+
+    esdtTokenData(): ContractCall {
+        const args: Uint8Array[] = [];
+        return { func: "esdt_token_data", args };
+    },
+
+    sampleStorageMapper(): ContractCall {
+        const args: Uint8Array[] = [];
+        return { func: "sample_storage_mapper", args };
+    },
+
+    itemForVec(): ContractCall {
+        const args: Uint8Array[] = [];
+        return { func: "item_for_vec", args };
+    },
+
+    itemForArrayVec(): ContractCall {
+        const args: Uint8Array[] = [];
+        return { func: "item_for_array_vec", args };
+    },
+
+    itemForManagedVec(): ContractCall {
+        const args: Uint8Array[] = [];
+        return { func: "item_for_managed_vec", args };
+    },
+
+    itemForArray(_array: Array<OnlyShowsUpAsNestedInArray>): ContractCall {
+        const args: Uint8Array[] = [];
+        SingleValue(ArrayCodec(OnlyShowsUpAsNestedInArrayCodec, 5)).encodeMulti(_array, args);
+        return { func: "item_for_array", args };
+    },
+
+    itemForBox(): ContractCall {
+        const args: Uint8Array[] = [];
+        return { func: "item_for_box", args };
+    },
+
+    itemForBoxedSlice(): ContractCall {
+        const args: Uint8Array[] = [];
+        return { func: "item_for_boxed_slice", args };
+    },
+
+    itemForRef(_ref: OnlyShowsUpAsNestedInRef): ContractCall {
+        const args: Uint8Array[] = [];
+        SingleValue(OnlyShowsUpAsNestedInRefCodec).encodeMulti(_ref, args);
+        return { func: "item_for_ref", args };
+    },
+
+    itemForSlice(_ref: Array<OnlyShowsUpAsNestedInSlice>): ContractCall {
+        const args: Uint8Array[] = [];
+        SingleValue(ListCodec(OnlyShowsUpAsNestedInSliceCodec)).encodeMulti(_ref, args);
+        return { func: "item_for_slice", args };
+    },
+
+    itemForOption(): ContractCall {
+        const args: Uint8Array[] = [];
+        return { func: "item_for_option", args };
+    },
+
+    /**
+     * Payable in: EGLD.
+     */
+    payableEgld(): ContractCall {
+        const args: Uint8Array[] = [];
+        return { func: "payable_egld", args };
+    },
+
+    /**
+     * Payable in: TOKEN-FOR-ABI.
+     */
+    payableSomeToken(): ContractCall {
+        const args: Uint8Array[] = [];
+        return { func: "payable_some_token", args };
+    },
+
+    /**
+     * Payable in: *.
+     */
+    payableAnyToken(): ContractCall {
+        const args: Uint8Array[] = [];
+        return { func: "payable_any_token", args };
+    },
+};
+
+export const AbiTesterResults = {
+    echoAbiTestType(returnData: Uint8Array[]): AbiTestType {
+        return decodeResults(returnData, SingleValue(AbiTestTypeCodec));
+    },
+
+    echoEnum(returnData: Uint8Array[]): AbiEnum {
+        return decodeResults(returnData, SingleValue(AbiEnumCodec));
+    },
+
+    multiResult3(returnData: Uint8Array[]): [number, Array<number>, Uint8Array] {
+        return decodeResults(returnData, MultiValue<[number, Array<number>, Uint8Array]>(SingleValue(I32Codec), SingleValue(ArrayCodec(U8Codec, 3)), SingleValue(BytesCodec)));
+    },
+
+    multiResult4(returnData: Uint8Array[]): [number, Array<number>, Uint8Array, OnlyShowsUpAsNested03] {
+        return decodeResults(returnData, MultiValue<[number, Array<number>, Uint8Array, OnlyShowsUpAsNested03]>(SingleValue(I32Codec), SingleValue(ArrayCodec(U8Codec, 3)), SingleValue(BytesCodec), SingleValue(OnlyShowsUpAsNested03Codec)));
+    },
+
+    multiResultVec(returnData: Uint8Array[]): Array<[OnlyShowsUpAsNested05, boolean, null]> {
+        return decodeResults(returnData, VariadicValue(MultiValue<[OnlyShowsUpAsNested05, boolean, null]>(SingleValue(OnlyShowsUpAsNested05Codec), SingleValue(BoolCodec), UnitValue)));
+    },
+
+    optionalResult(returnData: Uint8Array[]): OnlyShowsUpAsNested07 | undefined {
+        return decodeResults(returnData, OptionalValue(SingleValue(OnlyShowsUpAsNested07Codec)));
+    },
+
+    addressVsH256(returnData: Uint8Array[]): [Uint8Array, Uint8Array] {
+        return decodeResults(returnData, MultiValue<[Uint8Array, Uint8Array]>(SingleValue(AddressCodec), SingleValue(H256Codec)));
+    },
+
+    managedAddressVsByteArray(returnData: Uint8Array[]): [Uint8Array, Array<number>] {
+        return decodeResults(returnData, MultiValue<[Uint8Array, Array<number>]>(SingleValue(AddressCodec), SingleValue(ArrayCodec(U8Codec, 32))));
+    },
+
+    esdtLocalRole(returnData: Uint8Array[]): EsdtLocalRole {
+        return decodeResults(returnData, SingleValue(EsdtLocalRoleCodec));
+    },
+
+    esdtTokenPayment(returnData: Uint8Array[]): EsdtTokenPayment {
+        return decodeResults(returnData, SingleValue(EsdtTokenPaymentCodec));
+    },
+
+    esdtTokenData(returnData: Uint8Array[]): EsdtTokenData {
+        return decodeResults(returnData, SingleValue(EsdtTokenDataCodec));
+    },
+
+    sampleStorageMapper(returnData: Uint8Array[]): OnlyShowsUpAsNestedInSingleValueMapper {
+        return decodeResults(returnData, SingleValue(OnlyShowsUpAsNestedInSingleValueMapperCodec));
+    },
+
+    itemForVec(returnData: Uint8Array[]): Array<OnlyShowsUpAsNestedInVec> {
+        return decodeResults(returnData, SingleValue(ListCodec(OnlyShowsUpAsNestedInVecCodec)));
+    },
+
+    itemForArrayVec(returnData: Uint8Array[]): Array<OnlyShowsUpAsNestedInArrayVec> {
+        return decodeResults(returnData, SingleValue(ListCodec(OnlyShowsUpAsNestedInArrayVecCodec)));
+    },
+
+    itemForManagedVec(returnData: Uint8Array[]): Array<AbiManagedVecItem> {
+        return decodeResults(returnData, SingleValue(ListCodec(AbiManagedVecItemCodec)));
+    },
+
+    itemForBox(returnData: Uint8Array[]): OnlyShowsUpAsNestedInBox {
+        return decodeResults(returnData, SingleValue(OnlyShowsUpAsNestedInBoxCodec));
+    },
+
+    itemForBoxedSlice(returnData: Uint8Array[]): Array<OnlyShowsUpAsNestedInBoxedSlice> {
+        return decodeResults(returnData, SingleValue(ListCodec(OnlyShowsUpAsNestedInBoxedSliceCodec)));
+    },
+
+    itemForOption(returnData: Uint8Array[]): OnlyShowsUpAsNestedInOption | null {
+        return decodeResults(returnData, SingleValue(OptionCodec(OnlyShowsUpAsNestedInOptionCodec)));
+    },
+};
+
+export class AbiTesterQueries {
+    constructor(private readonly runQuery: QueryRunner) {}
+
+    async sampleStorageMapper(): Promise<OnlyShowsUpAsNestedInSingleValueMapper> {
+        const returnData = await this.runQuery(AbiTesterCalls.sampleStorageMapper());
+        return AbiTesterResults.sampleStorageMapper(returnData);
+    }
+
+    async itemForVec(): Promise<Array<OnlyShowsUpAsNestedInVec>> {
+        const returnData = await this.runQuery(AbiTesterCalls.itemForVec());
+        return AbiTesterResults.itemForVec(returnData);
+    }
+
+    async itemForArrayVec(): Promise<Array<OnlyShowsUpAsNestedInArrayVec>> {
+        const returnData = await this.runQuery(AbiTesterCalls.itemForArrayVec());
+        return AbiTesterResults.itemForArrayVec(returnData);
+    }
+
+    async itemForManagedVec(): Promise<Array<AbiManagedVecItem>> {
+        const returnData = await this.runQuery(AbiTesterCalls.itemForManagedVec());
+        return AbiTesterResults.itemForManagedVec(returnData);
+    }
+
+    async itemForArray(_array: Array<OnlyShowsUpAsNestedInArray>): Promise<void> {
+        await this.runQuery(AbiTesterCalls.itemForArray(_array));
+    }
+
+    async itemForBox(): Promise<OnlyShowsUpAsNestedInBox> {
+        const returnData = await this.runQuery(AbiTesterCalls.itemForBox());
+        return AbiTesterResults.itemForBox(returnData);
+    }
+
+    async itemForBoxedSlice(): Promise<Array<OnlyShowsUpAsNestedInBoxedSlice>> {
+        const returnData = await this.runQuery(AbiTesterCalls.itemForBoxedSlice());
+        return AbiTesterResults.itemForBoxedSlice(returnData);
+    }
+
+    async itemForRef(_ref: OnlyShowsUpAsNestedInRef): Promise<void> {
+        await this.runQuery(AbiTesterCalls.itemForRef(_ref));
+    }
+
+    async itemForSlice(_ref: Array<OnlyShowsUpAsNestedInSlice>): Promise<void> {
+        await this.runQuery(AbiTesterCalls.itemForSlice(_ref));
+    }
+
+    async itemForOption(): Promise<OnlyShowsUpAsNestedInOption | null> {
+        const returnData = await this.runQuery(AbiTesterCalls.itemForOption());
+        return AbiTesterResults.itemForOption(returnData);
+    }
+}
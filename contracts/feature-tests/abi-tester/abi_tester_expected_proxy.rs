@@ -0,0 +1,325 @@
+// Code generated by the multiversx-sc-meta proxy generator. DO NOT EDIT.
+// Contract: AbiTester
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+/// Contract whose sole purpose is to verify that
+/// the ABI generation framework works sa expected.
+///
+/// Note: any change in this contract must also be reflected in `abi_test_expected.abi.json`,
+/// including Rust docs.
+#[multiversx_sc::proxy]
+pub trait AbiTester {
+    /// Contract constructor.
+    #[init]
+    #[payable("EGLD")]
+    fn init(&self, _constructor_arg_1: i32, _constructor_arg_2: OnlyShowsUpInConstructor);
+
+    /// Example endpoint docs.
+    #[endpoint(echo_abi_test_type)]
+    fn echo_abi_test_type(&self, att: AbiTestType) -> AbiTestType;
+
+    #[endpoint(echo_enum)]
+    fn echo_enum(&self, e: AbiEnum) -> AbiEnum;
+
+    #[endpoint(take_managed_type)]
+    fn take_managed_type(&self, _arg: AbiManagedType<Self::Api>);
+
+    #[endpoint(multi_result_3)]
+    fn multi_result_3(&self) -> MultiValue3<i32, [u8; 3], ManagedBuffer<Self::Api>>;
+
+    #[endpoint(multi_result_4)]
+    fn multi_result_4(
+        &self,
+    ) -> MultiValue4<i32, [u8; 3], ManagedBuffer<Self::Api>, OnlyShowsUpAsNested03>;
+
+    #[endpoint(var_args)]
+    fn var_args(
+        &self,
+        _simple_arg: u32,
+        _var_args: MultiValueEncoded<Self::Api, MultiValue2<OnlyShowsUpAsNested04, i32>>,
+    );
+
+    #[endpoint(multi_result_vec)]
+    fn multi_result_vec(
+        &self,
+    ) -> MultiValueEncoded<Self::Api, MultiValue3<OnlyShowsUpAsNested05, bool, ()>>;
+
+    #[endpoint(optional_arg)]
+    fn optional_arg(&self, _simple_arg: u32, _opt_args: OptionalValue<OnlyShowsUpAsNested06>);
+
+    #[endpoint(optional_result)]
+    fn optional_result(&self) -> OptionalValue<OnlyShowsUpAsNested07>;
+
+    #[endpoint(address_vs_h256)]
+    fn address_vs_h256(
+        &self,
+        address: ManagedAddress<Self::Api>,
+        h256: ManagedByteArray<Self::Api, 32>,
+    ) -> MultiValue2<ManagedAddress<Self::Api>, ManagedByteArray<Self::Api, 32>>;
+
+    #[endpoint(managed_address_vs_byte_array)]
+    fn managed_address_vs_byte_array(
+        &self,
+        address: ManagedAddress<Self::Api>,
+        byte_array: [u8; 32],
+    ) -> MultiValue2<ManagedAddress<Self::Api>, [u8; 32]>;
+
+    #[endpoint(esdt_local_role)]
+    fn esdt_local_role(&self) -> EsdtLocalRole;
+
+    #[endpoint(esdt_token_payment)]
+    fn esdt_token_payment(&self) -> EsdtTokenPayment<Self::Api>;
+
+    #[endpoint(esdt_token_data)]
+    fn esdt_token_data(&self) -> EsdtTokenData<Self::Api>;
+
+    #[view(sample_storage_mapper)]
+    fn sample_storage_mapper(&self) -> OnlyShowsUpAsNestedInSingleValueMapper;
+
+    #[view(item_for_vec)]
+    fn item_for_vec(&self) -> multiversx_sc::types::heap::Vec<OnlyShowsUpAsNestedInVec>;
+
+    #[view(item_for_array_vec)]
+    fn item_for_array_vec(&self) -> multiversx_sc::types::heap::Vec<OnlyShowsUpAsNestedInArrayVec>;
+
+    #[view(item_for_managed_vec)]
+    fn item_for_managed_vec(&self) -> ManagedVec<Self::Api, AbiManagedVecItem>;
+
+    #[view(item_for_array)]
+    fn item_for_array(&self, _array: [OnlyShowsUpAsNestedInArray; 5]);
+
+    #[view(item_for_box)]
+    fn item_for_box(&self) -> OnlyShowsUpAsNestedInBox;
+
+    #[view(item_for_boxed_slice)]
+    fn item_for_boxed_slice(
+        &self,
+    ) -> multiversx_sc::types::heap::Vec<OnlyShowsUpAsNestedInBoxedSlice>;
+
+    #[view(item_for_ref)]
+    fn item_for_ref(&self, _ref: OnlyShowsUpAsNestedInRef);
+
+    #[view(item_for_slice)]
+    fn item_for_slice(&self, _ref: multiversx_sc::types::heap::Vec<OnlyShowsUpAsNestedInSlice>);
+
+    #[view(item_for_option)]
+    fn item_for_option(&self) -> Option<OnlyShowsUpAsNestedInOption>;
+
+    #[endpoint(payable_egld)]
+    #[payable("EGLD")]
+    fn payable_egld(&self);
+
+    #[endpoint(payable_some_token)]
+    #[payable("TOKEN-FOR-ABI")]
+    fn payable_some_token(&self);
+
+    #[endpoint(payable_any_token)]
+    #[payable("*")]
+    fn payable_any_token(&self);
+}
+
+/// Its only purpose is to test that the ABI generator works fine.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub enum AbiEnum {
+    Nothing,
+    Something(i32),
+    SomethingMore(u8, OnlyShowsUpAsNested08),
+    SomeStruct { a: u16, b: OnlyShowsUpAsNested09 },
+}
+
+/// Its only purpose is to test that the ABI generator works fine.
+#[derive(
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    TypeAbi,
+    Clone,
+    PartialEq,
+    Debug,
+    ManagedVecItem,
+)]
+pub struct AbiManagedType<M: ManagedTypeApi> {
+    pub big_uint: BigUint<M>,
+    pub integer: i32,
+    pub managed_buffer: ManagedBuffer<M>,
+}
+
+/// Its only purpose is to test that the ABI generator works fine.
+#[derive(
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    TypeAbi,
+    Clone,
+    PartialEq,
+    Debug,
+    ManagedVecItem,
+)]
+pub struct AbiManagedVecItem {
+    pub value1: u32,
+    pub value2: u32,
+}
+
+/// Its only purpose is to test that the ABI generator works fine.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct AbiTestType {
+    /// This type should only appear here.
+    pub nested: OnlyShowsUpAsNested01,
+    /// Tests that recursive types will not send the ABI generator into an infinite loop.
+    pub next: Option<multiversx_sc::types::heap::Box<AbiTestType>>,
+    /// Tests that tuples tell the ABI of their component types even if they appear nowhere else.
+    /// Also, just like above, recursive types need to work even when nested into a tuple.
+    pub tuple_madness: (
+        OnlyShowsUpAsNested02,
+        Option<multiversx_sc::types::heap::Box<AbiTestType>>,
+    ),
+}
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct OnlyShowsUpAsNested01;
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    TypeAbi,
+    Clone,
+    PartialEq,
+    Debug,
+    ManagedVecItem,
+)]
+pub struct OnlyShowsUpAsNested02 {
+    pub something: [u8; 0],
+}
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct OnlyShowsUpAsNested03;
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct OnlyShowsUpAsNested04;
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct OnlyShowsUpAsNested05;
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct OnlyShowsUpAsNested06;
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct OnlyShowsUpAsNested07;
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct OnlyShowsUpAsNested08;
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct OnlyShowsUpAsNested09;
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct OnlyShowsUpAsNestedInArray;
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct OnlyShowsUpAsNestedInArrayVec;
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct OnlyShowsUpAsNestedInBox;
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct OnlyShowsUpAsNestedInBoxedSlice;
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct OnlyShowsUpAsNestedInOption;
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct OnlyShowsUpAsNestedInRef;
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct OnlyShowsUpAsNestedInSingleValueMapper;
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct OnlyShowsUpAsNestedInSlice;
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct OnlyShowsUpAsNestedInVec;
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct OnlyShowsUpInConstructor {
+    pub something: (),
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct PayableEvent<M: ManagedTypeApi> {
+    pub token: TokenIdentifier<M>,
+    pub amount: BigUint<M>,
+}
+
+impl<M: ManagedTypeApi> PayableEvent<M> {
+    pub const IDENTIFIER: &'static [u8] = b"payable-event";
+
+    /// Decodes the event from the topics and the data of a log entry.
+    pub fn decode(
+        topics: &ManagedVec<M, ManagedBuffer<M>>,
+        data: &ManagedBuffer<M>,
+    ) -> Result<Self, DecodeError> {
+        if topics.len() != 2 {
+            return Result::Err(DecodeError::from("wrong number of event topics"));
+        }
+        if *topics.get(0) != *Self::IDENTIFIER {
+            return Result::Err(DecodeError::from("wrong event identifier"));
+        }
+        let token = <TokenIdentifier<M>>::top_decode((*topics.get(1)).clone())?;
+        let amount = <BigUint<M>>::top_decode(data.clone())?;
+        Result::Ok(PayableEvent { token, amount })
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct AddressH256Event<M: ManagedTypeApi> {
+    pub address: ManagedAddress<M>,
+    pub h256: ManagedByteArray<M, 32>,
+}
+
+impl<M: ManagedTypeApi> AddressH256Event<M> {
+    pub const IDENTIFIER: &'static [u8] = b"address-h256-event";
+
+    /// Decodes the event from the topics and the data of a log entry.
+    pub fn decode(
+        topics: &ManagedVec<M, ManagedBuffer<M>>,
+        data: &ManagedBuffer<M>,
+    ) -> Result<Self, DecodeError> {
+        if topics.len() != 3 {
+            return Result::Err(DecodeError::from("wrong number of event topics"));
+        }
+        if *topics.get(0) != *Self::IDENTIFIER {
+            return Result::Err(DecodeError::from("wrong event identifier"));
+        }
+        let address = <ManagedAddress<M>>::top_decode((*topics.get(1)).clone())?;
+        let h256 = <ManagedByteArray<M, 32>>::top_decode((*topics.get(2)).clone())?;
+        if !data.is_empty() {
+            return Result::Err(DecodeError::INPUT_TOO_LONG);
+        }
+        Result::Ok(AddressH256Event { address, h256 })
+    }
+}
@@ -0,0 +1,53 @@
+use std::{fs, fs::File, io::Write};
+
+use multiversx_sc::{
+    contract_base::ProxyObjBase,
+    types::{ManagedAddress, ManagedBuffer},
+};
+use multiversx_sc_meta::{abi_json::ContractAbiJson, generate_proxy::generate_proxy_module};
+use multiversx_sc_scenario::{DebugApi, ScenarioWorld};
+
+// the expected module is also compiled, so that it is checked to be valid Rust, not just the expected text
+#[allow(dead_code)]
+#[path = "../abi_tester_expected_proxy.rs"]
+mod abi_tester_expected_proxy;
+
+use abi_tester_expected_proxy::{AbiEnum, ProxyTrait as _};
+
+#[test]
+fn abi_tester_proxy_generated_ok() {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/abi-tester");
+
+    let multi_contract_config = multiversx_sc_meta::multi_contract_config::<abi_tester::AbiProvider>(
+        blockchain
+            .current_dir()
+            .join("multicontract.toml")
+            .to_str()
+            .unwrap(),
+    );
+
+    let main_contract = multi_contract_config.find_contract("abi-tester");
+    let main_contract_proxy = generate_proxy_module(&ContractAbiJson::from(&main_contract.abi));
+
+    // save generated module to disk for easier comparison in case something is off
+    let mut file = File::create("abi_tester_generated_proxy.rs").unwrap();
+    file.write_all(main_contract_proxy.as_bytes()).unwrap();
+
+    // load expected from disk & check!
+    assert_eq!(
+        main_contract_proxy,
+        fs::read_to_string("./abi_tester_expected_proxy.rs").unwrap()
+    );
+}
+
+#[test]
+fn abi_tester_expected_proxy_call() {
+    let _ = DebugApi::dummy();
+    let call = abi_tester_expected_proxy::Proxy::<DebugApi>::new_proxy_obj()
+        .contract(ManagedAddress::zero())
+        .echo_enum(AbiEnum::Something(5));
+
+    assert_eq!(call.endpoint_name, ManagedBuffer::from("echo_enum"));
+    assert_eq!(call.arg_buffer.to_raw_args_vec(), vec![vec![1, 0, 0, 0, 5]]);
+}
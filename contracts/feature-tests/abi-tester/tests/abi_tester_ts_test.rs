@@ -0,0 +1,39 @@
+use std::{fs, fs::File, io::Write};
+
+use multiversx_sc_meta::{abi_json::ContractAbiJson, generate_ts::generate_ts_bindings};
+use multiversx_sc_scenario::ScenarioWorld;
+
+#[test]
+fn abi_tester_ts_generated_ok() {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/abi-tester");
+
+    let multi_contract_config = multiversx_sc_meta::multi_contract_config::<abi_tester::AbiProvider>(
+        blockchain
+            .current_dir()
+            .join("multicontract.toml")
+            .to_str()
+            .unwrap(),
+    );
+
+    let main_contract = multi_contract_config.find_contract("abi-tester");
+    let view_contract = multi_contract_config.find_contract("abi-tester-ev");
+    let main_contract_ts = generate_ts_bindings(&ContractAbiJson::from(&main_contract.abi));
+    let view_contract_ts = generate_ts_bindings(&ContractAbiJson::from(&view_contract.abi));
+
+    // save generated bindings to disk for easier comparison in case something is off
+    let mut file = File::create("abi_tester_generated_main.ts").unwrap();
+    file.write_all(main_contract_ts.as_bytes()).unwrap();
+    let mut file = File::create("abi_tester_generated_view.ts").unwrap();
+    file.write_all(view_contract_ts.as_bytes()).unwrap();
+
+    // load expected from disk & check!
+    assert_eq!(
+        main_contract_ts,
+        fs::read_to_string("./abi_tester_expected_main.ts").unwrap()
+    );
+    assert_eq!(
+        view_contract_ts,
+        fs::read_to_string("./abi_tester_expected_view.ts").unwrap()
+    );
+}
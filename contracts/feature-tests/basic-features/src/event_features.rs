@@ -36,4 +36,13 @@ pub trait EventFeatures {
     ) {
         self.event_b(arg1, arg2, data.into_vec());
     }
+
+    /// Has several data fields, which get encoded like a struct.
+    #[event("event_c")]
+    fn event_c(&self, #[indexed] arg: u32, amount: &BigUint, name: &ManagedBuffer, timestamp: u64);
+
+    #[endpoint(logEventC)]
+    fn log_event_c(&self, arg: u32, amount: BigUint, name: ManagedBuffer, timestamp: u64) {
+        self.event_c(arg, &amount, &name, timestamp);
+    }
 }
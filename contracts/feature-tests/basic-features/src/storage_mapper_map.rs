@@ -4,6 +4,7 @@ multiversx_sc::imports!();
 #[multiversx_sc::module]
 pub trait MapMapperFeatures {
     #[view]
+    #[paginated_view]
     #[storage_mapper("map_mapper")]
     fn map_mapper(&self) -> MapMapper<u32, u32>;
 
@@ -4,6 +4,7 @@ multiversx_sc::imports!();
 #[multiversx_sc::module]
 pub trait VecMapperFeatures {
     #[view]
+    #[paginated_view]
     #[storage_mapper("vec_mapper")]
     fn vec_mapper(&self) -> VecMapper<u32>;
 
@@ -32,4 +33,10 @@ pub trait VecMapperFeatures {
     fn vec_mapper_len_at_address(&self, address: ManagedAddress) -> usize {
         self.vec_mapper().len_at_address(&address)
     }
+
+    /// Same storage, exposed under a custom public name, which also names its paginated view.
+    #[view(getVecMapper)]
+    #[paginated_view]
+    #[storage_mapper("vec_mapper")]
+    fn vec_mapper_renamed(&self) -> VecMapper<u32>;
 }
@@ -273,6 +273,11 @@ fn sc_properties_go() {
     multiversx_sc_scenario::run_go("scenarios/sc_properties.scen.json");
 }
 
+#[test]
+fn storage_protected_keys_go() {
+    multiversx_sc_scenario::run_go("scenarios/storage_protected_keys.scen.json");
+}
+
 #[test]
 fn storage_raw_api_features_go() {
     multiversx_sc_scenario::run_go("scenarios/storage_raw_api_features.scen.json");
@@ -315,6 +315,11 @@ fn sc_properties_rs() {
     multiversx_sc_scenario::run_rs("scenarios/sc_properties.scen.json", world());
 }
 
+#[test]
+fn storage_protected_keys_rs() {
+    multiversx_sc_scenario::run_rs("scenarios/storage_protected_keys.scen.json", world());
+}
+
 #[test]
 fn storage_raw_api_features_rs() {
     multiversx_sc_scenario::run_rs("scenarios/storage_raw_api_features.scen.json", world());
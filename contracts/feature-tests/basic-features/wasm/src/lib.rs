@@ -5,9 +5,9 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                          340
+// Endpoints:                          345
 // Async Callback:                       1
-// Total number of exported functions: 342
+// Total number of exported functions: 347
 
 #![no_std]
 #![feature(alloc_error_handler, lang_items)]
@@ -195,6 +195,7 @@ multiversx_sc_wasm_adapter::endpoints! {
         logEventA
         logEventARepeat
         logEventB
+        logEventC
         only_owner_endpoint
         only_user_account_endpoint
         require_equals
@@ -283,6 +284,7 @@ multiversx_sc_wasm_adapter::endpoints! {
         map_mapper_entry_or_insert_default
         map_mapper_entry_and_modify
         map_mapper_entry_or_insert_with_key
+        map_mapper_page
         map_storage_mapper_view
         map_storage_mapper_insert_default
         map_storage_mapper_contains_key
@@ -314,6 +316,9 @@ multiversx_sc_wasm_adapter::endpoints! {
         vec_mapper_get_at_address
         vec_mapper_len
         vec_mapper_len_at_address
+        getVecMapper
+        vec_mapper_page
+        getVecMapper_page
         token_attributes_set
         token_attributes_update
         token_attributes_get_attributes
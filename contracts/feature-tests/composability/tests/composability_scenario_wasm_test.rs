@@ -0,0 +1,53 @@
+use multiversx_sc_scenario::*;
+
+/// No contract is registered, so all of them run from their compiled wasm, in the embedded engine,
+/// including the ones they call, deploy or upgrade.
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/composability");
+    blockchain
+}
+
+#[test]
+fn forw_raw_sync_echo_wasm() {
+    multiversx_sc_scenario::run_rs("scenarios/forw_raw_sync_echo.scen.json", world());
+}
+
+#[test]
+fn forw_raw_async_echo_wasm() {
+    multiversx_sc_scenario::run_rs("scenarios/forw_raw_async_echo.scen.json", world());
+}
+
+#[test]
+fn forw_raw_contract_deploy_wasm() {
+    multiversx_sc_scenario::run_rs("scenarios/forw_raw_contract_deploy.scen.json", world());
+}
+
+#[test]
+fn forwarder_call_sync_retrieve_egld_wasm() {
+    multiversx_sc_scenario::run_rs(
+        "scenarios/forwarder_call_sync_retrieve_egld.scen.json",
+        world(),
+    );
+}
+
+#[test]
+fn forwarder_call_async_accept_egld_wasm() {
+    multiversx_sc_scenario::run_rs(
+        "scenarios/forwarder_call_async_accept_egld.scen.json",
+        world(),
+    );
+}
+
+#[test]
+fn forwarder_contract_deploy_wasm() {
+    multiversx_sc_scenario::run_rs("scenarios/forwarder_contract_deploy.scen.json", world());
+}
+
+#[test]
+fn promises_multi_transfer_wasm() {
+    multiversx_sc_scenario::run_rs(
+        "scenarios-promises/promises_multi_transfer.scen.json",
+        world(),
+    );
+}
@@ -0,0 +1,58 @@
+use multiversx_sc_modules::reentrancy_guard;
+
+multiversx_sc::imports!();
+
+#[multiversx_sc::module]
+pub trait NonReentrantTestModule: reentrancy_guard::ReentrancyGuardModule {
+    #[non_reentrant]
+    #[endpoint]
+    fn non_reentrant_endpoint(&self) {}
+
+    /// Reenters the contract, which is allowed, since this endpoint is not guarded.
+    #[endpoint]
+    fn call_non_reentrant_endpoint_from_self(&self) {
+        self.call_self_non_reentrant_endpoint();
+    }
+
+    /// Attempts to reenter the contract from a guarded endpoint, which fails.
+    #[non_reentrant]
+    #[endpoint]
+    fn non_reentrant_call_self(&self) {
+        self.call_self_non_reentrant_endpoint();
+    }
+
+    /// Ends in an async call, so it never returns, but must still leave the contract unlocked.
+    #[non_reentrant]
+    #[endpoint]
+    fn non_reentrant_async_call_self(&self) {
+        let sc_address = self.blockchain().get_sc_address();
+        self.send()
+            .contract_call::<()>(sc_address, "non_reentrant_endpoint".into())
+            .async_call()
+            .call_and_exit_ignore_callback()
+    }
+
+    /// The callback reenters the contract, which still counts towards this transaction.
+    #[endpoint]
+    fn async_call_self_reenter_from_callback(&self) {
+        let sc_address = self.blockchain().get_sc_address();
+        self.send()
+            .contract_call::<()>(sc_address, "non_reentrant_endpoint".into())
+            .async_call()
+            .with_callback(self.callbacks().reenter_from_callback())
+            .call_and_exit()
+    }
+
+    #[callback]
+    fn reenter_from_callback(&self) {
+        self.call_self_non_reentrant_endpoint();
+    }
+
+    fn call_self_non_reentrant_endpoint(&self) {
+        let sc_address = self.blockchain().get_sc_address();
+        let _: IgnoreValue = self
+            .send()
+            .contract_call::<()>(sc_address, "non_reentrant_endpoint".into())
+            .execute_on_dest_context();
+    }
+}
@@ -0,0 +1,15 @@
+use multiversx_sc_modules::access_control;
+
+multiversx_sc::imports!();
+
+#[multiversx_sc::module]
+pub trait OnlyRoleTestModule: access_control::AccessControlModule {
+    #[only_role("MINTER")]
+    #[endpoint]
+    fn only_minter_endpoint(&self) {}
+
+    #[only_role("MINTER")]
+    #[only_role("BURNER")]
+    #[endpoint]
+    fn only_minter_and_burner_endpoint(&self) {}
+}
@@ -7,11 +7,13 @@ mod internal_mod_b;
 mod internal_mod_c;
 mod internal_mod_d;
 mod internal_mod_init;
+mod non_reentrant_mod;
 mod ongoing_operation_mod_example;
 mod only_admin_derived_mod;
 mod only_admin_mod;
 mod only_owner_derived_mod;
 mod only_owner_mod;
+mod only_role_mod;
 pub mod token_merge_mod_impl;
 
 multiversx_sc::imports!();
@@ -23,6 +25,9 @@ multiversx_sc::imports!();
 /// - EsdtModule
 /// - GovernanceModule
 /// - PauseModule
+/// - AccessControlModule
+/// - TimelockModule
+/// - ReentrancyGuardModule
 #[multiversx_sc::contract]
 pub trait UseModule:
     ContractBase
@@ -36,6 +41,8 @@ pub trait UseModule:
     + only_owner_derived_mod::OnlyOwnerDerivedTestModule
     + only_admin_mod::OnlyAdminTestModule
     + only_admin_derived_mod::OnlyAdminDerivedTestModule
+    + only_role_mod::OnlyRoleTestModule
+    + non_reentrant_mod::NonReentrantTestModule
     + ongoing_operation_mod_example::OngoingOperationModExample
     + token_merge_mod_impl::TokenMergeModImpl
     + multiversx_sc_modules::claim_developer_rewards::ClaimDeveloperRewardsModule
@@ -46,11 +53,14 @@ pub trait UseModule:
     + multiversx_sc_modules::governance::governance_configurable::GovernanceConfigurablePropertiesModule
     + multiversx_sc_modules::governance::governance_events::GovernanceEventsModule
     + multiversx_sc_modules::pause::PauseModule
+    + multiversx_sc_modules::reentrancy_guard::ReentrancyGuardModule
     + multiversx_sc_modules::staking::StakingModule
+    + multiversx_sc_modules::timelock::TimelockModule
     + multiversx_sc_modules::token_merge::TokenMergeModule
     + multiversx_sc_modules::token_merge::merged_token_setup::MergedTokenSetupModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
     + multiversx_sc_modules::only_admin::OnlyAdminModule
+    + multiversx_sc_modules::access_control::AccessControlModule
     + multiversx_sc_modules::ongoing_operation::OngoingOperationModule
 {
     /// Validates that the "featureName" feature is on.
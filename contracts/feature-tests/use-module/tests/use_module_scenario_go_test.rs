@@ -28,6 +28,11 @@ fn use_module_only_admin_go() {
     multiversx_sc_scenario::run_go("scenarios/use_module_only_admin.scen.json");
 }
 
+#[test]
+fn use_module_only_role_go() {
+    multiversx_sc_scenario::run_go("scenarios/use_module_only_role.scen.json");
+}
+
 #[test]
 fn use_module_no_endpoint_go() {
     multiversx_sc_scenario::run_go("scenarios/use_module_no_endpoint.scen.json");
@@ -38,6 +43,11 @@ fn use_module_pause_go() {
     multiversx_sc_scenario::run_go("scenarios/use_module_pause.scen.json");
 }
 
+#[test]
+fn use_module_timelock_go() {
+    multiversx_sc_scenario::run_go("scenarios/use_module_timelock.scen.json");
+}
+
 #[test]
 fn use_module_ongoing_operation_go() {
     multiversx_sc_scenario::run_go("scenarios/use_module_ongoing_operation_example.scen.json");
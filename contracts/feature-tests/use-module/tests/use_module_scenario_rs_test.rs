@@ -76,6 +76,11 @@ fn use_module_only_admin_rs() {
     multiversx_sc_scenario::run_rs("scenarios/use_module_only_admin.scen.json", world());
 }
 
+#[test]
+fn use_module_only_role_rs() {
+    multiversx_sc_scenario::run_rs("scenarios/use_module_only_role.scen.json", world());
+}
+
 #[test]
 fn use_module_no_endpoint_rs() {
     multiversx_sc_scenario::run_rs("scenarios/use_module_no_endpoint.scen.json", world());
@@ -86,6 +91,16 @@ fn use_module_pause_rs() {
     multiversx_sc_scenario::run_rs("scenarios/use_module_pause.scen.json", world());
 }
 
+#[test]
+fn use_module_reentrancy_guard_rs() {
+    multiversx_sc_scenario::run_rs("scenarios/use_module_reentrancy_guard.scen.json", world());
+}
+
+#[test]
+fn use_module_timelock_rs() {
+    multiversx_sc_scenario::run_rs("scenarios/use_module_timelock.scen.json", world());
+}
+
 /// Will not work in scenarios-rs, since there is no gas usage
 #[test]
 #[ignore]
@@ -5,9 +5,9 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                           62
+// Endpoints:                           85
 // Async Callback:                       1
-// Total number of exported functions:  64
+// Total number of exported functions:  87
 
 #![no_std]
 #![feature(alloc_error_handler, lang_items)]
@@ -29,6 +29,13 @@ multiversx_sc_wasm_adapter::endpoints! {
         call_derived_not_owner_only
         only_admin_mod_endpoint
         call_derived_not_admin_only
+        only_minter_endpoint
+        only_minter_and_burner_endpoint
+        non_reentrant_endpoint
+        call_non_reentrant_endpoint_from_self
+        non_reentrant_call_self
+        non_reentrant_async_call_self
+        async_call_self_reenter_from_callback
         countTo100
         mergeTokens
         mergeTokensCustomAttributes
@@ -67,10 +74,20 @@ multiversx_sc_wasm_adapter::endpoints! {
         pause
         unpause
         isPaused
+        isReentrancyLocked
         stake
         unstake
         voteSlashMember
         slashMember
+        queueOperation
+        cancelOperation
+        executeOperation
+        setTimelockMinDelay
+        setTimelockGracePeriod
+        getOperation
+        getOperationStatus
+        getTimelockGracePeriod
+        getTimelockMinDelay
         issueMergedToken
         addMergeableTokensToWhitelist
         removeMergeableTokensFromWhitelist
@@ -80,6 +97,12 @@ multiversx_sc_wasm_adapter::endpoints! {
         addAdmin
         removeAdmin
         getAdmins
+        hasRole
+        getRoleAdmin
+        grantRole
+        revokeRole
+        renounceRole
+        setRoleAdmin
         callBack
     )
 }
@@ -0,0 +1,129 @@
+multiversx_sc::imports!();
+
+/// The role that administers all other roles, unless configured otherwise.
+pub const DEFAULT_ADMIN_ROLE: &[u8] = b"DEFAULT_ADMIN";
+
+/// Standard smart contract module for role-based access control.
+///
+/// Roles are identified by name. Each role has its own set of members,
+/// as well as an admin role, whose members are allowed to grant and revoke it.
+/// Unless configured otherwise, the admin role of every role is `DEFAULT_ADMIN`.
+/// The contract owner can always grant, revoke and reconfigure any role.
+///
+/// It offers:
+/// * endpoints to grant, revoke and renounce roles, which also emit events
+/// * an endpoint to change the admin role of a role
+/// * views to check role membership and the admin role of a role
+/// * the `require_caller_has_role` check, which is also what the `#[only_role("ROLE")]` endpoint annotation calls
+///
+#[multiversx_sc::module]
+pub trait AccessControlModule {
+    #[view(hasRole)]
+    fn has_role(&self, role: ManagedBuffer, address: ManagedAddress) -> bool {
+        self.role_members(&role).contains(&address)
+    }
+
+    #[view(getRoleAdmin)]
+    fn get_role_admin(&self, role: ManagedBuffer) -> ManagedBuffer {
+        let admin_role_mapper = self.role_admin(&role);
+        if admin_role_mapper.is_empty() {
+            ManagedBuffer::from(DEFAULT_ADMIN_ROLE)
+        } else {
+            admin_role_mapper.get()
+        }
+    }
+
+    #[endpoint(grantRole)]
+    fn grant_role(&self, role: ManagedBuffer, address: ManagedAddress) {
+        self.require_caller_is_role_admin(&role);
+        if !self.has_role(role.clone(), address.clone()) {
+            self.role_members(&role).add(&address);
+            let caller = self.blockchain().get_caller();
+            self.role_granted_event(&role, &address, &caller);
+        }
+    }
+
+    #[endpoint(revokeRole)]
+    fn revoke_role(&self, role: ManagedBuffer, address: ManagedAddress) {
+        self.require_caller_is_role_admin(&role);
+        self.remove_role_member(&role, &address);
+    }
+
+    /// Allows an account to give up one of its own roles.
+    #[endpoint(renounceRole)]
+    fn renounce_role(&self, role: ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.has_role(role.clone(), caller.clone()),
+            "Caller does not have the role"
+        );
+        self.remove_role_member(&role, &caller);
+    }
+
+    #[endpoint(setRoleAdmin)]
+    fn set_role_admin(&self, role: ManagedBuffer, admin_role: ManagedBuffer) {
+        self.require_caller_is_role_admin(&role);
+        let previous_admin_role = self.get_role_admin(role.clone());
+        self.role_admin(&role).set(&admin_role);
+        self.role_admin_changed_event(&role, &previous_admin_role, &admin_role);
+    }
+
+    fn remove_role_member(&self, role: &ManagedBuffer, address: &ManagedAddress) {
+        if self.has_role(role.clone(), address.clone()) {
+            self.role_members(role).remove(address);
+            let caller = self.blockchain().get_caller();
+            self.role_revoked_event(role, address, &caller);
+        }
+    }
+
+    fn require_caller_is_role_admin(&self, role: &ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        if caller == self.blockchain().get_owner_address() {
+            return;
+        }
+
+        let admin_role = self.get_role_admin(role.clone());
+        require!(
+            self.has_role(admin_role, caller),
+            "Caller is not an admin of the role"
+        );
+    }
+
+    fn require_caller_has_role(&self, role: &[u8]) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.has_role(ManagedBuffer::from(role), caller),
+            "Endpoint can only be called by accounts with the required role"
+        );
+    }
+
+    #[storage_mapper("access_control:role_members")]
+    fn role_members(&self, role: &ManagedBuffer) -> WhitelistMapper<ManagedAddress>;
+
+    #[storage_mapper("access_control:role_admin")]
+    fn role_admin(&self, role: &ManagedBuffer) -> SingleValueMapper<ManagedBuffer>;
+
+    #[event("roleGranted")]
+    fn role_granted_event(
+        &self,
+        #[indexed] role: &ManagedBuffer,
+        #[indexed] account: &ManagedAddress,
+        #[indexed] sender: &ManagedAddress,
+    );
+
+    #[event("roleRevoked")]
+    fn role_revoked_event(
+        &self,
+        #[indexed] role: &ManagedBuffer,
+        #[indexed] account: &ManagedAddress,
+        #[indexed] sender: &ManagedAddress,
+    );
+
+    #[event("roleAdminChanged")]
+    fn role_admin_changed_event(
+        &self,
+        #[indexed] role: &ManagedBuffer,
+        #[indexed] previous_admin_role: &ManagedBuffer,
+        #[indexed] new_admin_role: &ManagedBuffer,
+    );
+}
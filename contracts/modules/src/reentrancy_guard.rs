@@ -0,0 +1,39 @@
+multiversx_sc::imports!();
+
+/// Standard smart contract module that prevents endpoints from being reentered.
+///
+/// The lock is a storage flag, set when a guarded endpoint is entered and cleared when it returns.
+/// Any guarded endpoint called while the lock is set fails, e.g. when a contract called synchronously
+/// tries to call back into the contract that called it.
+///
+/// The flag only exists in storage while a guarded call is in progress:
+/// failed transactions revert it together with everything else, and upgrades find it empty.
+/// Guarded endpoints that end in an async call never return, so the framework releases the lock
+/// right before sending the call. Only exiting through the raw `async_call_raw` API skips this,
+/// and leaves the contract locked.
+///
+/// It offers:
+/// * the `reentrancy_guard_enter` and `reentrancy_guard_exit` methods,
+/// which is also what the `#[non_reentrant]` endpoint annotation calls
+/// * a view to check whether the lock is currently set
+///
+#[multiversx_sc::module]
+pub trait ReentrancyGuardModule {
+    #[view(isReentrancyLocked)]
+    fn is_reentrancy_locked(&self) -> bool {
+        !self.reentrancy_lock().is_empty()
+    }
+
+    fn reentrancy_guard_enter(&self) {
+        require!(!self.is_reentrancy_locked(), "Reentrant call");
+        self.reentrancy_lock().set(true);
+    }
+
+    fn reentrancy_guard_exit(&self) {
+        self.reentrancy_lock().clear();
+    }
+
+    /// Same key as `multiversx_sc::types::REENTRANCY_LOCK_STORAGE_KEY`, which the framework clears before async calls.
+    #[storage_mapper("reentrancy_guard:locked")]
+    fn reentrancy_lock(&self) -> SingleValueMapper<bool>;
+}
@@ -0,0 +1,236 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+/// Grace period applied when none was configured: 14 days.
+pub const DEFAULT_TIMELOCK_GRACE_PERIOD: u64 = 14 * 24 * 60 * 60;
+
+pub type TimelockOperationId = usize;
+
+#[derive(TypeAbi, TopEncode, TopDecode, PartialEq, Eq)]
+pub enum TimelockOperationStatus {
+    None,
+    Pending,
+    Ready,
+    Expired,
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct TimelockOperation<M: ManagedTypeApi> {
+    pub dest_address: ManagedAddress<M>,
+    pub endpoint_name: ManagedBuffer<M>,
+    pub arguments: ManagedVec<M, ManagedBuffer<M>>,
+    pub gas_limit: u64,
+    pub eta: u64,
+}
+
+/// Standard smart contract module that delays the execution of admin operations.
+///
+/// Operations are contract calls, either to the contract itself or to other contracts.
+/// They are queued together with an ETA, which is a block timestamp at least `minDelay` seconds in the future.
+/// Once the ETA is reached, the operation can be executed, but only until the grace period ends,
+/// after which it expires and can only be cancelled.
+///
+/// It offers:
+/// * owner-only endpoints to queue, cancel and execute operations, which also emit events
+/// * endpoints to change the minimum delay and the grace period, which can only be called via the timelock itself
+/// * views for the operations, their status and the timelock configuration
+///
+/// Contracts can also queue calls built with proxies directly, via `queue_contract_call`.
+///
+#[multiversx_sc::module]
+pub trait TimelockModule {
+    /// The delays can later only be changed via queued operations,
+    /// so the main SC should not write to the timelock storage directly.
+    fn init_timelock_module(&self, min_delay: u64, grace_period: u64) {
+        self.timelock_min_delay().set(min_delay);
+        self.timelock_grace_period().set(grace_period);
+    }
+
+    // endpoints - owner-only
+
+    #[only_owner]
+    #[endpoint(queueOperation)]
+    fn queue_operation(
+        &self,
+        dest_address: ManagedAddress,
+        endpoint_name: ManagedBuffer,
+        gas_limit: u64,
+        eta: u64,
+        arguments: MultiValueEncoded<ManagedBuffer>,
+    ) -> TimelockOperationId {
+        let mut contract_call =
+            ContractCallNoPayment::<Self::Api, ()>::new(dest_address, endpoint_name)
+                .with_gas_limit(gas_limit);
+        contract_call.arg_buffer = arguments.to_arg_buffer();
+
+        self.queue_contract_call(contract_call, eta)
+    }
+
+    #[only_owner]
+    #[endpoint(cancelOperation)]
+    fn cancel_operation(&self, operation_id: TimelockOperationId) {
+        require!(
+            self.get_operation_status(operation_id) != TimelockOperationStatus::None,
+            "Operation does not exist"
+        );
+
+        self.timelock_operation(operation_id).clear();
+        self.operation_cancelled_event(operation_id);
+    }
+
+    #[only_owner]
+    #[endpoint(executeOperation)]
+    fn execute_operation(&self, operation_id: TimelockOperationId) {
+        match self.get_operation_status(operation_id) {
+            TimelockOperationStatus::None => sc_panic!("Operation does not exist"),
+            TimelockOperationStatus::Pending => sc_panic!("Operation is not ready yet"),
+            TimelockOperationStatus::Expired => sc_panic!("Operation has expired"),
+            TimelockOperationStatus::Ready => {},
+        }
+
+        let operation = self.timelock_operation(operation_id).take();
+        let mut contract_call = ContractCallNoPayment::<Self::Api, ()>::new(
+            operation.dest_address,
+            operation.endpoint_name,
+        )
+        .with_gas_limit(operation.gas_limit);
+        contract_call.arg_buffer = operation.arguments.into();
+
+        let _: IgnoreValue = contract_call.execute_on_dest_context();
+
+        self.operation_executed_event(operation_id);
+    }
+
+    // endpoints - these can only be called by the SC itself,
+    // i.e. by queueing and executing an operation with the SC as destination
+
+    #[endpoint(setTimelockMinDelay)]
+    fn set_timelock_min_delay(&self, min_delay: u64) {
+        self.require_timelock_self_call();
+        self.timelock_min_delay().set(min_delay);
+    }
+
+    #[endpoint(setTimelockGracePeriod)]
+    fn set_timelock_grace_period(&self, grace_period: u64) {
+        self.require_timelock_self_call();
+        self.timelock_grace_period().set(grace_period);
+    }
+
+    // views
+
+    #[view(getOperation)]
+    fn get_operation(&self, operation_id: TimelockOperationId) -> TimelockOperation<Self::Api> {
+        let operation_mapper = self.timelock_operation(operation_id);
+        require!(!operation_mapper.is_empty(), "Operation does not exist");
+
+        operation_mapper.get()
+    }
+
+    #[view(getOperationStatus)]
+    fn get_operation_status(&self, operation_id: TimelockOperationId) -> TimelockOperationStatus {
+        let operation_mapper = self.timelock_operation(operation_id);
+        if operation_mapper.is_empty() {
+            return TimelockOperationStatus::None;
+        }
+
+        let eta = operation_mapper.get().eta;
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        if current_timestamp < eta {
+            TimelockOperationStatus::Pending
+        } else if current_timestamp > eta.saturating_add(self.get_timelock_grace_period()) {
+            TimelockOperationStatus::Expired
+        } else {
+            TimelockOperationStatus::Ready
+        }
+    }
+
+    #[view(getTimelockGracePeriod)]
+    fn get_timelock_grace_period(&self) -> u64 {
+        let grace_period_mapper = self.timelock_grace_period();
+        if grace_period_mapper.is_empty() {
+            DEFAULT_TIMELOCK_GRACE_PERIOD
+        } else {
+            grace_period_mapper.get()
+        }
+    }
+
+    // private
+
+    /// Queues a call, usually built via a proxy, to be executed at or after `eta`.
+    fn queue_contract_call<R>(
+        &self,
+        contract_call: ContractCallNoPayment<Self::Api, R>,
+        eta: u64,
+    ) -> TimelockOperationId {
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        let min_delay = self.timelock_min_delay().get();
+        let earliest_eta = current_timestamp
+            .checked_add(min_delay)
+            .unwrap_or_else(|| sc_panic!("Minimum delay too large"));
+        require!(eta >= earliest_eta, "ETA must satisfy the minimum delay");
+
+        let operation = TimelockOperation {
+            dest_address: contract_call.to,
+            endpoint_name: contract_call.endpoint_name,
+            arguments: contract_call
+                .arg_buffer
+                .into_multi_value_encoded()
+                .into_vec_of_buffers(),
+            gas_limit: contract_call.explicit_gas_limit,
+            eta,
+        };
+
+        let operation_id = self.timelock_last_operation_id().update(|last_id| {
+            *last_id += 1;
+            *last_id
+        });
+        self.operation_queued_event(operation_id, eta, &operation);
+        self.timelock_operation(operation_id).set(&operation);
+
+        operation_id
+    }
+
+    fn require_timelock_self_call(&self) {
+        let caller = self.blockchain().get_caller();
+        let sc_address = self.blockchain().get_sc_address();
+
+        require!(
+            caller == sc_address,
+            "Only the SC itself may call this function"
+        );
+    }
+
+    // storage
+
+    #[view(getTimelockMinDelay)]
+    #[storage_mapper("timelock:min_delay")]
+    fn timelock_min_delay(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("timelock:grace_period")]
+    fn timelock_grace_period(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("timelock:last_operation_id")]
+    fn timelock_last_operation_id(&self) -> SingleValueMapper<TimelockOperationId>;
+
+    #[storage_mapper("timelock:operation")]
+    fn timelock_operation(
+        &self,
+        operation_id: TimelockOperationId,
+    ) -> SingleValueMapper<TimelockOperation<Self::Api>>;
+
+    // events
+
+    #[event("operationQueued")]
+    fn operation_queued_event(
+        &self,
+        #[indexed] operation_id: TimelockOperationId,
+        #[indexed] eta: u64,
+        operation: &TimelockOperation<Self::Api>,
+    );
+
+    #[event("operationCancelled")]
+    fn operation_cancelled_event(&self, #[indexed] operation_id: TimelockOperationId);
+
+    #[event("operationExecuted")]
+    fn operation_executed_event(&self, #[indexed] operation_id: TimelockOperationId);
+}
@@ -33,6 +33,7 @@ pub struct EndpointAbi {
     pub rust_method_name: &'static str,
     pub only_owner: bool,
     pub only_admin: bool,
+    pub only_roles: &'static [&'static str],
     pub labels: &'static [&'static str],
     pub mutability: EndpointMutabilityAbi,
     pub payable_in_tokens: &'static [&'static str],
@@ -12,6 +12,10 @@ pub struct EventInputAbi {
 pub struct EventAbi {
     pub docs: &'static [&'static str],
     pub identifier: &'static str,
+    /// Indexed inputs are saved as topics, after the identifier.
+    ///
+    /// The non-indexed inputs make up the data. If there are several of them,
+    /// they are encoded one after the other, like the fields of a struct.
     pub inputs: Vec<EventInputAbi>,
 }
 
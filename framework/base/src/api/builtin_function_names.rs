@@ -13,3 +13,44 @@ pub const CLAIM_DEVELOPER_REWARDS_FUNC_NAME: &str = "ClaimDeveloperRewards";
 pub const SET_USERNAME_FUNC_NAME: &str = "SetUserName";
 pub const MIGRATE_USERNAME_FUNC_NAME: &str = "migrateUserName";
 pub const UPGRADE_CONTRACT_FUNC_NAME: &str = "upgradeContract";
+pub const ESDT_NFT_CREATE_ROLE_TRANSFER_FUNC_NAME: &str = "ESDTNFTCreateRoleTransfer";
+pub const ESDT_FREEZE_FUNC_NAME: &str = "ESDTFreeze";
+pub const ESDT_UNFREEZE_FUNC_NAME: &str = "ESDTUnFreeze";
+pub const ESDT_WIPE_FUNC_NAME: &str = "ESDTWipe";
+pub const ESDT_SET_LIMITED_TRANSFER_FUNC_NAME: &str = "ESDTSetLimitedTransfer";
+pub const ESDT_UNSET_LIMITED_TRANSFER_FUNC_NAME: &str = "ESDTUnSetLimitedTransfer";
+pub const ESDT_SET_BURN_ROLE_FOR_ALL_FUNC_NAME: &str = "ESDTSetBurnRoleForAll";
+pub const ESDT_UNSET_BURN_ROLE_FOR_ALL_FUNC_NAME: &str = "ESDTUnSetBurnRoleForAll";
+pub const ESDT_MODIFY_ROYALTIES_FUNC_NAME: &str = "ESDTModifyRoyalties";
+pub const ESDT_SET_NEW_URIS_FUNC_NAME: &str = "ESDTSetNewURIs";
+pub const SAVE_KEY_VALUE_FUNC_NAME: &str = "SaveKeyValue";
+
+/// All builtin function names, contract endpoints must not shadow them.
+pub const BUILTIN_FUNCTION_NAMES: &[&str] = &[
+    ESDT_LOCAL_MINT_FUNC_NAME,
+    ESDT_LOCAL_BURN_FUNC_NAME,
+    ESDT_MULTI_TRANSFER_FUNC_NAME,
+    ESDT_NFT_TRANSFER_FUNC_NAME,
+    ESDT_NFT_CREATE_FUNC_NAME,
+    ESDT_NFT_ADD_QUANTITY_FUNC_NAME,
+    ESDT_NFT_ADD_URI_FUNC_NAME,
+    ESDT_NFT_UPDATE_ATTRIBUTES_FUNC_NAME,
+    ESDT_NFT_BURN_FUNC_NAME,
+    ESDT_TRANSFER_FUNC_NAME,
+    CHANGE_OWNER_BUILTIN_FUNC_NAME,
+    CLAIM_DEVELOPER_REWARDS_FUNC_NAME,
+    SET_USERNAME_FUNC_NAME,
+    MIGRATE_USERNAME_FUNC_NAME,
+    UPGRADE_CONTRACT_FUNC_NAME,
+    ESDT_NFT_CREATE_ROLE_TRANSFER_FUNC_NAME,
+    ESDT_FREEZE_FUNC_NAME,
+    ESDT_UNFREEZE_FUNC_NAME,
+    ESDT_WIPE_FUNC_NAME,
+    ESDT_SET_LIMITED_TRANSFER_FUNC_NAME,
+    ESDT_UNSET_LIMITED_TRANSFER_FUNC_NAME,
+    ESDT_SET_BURN_ROLE_FOR_ALL_FUNC_NAME,
+    ESDT_UNSET_BURN_ROLE_FOR_ALL_FUNC_NAME,
+    ESDT_MODIFY_ROYALTIES_FUNC_NAME,
+    ESDT_SET_NEW_URIS_FUNC_NAME,
+    SAVE_KEY_VALUE_FUNC_NAME,
+];
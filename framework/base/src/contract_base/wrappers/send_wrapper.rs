@@ -4,7 +4,7 @@ use crate::codec::Empty;
 
 use crate::{
     api::{
-        BlockchainApi, BlockchainApiImpl, CallTypeApi, StorageReadApi,
+        BlockchainApi, BlockchainApiImpl, CallTypeApi, StorageReadApi, StorageWriteApi,
         CHANGE_OWNER_BUILTIN_FUNC_NAME, CLAIM_DEVELOPER_REWARDS_FUNC_NAME,
         ESDT_LOCAL_BURN_FUNC_NAME, ESDT_LOCAL_MINT_FUNC_NAME, ESDT_NFT_ADD_QUANTITY_FUNC_NAME,
         ESDT_NFT_ADD_URI_FUNC_NAME, ESDT_NFT_BURN_FUNC_NAME, ESDT_NFT_CREATE_FUNC_NAME,
@@ -30,14 +30,14 @@ use super::SendRawWrapper;
 #[derive(Default)]
 pub struct SendWrapper<A>
 where
-    A: CallTypeApi + StorageReadApi + BlockchainApi,
+    A: CallTypeApi + StorageReadApi + StorageWriteApi + BlockchainApi,
 {
     _phantom: PhantomData<A>,
 }
 
 impl<A> SendWrapper<A>
 where
-    A: CallTypeApi + StorageReadApi + BlockchainApi,
+    A: CallTypeApi + StorageReadApi + StorageWriteApi + BlockchainApi,
 {
     pub(crate) fn new() -> Self {
         SendWrapper {
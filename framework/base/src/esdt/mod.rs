@@ -2,4 +2,4 @@ mod properties;
 mod system_sc_proxy;
 
 pub use properties::*;
-pub use system_sc_proxy::ESDTSystemSmartContractProxy;
+pub use system_sc_proxy::{ESDTSystemSmartContractProxy, ESDT_SYSTEM_SC_ADDRESS_ARRAY};
@@ -37,6 +37,7 @@ pub fn external_view_contract_constructor_abi() -> EndpointAbi {
         rust_method_name: "",
         only_owner: false,
         only_admin: false,
+        only_roles: &[],
         labels: &[],
         mutability: EndpointMutabilityAbi::Mutable,
         payable_in_tokens: &[],
@@ -1,4 +1,4 @@
-use crate::codec::{TopEncode, TopEncodeMulti};
+use crate::codec::{NestedEncode, TopEncode, TopEncodeMulti};
 
 use crate::{
     api::{ErrorApi, LogApi, LogApiImpl, ManagedTypeApi},
@@ -40,6 +40,20 @@ where
     data_buffer
 }
 
+/// Appends one of the non-indexed fields of an event with several of them.
+///
+/// The fields are nested-encoded one after the other, the same way a struct containing them would be encoded.
+pub fn serialize_log_data_field<T, A>(data_buffer: &mut ManagedBuffer<A>, field: T)
+where
+    T: NestedEncode,
+    A: ErrorApi + ManagedTypeApi,
+{
+    let Ok(()) = field.dep_encode_or_handle_err(
+        data_buffer,
+        ExitCodecErrorHandler::<A>::from(err_msg::LOG_DATA_ENCODE_ERROR),
+    );
+}
+
 pub fn write_log<A>(topics: &ManagedVec<A, ManagedBuffer<A>>, data: &ManagedBuffer<A>)
 where
     A: LogApi + ManagedTypeApi,
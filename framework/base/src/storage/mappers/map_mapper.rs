@@ -1,9 +1,9 @@
 use core::marker::PhantomData;
 
-use super::{set_mapper, SetMapper, StorageClearable, StorageMapper};
+use super::{set_mapper, SetMapper, StorageClearable, StorageMapper, StoragePaginated};
 use crate::{
     abi::{TypeAbi, TypeDescriptionContainer, TypeName},
-    api::StorageMapperApi,
+    api::{ErrorApiImpl, StorageMapperApi},
     codec::{
         multi_encode_iter_or_handle_err, multi_types::MultiValue2, CodecFrom, EncodeErrorHandler,
         NestedDecode, NestedEncode, TopDecode, TopEncode, TopEncodeMulti, TopEncodeMultiOutput,
@@ -13,6 +13,7 @@ use crate::{
 };
 
 const MAPPED_VALUE_IDENTIFIER: &[u8] = b".mapped";
+static CURSOR_KEY_NOT_FOUND_ERR_MSG: &[u8] = b"cursor key not found";
 type Keys<'a, SA, T> = set_mapper::Iter<'a, SA, T>;
 
 pub struct MapMapper<SA, K, V>
@@ -160,6 +161,23 @@ where
     pub fn iter(&self) -> Iter<SA, K, V> {
         Iter::new(self)
     }
+
+    /// An iterator visiting the keys in the same order as `keys`,
+    /// but starting from the given key, inclusively.
+    /// If the key is not in the map, the iterator is empty.
+    pub fn keys_from(&self, key: &K) -> Keys<SA, K> {
+        self.keys_set.iter_from(key)
+    }
+
+    /// An iterator visiting the key-value pairs in the same order as `iter`,
+    /// but starting from the given key, inclusively.
+    /// If the key is not in the map, the iterator is empty.
+    pub fn iter_from(&self, key: &K) -> Iter<SA, K, V> {
+        Iter {
+            key_iter: self.keys_from(key),
+            hash_map: self,
+        }
+    }
 }
 
 pub struct Iter<'a, SA, K, V>
@@ -454,6 +472,40 @@ where
     }
 }
 
+/// Pages are indicated by the last key of the previous page, which works as a cursor token.
+/// A missing cursor means the first page.
+///
+/// Signals an error if the cursor key is no longer in the map.
+impl<SA, K, V> StoragePaginated<SA> for MapMapper<SA, K, V>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+    type Cursor = Option<K>;
+    type PageItem = MultiValue2<K, V>;
+
+    fn page(&self, cursor: Option<K>, size: usize) -> MultiValueEncoded<SA, MultiValue2<K, V>> {
+        let iter = match cursor {
+            Some(last_key) => {
+                if !self.contains_key(&last_key) {
+                    SA::error_api_impl().signal_error(CURSOR_KEY_NOT_FOUND_ERR_MSG);
+                }
+                let mut iter = self.iter_from(&last_key);
+                let _ = iter.next();
+                iter
+            },
+            None => self.iter(),
+        };
+
+        let mut result = MultiValueEncoded::new();
+        for (key, value) in iter.take(size) {
+            result.push(MultiValue2::from((key, value)));
+        }
+        result
+    }
+}
+
 impl<SA, K, V> CodecFrom<MapMapper<SA, K, V>> for MultiValueEncoded<SA, MultiValue2<K, V>>
 where
     SA: StorageMapperApi,
@@ -1,4 +1,9 @@
-use crate::{api::StorageMapperApi, storage::StorageKey};
+use crate::{
+    api::StorageMapperApi,
+    codec::{TopDecodeMulti, TopEncodeMulti},
+    storage::StorageKey,
+    types::MultiValueEncoded,
+};
 
 pub trait StorageMapper<SA>: 'static
 where
@@ -12,3 +17,21 @@ pub trait StorageClearable {
     /// Clears all the entries owned by the storage.
     fn clear(&mut self);
 }
+
+/// Storage mappers whose contents can be retrieved a page at a time.
+///
+/// Used by the `#[paginated_view]` annotation generated code,
+/// but can also be called directly from contract code.
+pub trait StoragePaginated<SA>: StorageMapper<SA>
+where
+    SA: StorageMapperApi,
+{
+    /// Indicates where a page starts. Its meaning depends on the mapper.
+    type Cursor: TopDecodeMulti;
+
+    /// The type of the items returned in a page.
+    type PageItem: TopEncodeMulti;
+
+    /// Retrieves at most `size` items, starting from the position indicated by the cursor.
+    fn page(&self, cursor: Self::Cursor, size: usize) -> MultiValueEncoded<SA, Self::PageItem>;
+}
@@ -21,7 +21,7 @@ pub use fungible_token_mapper::FungibleTokenMapper;
 pub use linked_list_mapper::{LinkedListMapper, LinkedListNode};
 pub use map_mapper::MapMapper;
 pub use map_storage_mapper::MapStorageMapper;
-pub use mapper::{StorageClearable, StorageMapper};
+pub use mapper::{StorageClearable, StorageMapper, StoragePaginated};
 pub use non_fungible_token_mapper::NonFungibleTokenMapper;
 pub use queue_mapper::QueueMapper;
 pub use set_mapper::SetMapper;
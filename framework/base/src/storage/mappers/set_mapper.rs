@@ -144,6 +144,14 @@ where
         self.queue_mapper.iter()
     }
 
+    /// An iterator visiting the elements in the same order as `iter`,
+    /// but starting from the given value, inclusively.
+    /// If the value is not in the set, the iterator is empty.
+    pub fn iter_from(&self, value: &T) -> Iter<SA, T> {
+        let node_id = self.get_node_id(value);
+        self.queue_mapper.iter_from_node_id(node_id)
+    }
+
     /// Checks the internal consistency of the collection. Used for unit tests.
     pub fn check_internal_consistency(&self) -> bool {
         self.queue_mapper.check_internal_consistency()
@@ -1,7 +1,7 @@
 use core::marker::PhantomData;
 
 pub use super::vec_mapper::Iter;
-use super::{StorageClearable, StorageMapper, VecMapper};
+use super::{StorageClearable, StorageMapper, StoragePaginated, VecMapper};
 use crate::{
     abi::{TypeAbi, TypeDescriptionContainer, TypeName},
     api::StorageMapperApi,
@@ -159,6 +159,15 @@ where
     pub fn iter(&self) -> Iter<SA, T> {
         self.vec_mapper.iter()
     }
+
+    /// An iterator visiting the elements starting from the given index.
+    /// Indexes start from 1, just like in `get_by_index`.
+    ///
+    /// Note that `swap_remove` moves the last element into the removed position,
+    /// so removals between calls can shift elements across pages.
+    pub fn iter_from(&self, index: usize) -> Iter<SA, T> {
+        self.vec_mapper.iter_from(index)
+    }
 }
 
 impl<SA, T> Extend<T> for UnorderedSetMapper<SA, T>
@@ -191,6 +200,20 @@ where
     }
 }
 
+/// Pages are indicated by the index of their first item, starting from 1.
+impl<SA, T> StoragePaginated<SA> for UnorderedSetMapper<SA, T>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + NestedEncode + NestedDecode + 'static,
+{
+    type Cursor = usize;
+    type PageItem = T;
+
+    fn page(&self, cursor: usize, size: usize) -> MultiValueEncoded<SA, T> {
+        self.vec_mapper.page(cursor, size)
+    }
+}
+
 impl<SA, T> CodecFrom<UnorderedSetMapper<SA, T>> for MultiValueEncoded<SA, T>
 where
     SA: StorageMapperApi,
@@ -1,4 +1,4 @@
-use super::{StorageClearable, StorageMapper};
+use super::{StorageClearable, StorageMapper, StoragePaginated};
 use crate::{
     abi::{TypeAbi, TypeDescriptionContainer, TypeName},
     api::{ErrorApiImpl, StorageMapperApi},
@@ -278,6 +278,15 @@ where
     pub fn iter(&self) -> Iter<SA, T> {
         Iter::new(self)
     }
+
+    /// Provides a forward iterator, starting from the given index.
+    /// Indexes start from 1, just like in `get`.
+    ///
+    /// Combine with `take` to only load a limited number of items, e.g. `iter_from(index).take(n)`.
+    /// An index past the end of the list simply produces an empty iterator.
+    pub fn iter_from(&self, index: usize) -> Iter<SA, T> {
+        Iter::new_from(self, index)
+    }
 }
 
 /// An iterator over the elements of a `VecMapper`.
@@ -300,8 +309,12 @@ where
     T: TopEncode + TopDecode + 'static,
 {
     fn new(vec: &'a VecMapper<SA, T>) -> Iter<'a, SA, T> {
+        Self::new_from(vec, 1)
+    }
+
+    fn new_from(vec: &'a VecMapper<SA, T>, index: usize) -> Iter<'a, SA, T> {
         Iter {
-            index: 1,
+            index: core::cmp::max(index, 1),
             len: vec.len(),
             vec,
         }
@@ -341,6 +354,25 @@ where
     }
 }
 
+/// Pages are indicated by the index of their first item, starting from 1.
+/// The cursor for the next page is the current cursor plus the page size.
+impl<SA, T> StoragePaginated<SA> for VecMapper<SA, T>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode,
+{
+    type Cursor = usize;
+    type PageItem = T;
+
+    fn page(&self, cursor: usize, size: usize) -> MultiValueEncoded<SA, T> {
+        let mut result = MultiValueEncoded::new();
+        for item in self.iter_from(cursor).take(size) {
+            result.push(item);
+        }
+        result
+    }
+}
+
 impl<SA, T> CodecFrom<VecMapper<SA, T>> for MultiValueEncoded<SA, T>
 where
     SA: StorageMapperApi,
@@ -1,2 +1,11 @@
+/// Contracts cannot write to any key starting with this prefix, they are reserved for the protocol.
+pub static ELROND_RESERVED_PREFIX: &[u8] = b"ELROND";
+
 pub static ELROND_REWARD_KEY: &[u8] = b"ELRONDreward";
 pub static ELROND_ESDT_LOCAL_ROLES_KEY: &[u8] = b"ELRONDroleesdt";
+
+/// Followed by the token identifier, and by the nonce for NFTs.
+pub static ELROND_ESDT_KEY: &[u8] = b"ELRONDesdt";
+
+/// Followed by the token identifier, holds the nonce of the last NFT created by the account.
+pub static ELROND_ESDT_NFT_LAST_NONCE_KEY: &[u8] = b"ELRONDnonce";
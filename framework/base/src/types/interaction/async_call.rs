@@ -1,9 +1,14 @@
 use crate::{
-    api::{CallTypeApi, StorageWriteApi},
+    api::{CallTypeApi, StorageReadApi, StorageWriteApi},
     contract_base::SendRawWrapper,
+    storage::StorageKey,
+    storage_clear, storage_get_len,
     types::{BigUint, CallbackClosure, ManagedAddress, ManagedArgBuffer, ManagedBuffer},
 };
 
+/// Storage key of the lock held by the reentrancy guard module while a `#[non_reentrant]` endpoint runs.
+pub const REENTRANCY_LOCK_STORAGE_KEY: &[u8] = b"reentrancy_guard:locked";
+
 #[must_use]
 pub struct AsyncCall<SA>
 where
@@ -31,9 +36,13 @@ where
 
 impl<SA> AsyncCall<SA>
 where
-    SA: CallTypeApi,
+    SA: CallTypeApi + StorageReadApi + StorageWriteApi,
 {
     pub fn call_and_exit_ignore_callback(&self) -> ! {
+        // the endpoint that holds the reentrancy lock never gets to release it,
+        // since the async call kills the execution
+        release_reentrancy_lock::<SA>();
+
         SendRawWrapper::<SA>::new().async_call_raw(
             &self.to,
             &self.egld_payment,
@@ -45,7 +54,7 @@ where
 
 impl<SA> AsyncCall<SA>
 where
-    SA: CallTypeApi + StorageWriteApi,
+    SA: CallTypeApi + StorageReadApi + StorageWriteApi,
 {
     pub fn call_and_exit(&self) -> ! {
         // first, save the callback closure
@@ -57,3 +66,13 @@ where
         self.call_and_exit_ignore_callback()
     }
 }
+
+fn release_reentrancy_lock<SA>()
+where
+    SA: CallTypeApi + StorageReadApi + StorageWriteApi,
+{
+    let storage_key = StorageKey::<SA>::new(REENTRANCY_LOCK_STORAGE_KEY);
+    if storage_get_len(storage_key.as_ref()) > 0 {
+        storage_clear(storage_key.as_ref());
+    }
+}
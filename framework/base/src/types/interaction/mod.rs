@@ -13,7 +13,7 @@ mod contract_call_with_multi_esdt;
 mod contract_deploy;
 
 pub use arg_buffer_managed::ManagedArgBuffer;
-pub use async_call::AsyncCall;
+pub use async_call::{AsyncCall, REENTRANCY_LOCK_STORAGE_KEY};
 pub use async_call_promises::AsyncCallPromises;
 pub use callback_closure::{
     new_callback_call, CallbackClosure, CallbackClosureForDeser, CallbackClosureMatcher,
@@ -8,6 +8,7 @@ fn generate_endpoint_snippet(
     endpoint_name: &str,
     only_owner: bool,
     only_admin: bool,
+    only_roles: &[String],
     mutability: EndpointMutabilityMetadata,
 ) -> proc_macro2::TokenStream {
     let endpoint_docs = &m.docs;
@@ -56,6 +57,7 @@ fn generate_endpoint_snippet(
             rust_method_name: #rust_method_name,
             only_owner: #only_owner,
             only_admin: #only_admin,
+            only_roles: &[ #(#only_roles),* ],
             mutability: #mutability_tokens,
             payable_in_tokens: &[ #(#payable_in_tokens),* ],
             inputs: multiversx_sc::types::heap::Vec::new(),
@@ -78,6 +80,7 @@ fn generate_endpoint_snippets(contract: &ContractTrait) -> Vec<proc_macro2::Toke
                     "init",
                     false,
                     false,
+                    &[],
                     EndpointMutabilityMetadata::Mutable,
                 );
                 Some(quote! {
@@ -91,6 +94,7 @@ fn generate_endpoint_snippets(contract: &ContractTrait) -> Vec<proc_macro2::Toke
                     &endpoint_metadata.public_name.to_string(),
                     endpoint_metadata.only_owner,
                     endpoint_metadata.only_admin,
+                    &endpoint_metadata.only_roles,
                     endpoint_metadata.mutability.clone(),
                 );
                 Some(quote! {
@@ -104,6 +108,7 @@ fn generate_endpoint_snippets(contract: &ContractTrait) -> Vec<proc_macro2::Toke
                     &callback_metadata.callback_name.to_string(),
                     false,
                     false,
+                    &[],
                     EndpointMutabilityMetadata::Mutable,
                 );
                 Some(quote! {
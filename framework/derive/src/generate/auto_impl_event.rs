@@ -2,17 +2,10 @@ use super::{method_gen, util::*};
 use crate::model::{Method, MethodArgument};
 
 pub fn generate_event_impl(m: &Method, event_identifier: &str) -> proc_macro2::TokenStream {
-    let mut data_arg: Option<&MethodArgument> = None;
-    let mut topic_args = Vec::<&MethodArgument>::new();
-    for arg in &m.method_args {
-        if arg.metadata.event_topic {
-            topic_args.push(arg);
-        } else if data_arg.is_none() {
-            data_arg = Some(arg);
-        } else {
-            panic!("only 1 data argument allowed in event log");
-        }
-    }
+    let (topic_args, data_args): (Vec<&MethodArgument>, Vec<&MethodArgument>) = m
+        .method_args
+        .iter()
+        .partition(|arg| arg.metadata.event_topic);
 
     let topic_push_snippets: Vec<proc_macro2::TokenStream> = topic_args
         .iter()
@@ -23,15 +16,29 @@ pub fn generate_event_impl(m: &Method, event_identifier: &str) -> proc_macro2::T
             }
         })
         .collect();
-    let data_buffer_snippet = if let Some(data_arg) = data_arg {
-        let data_pat = &data_arg.pat;
-        quote! {
-            let ___data_buffer___ = multiversx_sc::log_util::serialize_log_data(#data_pat);
-        }
-    } else {
-        quote! {
+    let data_buffer_snippet = match data_args.as_slice() {
+        [] => quote! {
             let ___data_buffer___ = multiversx_sc::types::ManagedBuffer::<Self::Api>::new();
-        }
+        },
+        [data_arg] => {
+            let data_pat = &data_arg.pat;
+            quote! {
+                let ___data_buffer___ = multiversx_sc::log_util::serialize_log_data(#data_pat);
+            }
+        },
+        _ => {
+            // several data fields are encoded like a struct containing all of them
+            let field_push_snippets = data_args.iter().map(|arg| {
+                let field_pat = &arg.pat;
+                quote! {
+                    multiversx_sc::log_util::serialize_log_data_field(&mut ___data_buffer___, #field_pat);
+                }
+            });
+            quote! {
+                let mut ___data_buffer___ = multiversx_sc::types::ManagedBuffer::<Self::Api>::new();
+                #(#field_push_snippets)*
+            }
+        },
     };
 
     let msig = method_gen::generate_sig_with_attributes(m);
@@ -67,9 +67,10 @@ pub fn generate_endpoint_call_method_body(m: &Method) -> proc_macro2::TokenStrea
     let only_owner_snippet = generate_only_owner_snippet(m);
     let only_admin_snippet = generate_only_admin_snippet(m);
     let only_user_account_snippet = generate_only_user_account_snippet(m);
+    let only_role_snippet = generate_only_role_snippet(m);
     let arg_load = generate_call_method_arg_load(m);
 
-    let call = generate_call_to_method_expr(m);
+    let call = generate_non_reentrant_call_expr(m, generate_call_to_method_expr(m));
     let body_with_result = generate_body_with_result(&m.return_type, &call);
 
     quote! {
@@ -78,6 +79,7 @@ pub fn generate_endpoint_call_method_body(m: &Method) -> proc_macro2::TokenStrea
         #only_owner_snippet
         #only_admin_snippet
         #only_user_account_snippet
+        #only_role_snippet
         #arg_load
         #body_with_result
     }
@@ -1,3 +1,4 @@
+use super::util::byte_str_slice_literal;
 use crate::model::{Method, PublicRole};
 
 pub fn generate_only_owner_snippet(m: &Method) -> proc_macro2::TokenStream {
@@ -32,3 +33,38 @@ pub fn generate_only_user_account_snippet(m: &Method) -> proc_macro2::TokenStrea
     }
     quote! {}
 }
+
+/// Wraps the call to the endpoint method in the reentrancy guard, if required.
+pub fn generate_non_reentrant_call_expr(
+    m: &Method,
+    call: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    if let PublicRole::Endpoint(endpoint_metadata) = &m.public_role {
+        if endpoint_metadata.non_reentrant {
+            return quote! {
+                {
+                    self.reentrancy_guard_enter();
+                    let result = #call;
+                    self.reentrancy_guard_exit();
+                    result
+                }
+            };
+        }
+    }
+    call
+}
+
+pub fn generate_only_role_snippet(m: &Method) -> proc_macro2::TokenStream {
+    if let PublicRole::Endpoint(endpoint_metadata) = &m.public_role {
+        let role_checks = endpoint_metadata.only_roles.iter().map(|role| {
+            let role_literal = byte_str_slice_literal(role.as_bytes());
+            quote! {
+                self.require_caller_has_role(#role_literal);
+            }
+        });
+        return quote! {
+            #(#role_checks)*
+        };
+    }
+    quote! {}
+}
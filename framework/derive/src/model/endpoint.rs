@@ -12,6 +12,10 @@ pub struct EndpointMetadata {
     pub only_owner: bool,
     pub only_admin: bool,
     pub only_user_account: bool,
+    /// Roles the caller must hold, as managed by the access control module.
+    pub only_roles: Vec<String>,
+    /// Guarded by the reentrancy guard module for the duration of the call.
+    pub non_reentrant: bool,
     pub mutability: EndpointMutabilityMetadata,
 }
 
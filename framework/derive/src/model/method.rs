@@ -12,6 +12,14 @@ pub enum AutoImpl {
     StorageClear { identifier: String },
     ProxyGetter,
 }
+/// Requested via `#[paginated_view]` on a storage mapper.
+#[derive(Clone, Debug)]
+pub struct PaginatedViewMetadata {
+    /// Explicit public name of the view.
+    /// When missing, it is derived from the public name of the storage mapper endpoint.
+    pub view_name: Option<syn::Ident>,
+}
+
 #[derive(Clone, Debug)]
 pub enum MethodImpl {
     /// Implementation auto-generated by the framework. There can (obviously) be only one per method.
@@ -44,6 +52,8 @@ pub struct Method {
     pub label_names: Vec<String>,
     pub return_type: syn::ReturnType,
     pub implementation: MethodImpl,
+    /// Paginated view generated for a storage mapper, if requested.
+    pub paginated_view: Option<PaginatedViewMetadata>,
 }
 
 impl Method {
@@ -16,6 +16,20 @@ pub fn is_only_user_account(attr: &syn::Attribute) -> bool {
     is_attribute_with_no_args(attr, ATTR_ONLY_USER_ACCOUNT)
 }
 
+pub fn is_non_reentrant(attr: &syn::Attribute) -> bool {
+    is_attribute_with_no_args(attr, ATTR_NON_REENTRANT)
+}
+
+pub struct OnlyRoleAttribute {
+    pub role: String,
+}
+
+impl OnlyRoleAttribute {
+    pub fn parse(attr: &syn::Attribute) -> Option<Self> {
+        is_attr_one_string_arg(attr, ATTR_ONLY_ROLE).map(|role| OnlyRoleAttribute { role })
+    }
+}
+
 pub fn is_callback_raw(attr: &syn::Attribute) -> bool {
     is_attribute_with_no_args(attr, ATTR_CALLBACK_RAW_DECL)
 }
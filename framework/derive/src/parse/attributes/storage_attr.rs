@@ -59,3 +59,22 @@ impl StorageClearAttribute {
         })
     }
 }
+
+/// Placed next to `#[storage_mapper]`,
+/// generates a view that returns the mapper contents one page at a time.
+pub struct PaginatedViewAttribute {
+    pub view_name: Option<syn::Ident>,
+}
+
+impl PaginatedViewAttribute {
+    pub fn parse(attr: &syn::Attribute) -> Option<Self> {
+        match is_attr_with_one_opt_token_tree_arg(attr, ATTR_PAGINATED_VIEW) {
+            None => None,
+            Some(Some(proc_macro2::TokenTree::Ident(ident))) => Some(PaginatedViewAttribute {
+                view_name: Some(ident),
+            }),
+            Some(None) => Some(PaginatedViewAttribute { view_name: None }),
+            _ => panic!("unexpected paginated view argument tokens"),
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::model::{AutoImpl, Method, MethodImpl};
+use crate::model::{AutoImpl, Method, MethodImpl, PaginatedViewMetadata};
 
 use super::attributes::*;
 
@@ -105,3 +105,17 @@ pub fn process_storage_clear_attribute(attr: &syn::Attribute, method: &mut Metho
         })
         .is_some()
 }
+
+pub fn process_paginated_view_attribute(attr: &syn::Attribute, method: &mut Method) -> bool {
+    PaginatedViewAttribute::parse(attr)
+        .map(|paginated_view| {
+            assert!(
+                method.paginated_view.is_none(),
+                "Only one `#[paginated_view]` allowed per storage mapper."
+            );
+            method.paginated_view = Some(PaginatedViewMetadata {
+                view_name: paginated_view.view_name,
+            });
+        })
+        .is_some()
+}
@@ -1,5 +1,6 @@
 use super::{
-    attributes::extract_doc, method_parse::process_method, parse_util::validate_attribute_args,
+    attributes::extract_doc, method_parse::process_method,
+    paginated_view_parse::generate_paginated_views, parse_util::validate_attribute_args,
     supertrait_parse::parse_supertrait,
 };
 use crate::{
@@ -30,7 +31,7 @@ pub fn parse_contract_trait(
         .map(parse_supertrait)
         .collect();
 
-    let methods: Vec<Method> = contract_trait
+    let mut methods: Vec<Method> = contract_trait
         .items
         .iter()
         .map(|itm| match itm {
@@ -38,6 +39,8 @@ pub fn parse_contract_trait(
             _ => panic!("Only methods allowed in contract traits"),
         })
         .collect();
+    let paginated_views = generate_paginated_views(&methods);
+    methods.extend(paginated_views);
 
     ContractTrait {
         docs,
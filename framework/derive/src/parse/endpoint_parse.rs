@@ -5,9 +5,10 @@ use crate::model::{
 
 use super::{
     attributes::{
-        is_callback_raw, is_init, is_only_admin, is_only_owner, is_only_user_account,
-        CallbackAttribute, EndpointAttribute, ExternalViewAttribute, LabelAttribute,
-        OutputNameAttribute, PromisesCallbackAttribute, ViewAttribute,
+        is_callback_raw, is_init, is_non_reentrant, is_only_admin, is_only_owner,
+        is_only_user_account, CallbackAttribute, EndpointAttribute, ExternalViewAttribute,
+        LabelAttribute, OnlyRoleAttribute, OutputNameAttribute, PromisesCallbackAttribute,
+        ViewAttribute,
     },
     MethodAttributesPass1,
 };
@@ -67,6 +68,33 @@ pub fn process_only_user_account_attribute(
     is_only_user_account
 }
 
+pub fn process_only_role_attribute(
+    attr: &syn::Attribute,
+    pass_1_data: &mut MethodAttributesPass1,
+) -> bool {
+    OnlyRoleAttribute::parse(attr)
+        .map(|only_role_attr| {
+            assert!(
+                !pass_1_data.only_roles.contains(&only_role_attr.role),
+                "Role `{}` specified more than once.",
+                only_role_attr.role
+            );
+            pass_1_data.only_roles.push(only_role_attr.role);
+        })
+        .is_some()
+}
+
+pub fn process_non_reentrant_attribute(
+    attr: &syn::Attribute,
+    pass_1_data: &mut MethodAttributesPass1,
+) -> bool {
+    let is_non_reentrant = is_non_reentrant(attr);
+    if is_non_reentrant {
+        pass_1_data.non_reentrant = true;
+    }
+    is_non_reentrant
+}
+
 pub fn process_endpoint_attribute(
     attr: &syn::Attribute,
     pass_1_data: &MethodAttributesPass1,
@@ -85,6 +113,8 @@ pub fn process_endpoint_attribute(
                 only_owner: pass_1_data.only_owner,
                 only_admin: pass_1_data.only_admin,
                 only_user_account: pass_1_data.only_user_account,
+                only_roles: pass_1_data.only_roles.clone(),
+                non_reentrant: pass_1_data.non_reentrant,
                 mutability: EndpointMutabilityMetadata::Mutable,
             });
         })
@@ -109,6 +139,8 @@ pub fn process_view_attribute(
                 only_owner: pass_1_data.only_owner,
                 only_admin: pass_1_data.only_admin,
                 only_user_account: pass_1_data.only_user_account,
+                only_roles: pass_1_data.only_roles.clone(),
+                non_reentrant: pass_1_data.non_reentrant,
                 mutability: EndpointMutabilityMetadata::Readonly,
             });
         })
@@ -133,6 +165,8 @@ pub fn process_external_view_attribute(
                 only_owner: pass_1_data.only_owner,
                 only_admin: pass_1_data.only_admin,
                 only_user_account: pass_1_data.only_user_account,
+                only_roles: pass_1_data.only_roles.clone(),
+                non_reentrant: pass_1_data.non_reentrant,
                 mutability: EndpointMutabilityMetadata::Readonly,
             });
         })
@@ -1,18 +1,21 @@
-use crate::model::{Method, MethodImpl, MethodPayableMetadata, PublicRole, TraitProperties};
+use crate::model::{
+    AutoImpl, Method, MethodImpl, MethodPayableMetadata, PublicRole, TraitProperties,
+};
 
 use super::{
     attributes::extract_doc,
     auto_impl_parse::{
-        process_event_attribute, process_legacy_event_attribute, process_proxy_attribute,
-        process_storage_clear_attribute, process_storage_get_attribute,
+        process_event_attribute, process_legacy_event_attribute, process_paginated_view_attribute,
+        process_proxy_attribute, process_storage_clear_attribute, process_storage_get_attribute,
         process_storage_is_empty_attribute, process_storage_mapper_attribute,
         process_storage_set_attribute,
     },
     extract_method_args, process_callback_attribute, process_callback_raw_attribute,
     process_endpoint_attribute, process_external_view_attribute, process_init_attribute,
-    process_label_names_attribute, process_only_admin_attribute, process_only_owner_attribute,
-    process_only_user_account_attribute, process_output_names_attribute, process_payable_attribute,
-    process_promises_callback_attribute, process_view_attribute,
+    process_label_names_attribute, process_non_reentrant_attribute, process_only_admin_attribute,
+    process_only_owner_attribute, process_only_role_attribute, process_only_user_account_attribute,
+    process_output_names_attribute, process_payable_attribute, process_promises_callback_attribute,
+    process_view_attribute,
 };
 pub struct MethodAttributesPass1 {
     pub method_name: String,
@@ -20,6 +23,8 @@ pub struct MethodAttributesPass1 {
     pub only_owner: bool,
     pub only_admin: bool,
     pub only_user_account: bool,
+    pub only_roles: Vec<String>,
+    pub non_reentrant: bool,
 }
 
 pub fn process_method(m: &syn::TraitItemMethod, trait_attributes: &TraitProperties) -> Method {
@@ -37,6 +42,8 @@ pub fn process_method(m: &syn::TraitItemMethod, trait_attributes: &TraitProperti
         only_owner: trait_attributes.only_owner,
         only_admin: trait_attributes.only_admin,
         only_user_account: trait_attributes.only_user_account,
+        only_roles: Vec::new(),
+        non_reentrant: false,
     };
     let mut first_pass_unprocessed_attributes = Vec::new();
 
@@ -57,6 +64,7 @@ pub fn process_method(m: &syn::TraitItemMethod, trait_attributes: &TraitProperti
         label_names: Vec::new(),
         return_type: m.sig.output.clone(),
         implementation,
+        paginated_view: None,
     };
 
     process_attributes_second_pass(
@@ -91,6 +99,8 @@ fn process_attribute_first_pass(
         || process_only_owner_attribute(attr, first_pass_data)
         || process_only_admin_attribute(attr, first_pass_data)
         || process_only_user_account_attribute(attr, first_pass_data)
+        || process_only_role_attribute(attr, first_pass_data)
+        || process_non_reentrant_attribute(attr, first_pass_data)
 }
 
 fn process_attributes_second_pass(
@@ -126,6 +136,7 @@ fn process_attribute_second_pass(
         || process_storage_mapper_attribute(attr, method)
         || process_storage_is_empty_attribute(attr, method)
         || process_storage_clear_attribute(attr, method)
+        || process_paginated_view_attribute(attr, method)
         || process_output_names_attribute(attr, method)
         || process_label_names_attribute(attr, method)
 }
@@ -138,5 +149,14 @@ fn validate_method(method: &Method) {
         ) || method.label_names.is_empty(),
         "Labels can only be placed on endpoints and constructors. Method '{}' is neither.",
         &method.name.to_string()
-    )
+    );
+    assert!(
+        method.paginated_view.is_none()
+            || matches!(
+                method.implementation,
+                MethodImpl::Generated(AutoImpl::StorageMapper { .. })
+            ),
+        "Paginated views can only be generated for storage mappers. Method '{}' is not annotated with `#[storage_mapper]`.",
+        &method.name.to_string()
+    );
 }
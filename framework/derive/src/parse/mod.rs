@@ -5,6 +5,7 @@ mod auto_impl_parse;
 mod contract_trait_parse;
 mod endpoint_parse;
 mod method_parse;
+mod paginated_view_parse;
 mod parse_util;
 mod payable_parse;
 mod split_path;
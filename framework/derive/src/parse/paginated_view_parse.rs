@@ -0,0 +1,185 @@
+use crate::model::{
+    ArgMetadata, EndpointMetadata, EndpointMutabilityMetadata, Method, MethodArgument, MethodImpl,
+    MethodPayableMetadata, PaginatedViewMetadata, PublicRole,
+};
+
+/// Creates the additional view methods requested via `#[paginated_view]` on storage mappers.
+///
+/// Each of them takes the same arguments as the storage mapper method,
+/// followed by a cursor indicating where the page starts and the maximum page size.
+pub fn generate_paginated_views(methods: &[Method]) -> Vec<Method> {
+    let views: Vec<Method> = methods
+        .iter()
+        .filter_map(|m| {
+            m.paginated_view
+                .as_ref()
+                .map(|paginated_view| paginated_view_method(m, paginated_view))
+        })
+        .collect();
+    for (index, view) in views.iter().enumerate() {
+        for other in methods.iter().chain(views[..index].iter()) {
+            check_no_collision(view, other);
+        }
+    }
+    views
+}
+
+/// Unless explicitly named, the view takes the public name of the storage mapper endpoint,
+/// followed by `_page`.
+fn paginated_view_public_name(
+    mapper_method: &Method,
+    paginated_view: &PaginatedViewMetadata,
+) -> syn::Ident {
+    if let Some(view_name) = &paginated_view.view_name {
+        return view_name.clone();
+    }
+    let base_name = match &mapper_method.public_role {
+        PublicRole::Endpoint(endpoint_metadata) => &endpoint_metadata.public_name,
+        _ => &mapper_method.name,
+    };
+    syn::Ident::new(&format!("{base_name}_page"), base_name.span())
+}
+
+fn endpoint_public_name(method: &Method) -> Option<&syn::Ident> {
+    match &method.public_role {
+        PublicRole::Endpoint(endpoint_metadata) => Some(&endpoint_metadata.public_name),
+        _ => None,
+    }
+}
+
+fn check_no_collision(view: &Method, other: &Method) {
+    assert!(
+        view.name != other.name,
+        "Cannot generate paginated view method '{}', because a method with the same name already exists.",
+        view.name
+    );
+    let view_public_name = endpoint_public_name(view).unwrap();
+    assert!(
+        endpoint_public_name(other) != Some(view_public_name),
+        "Paginated view name '{}' is already used by method '{}'. Pick another one with `#[paginated_view(name)]`.",
+        view_public_name,
+        other.name
+    );
+}
+
+fn paginated_view_method(mapper_method: &Method, paginated_view: &PaginatedViewMetadata) -> Method {
+    let mapper_type = match &mapper_method.return_type {
+        syn::ReturnType::Type(_, ty) => ty.clone(),
+        syn::ReturnType::Default => panic!(
+            "Storage mapper method '{}' must have a return type.",
+            mapper_method.name
+        ),
+    };
+    let paginated_trait = quote! {
+        <#mapper_type as multiversx_sc::storage::mappers::StoragePaginated<Self::Api>>
+    };
+
+    let mut method_args = mapper_method.method_args.clone();
+    method_args.push(generated_arg(
+        syn::parse_quote! { from },
+        syn::parse_quote! { #paginated_trait::Cursor },
+    ));
+    method_args.push(generated_arg(
+        syn::parse_quote! { size },
+        syn::parse_quote! { usize },
+    ));
+
+    let mapper_method_name = &mapper_method.name;
+    let mapper_arg_names = mapper_method.method_args.iter().map(|arg| &arg.pat);
+
+    Method {
+        docs: mapper_method.docs.clone(),
+        public_role: PublicRole::Endpoint(EndpointMetadata {
+            public_name: paginated_view_public_name(mapper_method, paginated_view),
+            payable: MethodPayableMetadata::NotPayable,
+            only_owner: false,
+            only_admin: false,
+            only_user_account: false,
+            only_roles: Vec::new(),
+            non_reentrant: false,
+            mutability: EndpointMutabilityMetadata::Readonly,
+        }),
+        name: syn::Ident::new(
+            &format!("{}_page", mapper_method.name),
+            mapper_method.name.span(),
+        ),
+        generics: mapper_method.generics.clone(),
+        unprocessed_attributes: Vec::new(),
+        method_args,
+        output_names: Vec::new(),
+        label_names: Vec::new(),
+        return_type: syn::parse_quote! {
+            -> multiversx_sc::types::MultiValueEncoded<Self::Api, #paginated_trait::PageItem>
+        },
+        implementation: MethodImpl::Explicit(syn::parse_quote! {
+            {
+                multiversx_sc::storage::mappers::StoragePaginated::page(
+                    &self.#mapper_method_name(#(#mapper_arg_names),*),
+                    from,
+                    size,
+                )
+            }
+        }),
+        paginated_view: None,
+    }
+}
+
+fn generated_arg(pat: syn::Pat, ty: syn::Type) -> MethodArgument {
+    MethodArgument {
+        original_pat: pat.clone(),
+        pat,
+        ty,
+        unprocessed_attributes: Vec::new(),
+        metadata: ArgMetadata::default(),
+    }
+}
+
+#[cfg(test)]
+fn parse_endpoint_names(trait_src: &str) -> Vec<(String, String)> {
+    let contract_trait: syn::ItemTrait = syn::parse_str(trait_src).unwrap();
+    super::parse_contract_trait(Vec::new(), &contract_trait)
+        .methods
+        .iter()
+        .filter_map(|m| endpoint_public_name(m).map(|name| (m.name.to_string(), name.to_string())))
+        .collect()
+}
+
+#[test]
+fn test_paginated_view_named_after_public_name() {
+    let names = parse_endpoint_names(
+        r#"trait Features {
+            #[view(getVecMapper)]
+            #[paginated_view]
+            #[storage_mapper("vec_mapper")]
+            fn vec_mapper(&self) -> VecMapper<u32>;
+
+            #[paginated_view(mapPage)]
+            #[view]
+            #[storage_mapper("map_mapper")]
+            fn map_mapper(&self) -> MapMapper<u32, u32>;
+        }"#,
+    );
+    assert!(names.contains(&(
+        "vec_mapper_page".to_string(),
+        "getVecMapper_page".to_string()
+    )));
+    assert!(names.contains(&("map_mapper_page".to_string(), "mapPage".to_string())));
+}
+
+#[test]
+#[should_panic(
+    expected = "Paginated view name 'getVecMapper_page' is already used by method 'other_endpoint'"
+)]
+fn test_paginated_view_endpoint_collision() {
+    parse_endpoint_names(
+        r#"trait Features {
+            #[view(getVecMapper)]
+            #[paginated_view]
+            #[storage_mapper("vec_mapper")]
+            fn vec_mapper(&self) -> VecMapper<u32>;
+
+            #[endpoint(getVecMapper_page)]
+            fn other_endpoint(&self) {}
+        }"#,
+    );
+}
@@ -0,0 +1,311 @@
+use crate::abi_json::{
+    ContractAbiJson, EndpointAbiJson, EndpointMutabilityAbiJson, InputAbiJson, OutputAbiJson,
+};
+
+use super::{abi_diff_types::TypeRenames, AbiChange};
+
+/// Multi-value types that callers are allowed to leave out.
+const OPTIONAL_INPUT_TYPE_PREFIXES: &[&str] = &["optional<", "variadic<", "counted-variadic<"];
+
+const ANY_TOKEN: &str = "*";
+
+pub(super) fn diff_constructors(
+    old: &ContractAbiJson,
+    new: &ContractAbiJson,
+    type_renames: &TypeRenames,
+    changes: &mut Vec<AbiChange>,
+) {
+    let item = "constructor";
+    match (&old.constructor, &new.constructor) {
+        (Some(old_constructor), Some(new_constructor)) => {
+            diff_inputs(
+                item,
+                &old_constructor.inputs,
+                &new_constructor.inputs,
+                type_renames,
+                changes,
+            );
+            diff_outputs(
+                item,
+                &old_constructor.outputs,
+                &new_constructor.outputs,
+                type_renames,
+                changes,
+            );
+            diff_payability(
+                item,
+                &old_constructor.payable_in_tokens,
+                &new_constructor.payable_in_tokens,
+                changes,
+            );
+        },
+        (Some(_), None) => changes.push(AbiChange::breaking(item, "removed")),
+        (None, Some(_)) => changes.push(AbiChange::compatible(item, "added")),
+        (None, None) => {},
+    }
+}
+
+pub(super) fn diff_endpoints(
+    old: &ContractAbiJson,
+    new: &ContractAbiJson,
+    type_renames: &TypeRenames,
+    changes: &mut Vec<AbiChange>,
+) {
+    for old_endpoint in &old.endpoints {
+        let item = format!("endpoint `{}`", old_endpoint.name);
+        if let Some(new_endpoint) = find_endpoint(&new.endpoints, &old_endpoint.name) {
+            diff_endpoint(&item, old_endpoint, new_endpoint, type_renames, changes);
+            continue;
+        }
+
+        let rename_candidates: Vec<&EndpointAbiJson> = new
+            .endpoints
+            .iter()
+            .filter(|new_endpoint| find_endpoint(&old.endpoints, &new_endpoint.name).is_none())
+            .filter(|new_endpoint| same_signature(old_endpoint, new_endpoint, type_renames))
+            .collect();
+        if let [renamed] = rename_candidates.as_slice() {
+            changes.push(AbiChange::breaking(
+                item,
+                format!("renamed to `{}`", renamed.name),
+            ));
+        } else {
+            changes.push(AbiChange::breaking(item, "removed"));
+        }
+    }
+
+    for new_endpoint in &new.endpoints {
+        if find_endpoint(&old.endpoints, &new_endpoint.name).is_none() {
+            changes.push(AbiChange::compatible(
+                format!("endpoint `{}`", new_endpoint.name),
+                "added",
+            ));
+        }
+    }
+}
+
+fn find_endpoint<'a>(endpoints: &'a [EndpointAbiJson], name: &str) -> Option<&'a EndpointAbiJson> {
+    endpoints.iter().find(|endpoint| endpoint.name == name)
+}
+
+fn same_signature(a: &EndpointAbiJson, b: &EndpointAbiJson, type_renames: &TypeRenames) -> bool {
+    a.mutability == b.mutability
+        && a.inputs.len() == b.inputs.len()
+        && a.inputs
+            .iter()
+            .zip(b.inputs.iter())
+            .all(|(a_input, b_input)| same_input_type(a_input, b_input, type_renames))
+        && same_outputs(&a.outputs, &b.outputs, type_renames)
+}
+
+fn diff_endpoint(
+    item: &str,
+    old: &EndpointAbiJson,
+    new: &EndpointAbiJson,
+    type_renames: &TypeRenames,
+    changes: &mut Vec<AbiChange>,
+) {
+    diff_inputs(item, &old.inputs, &new.inputs, type_renames, changes);
+    diff_outputs(item, &old.outputs, &new.outputs, type_renames, changes);
+    diff_mutability(item, &old.mutability, &new.mutability, changes);
+    diff_payability(
+        item,
+        &old.payable_in_tokens,
+        &new.payable_in_tokens,
+        changes,
+    );
+    diff_restriction(item, "owner", old.only_owner, new.only_owner, changes);
+    diff_restriction(item, "admin", old.only_admin, new.only_admin, changes);
+    diff_roles(item, &old.only_roles, &new.only_roles, changes);
+}
+
+fn same_input_type(a: &InputAbiJson, b: &InputAbiJson, type_renames: &TypeRenames) -> bool {
+    type_renames.same_type(&a.type_name, &b.type_name) && a.multi_arg == b.multi_arg
+}
+
+fn same_outputs(a: &[OutputAbiJson], b: &[OutputAbiJson], type_renames: &TypeRenames) -> bool {
+    a.len() == b.len()
+        && a.iter().zip(b.iter()).all(|(a_output, b_output)| {
+            type_renames.same_type(&a_output.type_name, &b_output.type_name)
+                && a_output.multi_result == b_output.multi_result
+        })
+}
+
+/// Argument names can change freely, only their types and positions matter.
+fn diff_inputs(
+    item: &str,
+    old_inputs: &[InputAbiJson],
+    new_inputs: &[InputAbiJson],
+    type_renames: &TypeRenames,
+    changes: &mut Vec<AbiChange>,
+) {
+    for (index, old_input) in old_inputs.iter().enumerate() {
+        match new_inputs.get(index) {
+            Some(new_input) if !same_input_type(old_input, new_input, type_renames) => {
+                changes.push(AbiChange::breaking(
+                    item,
+                    format!(
+                        "input #{index} `{}` changed type from `{}` to `{}`",
+                        old_input.arg_name, old_input.type_name, new_input.type_name
+                    ),
+                ));
+            },
+            Some(_) => {},
+            None => changes.push(AbiChange::breaking(
+                item,
+                format!("input #{index} `{}` removed", old_input.arg_name),
+            )),
+        }
+    }
+
+    for (index, new_input) in new_inputs.iter().enumerate().skip(old_inputs.len()) {
+        let description = format!(
+            "input #{index} `{}` of type `{}` added",
+            new_input.arg_name, new_input.type_name
+        );
+        if is_optional_input(new_input) {
+            changes.push(AbiChange::compatible(item, description));
+        } else {
+            changes.push(AbiChange::breaking(item, description));
+        }
+    }
+}
+
+fn is_optional_input(input: &InputAbiJson) -> bool {
+    OPTIONAL_INPUT_TYPE_PREFIXES
+        .iter()
+        .any(|prefix| input.type_name.starts_with(prefix))
+}
+
+/// Callers decode results positionally, so any change in outputs is breaking.
+fn diff_outputs(
+    item: &str,
+    old_outputs: &[OutputAbiJson],
+    new_outputs: &[OutputAbiJson],
+    type_renames: &TypeRenames,
+    changes: &mut Vec<AbiChange>,
+) {
+    if !same_outputs(old_outputs, new_outputs, type_renames) {
+        changes.push(AbiChange::breaking(
+            item,
+            format!(
+                "outputs changed from [{}] to [{}]",
+                output_types(old_outputs),
+                output_types(new_outputs)
+            ),
+        ));
+    }
+}
+
+fn output_types(outputs: &[OutputAbiJson]) -> String {
+    outputs
+        .iter()
+        .map(|output| {
+            if output.multi_result == Some(true) {
+                format!("{} (multi)", output.type_name)
+            } else {
+                output.type_name.clone()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Readonly endpoints can be queried, mutable ones cannot.
+fn diff_mutability(
+    item: &str,
+    old_mutability: &EndpointMutabilityAbiJson,
+    new_mutability: &EndpointMutabilityAbiJson,
+    changes: &mut Vec<AbiChange>,
+) {
+    if old_mutability == new_mutability {
+        return;
+    }
+
+    let description = format!("mutability changed from {old_mutability:?} to {new_mutability:?}");
+    if *new_mutability == EndpointMutabilityAbiJson::Mutable {
+        changes.push(AbiChange::breaking(item, description));
+    } else {
+        changes.push(AbiChange::compatible(item, description));
+    }
+}
+
+fn diff_payability(
+    item: &str,
+    old_tokens: &[String],
+    new_tokens: &[String],
+    changes: &mut Vec<AbiChange>,
+) {
+    for old_token in old_tokens {
+        if !accepts_token(new_tokens, old_token) {
+            changes.push(AbiChange::breaking(
+                item,
+                format!("no longer accepts payments in `{old_token}`"),
+            ));
+        }
+    }
+
+    for new_token in new_tokens {
+        if !accepts_token(old_tokens, new_token) {
+            changes.push(AbiChange::compatible(
+                item,
+                format!("now accepts payments in `{new_token}`"),
+            ));
+        }
+    }
+}
+
+fn accepts_token(payable_in_tokens: &[String], token: &str) -> bool {
+    payable_in_tokens
+        .iter()
+        .any(|accepted| accepted == ANY_TOKEN || accepted == token)
+}
+
+fn diff_restriction(
+    item: &str,
+    restricted_to: &str,
+    old_restriction: Option<bool>,
+    new_restriction: Option<bool>,
+    changes: &mut Vec<AbiChange>,
+) {
+    match (
+        old_restriction.unwrap_or_default(),
+        new_restriction.unwrap_or_default(),
+    ) {
+        (false, true) => changes.push(AbiChange::breaking(
+            item,
+            format!("now restricted to {restricted_to}"),
+        )),
+        (true, false) => changes.push(AbiChange::compatible(
+            item,
+            format!("no longer restricted to {restricted_to}"),
+        )),
+        _ => {},
+    }
+}
+
+/// Callers that were allowed before might not have the newly required roles.
+fn diff_roles(
+    item: &str,
+    old_roles: &[String],
+    new_roles: &[String],
+    changes: &mut Vec<AbiChange>,
+) {
+    for new_role in new_roles {
+        if !old_roles.contains(new_role) {
+            changes.push(AbiChange::breaking(
+                item,
+                format!("now restricted to role `{new_role}`"),
+            ));
+        }
+    }
+
+    for old_role in old_roles {
+        if !new_roles.contains(old_role) {
+            changes.push(AbiChange::compatible(
+                item,
+                format!("no longer restricted to role `{old_role}`"),
+            ));
+        }
+    }
+}
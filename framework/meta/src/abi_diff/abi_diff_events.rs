@@ -0,0 +1,100 @@
+use crate::abi_json::{ContractAbiJson, EventAbiJson, EventInputAbiJson};
+
+use super::{abi_diff_types::TypeRenames, AbiChange};
+
+/// Indexers decode events by identifier and argument position,
+/// so only the types and the indexed flags of the arguments matter.
+pub(super) fn diff_events(
+    old: &ContractAbiJson,
+    new: &ContractAbiJson,
+    type_renames: &TypeRenames,
+    changes: &mut Vec<AbiChange>,
+) {
+    for old_event in &old.events {
+        let item = format!("event `{}`", old_event.identifier);
+        match find_event(&new.events, &old_event.identifier) {
+            Some(new_event) => diff_event_inputs(
+                &item,
+                &old_event.inputs,
+                &new_event.inputs,
+                type_renames,
+                changes,
+            ),
+            None => changes.push(AbiChange::breaking(item, "removed")),
+        }
+    }
+
+    for new_event in &new.events {
+        if find_event(&old.events, &new_event.identifier).is_none() {
+            changes.push(AbiChange::compatible(
+                format!("event `{}`", new_event.identifier),
+                "added",
+            ));
+        }
+    }
+}
+
+fn find_event<'a>(events: &'a [EventAbiJson], identifier: &str) -> Option<&'a EventAbiJson> {
+    events.iter().find(|event| event.identifier == identifier)
+}
+
+fn diff_event_inputs(
+    item: &str,
+    old_inputs: &[EventInputAbiJson],
+    new_inputs: &[EventInputAbiJson],
+    type_renames: &TypeRenames,
+    changes: &mut Vec<AbiChange>,
+) {
+    for (index, old_input) in old_inputs.iter().enumerate() {
+        let Some(new_input) = new_inputs.get(index) else {
+            changes.push(AbiChange::breaking(
+                item,
+                format!("argument #{index} `{}` removed", old_input.arg_name),
+            ));
+            continue;
+        };
+
+        if is_indexed(old_input) != is_indexed(new_input) {
+            let description = if is_indexed(new_input) {
+                format!("argument #{index} `{}` is now indexed", old_input.arg_name)
+            } else {
+                format!(
+                    "argument #{index} `{}` is no longer indexed",
+                    old_input.arg_name
+                )
+            };
+            changes.push(AbiChange::breaking(item, description));
+        }
+        if !type_renames.same_type(&old_input.type_name, &new_input.type_name) {
+            changes.push(AbiChange::breaking(
+                item,
+                format!(
+                    "argument #{index} `{}` changed type from `{}` to `{}`",
+                    old_input.arg_name, old_input.type_name, new_input.type_name
+                ),
+            ));
+        } else if old_input.arg_name != new_input.arg_name {
+            changes.push(AbiChange::compatible(
+                item,
+                format!(
+                    "argument #{index} renamed from `{}` to `{}`",
+                    old_input.arg_name, new_input.arg_name
+                ),
+            ));
+        }
+    }
+
+    for (index, new_input) in new_inputs.iter().enumerate().skip(old_inputs.len()) {
+        changes.push(AbiChange::breaking(
+            item,
+            format!(
+                "argument #{index} `{}` of type `{}` added",
+                new_input.arg_name, new_input.type_name
+            ),
+        ));
+    }
+}
+
+fn is_indexed(input: &EventInputAbiJson) -> bool {
+    input.indexed == Some(true)
+}
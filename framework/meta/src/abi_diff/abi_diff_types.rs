@@ -0,0 +1,290 @@
+use std::collections::BTreeMap;
+
+use crate::abi_json::{
+    AbiTypeExpr, ContractAbiJson, EnumVariantDescriptionJson, StructFieldDescriptionJson,
+    TypeDescriptionJson,
+};
+
+use super::AbiChange;
+
+/// Type names are not encoded, so a type can be renamed without affecting callers.
+///
+/// A removed type counts as renamed if exactly one added type has the same structure.
+/// Field types are compared with the renames found so far,
+/// so types containing renamed types are matched in later passes.
+pub(super) struct TypeRenames {
+    old_to_new: BTreeMap<String, String>,
+}
+
+impl TypeRenames {
+    pub(super) fn find(old: &ContractAbiJson, new: &ContractAbiJson) -> Self {
+        let mut type_renames = TypeRenames {
+            old_to_new: BTreeMap::new(),
+        };
+        loop {
+            let found = type_renames.find_next(old, new);
+            if found.is_empty() {
+                return type_renames;
+            }
+            type_renames.old_to_new.extend(found);
+        }
+    }
+
+    /// Matches the removed and added types that are not already part of a rename.
+    fn find_next(&self, old: &ContractAbiJson, new: &ContractAbiJson) -> BTreeMap<String, String> {
+        let removed_types: Vec<(&String, &TypeDescriptionJson)> = old
+            .types
+            .iter()
+            .filter(|(type_name, _)| {
+                !new.types.contains_key(*type_name) && self.new_name(type_name).is_none()
+            })
+            .collect();
+        let added_types: Vec<(&String, &TypeDescriptionJson)> = new
+            .types
+            .iter()
+            .filter(|(type_name, _)| {
+                !old.types.contains_key(*type_name) && !self.is_rename_target(type_name)
+            })
+            .collect();
+
+        let mut found = BTreeMap::new();
+        for (old_name, old_type) in &removed_types {
+            let candidates: Vec<&(&String, &TypeDescriptionJson)> = added_types
+                .iter()
+                .filter(|(_, new_type)| self.same_structure(old_type, new_type))
+                .collect();
+            let [(new_name, new_type)] = candidates.as_slice() else {
+                continue;
+            };
+            let reverse_candidate_count = removed_types
+                .iter()
+                .filter(|(_, other_old_type)| self.same_structure(other_old_type, new_type))
+                .count();
+            if reverse_candidate_count == 1 {
+                found.insert(old_name.to_string(), new_name.to_string());
+            }
+        }
+        found
+    }
+
+    fn new_name(&self, old_name: &str) -> Option<&String> {
+        self.old_to_new.get(old_name)
+    }
+
+    fn is_rename_target(&self, new_name: &str) -> bool {
+        self.old_to_new.values().any(|target| target == new_name)
+    }
+
+    /// Whether the two type names denote the same type, taking renames into account.
+    pub(super) fn same_type(&self, old_type_name: &str, new_type_name: &str) -> bool {
+        old_type_name == new_type_name
+            || self.rename_expr(AbiTypeExpr::parse(old_type_name))
+                == AbiTypeExpr::parse(new_type_name)
+    }
+
+    fn rename_expr(&self, expr: AbiTypeExpr) -> AbiTypeExpr {
+        AbiTypeExpr {
+            name: self.new_name(&expr.name).cloned().unwrap_or(expr.name),
+            args: expr
+                .args
+                .into_iter()
+                .map(|arg| self.rename_expr(arg))
+                .collect(),
+        }
+    }
+
+    /// Same encoding, regardless of type and field names.
+    fn same_structure(&self, a: &TypeDescriptionJson, b: &TypeDescriptionJson) -> bool {
+        a.content_type == b.content_type
+            && self.same_field_types(&a.fields, &b.fields)
+            && a.variants.len() == b.variants.len()
+            && a.variants
+                .iter()
+                .zip(b.variants.iter())
+                .all(|(a_variant, b_variant)| {
+                    a_variant.name == b_variant.name
+                        && a_variant.discriminant == b_variant.discriminant
+                        && self.same_field_types(&a_variant.fields, &b_variant.fields)
+                })
+    }
+
+    fn same_field_types(
+        &self,
+        a: &[StructFieldDescriptionJson],
+        b: &[StructFieldDescriptionJson],
+    ) -> bool {
+        a.len() == b.len()
+            && a.iter()
+                .zip(b.iter())
+                .all(|(a_field, b_field)| self.same_type(&a_field.field_type, &b_field.field_type))
+    }
+}
+
+/// Compares the type descriptions with the same name,
+/// which also covers types only used nested in other types.
+///
+/// Field names are not encoded, so renaming a field is compatible.
+pub(super) fn diff_types(
+    old: &ContractAbiJson,
+    new: &ContractAbiJson,
+    type_renames: &TypeRenames,
+    changes: &mut Vec<AbiChange>,
+) {
+    for (type_name, old_type) in &old.types {
+        let item = format!("type `{type_name}`");
+        if let Some(new_type) = new.types.get(type_name) {
+            diff_type(&item, old_type, new_type, type_renames, changes);
+        } else if let Some(new_name) = type_renames.new_name(type_name) {
+            changes.push(AbiChange::compatible(
+                &item,
+                format!("renamed to `{new_name}`"),
+            ));
+            diff_type(&item, old_type, &new.types[new_name], type_renames, changes);
+        } else {
+            changes.push(AbiChange::breaking(item, "removed"));
+        }
+    }
+
+    for type_name in new.types.keys() {
+        if !old.types.contains_key(type_name) && !type_renames.is_rename_target(type_name) {
+            changes.push(AbiChange::compatible(
+                format!("type `{type_name}`"),
+                "added",
+            ));
+        }
+    }
+}
+
+fn diff_type(
+    item: &str,
+    old_type: &TypeDescriptionJson,
+    new_type: &TypeDescriptionJson,
+    type_renames: &TypeRenames,
+    changes: &mut Vec<AbiChange>,
+) {
+    if old_type.content_type != new_type.content_type {
+        changes.push(AbiChange::breaking(
+            item,
+            format!(
+                "changed from {} to {}",
+                old_type.content_type, new_type.content_type
+            ),
+        ));
+        return;
+    }
+
+    diff_fields(
+        item,
+        &old_type.fields,
+        &new_type.fields,
+        type_renames,
+        changes,
+    );
+    diff_variants(
+        item,
+        &old_type.variants,
+        &new_type.variants,
+        type_renames,
+        changes,
+    );
+}
+
+fn diff_fields(
+    item: &str,
+    old_fields: &[StructFieldDescriptionJson],
+    new_fields: &[StructFieldDescriptionJson],
+    type_renames: &TypeRenames,
+    changes: &mut Vec<AbiChange>,
+) {
+    for (index, old_field) in old_fields.iter().enumerate() {
+        match new_fields.get(index) {
+            Some(new_field)
+                if !type_renames.same_type(&old_field.field_type, &new_field.field_type) =>
+            {
+                changes.push(AbiChange::breaking(
+                    item,
+                    format!(
+                        "field #{index} `{}` changed type from `{}` to `{}`",
+                        old_field.name, old_field.field_type, new_field.field_type
+                    ),
+                ));
+            },
+            Some(new_field) if old_field.name != new_field.name => {
+                changes.push(AbiChange::compatible(
+                    item,
+                    format!(
+                        "field #{index} renamed from `{}` to `{}`",
+                        old_field.name, new_field.name
+                    ),
+                ));
+            },
+            Some(_) => {},
+            None => changes.push(AbiChange::breaking(
+                item,
+                format!("field #{index} `{}` removed", old_field.name),
+            )),
+        }
+    }
+
+    for (index, new_field) in new_fields.iter().enumerate().skip(old_fields.len()) {
+        changes.push(AbiChange::breaking(
+            item,
+            format!(
+                "field #{index} `{}` of type `{}` added",
+                new_field.name, new_field.field_type
+            ),
+        ));
+    }
+}
+
+fn diff_variants(
+    item: &str,
+    old_variants: &[EnumVariantDescriptionJson],
+    new_variants: &[EnumVariantDescriptionJson],
+    type_renames: &TypeRenames,
+    changes: &mut Vec<AbiChange>,
+) {
+    for old_variant in old_variants {
+        let Some(new_variant) = find_variant(new_variants, &old_variant.name) else {
+            changes.push(AbiChange::breaking(
+                item,
+                format!("variant `{}` removed", old_variant.name),
+            ));
+            continue;
+        };
+
+        if old_variant.discriminant != new_variant.discriminant {
+            changes.push(AbiChange::breaking(
+                item,
+                format!(
+                    "variant `{}` discriminant changed from {} to {}",
+                    old_variant.name, old_variant.discriminant, new_variant.discriminant
+                ),
+            ));
+        }
+        let variant_item = format!("{item}, variant `{}`", old_variant.name);
+        diff_fields(
+            &variant_item,
+            &old_variant.fields,
+            &new_variant.fields,
+            type_renames,
+            changes,
+        );
+    }
+
+    for new_variant in new_variants {
+        if find_variant(old_variants, &new_variant.name).is_none() {
+            changes.push(AbiChange::compatible(
+                item,
+                format!("variant `{}` added", new_variant.name),
+            ));
+        }
+    }
+}
+
+fn find_variant<'a>(
+    variants: &'a [EnumVariantDescriptionJson],
+    name: &str,
+) -> Option<&'a EnumVariantDescriptionJson> {
+    variants.iter().find(|variant| variant.name == name)
+}
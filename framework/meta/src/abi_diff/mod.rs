@@ -0,0 +1,74 @@
+mod abi_diff_endpoints;
+mod abi_diff_events;
+mod abi_diff_types;
+
+use std::fmt::Display;
+
+use crate::abi_json::ContractAbiJson;
+
+/// Whether existing callers (contracts, dApps, indexers) keep working after the change.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum AbiChangeSeverity {
+    Compatible,
+    Breaking,
+}
+
+impl Display for AbiChangeSeverity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AbiChangeSeverity::Compatible => write!(f, "compatible"),
+            AbiChangeSeverity::Breaking => write!(f, "breaking"),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct AbiChange {
+    pub severity: AbiChangeSeverity,
+    /// What the change applies to, e.g. "endpoint `deposit`", "type `Payment`".
+    pub item: String,
+    pub description: String,
+}
+
+impl AbiChange {
+    fn breaking(item: impl Into<String>, description: impl Into<String>) -> Self {
+        AbiChange {
+            severity: AbiChangeSeverity::Breaking,
+            item: item.into(),
+            description: description.into(),
+        }
+    }
+
+    fn compatible(item: impl Into<String>, description: impl Into<String>) -> Self {
+        AbiChange {
+            severity: AbiChangeSeverity::Compatible,
+            item: item.into(),
+            description: description.into(),
+        }
+    }
+
+    pub fn is_breaking(&self) -> bool {
+        self.severity == AbiChangeSeverity::Breaking
+    }
+}
+
+impl Display for AbiChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}] {}: {}", self.severity, self.item, self.description)
+    }
+}
+
+/// Lists all changes between two versions of a contract ABI, breaking ones first.
+///
+/// Docs, endpoint argument names and build info are ignored, since they do not affect callers.
+/// Renamed types are matched by structure, so the types using them are not reported as changed.
+pub fn diff_abi(old: &ContractAbiJson, new: &ContractAbiJson) -> Vec<AbiChange> {
+    let type_renames = abi_diff_types::TypeRenames::find(old, new);
+    let mut changes = Vec::new();
+    abi_diff_endpoints::diff_constructors(old, new, &type_renames, &mut changes);
+    abi_diff_endpoints::diff_endpoints(old, new, &type_renames, &mut changes);
+    abi_diff_events::diff_events(old, new, &type_renames, &mut changes);
+    abi_diff_types::diff_types(old, new, &type_renames, &mut changes);
+    changes.sort_by_key(|change| std::cmp::Reverse(change.severity));
+    changes
+}
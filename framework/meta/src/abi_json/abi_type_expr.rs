@@ -0,0 +1,66 @@
+const INNER_TYPE_SEPARATOR: char = '<';
+const INNER_TYPE_END: char = '>';
+const ARRAY_TYPE_PREFIX: &str = "array";
+
+/// An ABI type name, split into its generic structure, e.g. `List<Option<u32>>`.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct AbiTypeExpr {
+    pub name: String,
+    pub args: Vec<AbiTypeExpr>,
+}
+
+impl AbiTypeExpr {
+    pub fn parse(type_name: &str) -> Self {
+        let type_name = type_name.trim();
+        let Some(separator_index) = type_name.find(INNER_TYPE_SEPARATOR) else {
+            return AbiTypeExpr {
+                name: type_name.to_string(),
+                args: Vec::new(),
+            };
+        };
+
+        let inner = type_name[separator_index + 1..]
+            .strip_suffix(INNER_TYPE_END)
+            .unwrap_or_else(|| panic!("invalid ABI type name: {type_name}"));
+        AbiTypeExpr {
+            name: type_name[..separator_index].to_string(),
+            args: split_type_args(inner)
+                .into_iter()
+                .map(AbiTypeExpr::parse)
+                .collect(),
+        }
+    }
+
+    pub fn single_arg(&self) -> &AbiTypeExpr {
+        assert!(
+            self.args.len() == 1,
+            "ABI type {} expects exactly one type argument",
+            self.name
+        );
+        &self.args[0]
+    }
+
+    pub fn array_length(&self) -> Option<usize> {
+        self.name.strip_prefix(ARRAY_TYPE_PREFIX)?.parse().ok()
+    }
+}
+
+/// Splits at the commas that are not nested in another generic.
+fn split_type_args(inner: &str) -> Vec<&str> {
+    let mut result = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (index, c) in inner.char_indices() {
+        match c {
+            INNER_TYPE_SEPARATOR => depth += 1,
+            INNER_TYPE_END => depth -= 1,
+            ',' if depth == 0 => {
+                result.push(&inner[start..index]);
+                start = index + 1;
+            },
+            _ => {},
+        }
+    }
+    result.push(&inner[start..]);
+    result
+}
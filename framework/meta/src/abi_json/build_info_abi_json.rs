@@ -1,7 +1,7 @@
 use multiversx_sc::abi::{BuildInfoAbi, ContractCrateBuildAbi, FrameworkBuildAbi};
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct BuildInfoAbiJson {
     pub rustc: RustcAbiJson,
@@ -19,7 +19,7 @@ impl From<&BuildInfoAbi> for BuildInfoAbiJson {
     }
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Default, Clone, PartialEq, Eq, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct RustcAbiJson {
     pub version: String,
@@ -42,11 +42,12 @@ impl RustcAbiJson {
     }
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Default, Clone, PartialEq, Eq, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct ContractCrateBuildAbiJson {
     pub name: String,
     pub version: String,
+    #[serde(default)]
     #[serde(skip_serializing_if = "String::is_empty")]
     pub git_version: String,
 }
@@ -61,7 +62,7 @@ impl From<&ContractCrateBuildAbi> for ContractCrateBuildAbiJson {
     }
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Default, Clone, PartialEq, Eq, Debug)]
 pub struct FrameworkBuildAbiJson {
     pub name: String,
     pub version: String,
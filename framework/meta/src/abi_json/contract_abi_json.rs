@@ -6,15 +6,20 @@ use std::collections::BTreeMap;
 #[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ContractAbiJson {
+    #[serde(default)]
     pub build_info: BuildInfoAbiJson,
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub docs: Vec<String>,
     pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub constructor: Option<ConstructorAbiJson>,
     pub endpoints: Vec<EndpointAbiJson>,
+    #[serde(default)]
     pub events: Vec<EventAbiJson>,
+    #[serde(default)]
     pub has_callback: bool,
+    #[serde(default)]
     pub types: BTreeMap<String, TypeDescriptionJson>,
 }
 
@@ -51,3 +56,7 @@ pub fn serialize_abi_to_json(abi_json: &ContractAbiJson) -> String {
     serialized.push('\n');
     serialized
 }
+
+pub fn deserialize_abi_from_json(input: &str) -> Result<ContractAbiJson, String> {
+    serde_json::from_str(input).map_err(|err| err.to_string())
+}
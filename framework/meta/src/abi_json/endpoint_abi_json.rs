@@ -25,6 +25,7 @@ impl From<&InputAbi> for InputAbiJson {
 #[derive(Serialize, Deserialize)]
 pub struct OutputAbiJson {
     #[serde(rename = "name")]
+    #[serde(default)]
     #[serde(skip_serializing_if = "String::is_empty")]
     pub output_name: String,
     #[serde(rename = "type")]
@@ -44,7 +45,7 @@ impl From<&OutputAbi> for OutputAbiJson {
     }
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
 #[serde(rename_all = "camelCase")]
 pub enum EndpointMutabilityAbiJson {
     Mutable,
@@ -55,9 +56,11 @@ pub enum EndpointMutabilityAbiJson {
 /// Same as EndpointAbiJson but ignores the name
 #[derive(Serialize, Deserialize)]
 pub struct ConstructorAbiJson {
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub docs: Vec<String>,
     #[serde(rename = "payableInTokens")]
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub payable_in_tokens: Vec<String>,
     pub inputs: Vec<InputAbiJson>,
@@ -81,6 +84,7 @@ impl From<&EndpointAbi> for ConstructorAbiJson {
 
 #[derive(Serialize, Deserialize)]
 pub struct EndpointAbiJson {
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub docs: Vec<String>,
     pub name: String,
@@ -90,12 +94,18 @@ pub struct EndpointAbiJson {
     #[serde(rename = "onlyAdmin")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub only_admin: Option<bool>,
+    #[serde(rename = "onlyRoles")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub only_roles: Vec<String>,
     pub mutability: EndpointMutabilityAbiJson,
     #[serde(rename = "payableInTokens")]
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub payable_in_tokens: Vec<String>,
     pub inputs: Vec<InputAbiJson>,
     pub outputs: Vec<OutputAbiJson>,
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub labels: Vec<String>,
 }
@@ -107,6 +117,7 @@ impl From<&EndpointAbi> for EndpointAbiJson {
             name: abi.name.to_string(),
             only_owner: if abi.only_owner { Some(true) } else { None },
             only_admin: if abi.only_admin { Some(true) } else { None },
+            only_roles: abi.only_roles.iter().map(|&role| role.to_owned()).collect(),
             mutability: match abi.mutability {
                 EndpointMutabilityAbi::Mutable => EndpointMutabilityAbiJson::Mutable,
                 EndpointMutabilityAbi::Readonly => EndpointMutabilityAbiJson::Readonly,
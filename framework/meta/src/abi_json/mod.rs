@@ -1,10 +1,14 @@
+mod abi_type_expr;
 mod build_info_abi_json;
 mod contract_abi_json;
 mod endpoint_abi_json;
 mod event_abi_json;
 mod type_abi_json;
 
-pub use build_info_abi_json::{BuildInfoAbiJson, RustcAbiJson};
+pub use abi_type_expr::AbiTypeExpr;
+pub use build_info_abi_json::{
+    BuildInfoAbiJson, ContractCrateBuildAbiJson, FrameworkBuildAbiJson, RustcAbiJson,
+};
 pub use contract_abi_json::*;
 pub use endpoint_abi_json::*;
 pub use event_abi_json::*;
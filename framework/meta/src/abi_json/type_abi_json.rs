@@ -6,12 +6,15 @@ pub struct TypeDescriptionJson {
     #[serde(rename = "type")]
     pub content_type: String,
 
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub docs: Vec<String>,
 
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub variants: Vec<EnumVariantDescriptionJson>,
 
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub fields: Vec<StructFieldDescriptionJson>,
 }
@@ -53,6 +56,7 @@ impl From<&TypeDescription> for TypeDescriptionJson {
 
 #[derive(Serialize, Deserialize)]
 pub struct StructFieldDescriptionJson {
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub docs: Vec<String>,
     pub name: String,
@@ -72,10 +76,12 @@ impl From<&StructFieldDescription> for StructFieldDescriptionJson {
 
 #[derive(Serialize, Deserialize)]
 pub struct EnumVariantDescriptionJson {
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub docs: Vec<String>,
     pub name: String,
     pub discriminant: usize,
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub fields: Vec<StructFieldDescriptionJson>,
 }
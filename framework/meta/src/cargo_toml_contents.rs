@@ -43,6 +43,18 @@ impl CargoTomlContents {
             .expect("failed to write Cargo.toml contents to file");
     }
 
+    /// Assumes that a package section already exists.
+    pub fn package_name(&self) -> String {
+        self.toml_value
+            .get("package")
+            .expect("missing package in Cargo.toml")
+            .get("name")
+            .expect("missing package name in Cargo.toml")
+            .as_str()
+            .expect("package name not a string in Cargo.toml")
+            .to_string()
+    }
+
     /// Assumes that a package section already exists.
     pub fn change_package_name(&mut self, new_package_name: String) {
         let package = self
@@ -55,6 +67,55 @@ impl CargoTomlContents {
             .insert("name".to_string(), toml::Value::String(new_package_name));
     }
 
+    /// Sets the features of a dependency, removing the `features` entry if there are none.
+    ///
+    /// Returns false if the dependency is missing.
+    pub fn change_dependency_features(&mut self, dep_name: &str, features: &[String]) -> bool {
+        let Some(dependency) = self.dependencies_mut().get_mut(dep_name) else {
+            return false;
+        };
+        if let Value::String(version) = dependency {
+            let mut dependency_table = Table::new();
+            dependency_table.insert("version".to_string(), Value::String(version.clone()));
+            *dependency = Value::Table(dependency_table);
+        }
+
+        let dependency_table = dependency
+            .as_table_mut()
+            .expect("malformed dependency in Cargo.toml");
+        if features.is_empty() {
+            dependency_table.remove("features");
+        } else {
+            let feature_values = features.iter().cloned().map(Value::String).collect();
+            dependency_table.insert("features".to_string(), Value::Array(feature_values));
+        }
+        true
+    }
+
+    /// Sets the `opt-level` of the release profile.
+    ///
+    /// Numeric levels are written as integers, as Cargo expects.
+    pub fn change_release_opt_level(&mut self, opt_level: &str) {
+        let opt_level_value = match opt_level.parse::<i64>() {
+            Ok(numeric_level) => Value::Integer(numeric_level),
+            Err(_) => Value::String(opt_level.to_string()),
+        };
+        let root_table = self
+            .toml_value
+            .as_table_mut()
+            .expect("malformed Cargo.toml");
+        let release_profile = root_table
+            .entry("profile")
+            .or_insert_with(|| Value::Table(Table::new()))
+            .as_table_mut()
+            .expect("malformed profile in Cargo.toml")
+            .entry("release")
+            .or_insert_with(|| Value::Table(Table::new()))
+            .as_table_mut()
+            .expect("malformed release profile in Cargo.toml");
+        release_profile.insert("opt-level".to_string(), opt_level_value);
+    }
+
     pub fn dependencies_table(&self) -> Option<&Table> {
         if let Some(deps) = self.toml_value.get(CARGO_TOML_DEPENDENCIES) {
             deps.as_table()
@@ -43,11 +43,21 @@ pub struct BuildArgs {
     )]
     pub extract_imports: bool,
 
+    /// Also generate a JSON report with the size of each function.
+    /// Function names are only available together with `--wasm-symbols`.
+    #[arg(long = "size-report", verbatim_doc_comment)]
+    pub size_report: bool,
+
     /// Allows specifying the target directory where the Rust compiler will build the intermediary files.
     /// Sharing the same target directory can speed up building multiple contract crates at once.
     #[arg(long = "target-dir", verbatim_doc_comment)]
     pub target_dir: Option<String>,
 
+    /// Builds with the settings of a profile from multicontract.toml, e.g. `[profiles.debug]`.
+    /// Unless a suffix is given, the profile name is added to the names of the built contracts.
+    #[arg(long = "profile", verbatim_doc_comment)]
+    pub profile: Option<String>,
+
     /// Generate a twiggy top report after building.
     #[arg(long = "twiggy-top", verbatim_doc_comment)]
     pub twiggy_top: bool,
@@ -92,7 +102,9 @@ impl Default for BuildArgs {
             wat: false,
             emit_mir: false,
             extract_imports: true,
+            size_report: false,
             target_dir: None,
+            profile: None,
             twiggy_top: false,
             twiggy_paths: false,
             twiggy_monos: false,
@@ -108,6 +120,17 @@ impl BuildArgs {
     pub fn has_twiggy_call(&self) -> bool {
         self.twiggy_top || self.twiggy_paths || self.twiggy_monos || self.twiggy_dominators
     }
+
+    /// The arguments that can influence the resulting bytecode, as opposed to only the output location.
+    pub fn to_raw_reproducible(&self) -> Vec<String> {
+        BuildArgs {
+            wasm_name_override: None,
+            wasm_name_suffix: None,
+            target_dir: None,
+            ..self.clone()
+        }
+        .to_raw()
+    }
 }
 
 impl CliArgsToRaw for BuildArgs {
@@ -136,10 +159,17 @@ impl CliArgsToRaw for BuildArgs {
         if !self.extract_imports {
             raw.push("--no-imports".to_string());
         }
+        if self.size_report {
+            raw.push("--size-report".to_string());
+        }
         if let Some(target_dir) = &self.target_dir {
             raw.push("--target-dir".to_string());
             raw.push(target_dir.clone());
         }
+        if let Some(profile) = &self.profile {
+            raw.push("--profile".to_string());
+            raw.push(profile.clone());
+        }
         if self.twiggy_top {
             raw.push("--twiggy-top".to_string());
         }
@@ -66,6 +66,12 @@ pub enum ContractCliAction {
         about = "Generates a snippets project, based on the contract ABI."
     )]
     GenerateSnippets(GenerateSnippetsArgs),
+
+    #[command(
+        name = "generate-ts",
+        about = "Generates TypeScript types, codecs and call builders, based on the contract ABI."
+    )]
+    GenerateTs,
 }
 
 impl CliArgsToRaw for ContractCliAction {
@@ -97,6 +103,9 @@ impl CliArgsToRaw for ContractCliAction {
                 raw.push("snippets".to_string());
                 raw.append(&mut args.to_raw());
             },
+            ContractCliAction::GenerateTs => {
+                raw.push("generate-ts".to_string());
+            },
         }
         raw
     }
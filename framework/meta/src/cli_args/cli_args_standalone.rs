@@ -48,6 +48,48 @@ pub enum StandaloneCliAction {
         about = "Generates a report on the local depedencies of contract crates. Will explore indirect depdencies too."
     )]
     LocalDeps(LocalDepsArgs),
+
+    #[command(
+        name = "scen-fmt",
+        about = "Formats all JSON scenario files under given path. Use --check to only list unformatted files."
+    )]
+    ScenFmt(ScenFmtArgs),
+
+    #[command(
+        name = "abi-diff",
+        about = "Lists the changes between two versions of a contract ABI. Fails if any of them is breaking."
+    )]
+    AbiDiff(AbiDiffArgs),
+
+    #[command(
+        name = "generate-proxy",
+        about = "Generates a Rust module with the proxy, types and event decoders of a contract, based on its ABI JSON."
+    )]
+    GenerateProxy(GenerateProxyArgs),
+
+    #[command(
+        name = "new",
+        about = "Creates a new contract crate from one of the template contracts."
+    )]
+    New(NewArgs),
+
+    #[command(
+        name = "size-diff",
+        about = "Compares a contract size report against a baseline. Fails if the contract grew beyond the tolerance."
+    )]
+    SizeDiff(SizeDiffArgs),
+
+    #[command(
+        name = "verify",
+        about = "Rebuilds a contract with the recorded settings and checks that it matches the given code hash."
+    )]
+    Verify(VerifyArgs),
+
+    #[command(
+        name = "test-gen",
+        about = "Keeps the scenario test files of contract crates in sync with their scenarios directories."
+    )]
+    TestGen(TestGenArgs),
 }
 
 #[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
@@ -128,3 +170,129 @@ pub struct LocalDepsArgs {
     #[clap(global = true, default_value = "target")]
     pub ignore: Vec<String>,
 }
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct ScenFmtArgs {
+    /// Target directory where to format scenario files.
+    /// Will be current directory if not specified.
+    #[arg(long, verbatim_doc_comment)]
+    pub path: Option<String>,
+
+    /// Ignore all directories with these names.
+    #[arg(long, verbatim_doc_comment)]
+    #[clap(global = true, default_value = "target")]
+    pub ignore: Vec<String>,
+
+    /// Does not modify any files, fails if any of them is not formatted.
+    #[arg(long, verbatim_doc_comment)]
+    pub check: bool,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct AbiDiffArgs {
+    /// Path to the ABI JSON of the previous version.
+    #[arg(verbatim_doc_comment)]
+    pub old: String,
+
+    /// Path to the ABI JSON of the new version.
+    #[arg(verbatim_doc_comment)]
+    pub new: String,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct GenerateProxyArgs {
+    /// Path to the ABI JSON of the contract.
+    #[arg(verbatim_doc_comment)]
+    pub abi: String,
+
+    /// Path of the generated Rust module.
+    /// Will be next to the ABI, e.g. `adder_proxy.rs` for `adder.abi.json`, if not specified.
+    #[arg(long, verbatim_doc_comment)]
+    pub output: Option<String>,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct NewArgs {
+    /// The contract to copy. Any contract crate under the templates directory can be used,
+    /// e.g. `adder` or `crowdfunding-esdt`.
+    #[arg(long, verbatim_doc_comment)]
+    pub template: String,
+
+    /// The name of the new contract crate.
+    /// The new crate directory will also receive this name.
+    #[arg(long, verbatim_doc_comment)]
+    pub name: String,
+
+    /// Target directory where to create the new contract crate directory.
+    /// Will be current directory if not specified.
+    #[arg(long, verbatim_doc_comment)]
+    pub path: Option<String>,
+
+    /// Directory where to look for templates.
+    /// If not specified, the `contracts/examples` directory of the framework repository is used,
+    /// looking for the repository in the current directory and its parents.
+    #[arg(long = "templates-path", verbatim_doc_comment)]
+    pub templates_path: Option<String>,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct SizeDiffArgs {
+    /// Path to the baseline size report, e.g. a stored `adder.sizes.json`.
+    #[arg(verbatim_doc_comment)]
+    pub baseline: String,
+
+    /// Path to the size report of the current build.
+    #[arg(verbatim_doc_comment)]
+    pub current: String,
+
+    /// Number of bytes the contract is allowed to grow before the command fails.
+    #[arg(long, default_value_t = 0, verbatim_doc_comment)]
+    pub tolerance: usize,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct VerifyArgs {
+    /// Path to the contract crate.
+    /// Will be current directory if not specified.
+    #[arg(long, verbatim_doc_comment)]
+    pub path: Option<String>,
+
+    /// The output contract to verify, for crates producing several contracts.
+    /// Will be the contract crate name if not specified.
+    #[arg(long, verbatim_doc_comment)]
+    pub contract: Option<String>,
+
+    /// The wasm file to compare against, e.g. one downloaded from the chain.
+    #[arg(long, verbatim_doc_comment)]
+    pub wasm: Option<String>,
+
+    /// The code hash to compare against, in hex or base64, as found in the account data.
+    /// If neither this nor the wasm file is specified, the wasm from the output directory is used.
+    #[arg(long = "code-hash", verbatim_doc_comment)]
+    pub code_hash: Option<String>,
+
+    /// Also write a .tar.gz with the contract sources and build records, if the build is verified.
+    #[arg(long, verbatim_doc_comment)]
+    pub package: Option<String>,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct TestGenArgs {
+    /// Target directory where to update the scenario test files of all contract crates.
+    /// Will be current directory if not specified.
+    #[arg(long, verbatim_doc_comment)]
+    pub path: Option<String>,
+
+    /// Ignore all directories with these names.
+    #[arg(long, verbatim_doc_comment)]
+    #[clap(global = true, default_value = "target")]
+    pub ignore: Vec<String>,
+
+    /// Also create the scenario test files for contract crates that do not have them yet.
+    #[arg(long, verbatim_doc_comment)]
+    pub create: bool,
+
+    /// Does not modify any files, fails if any of them is out of sync with the scenarios.
+    #[arg(long, verbatim_doc_comment)]
+    pub check: bool,
+}
@@ -0,0 +1,214 @@
+/// The functions available to contracts in the first version of the VM Executive Interface.
+pub const EI_1_0_NAMES: &[&str] = &[
+    "getSCAddress",
+    "managedSCAddress",
+    "getOwnerAddress",
+    "managedOwnerAddress",
+    "getCaller",
+    "managedCaller",
+    "getShardOfAddress",
+    "isSmartContract",
+    "blockHash",
+    "getFunction",
+    "getGasLeft",
+    "getBlockTimestamp",
+    "getBlockNonce",
+    "getBlockRound",
+    "getBlockEpoch",
+    "getBlockRandomSeed",
+    "getStateRootHash",
+    "getPrevBlockTimestamp",
+    "getPrevBlockNonce",
+    "getPrevBlockRound",
+    "getPrevBlockEpoch",
+    "getPrevBlockRandomSeed",
+    "getOriginalTxHash",
+    "managedGetPrevBlockRandomSeed",
+    "managedGetBlockRandomSeed",
+    "managedGetStateRootHash",
+    "managedGetOriginalTxHash",
+    "bigIntSetInt64",
+    "bigIntGetExternalBalance",
+    "bigIntGetESDTExternalBalance",
+    "getCurrentESDTNFTNonce",
+    "getESDTTokenData",
+    "getESDTNFTNameLength",
+    "getESDTNFTAttributeLength",
+    "getESDTNFTURILength",
+    "managedGetESDTTokenData",
+    "getESDTLocalRoles",
+    "checkNoPayment",
+    "bigIntGetCallValue",
+    "managedGetMultiESDTCallValue",
+    "getNumESDTTransfers",
+    "bigIntGetESDTCallValue",
+    "getESDTTokenName",
+    "getESDTTokenNonce",
+    "getESDTTokenType",
+    "bigIntGetESDTCallValueByIndex",
+    "getESDTTokenNameByIndex",
+    "getESDTTokenNonceByIndex",
+    "getESDTTokenTypeByIndex",
+    "sha256",
+    "managedSha256",
+    "keccak256",
+    "managedKeccak256",
+    "ripemd160",
+    "managedRipemd160",
+    "verifyBLS",
+    "managedVerifyBLS",
+    "verifyEd25519",
+    "managedVerifyEd25519",
+    "verifySecp256k1",
+    "managedVerifySecp256k1",
+    "verifyCustomSecp256k1",
+    "managedVerifyCustomSecp256k1",
+    "encodeSecp256k1DerSignature",
+    "managedEncodeSecp256k1DerSignature",
+    "getNumArguments",
+    "getArgumentLength",
+    "getArgument",
+    "mBufferGetArgument",
+    "bigIntGetUnsignedArgument",
+    "bigIntGetSignedArgument",
+    "smallIntGetUnsignedArgument",
+    "smallIntGetSignedArgument",
+    "finish",
+    "bigIntFinishUnsigned",
+    "bigIntFinishSigned",
+    "smallIntFinishUnsigned",
+    "smallIntFinishSigned",
+    "mBufferFinish",
+    "managedSignalError",
+    "writeLog",
+    "writeEventLog",
+    "managedWriteLog",
+    "bigFloatNewFromParts",
+    "bigFloatNewFromFrac",
+    "bigFloatNewFromSci",
+    "bigFloatAdd",
+    "bigFloatSub",
+    "bigFloatMul",
+    "bigFloatDiv",
+    "bigFloatAbs",
+    "bigFloatNeg",
+    "bigFloatCmp",
+    "bigFloatSign",
+    "bigFloatClone",
+    "bigFloatSqrt",
+    "bigFloatPow",
+    "bigFloatFloor",
+    "bigFloatCeil",
+    "bigFloatTruncate",
+    "bigFloatIsInt",
+    "bigFloatSetInt64",
+    "bigFloatSetBigInt",
+    "bigFloatGetConstPi",
+    "bigFloatGetConstE",
+    "bigIntNew",
+    "bigIntUnsignedByteLength",
+    "bigIntGetUnsignedBytes",
+    "bigIntSetUnsignedBytes",
+    "bigIntSignedByteLength",
+    "bigIntGetSignedBytes",
+    "bigIntSetSignedBytes",
+    "bigIntIsInt64",
+    "bigIntGetInt64",
+    "bigIntAdd",
+    "bigIntSub",
+    "bigIntMul",
+    "bigIntTDiv",
+    "bigIntTMod",
+    "bigIntAbs",
+    "bigIntNeg",
+    "bigIntSign",
+    "bigIntCmp",
+    "bigIntSqrt",
+    "bigIntPow",
+    "bigIntLog2",
+    "bigIntAnd",
+    "bigIntOr",
+    "bigIntXor",
+    "bigIntShr",
+    "bigIntShl",
+    "bigIntToString",
+    "createEC",
+    "managedCreateEC",
+    "ellipticCurveGetValues",
+    "getCurveLengthEC",
+    "getPrivKeyByteLengthEC",
+    "addEC",
+    "doubleEC",
+    "isOnCurveEC",
+    "scalarMultEC",
+    "managedScalarMultEC",
+    "scalarBaseMultEC",
+    "managedScalarBaseMultEC",
+    "marshalEC",
+    "managedMarshalEC",
+    "marshalCompressedEC",
+    "managedMarshalCompressedEC",
+    "unmarshalEC",
+    "managedUnmarshalEC",
+    "unmarshalCompressedEC",
+    "managedUnmarshalCompressedEC",
+    "generateKeyEC",
+    "managedGenerateKeyEC",
+    "mBufferNew",
+    "mBufferNewFromBytes",
+    "mBufferGetLength",
+    "mBufferGetBytes",
+    "mBufferGetByteSlice",
+    "mBufferCopyByteSlice",
+    "mBufferEq",
+    "mBufferSetBytes",
+    "mBufferSetByteSlice",
+    "mBufferSetRandom",
+    "mBufferAppend",
+    "mBufferAppendBytes",
+    "mBufferToBigIntUnsigned",
+    "mBufferToBigIntSigned",
+    "mBufferFromBigIntUnsigned",
+    "mBufferFromBigIntSigned",
+    "mBufferToBigFloat",
+    "mBufferFromBigFloat",
+    "transferValue",
+    "transferValueExecute",
+    "transferESDTExecute",
+    "transferESDTNFTExecute",
+    "multiTransferESDTNFTExecute",
+    "asyncCall",
+    "createContract",
+    "deployFromSourceContract",
+    "upgradeFromSourceContract",
+    "upgradeContract",
+    "executeOnDestContext",
+    "executeOnSameContext",
+    "executeReadOnly",
+    "managedMultiTransferESDTNFTExecute",
+    "managedTransferValueExecute",
+    "managedExecuteOnDestContext",
+    "managedExecuteOnSameContext",
+    "managedExecuteReadOnly",
+    "managedCreateContract",
+    "managedDeployFromSourceContract",
+    "managedUpgradeContract",
+    "managedUpgradeFromSourceContract",
+    "managedAsyncCall",
+    "managedCreateAsyncCall",
+    "getNumReturnData",
+    "managedGetReturnData",
+    "getReturnDataSize",
+    "getReturnData",
+    "cleanReturnData",
+    "deleteFromReturnData",
+    "storageStore",
+    "storageLoadLength",
+    "storageLoad",
+    "bigIntStorageStoreUnsigned",
+    "bigIntStorageLoadUnsigned",
+    "mBufferStorageStore",
+    "mBufferStorageLoad",
+    "mBufferStorageLoadFromAddress",
+    "signalError",
+];
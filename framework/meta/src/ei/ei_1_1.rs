@@ -0,0 +1,9 @@
+/// The functions added to the Executive Interface in version 1.1.
+pub const EI_1_1_ADDED_NAMES: &[&str] = &[
+    "managedIsESDTFrozen",
+    "managedIsESDTPaused",
+    "managedIsESDTLimitedTransfer",
+    "managedBufferToHex",
+    "validateTokenIdentifier",
+    "managedGetCallbackClosure",
+];
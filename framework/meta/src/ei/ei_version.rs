@@ -0,0 +1,40 @@
+use super::{EI_1_0_NAMES, EI_1_1_ADDED_NAMES};
+
+/// Versions of the VM Executive Interface (EI), i.e. the set of functions the VM provides to contracts.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum EIVersion {
+    V1_0,
+    #[default]
+    V1_1,
+}
+
+impl EIVersion {
+    /// Parses the version, as written in `multicontract.toml`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "1.0" => Some(EIVersion::V1_0),
+            "1.1" => Some(EIVersion::V1_1),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            EIVersion::V1_0 => "1.0",
+            EIVersion::V1_1 => "1.1",
+        }
+    }
+
+    /// Each version keeps all functions from the previous one.
+    pub fn ei_names(&self) -> Vec<&'static str> {
+        let mut names = EI_1_0_NAMES.to_vec();
+        if *self >= EIVersion::V1_1 {
+            names.extend_from_slice(EI_1_1_ADDED_NAMES);
+        }
+        names
+    }
+
+    pub fn contains_ei_function(&self, name: &str) -> bool {
+        self.ei_names().contains(&name)
+    }
+}
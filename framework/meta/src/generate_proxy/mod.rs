@@ -0,0 +1,8 @@
+mod proxy_event_gen;
+mod proxy_gen_common;
+mod proxy_gen_main;
+mod proxy_trait_gen;
+mod proxy_type_gen;
+mod proxy_type_map;
+
+pub use proxy_gen_main::generate_proxy_module;
@@ -0,0 +1,187 @@
+use std::fmt::Write;
+
+use crate::abi_json::{AbiTypeExpr, EventAbiJson, EventInputAbiJson};
+
+use super::{
+    proxy_gen_common::{rust_identifier, rust_type_name, write_docs},
+    proxy_type_map::{RustTypeMapper, TYPE_API_GENERIC},
+};
+
+const EVENT_TYPE_NAME_SUFFIX: &str = "Event";
+
+fn event_type_name(event: &EventAbiJson) -> String {
+    let type_name = rust_type_name(&event.identifier);
+    if type_name.ends_with(EVENT_TYPE_NAME_SUFFIX) {
+        type_name
+    } else {
+        format!("{type_name}{EVENT_TYPE_NAME_SUFFIX}")
+    }
+}
+
+fn input_rust_type(mapper: &RustTypeMapper, input: &EventInputAbiJson) -> String {
+    mapper.rust_type(&AbiTypeExpr::parse(&input.type_name), TYPE_API_GENERIC)
+}
+
+fn is_indexed(input: &EventInputAbiJson) -> bool {
+    input.indexed.unwrap_or_default()
+}
+
+/// A struct with the event arguments, that decodes them from the topics and data of a log entry.
+///
+/// The first topic is the event identifier, followed by one topic for each indexed argument.
+/// The non-indexed arguments are encoded together in the data, as if they were fields of a struct.
+pub(super) fn write_event_decoder(out: &mut String, mapper: &RustTypeMapper, event: &EventAbiJson) {
+    let type_name = event_type_name(event);
+    let managed = event
+        .inputs
+        .iter()
+        .any(|input| mapper.expr_is_managed(&AbiTypeExpr::parse(&input.type_name)));
+    let (impl_generics, type_generics, method_generics) = if managed {
+        (
+            format!("<{TYPE_API_GENERIC}: ManagedTypeApi>"),
+            format!("<{TYPE_API_GENERIC}>"),
+            String::new(),
+        )
+    } else {
+        (
+            String::new(),
+            String::new(),
+            format!("<{TYPE_API_GENERIC}: ManagedTypeApi>"),
+        )
+    };
+
+    write_docs(out, "", &event.docs);
+    writeln!(out, "#[derive(Clone, PartialEq, Debug)]").unwrap();
+    if event.inputs.is_empty() {
+        writeln!(out, "pub struct {type_name};").unwrap();
+    } else {
+        writeln!(out, "pub struct {type_name}{impl_generics} {{").unwrap();
+        for input in &event.inputs {
+            writeln!(
+                out,
+                "    pub {}: {},",
+                rust_identifier(&input.arg_name),
+                input_rust_type(mapper, input)
+            )
+            .unwrap();
+        }
+        writeln!(out, "}}").unwrap();
+    }
+    writeln!(out).unwrap();
+
+    writeln!(out, "impl{impl_generics} {type_name}{type_generics} {{").unwrap();
+    writeln!(
+        out,
+        "    pub const IDENTIFIER: &'static [u8] = b\"{}\";",
+        event.identifier
+    )
+    .unwrap();
+    writeln!(out).unwrap();
+    writeln!(
+        out,
+        "    /// Decodes the event from the topics and the data of a log entry."
+    )
+    .unwrap();
+    writeln!(out, "    pub fn decode{method_generics}(").unwrap();
+    writeln!(
+        out,
+        "        topics: &ManagedVec<{TYPE_API_GENERIC}, ManagedBuffer<{TYPE_API_GENERIC}>>,"
+    )
+    .unwrap();
+    writeln!(out, "        data: &ManagedBuffer<{TYPE_API_GENERIC}>,").unwrap();
+    writeln!(out, "    ) -> Result<Self, DecodeError> {{").unwrap();
+    write_decode_body(out, mapper, &type_name, &event.inputs);
+    writeln!(out, "    }}").unwrap();
+    writeln!(out, "}}").unwrap();
+}
+
+fn write_decode_body(
+    out: &mut String,
+    mapper: &RustTypeMapper,
+    type_name: &str,
+    inputs: &[EventInputAbiJson],
+) {
+    let indexed: Vec<&EventInputAbiJson> =
+        inputs.iter().filter(|input| is_indexed(input)).collect();
+    let data: Vec<&EventInputAbiJson> = inputs.iter().filter(|input| !is_indexed(input)).collect();
+
+    // `Ok` and `Err` are shadowed by the `SCResult` variants in the contract imports.
+    writeln!(out, "        if topics.len() != {} {{", indexed.len() + 1).unwrap();
+    writeln!(
+        out,
+        "            return Result::Err(DecodeError::from(\"wrong number of event topics\"));"
+    )
+    .unwrap();
+    writeln!(out, "        }}").unwrap();
+    writeln!(out, "        if *topics.get(0) != *Self::IDENTIFIER {{").unwrap();
+    writeln!(
+        out,
+        "            return Result::Err(DecodeError::from(\"wrong event identifier\"));"
+    )
+    .unwrap();
+    writeln!(out, "        }}").unwrap();
+
+    for (index, input) in indexed.iter().enumerate() {
+        writeln!(
+            out,
+            "        let {} = <{}>::top_decode((*topics.get({})).clone())?;",
+            rust_identifier(&input.arg_name),
+            input_rust_type(mapper, input),
+            index + 1
+        )
+        .unwrap();
+    }
+
+    match data.as_slice() {
+        [] => {
+            writeln!(out, "        if !data.is_empty() {{").unwrap();
+            writeln!(
+                out,
+                "            return Result::Err(DecodeError::INPUT_TOO_LONG);"
+            )
+            .unwrap();
+            writeln!(out, "        }}").unwrap();
+        },
+        [input] => {
+            writeln!(
+                out,
+                "        let {} = <{}>::top_decode(data.clone())?;",
+                rust_identifier(&input.arg_name),
+                input_rust_type(mapper, input)
+            )
+            .unwrap();
+        },
+        _ => {
+            let names: Vec<String> = data
+                .iter()
+                .map(|input| rust_identifier(&input.arg_name))
+                .collect();
+            let types: Vec<String> = data
+                .iter()
+                .map(|input| input_rust_type(mapper, input))
+                .collect();
+            writeln!(
+                out,
+                "        let ({}) = <({})>::top_decode(data.clone())?;",
+                names.join(", "),
+                types.join(", ")
+            )
+            .unwrap();
+        },
+    }
+
+    if inputs.is_empty() {
+        writeln!(out, "        Result::Ok({type_name})").unwrap();
+    } else {
+        let names: Vec<String> = inputs
+            .iter()
+            .map(|input| rust_identifier(&input.arg_name))
+            .collect();
+        writeln!(
+            out,
+            "        Result::Ok({type_name} {{ {} }})",
+            names.join(", ")
+        )
+        .unwrap();
+    }
+}
@@ -0,0 +1,60 @@
+use std::fmt::Write;
+
+/// ABI names are allowed to clash with these, Rust identifiers are not.
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "box", "break", "const", "continue", "crate", "dyn", "else", "enum",
+    "extern", "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move",
+    "mut", "pub", "ref", "return", "static", "struct", "trait", "true", "try", "type", "unsafe",
+    "use", "where", "while", "yield",
+];
+
+pub(super) fn write_docs(out: &mut String, indent: &str, docs: &[String]) {
+    for doc in docs {
+        let line = doc.trim_end();
+        if line.is_empty() {
+            writeln!(out, "{indent}///").unwrap();
+        } else {
+            writeln!(out, "{indent}/// {line}").unwrap();
+        }
+    }
+}
+
+/// Converts an endpoint name, such as `getSum`, to a method name, such as `get_sum`.
+pub(super) fn rust_method_name(endpoint_name: &str) -> String {
+    let mut result = String::new();
+    for c in endpoint_name.chars() {
+        if c.is_uppercase() {
+            if !result.is_empty() && !result.ends_with('_') {
+                result.push('_');
+            }
+            result.extend(c.to_lowercase());
+        } else {
+            result.push(c);
+        }
+    }
+    rust_identifier(&result)
+}
+
+/// Converts an event identifier, such as `payable-event`, to a type name, such as `PayableEvent`.
+pub(super) fn rust_type_name(identifier: &str) -> String {
+    let mut result = String::new();
+    for part in identifier
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|part| !part.is_empty())
+    {
+        let mut chars = part.chars();
+        if let Some(first) = chars.next() {
+            result.extend(first.to_uppercase());
+            result.push_str(chars.as_str());
+        }
+    }
+    result
+}
+
+pub(super) fn rust_identifier(name: &str) -> String {
+    if RUST_KEYWORDS.contains(&name) {
+        format!("r#{name}")
+    } else {
+        name.to_string()
+    }
+}
@@ -0,0 +1,32 @@
+use crate::abi_json::ContractAbiJson;
+
+use super::{
+    proxy_event_gen::write_event_decoder, proxy_trait_gen::write_proxy_trait,
+    proxy_type_gen::write_type, proxy_type_map::RustTypeMapper,
+};
+
+/// Generates a standalone Rust module for calling a contract, based only on its ABI:
+/// the proxy trait, the structs and enums used in the endpoints and a decoder for each event.
+pub fn generate_proxy_module(abi: &ContractAbiJson) -> String {
+    let mapper = RustTypeMapper::new(&abi.types);
+    let mut out = String::new();
+    out.push_str(&format!(
+        "// Code generated by the multiversx-sc-meta proxy generator. DO NOT EDIT.\n\
+         // Contract: {}\n\n",
+        abi.name
+    ));
+    out.push_str("multiversx_sc::imports!();\n");
+    out.push_str("multiversx_sc::derive_imports!();\n\n");
+    write_proxy_trait(&mut out, &mapper, abi);
+
+    for (type_name, type_description) in mapper.generated_types() {
+        out.push('\n');
+        write_type(&mut out, &mapper, type_name, type_description);
+    }
+
+    for event in &abi.events {
+        out.push('\n');
+        write_event_decoder(&mut out, &mapper, event);
+    }
+    out
+}
@@ -0,0 +1,127 @@
+use std::fmt::Write;
+
+use crate::abi_json::{
+    AbiTypeExpr, ContractAbiJson, EndpointMutabilityAbiJson, InputAbiJson, OutputAbiJson,
+};
+
+use super::{
+    proxy_gen_common::{rust_identifier, rust_method_name, write_docs},
+    proxy_type_map::RustTypeMapper,
+};
+
+const INDENT: &str = "    ";
+const MAX_LINE_WIDTH: usize = 100;
+
+/// Inside the proxy trait, managed types are always instantiated with the contract API.
+const PROXY_API: &str = "Self::Api";
+
+/// Payments in any token are declared with a wildcard, as are the endpoints accepting several specific tokens.
+const ANY_TOKEN: &str = "*";
+
+fn input_params(mapper: &RustTypeMapper, inputs: &[InputAbiJson]) -> Vec<String> {
+    let mut params = vec!["&self".to_string()];
+    for input in inputs {
+        params.push(format!(
+            "{}: {}",
+            rust_identifier(&input.arg_name),
+            mapper.multi_rust_type(&AbiTypeExpr::parse(&input.type_name), PROXY_API)
+        ));
+    }
+    params
+}
+
+/// Keeps the lines within the usual width, by placing the parameters on separate lines if needed.
+fn write_method_signature(
+    out: &mut String,
+    method_name: &str,
+    params: &[String],
+    return_type: &str,
+) {
+    let single_line = format!(
+        "{INDENT}fn {method_name}({}){return_type};",
+        params.join(", ")
+    );
+    if single_line.len() <= MAX_LINE_WIDTH {
+        writeln!(out, "{single_line}").unwrap();
+        return;
+    }
+
+    writeln!(out, "{INDENT}fn {method_name}(").unwrap();
+    for param in params {
+        writeln!(out, "{INDENT}{INDENT}{param},").unwrap();
+    }
+    writeln!(out, "{INDENT}){return_type};").unwrap();
+}
+
+/// Several results are returned as a multi-value, same as the original endpoint had to.
+fn return_type(mapper: &RustTypeMapper, outputs: &[OutputAbiJson]) -> String {
+    let exprs: Vec<AbiTypeExpr> = outputs
+        .iter()
+        .map(|output| AbiTypeExpr::parse(&output.type_name))
+        .collect();
+    match exprs.len() {
+        0 => String::new(),
+        1 => format!(" -> {}", mapper.multi_rust_type(&exprs[0], PROXY_API)),
+        _ => format!(
+            " -> {}",
+            mapper.multi_rust_type(
+                &AbiTypeExpr {
+                    name: "multi".to_string(),
+                    args: exprs,
+                },
+                PROXY_API
+            )
+        ),
+    }
+}
+
+fn write_payable(out: &mut String, payable_in_tokens: &[String]) {
+    match payable_in_tokens {
+        [] => {},
+        [token] => writeln!(out, "{INDENT}#[payable(\"{token}\")]").unwrap(),
+        _ => writeln!(out, "{INDENT}#[payable(\"{ANY_TOKEN}\")]").unwrap(),
+    }
+}
+
+/// The proxy trait, with a method for the constructor and for each endpoint.
+pub(super) fn write_proxy_trait(out: &mut String, mapper: &RustTypeMapper, abi: &ContractAbiJson) {
+    write_docs(out, "", &abi.docs);
+    writeln!(out, "#[multiversx_sc::proxy]").unwrap();
+    writeln!(out, "pub trait {} {{", abi.name).unwrap();
+
+    let mut first = true;
+    if let Some(constructor) = &abi.constructor {
+        write_docs(out, INDENT, &constructor.docs);
+        writeln!(out, "{INDENT}#[init]").unwrap();
+        write_payable(out, &constructor.payable_in_tokens);
+        write_method_signature(
+            out,
+            "init",
+            &input_params(mapper, &constructor.inputs),
+            &return_type(mapper, &constructor.outputs),
+        );
+        first = false;
+    }
+
+    for endpoint in &abi.endpoints {
+        if !first {
+            writeln!(out).unwrap();
+        }
+        first = false;
+
+        write_docs(out, INDENT, &endpoint.docs);
+        let endpoint_attr = match endpoint.mutability {
+            EndpointMutabilityAbiJson::Readonly => "view",
+            _ => "endpoint",
+        };
+        writeln!(out, "{INDENT}#[{endpoint_attr}({})]", endpoint.name).unwrap();
+        write_payable(out, &endpoint.payable_in_tokens);
+        write_method_signature(
+            out,
+            &rust_method_name(&endpoint.name),
+            &input_params(mapper, &endpoint.inputs),
+            &return_type(mapper, &endpoint.outputs),
+        );
+    }
+    writeln!(out, "}}").unwrap();
+}
@@ -0,0 +1,141 @@
+use std::{collections::BTreeSet, fmt::Write};
+
+use crate::abi_json::{
+    AbiTypeExpr, EnumVariantDescriptionJson, StructFieldDescriptionJson, TypeDescriptionJson,
+};
+
+use super::{
+    proxy_gen_common::{rust_identifier, write_docs},
+    proxy_type_map::{has_tuple_field_names, RustTypeMapper, TYPE_API_GENERIC},
+};
+
+const STRUCT_CONTENT_TYPE: &str = "struct";
+const ENUM_CONTENT_TYPE: &str = "enum";
+
+const DERIVES: &str =
+    "TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug";
+const MANAGED_VEC_ITEM_DERIVE: &str = "ManagedVecItem";
+
+/// Writes a struct or an enum with the same encoding and ABI as the original contract type.
+pub(super) fn write_type(
+    out: &mut String,
+    mapper: &RustTypeMapper,
+    type_name: &str,
+    type_description: &TypeDescriptionJson,
+) {
+    write_docs(out, "", &type_description.docs);
+    writeln!(out, "#[derive({DERIVES})]").unwrap();
+    if mapper.is_managed_vec_item(type_name) {
+        writeln!(out, "#[derive({MANAGED_VEC_ITEM_DERIVE})]").unwrap();
+    }
+
+    let generics = if mapper.is_managed_type(type_name) {
+        format!("<{TYPE_API_GENERIC}: ManagedTypeApi>")
+    } else {
+        String::new()
+    };
+    let boxed_types = mapper.types_reaching(type_name);
+    match type_description.content_type.as_str() {
+        STRUCT_CONTENT_TYPE => write_struct(
+            out,
+            mapper,
+            &format!("{type_name}{generics}"),
+            &type_description.fields,
+            &boxed_types,
+        ),
+        ENUM_CONTENT_TYPE => write_enum(
+            out,
+            mapper,
+            &format!("{type_name}{generics}"),
+            &type_description.variants,
+            &boxed_types,
+        ),
+        other => panic!("unsupported content type {other} for type {type_name}"),
+    }
+}
+
+fn field_rust_type(
+    mapper: &RustTypeMapper,
+    field: &StructFieldDescriptionJson,
+    boxed_types: &BTreeSet<String>,
+) -> String {
+    mapper.field_rust_type(
+        &AbiTypeExpr::parse(&field.field_type),
+        TYPE_API_GENERIC,
+        boxed_types,
+    )
+}
+
+fn write_struct(
+    out: &mut String,
+    mapper: &RustTypeMapper,
+    declaration: &str,
+    fields: &[StructFieldDescriptionJson],
+    boxed_types: &BTreeSet<String>,
+) {
+    if fields.is_empty() {
+        writeln!(out, "pub struct {declaration};").unwrap();
+    } else if has_tuple_field_names(fields) {
+        let field_types: Vec<String> = fields
+            .iter()
+            .map(|field| format!("pub {}", field_rust_type(mapper, field, boxed_types)))
+            .collect();
+        writeln!(out, "pub struct {declaration}({});", field_types.join(", ")).unwrap();
+    } else {
+        writeln!(out, "pub struct {declaration} {{").unwrap();
+        write_named_fields(out, "    ", "pub ", mapper, fields, boxed_types);
+        writeln!(out, "}}").unwrap();
+    }
+}
+
+fn write_named_fields(
+    out: &mut String,
+    indent: &str,
+    visibility: &str,
+    mapper: &RustTypeMapper,
+    fields: &[StructFieldDescriptionJson],
+    boxed_types: &BTreeSet<String>,
+) {
+    for field in fields {
+        write_docs(out, indent, &field.docs);
+        writeln!(
+            out,
+            "{indent}{visibility}{}: {},",
+            rust_identifier(&field.name),
+            field_rust_type(mapper, field, boxed_types)
+        )
+        .unwrap();
+    }
+}
+
+/// Variants are declared in discriminant order, since the codec derives number them by position.
+fn write_enum(
+    out: &mut String,
+    mapper: &RustTypeMapper,
+    declaration: &str,
+    variants: &[EnumVariantDescriptionJson],
+    boxed_types: &BTreeSet<String>,
+) {
+    let mut variants: Vec<&EnumVariantDescriptionJson> = variants.iter().collect();
+    variants.sort_by_key(|variant| variant.discriminant);
+
+    writeln!(out, "pub enum {declaration} {{").unwrap();
+    for variant in variants {
+        write_docs(out, "    ", &variant.docs);
+        if variant.fields.is_empty() {
+            writeln!(out, "    {},", variant.name).unwrap();
+        } else if has_tuple_field_names(&variant.fields) {
+            let field_types: Vec<String> = variant
+                .fields
+                .iter()
+                .map(|field| field_rust_type(mapper, field, boxed_types))
+                .collect();
+            writeln!(out, "    {}({}),", variant.name, field_types.join(", ")).unwrap();
+        } else {
+            writeln!(out, "    {} {{", variant.name).unwrap();
+            write_named_fields(out, "        ", "", mapper, &variant.fields, boxed_types);
+            writeln!(out, "    }},").unwrap();
+        }
+    }
+    writeln!(out, "}}").unwrap();
+}
@@ -0,0 +1,391 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::abi_json::{AbiTypeExpr, StructFieldDescriptionJson, TypeDescriptionJson};
+
+/// Rust type for the types known to the framework.
+/// The `managed` flag indicates that the type takes the managed type API as first generic argument.
+const LEAF_TYPES: &[(&str, &str, bool)] = &[
+    ("u8", "u8", false),
+    ("u16", "u16", false),
+    ("u32", "u32", false),
+    ("u64", "u64", false),
+    ("usize", "usize", false),
+    ("NonZeroUsize", "core::num::NonZeroUsize", false),
+    ("i8", "i8", false),
+    ("i16", "i16", false),
+    ("i32", "i32", false),
+    ("i64", "i64", false),
+    ("isize", "isize", false),
+    ("bool", "bool", false),
+    ("BigUint", "BigUint", true),
+    ("BigInt", "BigInt", true),
+    ("BigFloat", "BigFloat", true),
+    ("bytes", "ManagedBuffer", true),
+    ("utf-8 string", "ManagedBuffer", true),
+    ("Address", "ManagedAddress", true),
+    ("TokenIdentifier", "TokenIdentifier", true),
+    (
+        "EgldOrEsdtTokenIdentifier",
+        "EgldOrEsdtTokenIdentifier",
+        true,
+    ),
+    ("CodeMetadata", "CodeMetadata", false),
+    (
+        "OperationCompletionStatus",
+        "OperationCompletionStatus",
+        false,
+    ),
+    ("()", "()", false),
+];
+
+/// Types that also show up in the ABI type descriptions, but are taken from the framework instead of being generated.
+const FRAMEWORK_TYPES: &[(&str, bool)] = &[
+    ("EsdtTokenPayment", true),
+    ("EsdtTokenData", true),
+    ("EgldOrEsdtTokenPayment", true),
+    ("EsdtLocalRole", false),
+    ("EsdtTokenType", false),
+];
+
+/// Types that can be stored in a `ManagedVec`, out of the leaf and framework types.
+const MANAGED_VEC_ITEM_TYPES: &[&str] = &[
+    "u8",
+    "u16",
+    "u32",
+    "u64",
+    "usize",
+    "i32",
+    "i64",
+    "bool",
+    "BigUint",
+    "BigInt",
+    "bytes",
+    "utf-8 string",
+    "Address",
+    "H256",
+    "TokenIdentifier",
+    "EgldOrEsdtTokenIdentifier",
+    "EsdtTokenPayment",
+    "EsdtLocalRole",
+];
+
+const ENUM_CONTENT_TYPE: &str = "enum";
+
+const H256_TYPE_NAME: &str = "H256";
+const H256_LENGTH: usize = 32;
+
+/// Types the generator knows nothing about are passed around as raw top-encoded bytes.
+const RAW_BYTES_RUST_TYPE: &str = "ManagedBuffer";
+
+/// Boxes recursive fields, just like they would have to be boxed in the original contract.
+const BOX_RUST_TYPE: &str = "multiversx_sc::types::heap::Box";
+
+/// Lists of items that cannot be stored in a `ManagedVec`.
+const VEC_RUST_TYPE: &str = "multiversx_sc::types::heap::Vec";
+
+/// The generic argument that all generated types requiring the managed type API receive.
+pub const TYPE_API_GENERIC: &str = "M";
+
+/// Maps ABI types to Rust types, as they would appear in a contract.
+///
+/// The managed type API is passed explicitly, since it is `Self::Api` in the proxy trait,
+/// but a generic argument in the generated types and event decoders.
+pub struct RustTypeMapper<'a> {
+    types: &'a BTreeMap<String, TypeDescriptionJson>,
+    managed_types: BTreeSet<String>,
+    managed_vec_items: BTreeSet<String>,
+}
+
+impl<'a> RustTypeMapper<'a> {
+    pub fn new(types: &'a BTreeMap<String, TypeDescriptionJson>) -> Self {
+        let mut mapper = RustTypeMapper {
+            types,
+            managed_types: BTreeSet::new(),
+            managed_vec_items: BTreeSet::new(),
+        };
+        mapper.find_managed_types();
+        mapper.find_managed_vec_items();
+        mapper
+    }
+
+    /// Custom types that need to be generated, framework types excluded.
+    pub fn generated_types(&self) -> impl Iterator<Item = (&'a String, &'a TypeDescriptionJson)> {
+        self.types
+            .iter()
+            .filter(|(type_name, _)| find_framework_type(type_name).is_none())
+    }
+
+    /// Whether the generated type receives the managed type API as generic argument.
+    pub fn is_managed_type(&self, type_name: &str) -> bool {
+        self.managed_types.contains(type_name)
+    }
+
+    /// Whether the generated type derives `ManagedVecItem`.
+    pub fn is_managed_vec_item(&self, type_name: &str) -> bool {
+        self.managed_vec_items.contains(type_name)
+    }
+
+    pub fn rust_type(&self, expr: &AbiTypeExpr, api: &str) -> String {
+        self.field_rust_type(expr, api, &BTreeSet::new())
+    }
+
+    /// Same as `rust_type`, but boxes references to the given types, unless already behind a list.
+    pub fn field_rust_type(
+        &self,
+        expr: &AbiTypeExpr,
+        api: &str,
+        boxed_types: &BTreeSet<String>,
+    ) -> String {
+        match expr.name.as_str() {
+            "List" | "Queue" => {
+                let item = expr.single_arg();
+                let item_type = self.rust_type(item, api);
+                if self.expr_is_managed_vec_item(item) {
+                    format!("ManagedVec<{api}, {item_type}>")
+                } else {
+                    format!("{VEC_RUST_TYPE}<{item_type}>")
+                }
+            },
+            "Option" => format!(
+                "Option<{}>",
+                self.field_rust_type(expr.single_arg(), api, boxed_types)
+            ),
+            "tuple" => format!(
+                "({})",
+                join(
+                    expr.args
+                        .iter()
+                        .map(|arg| self.field_rust_type(arg, api, boxed_types))
+                )
+            ),
+            _ if expr.array_length().is_some() => format!(
+                "[{}; {}]",
+                self.field_rust_type(expr.single_arg(), api, boxed_types),
+                expr.array_length().unwrap()
+            ),
+            H256_TYPE_NAME => format!("ManagedByteArray<{api}, {H256_LENGTH}>"),
+            name if boxed_types.contains(name) => {
+                format!("{BOX_RUST_TYPE}<{}>", self.custom_rust_type(name, api))
+            },
+            name if self.is_custom_type(name) => self.custom_rust_type(name, api),
+            name => match find_leaf_type(name) {
+                Some((_, rust_type, true)) => format!("{rust_type}<{api}>"),
+                Some((_, rust_type, false)) => rust_type.to_string(),
+                None => format!("{RAW_BYTES_RUST_TYPE}<{api}>"),
+            },
+        }
+    }
+
+    /// Same as `rust_type`, but also accepts the multi-value types allowed in arguments and results.
+    pub fn multi_rust_type(&self, expr: &AbiTypeExpr, api: &str) -> String {
+        match expr.name.as_str() {
+            "variadic" => format!(
+                "MultiValueEncoded<{api}, {}>",
+                self.multi_rust_type(expr.single_arg(), api)
+            ),
+            "counted-variadic" => format!(
+                "MultiValueManagedVecCounted<{api}, {}>",
+                self.multi_rust_type(expr.single_arg(), api)
+            ),
+            "optional" => format!(
+                "OptionalValue<{}>",
+                self.multi_rust_type(expr.single_arg(), api)
+            ),
+            "multi" if expr.args.len() == 1 => self.multi_rust_type(expr.single_arg(), api),
+            "multi" => format!(
+                "MultiValue{}<{}>",
+                expr.args.len(),
+                join(expr.args.iter().map(|arg| self.multi_rust_type(arg, api)))
+            ),
+            _ => self.rust_type(expr, api),
+        }
+    }
+
+    /// Whether the Rust type needs the managed type API.
+    pub fn expr_is_managed(&self, expr: &AbiTypeExpr) -> bool {
+        match expr.name.as_str() {
+            "List" | "Queue" => {
+                self.expr_is_managed_vec_item(expr.single_arg())
+                    || self.expr_is_managed(expr.single_arg())
+            },
+            "Option" | "tuple" => expr.args.iter().any(|arg| self.expr_is_managed(arg)),
+            _ if expr.array_length().is_some() => self.expr_is_managed(expr.single_arg()),
+            H256_TYPE_NAME => true,
+            name if self.is_custom_type(name) => self.is_managed_custom_type(name),
+            name => match find_leaf_type(name) {
+                Some((_, _, managed)) => *managed,
+                None => true,
+            },
+        }
+    }
+
+    fn expr_is_managed_vec_item(&self, expr: &AbiTypeExpr) -> bool {
+        match expr.name.as_str() {
+            "List" | "Queue" | "Option" => self.expr_is_managed_vec_item(expr.single_arg()),
+            _ if expr.array_length().is_some() => expr.single_arg().name == "u8",
+            name if MANAGED_VEC_ITEM_TYPES.contains(&name) => true,
+            name if self.is_custom_type(name) => self.managed_vec_items.contains(name),
+            name => find_leaf_type(name).is_none(),
+        }
+    }
+
+    fn is_custom_type(&self, type_name: &str) -> bool {
+        self.types.contains_key(type_name) || find_framework_type(type_name).is_some()
+    }
+
+    fn is_managed_custom_type(&self, type_name: &str) -> bool {
+        match find_framework_type(type_name) {
+            Some((_, managed)) => *managed,
+            None => self.managed_types.contains(type_name),
+        }
+    }
+
+    fn custom_rust_type(&self, type_name: &str, api: &str) -> String {
+        if self.is_managed_custom_type(type_name) {
+            format!("{type_name}<{api}>")
+        } else {
+            type_name.to_string()
+        }
+    }
+
+    /// A type is managed if any of its fields is, recursive types included.
+    fn find_managed_types(&mut self) {
+        loop {
+            let newly_managed: Vec<String> = self
+                .generated_types()
+                .filter(|(type_name, type_description)| {
+                    !self.managed_types.contains(*type_name)
+                        && all_fields(type_description).any(|field| {
+                            self.expr_is_managed(&AbiTypeExpr::parse(&field.field_type))
+                        })
+                })
+                .map(|(type_name, _)| type_name.clone())
+                .collect();
+            if newly_managed.is_empty() {
+                return;
+            }
+            self.managed_types.extend(newly_managed);
+        }
+    }
+
+    /// The `ManagedVecItem` derive only supports structs with named fields and enums without fields.
+    /// Recursive types can never be managed vec items, since their payload would be infinite.
+    fn find_managed_vec_items(&mut self) {
+        loop {
+            let new_items: Vec<String> = self
+                .generated_types()
+                .filter(|(type_name, type_description)| {
+                    !self.managed_vec_items.contains(*type_name)
+                        && self.can_derive_managed_vec_item(type_description)
+                })
+                .map(|(type_name, _)| type_name.clone())
+                .collect();
+            if new_items.is_empty() {
+                return;
+            }
+            self.managed_vec_items.extend(new_items);
+        }
+    }
+
+    fn can_derive_managed_vec_item(&self, type_description: &TypeDescriptionJson) -> bool {
+        if type_description.content_type == ENUM_CONTENT_TYPE {
+            return type_description
+                .variants
+                .iter()
+                .enumerate()
+                .all(|(index, variant)| {
+                    variant.fields.is_empty() && variant.discriminant == index
+                });
+        }
+
+        !type_description.fields.is_empty()
+            && !has_tuple_field_names(&type_description.fields)
+            && type_description
+                .fields
+                .iter()
+                .all(|field| self.expr_is_managed_vec_item(&AbiTypeExpr::parse(&field.field_type)))
+    }
+
+    /// The custom types that contain the given type, directly or through other types, but not behind a list.
+    /// Fields of the given type that reference them need to be boxed, to keep the type size finite.
+    pub fn types_reaching(&self, type_name: &str) -> BTreeSet<String> {
+        self.generated_types()
+            .map(|(name, _)| name)
+            .filter(|name| self.directly_reaches(name, type_name))
+            .cloned()
+            .collect()
+    }
+
+    fn directly_reaches(&self, from: &str, to: &str) -> bool {
+        let mut visited = BTreeSet::new();
+        let mut stack = vec![from.to_string()];
+        while let Some(current) = stack.pop() {
+            let Some(type_description) = self.types.get(&current) else {
+                continue;
+            };
+            for field in all_fields(type_description) {
+                let mut referenced = Vec::new();
+                collect_direct_type_names(&AbiTypeExpr::parse(&field.field_type), &mut referenced);
+                for name in referenced {
+                    if name == to {
+                        return true;
+                    }
+                    if visited.insert(name.clone()) {
+                        stack.push(name);
+                    }
+                }
+            }
+        }
+        false
+    }
+}
+
+/// The type names that are stored inline, i.e. not behind a list.
+fn collect_direct_type_names(expr: &AbiTypeExpr, result: &mut Vec<String>) {
+    match expr.name.as_str() {
+        "List" | "Queue" => {},
+        "Option" | "tuple" => {
+            for arg in &expr.args {
+                collect_direct_type_names(arg, result);
+            }
+        },
+        _ if expr.array_length().is_some() => collect_direct_type_names(expr.single_arg(), result),
+        name => result.push(name.to_string()),
+    }
+}
+
+/// The fields of a struct, or of all variants of an enum.
+pub fn all_fields(
+    type_description: &TypeDescriptionJson,
+) -> impl Iterator<Item = &StructFieldDescriptionJson> {
+    type_description.fields.iter().chain(
+        type_description
+            .variants
+            .iter()
+            .flat_map(|variant| variant.fields.iter()),
+    )
+}
+
+/// Tuple structs and tuple enum variants have their fields named by index.
+pub fn has_tuple_field_names(fields: &[StructFieldDescriptionJson]) -> bool {
+    !fields.is_empty()
+        && fields
+            .iter()
+            .all(|field| field.name.parse::<usize>().is_ok())
+}
+
+fn find_leaf_type(name: &str) -> Option<&'static (&'static str, &'static str, bool)> {
+    LEAF_TYPES
+        .iter()
+        .find(|(abi_type_name, _, _)| *abi_type_name == name)
+}
+
+fn find_framework_type(name: &str) -> Option<&'static (&'static str, bool)> {
+    FRAMEWORK_TYPES
+        .iter()
+        .find(|(type_name, _)| *type_name == name)
+}
+
+fn join(items: impl Iterator<Item = String>) -> String {
+    items.collect::<Vec<_>>().join(", ")
+}
@@ -0,0 +1,8 @@
+mod ts_codec_prelude;
+mod ts_endpoint_gen;
+mod ts_gen_common;
+mod ts_gen_main;
+mod ts_type_gen;
+mod ts_type_map;
+
+pub use ts_gen_main::generate_ts_bindings;
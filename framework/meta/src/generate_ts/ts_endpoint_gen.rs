@@ -0,0 +1,211 @@
+use std::fmt::Write;
+
+use crate::abi_json::{
+    AbiTypeExpr, ContractAbiJson, EndpointAbiJson, EndpointMutabilityAbiJson, InputAbiJson,
+    OutputAbiJson,
+};
+
+use super::{
+    ts_gen_common::{ts_identifier, ts_method_name, write_docs},
+    ts_type_map::TsTypeMapper,
+};
+
+const INDENT: &str = "    ";
+const BODY_INDENT: &str = "        ";
+
+fn input_params(mapper: &TsTypeMapper, inputs: &[InputAbiJson]) -> String {
+    inputs
+        .iter()
+        .map(|input| {
+            format!(
+                "{}: {}",
+                ts_identifier(input.arg_name.clone()),
+                mapper.multi_ts_type(&AbiTypeExpr::parse(&input.type_name))
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn input_names(inputs: &[InputAbiJson]) -> String {
+    inputs
+        .iter()
+        .map(|input| ts_identifier(input.arg_name.clone()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn write_args_encode(out: &mut String, mapper: &TsTypeMapper, inputs: &[InputAbiJson]) {
+    writeln!(out, "{BODY_INDENT}const args: Uint8Array[] = [];").unwrap();
+    for input in inputs {
+        writeln!(
+            out,
+            "{BODY_INDENT}{}.encodeMulti({}, args);",
+            mapper.multi_codec(&AbiTypeExpr::parse(&input.type_name)),
+            ts_identifier(input.arg_name.clone())
+        )
+        .unwrap();
+    }
+}
+
+/// A single result is returned as is, several results as a tuple.
+fn results_expr(outputs: &[OutputAbiJson]) -> AbiTypeExpr {
+    let mut exprs: Vec<AbiTypeExpr> = outputs
+        .iter()
+        .map(|output| AbiTypeExpr::parse(&output.type_name))
+        .collect();
+    if exprs.len() == 1 {
+        exprs.remove(0)
+    } else {
+        AbiTypeExpr {
+            name: "multi".to_string(),
+            args: exprs,
+        }
+    }
+}
+
+fn results_ts_type(mapper: &TsTypeMapper, outputs: &[OutputAbiJson]) -> String {
+    mapper.multi_ts_type(&results_expr(outputs))
+}
+
+fn endpoint_docs(endpoint: &EndpointAbiJson) -> Vec<String> {
+    let mut docs = endpoint.docs.clone();
+    if !endpoint.payable_in_tokens.is_empty() {
+        if !docs.is_empty() {
+            docs.push(String::new());
+        }
+        docs.push(format!(
+            "Payable in: {}.",
+            endpoint.payable_in_tokens.join(", ")
+        ));
+    }
+    docs
+}
+
+/// Builds the function name and the top-encoded arguments of each endpoint call.
+pub(super) fn write_calls(out: &mut String, mapper: &TsTypeMapper, abi: &ContractAbiJson) {
+    writeln!(out, "export const {}Calls = {{", abi.name).unwrap();
+    if let Some(constructor) = &abi.constructor {
+        write_docs(out, INDENT, &constructor.docs);
+        writeln!(
+            out,
+            "{INDENT}init({}): Uint8Array[] {{",
+            input_params(mapper, &constructor.inputs)
+        )
+        .unwrap();
+        write_args_encode(out, mapper, &constructor.inputs);
+        writeln!(out, "{BODY_INDENT}return args;").unwrap();
+        writeln!(out, "{INDENT}}},").unwrap();
+    }
+
+    for endpoint in &abi.endpoints {
+        writeln!(out).unwrap();
+        write_docs(out, INDENT, &endpoint_docs(endpoint));
+        writeln!(
+            out,
+            "{INDENT}{}({}): ContractCall {{",
+            ts_method_name(&endpoint.name),
+            input_params(mapper, &endpoint.inputs)
+        )
+        .unwrap();
+        write_args_encode(out, mapper, &endpoint.inputs);
+        writeln!(
+            out,
+            "{BODY_INDENT}return {{ func: \"{}\", args }};",
+            endpoint.name
+        )
+        .unwrap();
+        writeln!(out, "{INDENT}}},").unwrap();
+    }
+    writeln!(out, "}};").unwrap();
+}
+
+/// Decodes the return data of the endpoints that have results.
+pub(super) fn write_results(out: &mut String, mapper: &TsTypeMapper, abi: &ContractAbiJson) {
+    let endpoints_with_results: Vec<&EndpointAbiJson> = abi
+        .endpoints
+        .iter()
+        .filter(|endpoint| !endpoint.outputs.is_empty())
+        .collect();
+    if endpoints_with_results.is_empty() {
+        writeln!(out, "export const {}Results = {{}};", abi.name).unwrap();
+        return;
+    }
+
+    writeln!(out, "export const {}Results = {{", abi.name).unwrap();
+    for (index, endpoint) in endpoints_with_results.iter().enumerate() {
+        if index > 0 {
+            writeln!(out).unwrap();
+        }
+
+        writeln!(
+            out,
+            "{INDENT}{}(returnData: Uint8Array[]): {} {{",
+            ts_method_name(&endpoint.name),
+            results_ts_type(mapper, &endpoint.outputs)
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "{BODY_INDENT}return decodeResults(returnData, {});",
+            mapper.multi_codec(&results_expr(&endpoint.outputs))
+        )
+        .unwrap();
+        writeln!(out, "{INDENT}}},").unwrap();
+    }
+    writeln!(out, "}};").unwrap();
+}
+
+/// Runs the readonly endpoints as VM queries, through a query runner provided by the caller.
+pub(super) fn write_queries(out: &mut String, mapper: &TsTypeMapper, abi: &ContractAbiJson) {
+    writeln!(out, "export class {}Queries {{", abi.name).unwrap();
+    writeln!(
+        out,
+        "{INDENT}constructor(private readonly runQuery: QueryRunner) {{}}"
+    )
+    .unwrap();
+    for endpoint in &abi.endpoints {
+        if endpoint.mutability != EndpointMutabilityAbiJson::Readonly {
+            continue;
+        }
+
+        let method_name = ts_method_name(&endpoint.name);
+        let call_expr = format!(
+            "{}Calls.{method_name}({})",
+            abi.name,
+            input_names(&endpoint.inputs)
+        );
+        writeln!(out).unwrap();
+        write_docs(out, INDENT, &endpoint.docs);
+        if endpoint.outputs.is_empty() {
+            writeln!(
+                out,
+                "{INDENT}async {method_name}({}): Promise<void> {{",
+                input_params(mapper, &endpoint.inputs)
+            )
+            .unwrap();
+            writeln!(out, "{BODY_INDENT}await this.runQuery({call_expr});").unwrap();
+        } else {
+            writeln!(
+                out,
+                "{INDENT}async {method_name}({}): Promise<{}> {{",
+                input_params(mapper, &endpoint.inputs),
+                results_ts_type(mapper, &endpoint.outputs)
+            )
+            .unwrap();
+            writeln!(
+                out,
+                "{BODY_INDENT}const returnData = await this.runQuery({call_expr});"
+            )
+            .unwrap();
+            writeln!(
+                out,
+                "{BODY_INDENT}return {}Results.{method_name}(returnData);",
+                abi.name
+            )
+            .unwrap();
+        }
+        writeln!(out, "{INDENT}}}").unwrap();
+    }
+    writeln!(out, "}}").unwrap();
+}
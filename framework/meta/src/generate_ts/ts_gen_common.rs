@@ -0,0 +1,74 @@
+use std::fmt::Write;
+
+/// Rust allows these as argument or field names, TypeScript does not.
+const TS_RESERVED_WORDS: &[&str] = &[
+    "case",
+    "catch",
+    "class",
+    "const",
+    "debugger",
+    "default",
+    "delete",
+    "do",
+    "export",
+    "extends",
+    "finally",
+    "function",
+    "import",
+    "instanceof",
+    "new",
+    "null",
+    "switch",
+    "this",
+    "throw",
+    "try",
+    "typeof",
+    "var",
+    "void",
+    "with",
+    "yield",
+];
+
+pub(super) fn write_docs(out: &mut String, indent: &str, docs: &[String]) {
+    if docs.is_empty() {
+        return;
+    }
+
+    writeln!(out, "{indent}/**").unwrap();
+    for doc in docs {
+        let line = doc.trim().replace("*/", "* /");
+        if line.is_empty() {
+            writeln!(out, "{indent} *").unwrap();
+        } else {
+            writeln!(out, "{indent} * {line}").unwrap();
+        }
+    }
+    writeln!(out, "{indent} */").unwrap();
+}
+
+/// Converts an endpoint name, such as `echo_abi_test_type`, to a method name, such as `echoAbiTestType`.
+pub(super) fn ts_method_name(endpoint_name: &str) -> String {
+    let mut parts = endpoint_name.split('_').filter(|part| !part.is_empty());
+    let mut result = parts.next().unwrap_or_default().to_string();
+    for part in parts {
+        let mut chars = part.chars();
+        if let Some(first) = chars.next() {
+            result.extend(first.to_uppercase());
+            result.push_str(chars.as_str());
+        }
+    }
+    ts_identifier(result)
+}
+
+pub(super) fn ts_identifier(name: String) -> String {
+    if TS_RESERVED_WORDS.contains(&name.as_str()) {
+        format!("{name}_")
+    } else {
+        name
+    }
+}
+
+/// Tuple structs and tuple enum variants have their fields named by index.
+pub(super) fn has_tuple_field_names<'a>(mut field_names: impl Iterator<Item = &'a str>) -> bool {
+    field_names.all(|name| name.parse::<usize>().is_ok())
+}
@@ -0,0 +1,54 @@
+use std::{
+    fs::{create_dir_all, File},
+    io::Write,
+};
+
+use crate::{abi_json::ContractAbiJson, meta_config::MetaConfig};
+
+use super::{
+    ts_codec_prelude::TS_CODEC_PRELUDE,
+    ts_endpoint_gen::{write_calls, write_queries, write_results},
+    ts_type_gen::write_type,
+    ts_type_map::TsTypeMapper,
+};
+
+impl MetaConfig {
+    /// Writes TypeScript bindings next to the ABI of each output contract.
+    pub fn generate_ts(&self) {
+        create_dir_all(&self.output_dir).unwrap();
+        for output_contract in &self.output_contracts.contracts {
+            let abi_json = ContractAbiJson::from(&output_contract.abi);
+            let ts_file_path = format!("{}/{}", self.output_dir, output_contract.ts_output_name());
+            let mut ts_file = File::create(&ts_file_path).unwrap();
+            write!(ts_file, "{}", generate_ts_bindings(&abi_json)).unwrap();
+            println!("Generated {ts_file_path}");
+        }
+    }
+}
+
+/// Generates a self-contained TypeScript module for a contract:
+/// the codec runtime, a type and a codec for each struct and enum in the ABI,
+/// call builders for all endpoints, result decoders and query wrappers for the readonly ones.
+pub fn generate_ts_bindings(abi: &ContractAbiJson) -> String {
+    let mapper = TsTypeMapper::new(&abi.types);
+    let mut out = String::new();
+    out.push_str(&format!(
+        "// Code generated by the multiversx-sc-meta TypeScript generator. DO NOT EDIT.\n\
+         // Contract: {}\n\n",
+        abi.name
+    ));
+    out.push_str(TS_CODEC_PRELUDE);
+
+    for (type_name, type_description) in &abi.types {
+        out.push('\n');
+        write_type(&mut out, &mapper, type_name, type_description);
+    }
+
+    out.push('\n');
+    write_calls(&mut out, &mapper, abi);
+    out.push('\n');
+    write_results(&mut out, &mapper, abi);
+    out.push('\n');
+    write_queries(&mut out, &mapper, abi);
+    out
+}
@@ -0,0 +1,315 @@
+use std::fmt::Write;
+
+use crate::abi_json::{
+    AbiTypeExpr, EnumVariantDescriptionJson, StructFieldDescriptionJson, TypeDescriptionJson,
+};
+
+use super::{
+    ts_gen_common::{has_tuple_field_names, write_docs},
+    ts_type_map::TsTypeMapper,
+};
+
+const STRUCT_CONTENT_TYPE: &str = "struct";
+const ENUM_CONTENT_TYPE: &str = "enum";
+
+pub(super) fn write_type(
+    out: &mut String,
+    mapper: &TsTypeMapper,
+    type_name: &str,
+    type_description: &TypeDescriptionJson,
+) {
+    write_docs(out, "", &type_description.docs);
+    match type_description.content_type.as_str() {
+        STRUCT_CONTENT_TYPE => write_struct(out, mapper, type_name, &type_description.fields),
+        ENUM_CONTENT_TYPE => {
+            if type_description
+                .variants
+                .iter()
+                .all(|variant| variant.fields.is_empty())
+            {
+                write_simple_enum(out, type_name, &type_description.variants);
+            } else {
+                write_enum(out, mapper, type_name, &type_description.variants);
+            }
+        },
+        other => panic!("unsupported content type {other} for type {type_name}"),
+    }
+}
+
+fn is_tuple(fields: &[StructFieldDescriptionJson]) -> bool {
+    !fields.is_empty() && has_tuple_field_names(fields.iter().map(|field| field.name.as_str()))
+}
+
+fn field_codec(mapper: &TsTypeMapper, field: &StructFieldDescriptionJson) -> String {
+    mapper.codec(&AbiTypeExpr::parse(&field.field_type))
+}
+
+fn field_ts_type(mapper: &TsTypeMapper, field: &StructFieldDescriptionJson) -> String {
+    mapper.ts_type(&AbiTypeExpr::parse(&field.field_type))
+}
+
+/// Accesses a field of `value_expr`, by position for tuples, by name otherwise.
+fn field_access(value_expr: &str, fields: &[StructFieldDescriptionJson], index: usize) -> String {
+    if is_tuple(fields) {
+        format!("{value_expr}[{index}]")
+    } else {
+        format!("{value_expr}.{}", fields[index].name)
+    }
+}
+
+/// The fields of a variant, written inline, as a tuple or as an object type.
+fn inline_fields_ts_type(mapper: &TsTypeMapper, fields: &[StructFieldDescriptionJson]) -> String {
+    let field_types: Vec<String> = fields
+        .iter()
+        .map(|field| {
+            if is_tuple(fields) {
+                field_ts_type(mapper, field)
+            } else {
+                format!("{}: {}", field.name, field_ts_type(mapper, field))
+            }
+        })
+        .collect();
+    if is_tuple(fields) {
+        format!("[{}]", field_types.join(", "))
+    } else {
+        format!("{{ {} }}", field_types.join("; "))
+    }
+}
+
+fn inline_fields_decode(mapper: &TsTypeMapper, fields: &[StructFieldDescriptionJson]) -> String {
+    let decoded: Vec<String> = fields
+        .iter()
+        .map(|field| {
+            let decode = format!("{}.decodeNested(input)", field_codec(mapper, field));
+            if is_tuple(fields) {
+                decode
+            } else {
+                format!("{}: {decode}", field.name)
+            }
+        })
+        .collect();
+    if is_tuple(fields) {
+        format!("[{}]", decoded.join(", "))
+    } else {
+        format!("{{ {} }}", decoded.join(", "))
+    }
+}
+
+fn write_fields_encode(
+    out: &mut String,
+    indent: &str,
+    mapper: &TsTypeMapper,
+    value_expr: &str,
+    fields: &[StructFieldDescriptionJson],
+) {
+    for (index, field) in fields.iter().enumerate() {
+        writeln!(
+            out,
+            "{indent}{}.encodeNested({}, out);",
+            field_codec(mapper, field),
+            field_access(value_expr, fields, index)
+        )
+        .unwrap();
+    }
+}
+
+fn write_struct(
+    out: &mut String,
+    mapper: &TsTypeMapper,
+    type_name: &str,
+    fields: &[StructFieldDescriptionJson],
+) {
+    if is_tuple(fields) {
+        writeln!(
+            out,
+            "export type {type_name} = {};",
+            inline_fields_ts_type(mapper, fields)
+        )
+        .unwrap();
+    } else if fields.is_empty() {
+        writeln!(out, "export interface {type_name} {{}}").unwrap();
+    } else {
+        writeln!(out, "export interface {type_name} {{").unwrap();
+        for field in fields {
+            write_docs(out, "    ", &field.docs);
+            writeln!(out, "    {}: {};", field.name, field_ts_type(mapper, field)).unwrap();
+        }
+        writeln!(out, "}}").unwrap();
+    }
+    writeln!(out).unwrap();
+
+    writeln!(
+        out,
+        "export const {type_name}Codec: Codec<{type_name}> = nestedCodec<{type_name}>({{"
+    )
+    .unwrap();
+    if fields.is_empty() {
+        writeln!(out, "    encodeNested() {{}},").unwrap();
+        writeln!(out, "    decodeNested() {{").unwrap();
+        writeln!(out, "        return {{}};").unwrap();
+        writeln!(out, "    }},").unwrap();
+    } else {
+        writeln!(out, "    encodeNested(value, out) {{").unwrap();
+        write_fields_encode(out, "        ", mapper, "value", fields);
+        writeln!(out, "    }},").unwrap();
+        writeln!(out, "    decodeNested(input) {{").unwrap();
+        if is_tuple(fields) {
+            writeln!(out, "        return [").unwrap();
+        } else {
+            writeln!(out, "        return {{").unwrap();
+        }
+        for field in fields {
+            let decode = format!("{}.decodeNested(input)", field_codec(mapper, field));
+            if is_tuple(fields) {
+                writeln!(out, "            {decode},").unwrap();
+            } else {
+                writeln!(out, "            {}: {decode},", field.name).unwrap();
+            }
+        }
+        if is_tuple(fields) {
+            writeln!(out, "        ];").unwrap();
+        } else {
+            writeln!(out, "        }};").unwrap();
+        }
+        writeln!(out, "    }},").unwrap();
+    }
+    writeln!(out, "}});").unwrap();
+}
+
+fn first_variant_fieldless(variants: &[EnumVariantDescriptionJson]) -> bool {
+    variants
+        .iter()
+        .any(|variant| variant.discriminant == 0 && variant.fields.is_empty())
+}
+
+fn write_enum_codec_start(out: &mut String, type_name: &str) {
+    writeln!(
+        out,
+        "export const {type_name}Codec: Codec<{type_name}> = enumCodec<{type_name}>("
+    )
+    .unwrap();
+    writeln!(out, "    {{").unwrap();
+}
+
+fn write_enum_codec_end(
+    out: &mut String,
+    type_name: &str,
+    variants: &[EnumVariantDescriptionJson],
+) {
+    writeln!(out, "                default:").unwrap();
+    writeln!(
+        out,
+        "                    throw new Error(`invalid {type_name} discriminant: ${{discriminant}}`);"
+    )
+    .unwrap();
+    writeln!(out, "            }}").unwrap();
+    writeln!(out, "        }},").unwrap();
+    writeln!(out, "    }},").unwrap();
+    writeln!(out, "    {},", first_variant_fieldless(variants)).unwrap();
+    writeln!(out, ");").unwrap();
+}
+
+/// Enums without fields are plain string unions.
+fn write_simple_enum(out: &mut String, type_name: &str, variants: &[EnumVariantDescriptionJson]) {
+    writeln!(out, "export type {type_name} =").unwrap();
+    for (index, variant) in variants.iter().enumerate() {
+        let terminator = if index + 1 == variants.len() { ";" } else { "" };
+        writeln!(out, "    | \"{}\"{terminator}", variant.name).unwrap();
+    }
+    writeln!(out).unwrap();
+
+    write_enum_codec_start(out, type_name);
+    writeln!(out, "        encodeNested(value, out) {{").unwrap();
+    writeln!(out, "            switch (value) {{").unwrap();
+    for variant in variants {
+        writeln!(out, "                case \"{}\":", variant.name).unwrap();
+        writeln!(
+            out,
+            "                    out.writeU8({});",
+            variant.discriminant
+        )
+        .unwrap();
+        writeln!(out, "                    break;").unwrap();
+    }
+    writeln!(out, "            }}").unwrap();
+    writeln!(out, "        }},").unwrap();
+    writeln!(out, "        decodeNested(input) {{").unwrap();
+    writeln!(out, "            const discriminant = input.readU8();").unwrap();
+    writeln!(out, "            switch (discriminant) {{").unwrap();
+    for variant in variants {
+        writeln!(out, "                case {}:", variant.discriminant).unwrap();
+        writeln!(out, "                    return \"{}\";", variant.name).unwrap();
+    }
+    write_enum_codec_end(out, type_name, variants);
+}
+
+/// Enums with fields become discriminated unions, tagged by `kind`.
+fn write_enum(
+    out: &mut String,
+    mapper: &TsTypeMapper,
+    type_name: &str,
+    variants: &[EnumVariantDescriptionJson],
+) {
+    writeln!(out, "export type {type_name} =").unwrap();
+    for (index, variant) in variants.iter().enumerate() {
+        let terminator = if index + 1 == variants.len() { ";" } else { "" };
+        if variant.fields.is_empty() {
+            writeln!(out, "    | {{ kind: \"{}\" }}{terminator}", variant.name).unwrap();
+        } else {
+            writeln!(
+                out,
+                "    | {{ kind: \"{}\"; fields: {} }}{terminator}",
+                variant.name,
+                inline_fields_ts_type(mapper, &variant.fields)
+            )
+            .unwrap();
+        }
+    }
+    writeln!(out).unwrap();
+
+    write_enum_codec_start(out, type_name);
+    writeln!(out, "        encodeNested(value, out) {{").unwrap();
+    writeln!(out, "            switch (value.kind) {{").unwrap();
+    for variant in variants {
+        writeln!(out, "                case \"{}\":", variant.name).unwrap();
+        writeln!(
+            out,
+            "                    out.writeU8({});",
+            variant.discriminant
+        )
+        .unwrap();
+        write_fields_encode(
+            out,
+            "                    ",
+            mapper,
+            "value.fields",
+            &variant.fields,
+        );
+        writeln!(out, "                    break;").unwrap();
+    }
+    writeln!(out, "            }}").unwrap();
+    writeln!(out, "        }},").unwrap();
+    writeln!(out, "        decodeNested(input) {{").unwrap();
+    writeln!(out, "            const discriminant = input.readU8();").unwrap();
+    writeln!(out, "            switch (discriminant) {{").unwrap();
+    for variant in variants {
+        writeln!(out, "                case {}:", variant.discriminant).unwrap();
+        if variant.fields.is_empty() {
+            writeln!(
+                out,
+                "                    return {{ kind: \"{}\" }};",
+                variant.name
+            )
+            .unwrap();
+        } else {
+            writeln!(
+                out,
+                "                    return {{ kind: \"{}\", fields: {} }};",
+                variant.name,
+                inline_fields_decode(mapper, &variant.fields)
+            )
+            .unwrap();
+        }
+    }
+    write_enum_codec_end(out, type_name, variants);
+}
@@ -0,0 +1,139 @@
+use std::collections::BTreeMap;
+
+use crate::abi_json::{AbiTypeExpr, TypeDescriptionJson};
+
+/// TypeScript type and codec expression for the types known to the framework.
+const LEAF_TYPES: &[(&str, &str, &str)] = &[
+    ("u8", "number", "U8Codec"),
+    ("u16", "number", "U16Codec"),
+    ("u32", "number", "U32Codec"),
+    ("usize", "number", "U32Codec"),
+    ("NonZeroUsize", "number", "U32Codec"),
+    ("u64", "bigint", "U64Codec"),
+    ("i8", "number", "I8Codec"),
+    ("i16", "number", "I16Codec"),
+    ("i32", "number", "I32Codec"),
+    ("isize", "number", "I32Codec"),
+    ("i64", "bigint", "I64Codec"),
+    ("bool", "boolean", "BoolCodec"),
+    ("BigUint", "bigint", "BigUintCodec"),
+    ("BigInt", "bigint", "BigIntCodec"),
+    ("bytes", "Uint8Array", "BytesCodec"),
+    ("utf-8 string", "string", "Utf8StringCodec"),
+    ("TokenIdentifier", "string", "Utf8StringCodec"),
+    ("EgldOrEsdtTokenIdentifier", "string", "Utf8StringCodec"),
+    ("Address", "Uint8Array", "AddressCodec"),
+    ("H256", "Uint8Array", "H256Codec"),
+    ("CodeMetadata", "number", "CodeMetadataCodec"),
+    ("()", "null", "UnitCodec"),
+];
+
+/// Types the generator knows nothing about are passed around as raw top-encoded bytes.
+const RAW_BYTES_TS_TYPE: &str = "Uint8Array";
+const RAW_BYTES_CODEC: &str = "RawBytesCodec";
+
+/// Unlike the other types, the unit type produces no arguments at all.
+const UNIT_MULTI_CODEC: &str = "UnitValue";
+
+/// Maps ABI types to TypeScript types and to codec expressions from the generated prelude.
+pub struct TsTypeMapper<'a> {
+    types: &'a BTreeMap<String, TypeDescriptionJson>,
+}
+
+impl<'a> TsTypeMapper<'a> {
+    pub fn new(types: &'a BTreeMap<String, TypeDescriptionJson>) -> Self {
+        TsTypeMapper { types }
+    }
+
+    pub fn ts_type(&self, expr: &AbiTypeExpr) -> String {
+        match expr.name.as_str() {
+            "List" | "Queue" => format!("Array<{}>", self.ts_type(expr.single_arg())),
+            "Option" => format!("{} | null", self.ts_type(expr.single_arg())),
+            "tuple" => format!("[{}]", self.ts_types(&expr.args)),
+            _ if expr.array_length().is_some() => {
+                format!("Array<{}>", self.ts_type(expr.single_arg()))
+            },
+            name if self.types.contains_key(name) => name.to_string(),
+            name => find_leaf_type(name)
+                .map(|(_, ts_type, _)| ts_type.to_string())
+                .unwrap_or_else(|| RAW_BYTES_TS_TYPE.to_string()),
+        }
+    }
+
+    pub fn codec(&self, expr: &AbiTypeExpr) -> String {
+        match expr.name.as_str() {
+            "List" | "Queue" => format!("ListCodec({})", self.codec(expr.single_arg())),
+            "Option" => format!("OptionCodec({})", self.codec(expr.single_arg())),
+            "tuple" => format!(
+                "TupleCodec<[{}]>({})",
+                self.ts_types(&expr.args),
+                self.codecs(&expr.args)
+            ),
+            _ if expr.array_length().is_some() => format!(
+                "ArrayCodec({}, {})",
+                self.codec(expr.single_arg()),
+                expr.array_length().unwrap()
+            ),
+            name if self.types.contains_key(name) => format!("{name}Codec"),
+            name => find_leaf_type(name)
+                .map(|(_, _, codec)| codec.to_string())
+                .unwrap_or_else(|| RAW_BYTES_CODEC.to_string()),
+        }
+    }
+
+    /// Same as `ts_type`, but also accepts the multi-value types allowed in arguments and results.
+    pub fn multi_ts_type(&self, expr: &AbiTypeExpr) -> String {
+        match expr.name.as_str() {
+            "variadic" | "counted-variadic" => {
+                format!("Array<{}>", self.multi_ts_type(expr.single_arg()))
+            },
+            "optional" => format!("{} | undefined", self.multi_ts_type(expr.single_arg())),
+            "multi" => format!("[{}]", self.multi_ts_types(&expr.args)),
+            _ => self.ts_type(expr),
+        }
+    }
+
+    pub fn multi_codec(&self, expr: &AbiTypeExpr) -> String {
+        match expr.name.as_str() {
+            "variadic" => format!("VariadicValue({})", self.multi_codec(expr.single_arg())),
+            "counted-variadic" => format!(
+                "CountedVariadicValue({})",
+                self.multi_codec(expr.single_arg())
+            ),
+            "optional" => format!("OptionalValue({})", self.multi_codec(expr.single_arg())),
+            "multi" => format!(
+                "MultiValue<[{}]>({})",
+                self.multi_ts_types(&expr.args),
+                self.multi_codecs(&expr.args)
+            ),
+            "()" => UNIT_MULTI_CODEC.to_string(),
+            _ => format!("SingleValue({})", self.codec(expr)),
+        }
+    }
+
+    pub fn multi_ts_types(&self, exprs: &[AbiTypeExpr]) -> String {
+        join(exprs.iter().map(|expr| self.multi_ts_type(expr)))
+    }
+
+    pub fn multi_codecs(&self, exprs: &[AbiTypeExpr]) -> String {
+        join(exprs.iter().map(|expr| self.multi_codec(expr)))
+    }
+
+    fn ts_types(&self, exprs: &[AbiTypeExpr]) -> String {
+        join(exprs.iter().map(|expr| self.ts_type(expr)))
+    }
+
+    fn codecs(&self, exprs: &[AbiTypeExpr]) -> String {
+        join(exprs.iter().map(|expr| self.codec(expr)))
+    }
+}
+
+fn find_leaf_type(name: &str) -> Option<&'static (&'static str, &'static str, &'static str)> {
+    LEAF_TYPES
+        .iter()
+        .find(|(abi_type_name, _, _)| *abi_type_name == name)
+}
+
+fn join(items: impl Iterator<Item = String>) -> String {
+    items.collect::<Vec<_>>().join(", ")
+}
@@ -1,18 +1,33 @@
+pub mod abi_diff;
 pub mod abi_json;
 mod cargo_toml_contents;
 pub mod cli_args;
+pub mod ei;
 mod folder_structure;
+pub mod generate_proxy;
 mod generate_snippets;
+pub mod generate_ts;
 mod local_deps;
 mod meta_abi;
+mod meta_abi_diff;
 mod meta_all;
 mod meta_cli;
 mod meta_config;
+mod meta_exit;
+mod meta_generate_proxy;
 mod meta_info;
-mod meta_validate_abi;
+mod meta_size_diff;
+mod meta_test_gen;
+pub mod meta_validate_abi;
+mod meta_verify;
 mod meta_wasm_tools;
 pub mod output_contract;
 mod sc_upgrade;
+mod scen_fmt;
+pub mod template;
+pub mod test_gen;
+pub mod verify;
+pub mod wasm_analysis;
 
 pub use cargo_toml_contents::CargoTomlContents;
 pub use meta_cli::{cli_main, cli_main_standalone, multi_contract_config};
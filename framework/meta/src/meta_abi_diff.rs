@@ -0,0 +1,39 @@
+use colored::Colorize;
+
+use crate::{
+    abi_diff::{diff_abi, AbiChangeSeverity},
+    abi_json::{deserialize_abi_from_json, ContractAbiJson},
+    cli_args::AbiDiffArgs,
+    meta_exit::load_input_file,
+};
+
+/// Exit code when at least one breaking change was found, so the command can act as a release gate.
+const BREAKING_CHANGES_EXIT_CODE: i32 = 1;
+
+pub fn abi_diff(args: &AbiDiffArgs) {
+    let old = load_abi(&args.old);
+    let new = load_abi(&args.new);
+
+    let changes = diff_abi(&old, &new);
+    for change in &changes {
+        match change.severity {
+            AbiChangeSeverity::Breaking => println!("{}", change.to_string().red()),
+            AbiChangeSeverity::Compatible => println!("{}", change.to_string().green()),
+        }
+    }
+
+    let num_breaking = changes.iter().filter(|change| change.is_breaking()).count();
+    println!(
+        "{} changes, {num_breaking} breaking, {} compatible.",
+        changes.len(),
+        changes.len() - num_breaking
+    );
+
+    if num_breaking > 0 {
+        std::process::exit(BREAKING_CHANGES_EXIT_CODE);
+    }
+}
+
+fn load_abi(path: &str) -> ContractAbiJson {
+    load_input_file(path, "ABI", deserialize_abi_from_json)
+}
@@ -35,6 +35,15 @@ fn perform_call_all_meta(path: impl AsRef<Path>, ignore: &[String], raw_args: Ve
 }
 
 pub fn call_contract_meta(contract_crate_path: &Path, cargo_run_args: &[String]) {
+    call_contract_meta_with_env(contract_crate_path, cargo_run_args, &[]);
+}
+
+/// Also sets environment variables for the meta crate, which are passed on to the contract builds.
+pub fn call_contract_meta_with_env(
+    contract_crate_path: &Path,
+    cargo_run_args: &[String],
+    env: &[(&str, &str)],
+) {
     let meta_path = contract_crate_path.join("meta");
     assert!(
         meta_path.exists(),
@@ -52,6 +61,7 @@ pub fn call_contract_meta(contract_crate_path: &Path, cargo_run_args: &[String])
     let exit_status = Command::new("cargo")
         .current_dir(&meta_path)
         .args(std::iter::once(&"run".to_string()).chain(cargo_run_args.iter()))
+        .envs(env.iter().copied())
         .spawn()
         .expect("failed to spawn cargo run process in meta crate")
         .wait()
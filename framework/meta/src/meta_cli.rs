@@ -4,9 +4,16 @@ use super::{
 use crate::{
     cli_args::{ContractCliAction, ContractCliArgs, StandaloneCliAction, StandaloneCliArgs},
     local_deps::local_deps,
+    meta_abi_diff::abi_diff,
     meta_all::call_all_meta,
+    meta_generate_proxy::generate_proxy,
     meta_info::call_info,
+    meta_size_diff::size_diff,
+    meta_test_gen::test_gen_tool,
+    meta_verify::verify,
     sc_upgrade::upgrade_sc,
+    scen_fmt::format_scenarios,
+    template::create_contract,
 };
 use clap::Parser;
 use multiversx_sc::contract_base::ContractAbiProvider;
@@ -23,6 +30,27 @@ pub fn cli_main_standalone() {
         Some(StandaloneCliAction::LocalDeps(args)) => {
             local_deps(args);
         },
+        Some(StandaloneCliAction::ScenFmt(args)) => {
+            format_scenarios(args);
+        },
+        Some(StandaloneCliAction::AbiDiff(args)) => {
+            abi_diff(args);
+        },
+        Some(StandaloneCliAction::GenerateProxy(args)) => {
+            generate_proxy(args);
+        },
+        Some(StandaloneCliAction::New(args)) => {
+            create_contract(args);
+        },
+        Some(StandaloneCliAction::SizeDiff(args)) => {
+            size_diff(args);
+        },
+        Some(StandaloneCliAction::Verify(args)) => {
+            verify(args);
+        },
+        Some(StandaloneCliAction::TestGen(args)) => {
+            test_gen_tool(args);
+        },
         None => {},
     }
 }
@@ -45,6 +73,7 @@ pub fn cli_main<AbiObj: ContractAbiProvider>() {
         ContractCliAction::GenerateSnippets(gs_args) => {
             meta_config_opt.generate_rust_snippets(&gs_args)
         },
+        ContractCliAction::GenerateTs => meta_config_opt.generate_ts(),
     }
 }
 
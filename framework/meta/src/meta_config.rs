@@ -1,12 +1,20 @@
-use std::fs;
+use std::{collections::BTreeSet, fs};
 
 use multiversx_sc::abi::ContractAbi;
 
-use crate::{meta_wasm_tools::check_tools_installed, CargoTomlContents};
+use crate::{
+    meta_validate_abi::{load_contract_source, report_abi_validation_issues, validate_abi_rules},
+    meta_wasm_tools::check_tools_installed,
+    CargoTomlContents,
+};
 
-use super::{cli_args::BuildArgs, output_contract::OutputContractConfig};
+use super::{
+    cli_args::BuildArgs,
+    output_contract::{OutputContractConfig, OutputContractSettings},
+};
 
 const OUTPUT_RELATIVE_PATH: &str = "../output";
+const CONTRACT_SRC_RELATIVE_PATH: &str = "../src";
 const SNIPPETS_RELATIVE_PATH: &str = "../interact-rs";
 const MULTI_CONTRACT_CONFIG_RELATIVE_PATH: &str = "../multicontract.toml";
 const WASM_LIB_PATH: &str = "../wasm/src/lib.rs";
@@ -28,6 +36,18 @@ impl MetaConfig {
             &original_contract_abi,
         );
 
+        let contract_source = if output_contracts.abi_validation.needs_contract_source() {
+            load_contract_source(CONTRACT_SRC_RELATIVE_PATH)
+        } else {
+            None
+        };
+        let issues = validate_abi_rules(
+            &original_contract_abi,
+            &output_contracts,
+            contract_source.as_deref(),
+        );
+        report_abi_validation_issues(&issues);
+
         MetaConfig {
             load_abi_git_version,
             output_dir: OUTPUT_RELATIVE_PATH.to_string(),
@@ -41,7 +61,7 @@ impl MetaConfig {
     pub fn generate_wasm_crates(&mut self) {
         self.remove_unexpected_wasm_crates();
         self.create_wasm_crate_dirs();
-        self.generate_cargo_toml_for_secondary_contracts();
+        self.generate_wasm_cargo_toml_files();
         self.generate_wasm_src_lib();
         copy_to_wasm_unmanaged_ei();
     }
@@ -54,15 +74,34 @@ impl MetaConfig {
 
     /// Cargo.toml files for secondary contracts are generated from the main contract Cargo.toml,
     /// by changing the package name.
-    pub fn generate_cargo_toml_for_secondary_contracts(&mut self) {
+    ///
+    /// The features and optimization level of all wasm crates come from the contract settings.
+    /// The main contract Cargo.toml is only rewritten if these change.
+    pub fn generate_wasm_cargo_toml_files(&mut self) {
         let main_contract = self.output_contracts.main_contract();
 
         // using the same local structure for all contracts is enough for now
-        let mut cargo_toml_contents =
+        let main_cargo_toml_contents =
             CargoTomlContents::load_from_file(main_contract.cargo_toml_path());
-        for secondary_contract in self.output_contracts.secondary_contracts() {
-            cargo_toml_contents.change_package_name(secondary_contract.wasm_crate_name());
-            cargo_toml_contents.save_to_file(secondary_contract.cargo_toml_path());
+        let feature_dependency_names: BTreeSet<String> = self
+            .output_contracts
+            .contracts
+            .iter()
+            .flat_map(|contract| contract.feature_dependency_names())
+            .collect();
+        for output_contract in &self.output_contracts.contracts {
+            let mut cargo_toml_contents = main_cargo_toml_contents.clone();
+            if !output_contract.main {
+                cargo_toml_contents.change_package_name(output_contract.wasm_crate_name());
+            }
+            output_contract
+                .apply_settings_to_cargo_toml(&mut cargo_toml_contents, &feature_dependency_names);
+
+            if !output_contract.main
+                || cargo_toml_contents.toml_value != main_cargo_toml_contents.toml_value
+            {
+                cargo_toml_contents.save_to_file(output_contract.cargo_toml_path());
+            }
         }
     }
 
@@ -75,8 +114,41 @@ impl MetaConfig {
     pub fn build(&mut self, mut build_args: BuildArgs) {
         check_tools_installed(&mut build_args);
 
+        let Some(profile_name) = build_args.profile.clone() else {
+            self.build_contracts(&build_args);
+            return;
+        };
+
+        if build_args.wasm_name_suffix.is_none() {
+            build_args.wasm_name_suffix = Some(profile_name.clone());
+        }
+
+        // the profile only applies to this build,
+        // the wasm crate sources are restored afterwards, even if the build fails
+        let _wasm_src_lib_snapshot = WasmSrcLibSnapshot::take(&self.output_contracts);
+        let contract_settings: Vec<OutputContractSettings> = self
+            .output_contracts
+            .contracts
+            .iter()
+            .map(|contract| contract.settings.clone())
+            .collect();
+        self.output_contracts.apply_profile(&profile_name);
+        self.generate_wasm_src_lib();
+        self.build_contracts(&build_args);
+
+        for (contract, settings) in self
+            .output_contracts
+            .contracts
+            .iter_mut()
+            .zip(contract_settings)
+        {
+            contract.settings = settings;
+        }
+    }
+
+    fn build_contracts(&self, build_args: &BuildArgs) {
         for output_contract in &self.output_contracts.contracts {
-            output_contract.build_contract(&build_args, self.output_dir.as_str());
+            output_contract.build_contract(build_args, self.output_dir.as_str());
         }
     }
 
@@ -139,6 +211,36 @@ impl MetaConfig {
     }
 }
 
+/// Restores the wasm crate sources when dropped, so that a failed build also leaves them unchanged.
+struct WasmSrcLibSnapshot {
+    files: Vec<(String, Vec<u8>)>,
+}
+
+impl WasmSrcLibSnapshot {
+    fn take(output_contracts: &OutputContractConfig) -> Self {
+        let files = output_contracts
+            .contracts
+            .iter()
+            .map(|contract| contract.wasm_src_lib_path())
+            .filter_map(|path| {
+                let contents = fs::read(&path).ok()?;
+                Some((path, contents))
+            })
+            .collect();
+        WasmSrcLibSnapshot { files }
+    }
+}
+
+impl Drop for WasmSrcLibSnapshot {
+    fn drop(&mut self) {
+        for (path, contents) in &self.files {
+            if let Err(err) = fs::write(path, contents) {
+                eprintln!("failed to restore {path}: {err}");
+            }
+        }
+    }
+}
+
 /// This one is useful for some of the special unmanaged EI tests in the framework.
 /// Will do nothing for regular contracts.
 fn copy_to_wasm_unmanaged_ei() {
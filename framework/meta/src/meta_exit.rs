@@ -0,0 +1,26 @@
+use std::fs;
+
+use colored::Colorize;
+
+/// Exit code of the commands reading input files, when these could not be loaded.
+///
+/// Code 1 is left for the checks themselves failing, so scripts can tell the two apart.
+const INVALID_INPUT_EXIT_CODE: i32 = 2;
+
+pub(crate) fn exit_invalid_input(message: &str) -> ! {
+    eprintln!("{}", message.red());
+    std::process::exit(INVALID_INPUT_EXIT_CODE);
+}
+
+/// Reads and parses an input file of a command, exits if either fails.
+pub(crate) fn load_input_file<T, F>(path: &str, description: &str, parse: F) -> T
+where
+    F: FnOnce(&str) -> Result<T, String>,
+{
+    fs::read_to_string(path)
+        .map_err(|err| err.to_string())
+        .and_then(|contents| parse(&contents))
+        .unwrap_or_else(|err| {
+            exit_invalid_input(&format!("Could not load {description} {path}: {err}"))
+        })
+}
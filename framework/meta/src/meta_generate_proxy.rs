@@ -0,0 +1,35 @@
+use std::{fs, path::Path};
+
+use crate::{
+    abi_json::deserialize_abi_from_json, cli_args::GenerateProxyArgs,
+    generate_proxy::generate_proxy_module, meta_exit::load_input_file,
+};
+
+const ABI_JSON_SUFFIX: &str = ".abi.json";
+const JSON_SUFFIX: &str = ".json";
+const PROXY_FILE_SUFFIX: &str = "_proxy.rs";
+
+pub fn generate_proxy(args: &GenerateProxyArgs) {
+    let abi_json = load_input_file(&args.abi, "ABI", deserialize_abi_from_json);
+
+    let output_path = args
+        .output
+        .clone()
+        .unwrap_or_else(|| default_output_path(&args.abi));
+    fs::write(&output_path, generate_proxy_module(&abi_json)).unwrap();
+    println!("Generated {output_path}");
+}
+
+/// The module sits next to the ABI, with a name that is also a valid Rust module name.
+fn default_output_path(abi_path: &str) -> String {
+    let path = Path::new(abi_path);
+    let file_name = path.file_name().unwrap().to_string_lossy();
+    let stem = file_name
+        .strip_suffix(ABI_JSON_SUFFIX)
+        .or_else(|| file_name.strip_suffix(JSON_SUFFIX))
+        .unwrap_or(&file_name);
+    let module_file_name = format!("{}{PROXY_FILE_SUFFIX}", stem.replace('-', "_"));
+    path.with_file_name(module_file_name)
+        .to_string_lossy()
+        .to_string()
+}
@@ -0,0 +1,66 @@
+use colored::Colorize;
+
+use crate::{
+    cli_args::SizeDiffArgs,
+    meta_exit::load_input_file,
+    wasm_analysis::{diff_size_reports, WasmSizeReport},
+};
+
+/// Exit code when the contract grew by more than the tolerance.
+const SIZE_REGRESSION_EXIT_CODE: i32 = 1;
+
+fn format_optional_size(size: Option<usize>) -> String {
+    match size {
+        Some(size) => size.to_string(),
+        None => "-".to_string(),
+    }
+}
+
+fn format_difference(before: usize, after: usize) -> String {
+    let difference = after as i64 - before as i64;
+    if before == 0 {
+        return format!("{difference:+}");
+    }
+    let percentage = difference as f64 / before as f64 * 100.0;
+    format!("{difference:+}, {percentage:+.2}%")
+}
+
+pub fn size_diff(args: &SizeDiffArgs) {
+    let baseline = load_size_report(&args.baseline);
+    let current = load_size_report(&args.current);
+
+    for change in diff_size_reports(&baseline, &current) {
+        let line = format!(
+            "{}: {} -> {} ({:+})",
+            change.name,
+            format_optional_size(change.before),
+            format_optional_size(change.after),
+            change.difference()
+        );
+        if change.difference() > 0 {
+            println!("{}", line.red());
+        } else {
+            println!("{}", line.green());
+        }
+    }
+
+    let summary = format!(
+        "Total: {} -> {} bytes ({}), code: {} -> {}, data: {} -> {}.",
+        baseline.wasm_size,
+        current.wasm_size,
+        format_difference(baseline.wasm_size, current.wasm_size),
+        baseline.code_size,
+        current.code_size,
+        baseline.data_size,
+        current.data_size,
+    );
+    if current.wasm_size > baseline.wasm_size + args.tolerance {
+        println!("{}", summary.red());
+        std::process::exit(SIZE_REGRESSION_EXIT_CODE);
+    }
+    println!("{}", summary.green());
+}
+
+fn load_size_report(path: &str) -> WasmSizeReport {
+    load_input_file(path, "size report", WasmSizeReport::from_json)
+}
@@ -0,0 +1,144 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use colored::Colorize;
+
+use crate::{
+    cli_args::TestGenArgs,
+    folder_structure::{RelevantDirectories, CARGO_TOML_FILE_NAME},
+    test_gen::{find_scenario_files, world_fn_code, ScenarioTestFile, ScenarioTestKind},
+    CargoTomlContents,
+};
+
+const SCENARIOS_DIR_NAME: &str = "scenarios";
+const TESTS_DIR_NAME: &str = "tests";
+
+pub fn test_gen_tool(args: &TestGenArgs) {
+    let path = if let Some(some_path) = &args.path {
+        some_path.as_str()
+    } else {
+        "./"
+    };
+
+    let dirs = RelevantDirectories::find_all(path, args.ignore.as_slice());
+    let mut num_outdated = 0;
+    for contract_dir in dirs.iter_contract_crates() {
+        num_outdated += process_contract_crate(&contract_dir.path, args);
+    }
+
+    println!(
+        "{} contract crates, {num_outdated} test files {}.",
+        dirs.iter_contract_crates().count(),
+        if args.check { "out of sync" } else { "updated" },
+    );
+
+    if args.check && num_outdated > 0 {
+        std::process::exit(1);
+    }
+}
+
+/// Returns the number of test files that were (or, in check mode, would have been) changed.
+fn process_contract_crate(contract_path: &Path, args: &TestGenArgs) -> usize {
+    let scenarios_path = contract_path.join(SCENARIOS_DIR_NAME);
+    if !scenarios_path.is_dir() {
+        return 0;
+    }
+
+    let scenario_names = find_scenario_files(&scenarios_path);
+    let package_name =
+        CargoTomlContents::load_from_file(contract_path.join(CARGO_TOML_FILE_NAME)).package_name();
+
+    let tests_path = contract_path.join(TESTS_DIR_NAME);
+    let mut num_outdated = 0;
+    for kind in ScenarioTestKind::ALL {
+        let test_paths = find_test_files(&tests_path, kind);
+        if test_paths.len() > 1 {
+            println!(
+                "{}",
+                format!(
+                    "Skipping {}: several {} files found.",
+                    tests_path.display(),
+                    kind.test_file_suffix()
+                )
+                .yellow()
+            );
+            continue;
+        }
+
+        let (test_path, old_code) = if let Some(test_path) = test_paths.into_iter().next() {
+            let old_code =
+                fs::read_to_string(&test_path).expect("failed to read scenario test file");
+            (test_path, old_code)
+        } else if args.create && !scenario_names.is_empty() {
+            let test_path = tests_path.join(kind.default_test_file_name(&package_name));
+            (test_path, String::new())
+        } else {
+            continue;
+        };
+
+        let mut test_file = ScenarioTestFile::parse(&old_code);
+        if kind == ScenarioTestKind::Rs && test_file.header.is_empty() {
+            test_file.header =
+                world_fn_code(&workspace_relative_path(contract_path), &package_name);
+        }
+        test_file.sync(kind, &scenario_names);
+        let new_code = test_file.to_code();
+        if new_code == old_code {
+            continue;
+        }
+
+        num_outdated += 1;
+        if args.check {
+            println!("{}", test_path.display());
+        } else {
+            fs::create_dir_all(&tests_path).expect("failed to create tests directory");
+            fs::write(&test_path, new_code).expect("failed to write scenario test file");
+            println!("{}", format!("Updated {}", test_path.display()).green());
+        }
+    }
+    num_outdated
+}
+
+fn find_test_files(tests_path: &Path, kind: ScenarioTestKind) -> Vec<PathBuf> {
+    let Ok(read_dir) = fs::read_dir(tests_path) else {
+        return Vec::new();
+    };
+
+    let mut test_paths: Vec<PathBuf> = read_dir
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| {
+            matches!(
+                path.file_name().and_then(|file_name| file_name.to_str()),
+                Some(file_name) if kind.is_test_file_name(file_name)
+            )
+        })
+        .collect();
+    test_paths.sort();
+    test_paths
+}
+
+/// The path passed to `set_current_dir_from_workspace`,
+/// i.e. the contract crate path relative to the closest enclosing workspace.
+fn workspace_relative_path(contract_path: &Path) -> String {
+    let workspace_path = find_workspace_path(contract_path).unwrap_or(contract_path);
+    let relative_path = pathdiff::diff_paths(contract_path, workspace_path).unwrap_or_default();
+    relative_path
+        .iter()
+        .map(|component| component.to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn find_workspace_path(contract_path: &Path) -> Option<&Path> {
+    contract_path.ancestors().find(|dir| {
+        let cargo_toml_path: PathBuf = dir.join(CARGO_TOML_FILE_NAME);
+        cargo_toml_path.is_file()
+            && CargoTomlContents::load_from_file(&cargo_toml_path)
+                .toml_value
+                .get("workspace")
+                .is_some()
+    })
+}
@@ -1,4 +1,55 @@
-use multiversx_sc::abi::ContractAbi;
+use std::{collections::BTreeMap, fs, path::Path};
+
+use colored::Colorize;
+use multiversx_sc::{
+    abi::{ContractAbi, EndpointAbi, EndpointMutabilityAbi, TypeContents},
+    api::BUILTIN_FUNCTION_NAMES,
+};
+use serde::Deserialize;
+
+use crate::output_contract::OutputContractConfig;
+
+/// Type names produced by the framework, which never come with a type description.
+const FRAMEWORK_TYPE_NAMES: &[&str] = &[
+    "u8",
+    "u16",
+    "u32",
+    "u64",
+    "i8",
+    "i16",
+    "i32",
+    "i64",
+    "bool",
+    "NonZeroUsize",
+    "BigUint",
+    "BigInt",
+    "BigFloat",
+    "bytes",
+    "utf-8 string",
+    "Address",
+    "H256",
+    "TokenIdentifier",
+    "EgldOrEsdtTokenIdentifier",
+    "CodeMetadata",
+    "EllipticCurve",
+    "MessageHashType",
+    "OperationCompletionStatus",
+    "Sign",
+    "ignore",
+];
+
+/// Type names that only wrap their type arguments.
+const GENERIC_TYPE_NAMES: &[&str] = &[
+    "List",
+    "Option",
+    "Queue",
+    "AsyncCallResult",
+    "tuple",
+    "multi",
+    "optional",
+    "variadic",
+    "counted-variadic",
+];
 
 fn validate_abi_constructor(abi: &ContractAbi) -> Result<(), &'static str> {
     match abi.constructors.len() {
@@ -12,3 +63,345 @@ pub fn validate_abi(abi: &ContractAbi) -> Result<(), &'static str> {
     validate_abi_constructor(abi)?;
     Ok(())
 }
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AbiValidationSeverity {
+    Allow,
+    Warn,
+    Deny,
+}
+
+/// The rules that can be configured in the `[abi-validation]` section of `multicontract.toml`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AbiValidationRule {
+    /// Two methods, possibly from different modules or labels, share an endpoint name.
+    DuplicateEndpointName,
+    /// An endpoint has the same name as a builtin function, so it can never be called.
+    BuiltinFunctionName,
+    /// A type is referenced in the ABI, but there is no description for it.
+    UndescribedType,
+    /// A view or pure endpoint accepts payments.
+    PayableView,
+    /// A callback is never attached to a call via the callback proxy, `self.callbacks()`.
+    ///
+    /// Allowed by default, since it searches the contract source text, which can give false positives.
+    CallbackWithoutProxyCall,
+    /// An external view contract exposes an endpoint that writes to storage.
+    ExternalViewMutableEndpoint,
+}
+
+impl AbiValidationRule {
+    pub fn name(&self) -> &'static str {
+        match self {
+            AbiValidationRule::DuplicateEndpointName => "duplicate-endpoint-name",
+            AbiValidationRule::BuiltinFunctionName => "builtin-function-name",
+            AbiValidationRule::UndescribedType => "undescribed-type",
+            AbiValidationRule::PayableView => "payable-view",
+            AbiValidationRule::CallbackWithoutProxyCall => "callback-without-proxy-call",
+            AbiValidationRule::ExternalViewMutableEndpoint => "external-view-mutable-endpoint",
+        }
+    }
+
+    pub fn default_severity(&self) -> AbiValidationSeverity {
+        match self {
+            AbiValidationRule::DuplicateEndpointName | AbiValidationRule::BuiltinFunctionName => {
+                AbiValidationSeverity::Deny
+            },
+            AbiValidationRule::CallbackWithoutProxyCall => AbiValidationSeverity::Allow,
+            _ => AbiValidationSeverity::Warn,
+        }
+    }
+}
+
+/// Severity overrides, by rule.
+#[derive(Clone, Default, Debug, Deserialize)]
+#[serde(transparent)]
+pub struct AbiValidationSettings(pub BTreeMap<AbiValidationRule, AbiValidationSeverity>);
+
+impl AbiValidationSettings {
+    pub fn severity(&self, rule: AbiValidationRule) -> AbiValidationSeverity {
+        self.0
+            .get(&rule)
+            .copied()
+            .unwrap_or_else(|| rule.default_severity())
+    }
+
+    /// The contract sources only need to be loaded for the rules that search them.
+    pub fn needs_contract_source(&self) -> bool {
+        self.severity(AbiValidationRule::CallbackWithoutProxyCall) != AbiValidationSeverity::Allow
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct AbiValidationIssue {
+    pub rule: AbiValidationRule,
+    pub severity: AbiValidationSeverity,
+    pub message: String,
+}
+
+/// Checks the contract ABI against all rules that are not allowed in the settings.
+///
+/// The contract source is only needed for the callback rule, which is skipped without it.
+pub fn validate_abi_rules(
+    original_abi: &ContractAbi,
+    output_contracts: &OutputContractConfig,
+    contract_source: Option<&str>,
+) -> Vec<AbiValidationIssue> {
+    let mut messages = Vec::new();
+    check_duplicate_endpoint_names(original_abi, &mut messages);
+    check_builtin_function_names(original_abi, &mut messages);
+    check_undescribed_types(original_abi, &mut messages);
+    check_payable_views(original_abi, &mut messages);
+    if let Some(contract_source) = contract_source {
+        check_callbacks_without_proxy_calls(original_abi, contract_source, &mut messages);
+    }
+    check_external_view_mutable_endpoints(output_contracts, &mut messages);
+
+    messages
+        .into_iter()
+        .map(|(rule, message)| AbiValidationIssue {
+            rule,
+            severity: output_contracts.abi_validation.severity(rule),
+            message,
+        })
+        .filter(|issue| issue.severity != AbiValidationSeverity::Allow)
+        .collect()
+}
+
+/// Prints all issues, fails if any of them is denied.
+pub fn report_abi_validation_issues(issues: &[AbiValidationIssue]) {
+    for issue in issues {
+        let line = format!("[{}] {}", issue.rule.name(), issue.message);
+        match issue.severity {
+            AbiValidationSeverity::Deny => println!("{} {}", "error:".red(), line.red()),
+            _ => println!("{} {}", "warning:".yellow(), line.yellow()),
+        }
+    }
+
+    let num_denied = issues
+        .iter()
+        .filter(|issue| issue.severity == AbiValidationSeverity::Deny)
+        .count();
+    assert!(
+        num_denied == 0,
+        "ABI validation failed with {num_denied} error(s). Severities can be changed in the [abi-validation] section of multicontract.toml."
+    );
+}
+
+/// Loads all Rust sources of the contract crate, without whitespace, so that method chains can be searched.
+pub fn load_contract_source<P: AsRef<Path>>(src_path: P) -> Option<String> {
+    let mut result = String::new();
+    collect_rust_sources(src_path.as_ref(), &mut result).ok()?;
+    Some(result)
+}
+
+fn collect_rust_sources(path: &Path, result: &mut String) -> std::io::Result<()> {
+    for entry in fs::read_dir(path)? {
+        let entry_path = entry?.path();
+        if entry_path.is_dir() {
+            collect_rust_sources(&entry_path, result)?;
+        } else if entry_path.extension().map_or(false, |ext| ext == "rs") {
+            let contents = fs::read_to_string(&entry_path)?;
+            result.extend(contents.chars().filter(|c| !c.is_whitespace()));
+        }
+    }
+    Ok(())
+}
+
+type RuleMessages = Vec<(AbiValidationRule, String)>;
+
+fn describe_endpoint(endpoint: &EndpointAbi) -> String {
+    if endpoint.labels.is_empty() {
+        format!("`{}`", endpoint.rust_method_name)
+    } else {
+        format!(
+            "`{}` (labels: {})",
+            endpoint.rust_method_name,
+            endpoint.labels.join(", ")
+        )
+    }
+}
+
+/// Modules inherited via several paths show up more than once, but with the same method, which is fine.
+fn check_duplicate_endpoint_names(abi: &ContractAbi, messages: &mut RuleMessages) {
+    let mut by_name: BTreeMap<&str, Vec<&EndpointAbi>> = BTreeMap::new();
+    for endpoint in abi.constructors.iter().chain(abi.endpoints.iter()) {
+        let same_name = by_name.entry(endpoint.name).or_default();
+        if !same_name
+            .iter()
+            .any(|other| other.rust_method_name == endpoint.rust_method_name)
+        {
+            same_name.push(endpoint);
+        }
+    }
+
+    for (name, endpoints) in by_name {
+        if endpoints.len() > 1 {
+            let methods: Vec<String> = endpoints.iter().map(|e| describe_endpoint(e)).collect();
+            messages.push((
+                AbiValidationRule::DuplicateEndpointName,
+                format!(
+                    "endpoint name `{name}` is used by several methods: {}",
+                    methods.join(", ")
+                ),
+            ));
+        }
+    }
+}
+
+fn check_builtin_function_names(abi: &ContractAbi, messages: &mut RuleMessages) {
+    for endpoint in &abi.endpoints {
+        if BUILTIN_FUNCTION_NAMES.contains(&endpoint.name) {
+            messages.push((
+                AbiValidationRule::BuiltinFunctionName,
+                format!(
+                    "endpoint `{}` has the same name as a builtin function",
+                    endpoint.name
+                ),
+            ));
+        }
+    }
+}
+
+fn check_undescribed_types(abi: &ContractAbi, messages: &mut RuleMessages) {
+    // type name -> where it was first referenced
+    let mut referenced: BTreeMap<String, String> = BTreeMap::new();
+    let mut reference = |type_name: &str, location: String| {
+        for leaf in leaf_type_names(type_name) {
+            referenced
+                .entry(leaf.to_string())
+                .or_insert_with(|| location.clone());
+        }
+    };
+
+    for endpoint in abi.constructors.iter().chain(abi.endpoints.iter()) {
+        for input in &endpoint.inputs {
+            reference(&input.type_name, format!("endpoint `{}`", endpoint.name));
+        }
+        for output in &endpoint.outputs {
+            reference(&output.type_name, format!("endpoint `{}`", endpoint.name));
+        }
+    }
+    for event in &abi.events {
+        for input in &event.inputs {
+            reference(&input.type_name, format!("event `{}`", event.identifier));
+        }
+    }
+    for (type_name, type_description) in abi.type_descriptions.0.iter() {
+        let location = format!("type `{type_name}`");
+        match &type_description.contents {
+            TypeContents::Struct(fields) => {
+                for field in fields {
+                    reference(&field.field_type, location.clone());
+                }
+            },
+            TypeContents::Enum(variants) => {
+                for field in variants.iter().flat_map(|variant| variant.fields.iter()) {
+                    reference(&field.field_type, location.clone());
+                }
+            },
+            TypeContents::NotSpecified => {},
+        }
+    }
+
+    for (type_name, location) in referenced {
+        let described = abi
+            .type_descriptions
+            .0
+            .get(type_name.as_str())
+            .map_or(false, |description| description.contents.is_specified());
+        if !described && !FRAMEWORK_TYPE_NAMES.contains(&type_name.as_str()) {
+            messages.push((
+                AbiValidationRule::UndescribedType,
+                format!("type `{type_name}`, referenced in {location}, has no description"),
+            ));
+        }
+    }
+}
+
+/// Splits a type name like `List<tuple<u32,Payment>>` into `u32` and `Payment`.
+fn leaf_type_names(type_name: &str) -> impl Iterator<Item = &str> {
+    type_name
+        .split(|c: char| c == '<' || c == '>' || c == ',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .filter(|name| !GENERIC_TYPE_NAMES.contains(name) && !is_array_type_name(name))
+}
+
+/// Fixed size arrays are named `array<N>`, e.g. `array32<u8>`.
+fn is_array_type_name(name: &str) -> bool {
+    name.strip_prefix("array")
+        .map_or(false, |len| len.chars().all(|c| c.is_ascii_digit()))
+}
+
+fn check_payable_views(abi: &ContractAbi, messages: &mut RuleMessages) {
+    for endpoint in &abi.endpoints {
+        let is_view = matches!(
+            endpoint.mutability,
+            EndpointMutabilityAbi::Readonly | EndpointMutabilityAbi::Pure
+        );
+        if is_view && !endpoint.payable_in_tokens.is_empty() {
+            messages.push((
+                AbiValidationRule::PayableView,
+                format!(
+                    "view `{}` is payable in {}",
+                    endpoint.name,
+                    endpoint.payable_in_tokens.join(", ")
+                ),
+            ));
+        }
+    }
+}
+
+fn check_callbacks_without_proxy_calls(
+    abi: &ContractAbi,
+    contract_source: &str,
+    messages: &mut RuleMessages,
+) {
+    // raw callbacks are called for all async calls, without a proxy
+    let has_proxy_callbacks = abi.has_callback && !contract_source.contains("#[callback_raw]");
+    if has_proxy_callbacks && !contract_source.contains("callbacks().") {
+        messages.push((
+            AbiValidationRule::CallbackWithoutProxyCall,
+            "the contract has callbacks, but never calls `self.callbacks()`".to_string(),
+        ));
+    }
+
+    for callback in &abi.promise_callbacks {
+        let proxy_call = format!("callbacks().{}(", callback.rust_method_name);
+        if !contract_source.contains(&proxy_call) {
+            messages.push((
+                AbiValidationRule::CallbackWithoutProxyCall,
+                format!(
+                    "promise callback `{}` is never passed to a call via `self.callbacks()`",
+                    callback.rust_method_name
+                ),
+            ));
+        }
+    }
+}
+
+fn check_external_view_mutable_endpoints(
+    output_contracts: &OutputContractConfig,
+    messages: &mut RuleMessages,
+) {
+    for contract in &output_contracts.contracts {
+        if !contract.settings.external_view {
+            continue;
+        }
+
+        for endpoint in &contract.abi.endpoints {
+            if matches!(endpoint.mutability, EndpointMutabilityAbi::Mutable) {
+                messages.push((
+                    AbiValidationRule::ExternalViewMutableEndpoint,
+                    format!(
+                        "external view contract `{}` exposes mutable endpoint `{}`",
+                        contract.contract_name, endpoint.name
+                    ),
+                ));
+            }
+        }
+    }
+}
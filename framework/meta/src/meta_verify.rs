@@ -0,0 +1,227 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use colored::Colorize;
+
+use crate::{
+    abi_json::deserialize_abi_from_json,
+    cli_args::VerifyArgs,
+    folder_structure::CARGO_TOML_FILE_NAME,
+    meta_all::call_contract_meta_with_env,
+    meta_exit::exit_invalid_input,
+    verify::{
+        code_hash_hex, diff_build_records, find_installed_toolchain, parse_code_hash,
+        toolchain_install_command, write_source_package, BuildRecordJson,
+    },
+    CargoTomlContents,
+};
+
+const OUTPUT_DIRECTORY: &str = "output";
+
+/// Keeps the rebuilt contracts apart from the existing outputs.
+const VERIFY_WASM_SUFFIX: &str = "verify";
+
+/// Exit code when the rebuilt contract does not match.
+const MISMATCH_EXIT_CODE: i32 = 1;
+
+pub fn verify(args: &VerifyArgs) {
+    let crate_path = PathBuf::from(args.path.as_deref().unwrap_or("./"));
+    let output_path = crate_path.join(OUTPUT_DIRECTORY);
+    let contract_name = args.contract.clone().unwrap_or_else(|| {
+        CargoTomlContents::load_from_file(crate_path.join(CARGO_TOML_FILE_NAME)).package_name()
+    });
+
+    let (expected_code_hash, expected_source) =
+        expected_code_hash(args, &output_path, &contract_name);
+    let recorded = load_recorded_build(&output_path, &contract_name);
+    if !recorded.code_hash.is_empty() && recorded.code_hash != expected_code_hash {
+        println!(
+            "{}",
+            format!(
+                "Warning: the recorded build of {contract_name} produced code hash {}, not the expected one.",
+                recorded.code_hash
+            )
+            .yellow()
+        );
+    }
+
+    let (rebuilt_code_hash, rebuilt) =
+        rebuild(&crate_path, &output_path, &contract_name, &recorded);
+
+    println!("Expected code hash: {expected_code_hash} ({expected_source})");
+    println!("Rebuilt code hash:  {rebuilt_code_hash}");
+    if rebuilt_code_hash != expected_code_hash {
+        println!("{}", "The rebuilt contract does not match.".red());
+        let differences = diff_build_records(&recorded, &rebuilt);
+        if differences.is_empty() {
+            println!(
+                "{}",
+                "The build settings are the same, so the sources or the Cargo.lock files must differ."
+                    .red()
+            );
+        }
+        for difference in differences {
+            println!(
+                "{}",
+                format!(
+                    "{}: recorded `{}`, current `{}`",
+                    difference.setting, difference.recorded, difference.current
+                )
+                .red()
+            );
+        }
+        std::process::exit(MISMATCH_EXIT_CODE);
+    }
+    println!("{}", "The rebuilt contract matches.".green());
+
+    if let Some(package) = &args.package {
+        let external_deps = write_source_package(&crate_path, Path::new(package));
+        println!(
+            "{}",
+            format!("Source package written to {package}.").green()
+        );
+        if !external_deps.is_empty() {
+            println!(
+                "{}",
+                format!(
+                    "Warning: path dependencies outside the contract crate are not in the package: {}",
+                    external_deps.join(", ")
+                )
+                .yellow()
+            );
+        }
+    }
+}
+
+/// Yields the hex code hash, together with where it came from.
+fn expected_code_hash(
+    args: &VerifyArgs,
+    output_path: &Path,
+    contract_name: &str,
+) -> (String, String) {
+    if let Some(code_hash) = &args.code_hash {
+        let code_hash = parse_code_hash(code_hash).unwrap_or_else(|err| exit_invalid_input(&err));
+        return (code_hash, "given code hash".to_string());
+    }
+
+    let wasm_path = match &args.wasm {
+        Some(wasm) => PathBuf::from(wasm),
+        None => output_path.join(format!("{contract_name}.wasm")),
+    };
+    let wasm_bytes = fs::read(&wasm_path).unwrap_or_else(|err| {
+        exit_invalid_input(&format!("Could not read {}: {err}", wasm_path.display()))
+    });
+    (code_hash_hex(&wasm_bytes), wasm_path.display().to_string())
+}
+
+/// Falls back to the build info in the ABI for contracts built before the build records were introduced.
+fn load_recorded_build(output_path: &Path, contract_name: &str) -> BuildRecordJson {
+    let build_record_path = output_path.join(format!("{contract_name}.build.json"));
+    if let Ok(build_record) = BuildRecordJson::load_from_file(&build_record_path) {
+        return build_record;
+    }
+
+    let abi_path = output_path.join(format!("{contract_name}.abi.json"));
+    let abi = fs::read_to_string(&abi_path)
+        .map_err(|err| err.to_string())
+        .and_then(|contents| deserialize_abi_from_json(&contents))
+        .unwrap_or_else(|err| {
+            exit_invalid_input(&format!(
+                "Could not load the build record or the ABI of {contract_name}: {err}"
+            ))
+        });
+    BuildRecordJson::from_abi_build_info(abi.build_info)
+}
+
+/// The rebuild has to use the recorded compiler, whichever toolchain is the default.
+fn recorded_toolchain(recorded: &BuildRecordJson) -> String {
+    let Some(toolchain) = find_installed_toolchain(&recorded.rustc) else {
+        let mut message = format!(
+            "The recorded compiler, {}, is not installed.",
+            recorded.rustc.short
+        );
+        if let Some(install_command) = toolchain_install_command(&recorded.rustc) {
+            message.push_str(&format!(" Install it with `{install_command}`."));
+        }
+        exit_invalid_input(&message);
+    };
+    toolchain
+}
+
+/// Builds all contracts of the crate again, under different names, then cleans up.
+///
+/// The recorded toolchain and rustflags are pinned via the environment of the meta crate,
+/// the rest of the settings come from the recorded build arguments.
+/// Building also regenerates the ABIs, so they are restored afterwards.
+fn rebuild(
+    crate_path: &Path,
+    output_path: &Path,
+    contract_name: &str,
+    recorded: &BuildRecordJson,
+) -> (String, BuildRecordJson) {
+    let toolchain = recorded_toolchain(recorded);
+    println!("Rebuilding with toolchain {toolchain}.");
+    let mut env = vec![("RUSTUP_TOOLCHAIN", toolchain.as_str())];
+    if !recorded.code_hash.is_empty() {
+        env.push(("RUSTFLAGS", recorded.rustflags.as_str()));
+    }
+
+    let abi_snapshots = snapshot_abis(output_path);
+
+    let mut meta_args = vec!["build".to_string()];
+    meta_args.extend(recorded.build_args.iter().cloned());
+    meta_args.push("--wasm-suffix".to_string());
+    meta_args.push(VERIFY_WASM_SUFFIX.to_string());
+    call_contract_meta_with_env(crate_path, &meta_args, &env);
+
+    let rebuilt_name = recorded.rebuilt_output_name(contract_name, VERIFY_WASM_SUFFIX);
+    let wasm_bytes =
+        fs::read(output_path.join(format!("{rebuilt_name}.wasm"))).unwrap_or_else(|err| {
+            exit_invalid_input(&format!("Could not read rebuilt contract: {err}"))
+        });
+    let rebuilt =
+        BuildRecordJson::load_from_file(output_path.join(format!("{rebuilt_name}.build.json")))
+            .unwrap_or_else(|err| {
+                exit_invalid_input(&format!("Could not read rebuilt build record: {err}"))
+            });
+
+    for (abi_path, contents) in abi_snapshots {
+        fs::write(abi_path, contents).expect("failed to restore ABI file");
+    }
+    remove_rebuilt_outputs(output_path);
+
+    (code_hash_hex(&wasm_bytes), rebuilt)
+}
+
+fn snapshot_abis(output_path: &Path) -> Vec<(PathBuf, Vec<u8>)> {
+    let Ok(read_dir) = fs::read_dir(output_path) else {
+        return Vec::new();
+    };
+    read_dir
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| {
+            matches!(path.file_name(), Some(name) if name.to_string_lossy().ends_with(".abi.json"))
+        })
+        .map(|path| {
+            let contents = fs::read(&path).expect("failed to read ABI file");
+            (path, contents)
+        })
+        .collect()
+}
+
+fn remove_rebuilt_outputs(output_path: &Path) {
+    let rebuilt_marker = format!("-{VERIFY_WASM_SUFFIX}.");
+    for entry in fs::read_dir(output_path).expect("error reading output directory") {
+        let entry = entry.unwrap();
+        if entry
+            .file_name()
+            .to_string_lossy()
+            .contains(&rebuilt_marker)
+        {
+            fs::remove_file(entry.path()).expect("failed to remove rebuilt output");
+        }
+    }
+}
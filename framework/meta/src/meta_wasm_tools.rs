@@ -4,7 +4,6 @@ use crate::cli_args::BuildArgs;
 
 const WASM_OPT_NAME: &str = "wasm-opt";
 const WASM2WAT_NAME: &str = "wasm2wat";
-const WASM_OBJDUMP_NAME: &str = "wasm-objdump";
 const TWIGGY_NAME: &str = "twiggy";
 
 pub(crate) fn check_tools_installed(build_args: &mut BuildArgs) {
@@ -16,10 +15,6 @@ pub(crate) fn check_tools_installed(build_args: &mut BuildArgs) {
         println!("Warning: {WASM2WAT_NAME} not installed");
         build_args.wat = false;
     }
-    if build_args.extract_imports && !is_wasm_objdump_installed() {
-        println!("Warning: {WASM_OBJDUMP_NAME} not installed");
-        build_args.extract_imports = false;
-    }
     if build_args.has_twiggy_call() && !is_twiggy_installed() {
         println!("Warning: {TWIGGY_NAME} not installed");
         build_args.twiggy_top = false;
@@ -43,23 +38,26 @@ fn is_wasm2wat_installed() -> bool {
         .is_ok()
 }
 
-fn is_wasm_objdump_installed() -> bool {
-    Command::new(WASM_OBJDUMP_NAME)
+fn is_twiggy_installed() -> bool {
+    Command::new(TWIGGY_NAME)
         .args(["--version"])
         .output()
         .is_ok()
 }
 
-fn is_twiggy_installed() -> bool {
-    Command::new(TWIGGY_NAME)
+pub(crate) fn wasm_opt_version() -> String {
+    let output = Command::new(WASM_OPT_NAME)
         .args(["--version"])
         .output()
-        .is_ok()
+        .expect("failed to execute wasm-opt");
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
 }
 
-pub(crate) fn run_wasm_opt(output_wasm_path: &str) {
+pub(crate) fn run_wasm_opt(output_wasm_path: &str, wasm_opt_flags: &[String]) {
     let exit_status = Command::new(WASM_OPT_NAME)
-        .args([output_wasm_path, "-Oz", "--output", output_wasm_path])
+        .arg(output_wasm_path)
+        .args(wasm_opt_flags)
+        .args(["--output", output_wasm_path])
         .spawn()
         .expect("failed to spawn wasm-opt process")
         .wait()
@@ -79,31 +77,6 @@ pub(crate) fn run_wasm2wat(output_wasm_path: &str, output_wat_path: &str) {
     assert!(exit_status.success(), "wasm2wat process failed");
 }
 
-pub(crate) fn run_wasm_objdump(output_wasm_path: &str) -> String {
-    let output = Command::new(WASM_OBJDUMP_NAME)
-        .args([output_wasm_path, "--details", "--section", "Import"])
-        .output()
-        .expect("failed to execute wasm-objdump");
-
-    assert!(
-        output.status.success(),
-        "wasm-objdump exited with error: {}",
-        core::str::from_utf8(&output.stderr).unwrap()
-    );
-    String::from_utf8(output.stdout).unwrap()
-}
-
-pub(crate) fn parse_imports(result: &str) -> Vec<String> {
-    let mut import_names = Vec::new();
-    for line in result.lines() {
-        let split = line.split("<- env.").collect::<Vec<_>>();
-        if split.len() == 2 {
-            import_names.push(split[1].to_string());
-        }
-    }
-    import_names
-}
-
 fn run_with_stdout_file<I, S>(stdout_file_name: &str, args: I)
 where
     I: IntoIterator<Item = S>,
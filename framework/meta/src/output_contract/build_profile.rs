@@ -0,0 +1,131 @@
+use super::{BuildProfileSerde, OutputContractSettings};
+
+/// Selects the global allocator of the contract, in the generated wasm crate.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum ContractAllocator {
+    /// The `wee_alloc` allocator. Frees memory, but adds the most to the contract size.
+    #[default]
+    WeeAlloc,
+
+    /// Signals an error on any allocation. For contracts that only use managed types.
+    Fail,
+
+    /// Grows the memory as needed, never frees.
+    Leaking,
+
+    /// Allocates from a static 64 KiB buffer, never frees.
+    Static64k,
+}
+
+impl ContractAllocator {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "wee-alloc" => Some(ContractAllocator::WeeAlloc),
+            "fail" => Some(ContractAllocator::Fail),
+            "leaking" => Some(ContractAllocator::Leaking),
+            "static64k" => Some(ContractAllocator::Static64k),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ContractAllocator::WeeAlloc => "wee-alloc",
+            ContractAllocator::Fail => "fail",
+            ContractAllocator::Leaking => "leaking",
+            ContractAllocator::Static64k => "static64k",
+        }
+    }
+
+    /// The line declaring the allocator in the wasm crate `lib.rs`.
+    pub fn macro_invocation(&self) -> &'static str {
+        match self {
+            ContractAllocator::WeeAlloc => "multiversx_sc_wasm_adapter::allocator!();",
+            ContractAllocator::Fail => "multiversx_sc_wasm_adapter::allocator!(fail);",
+            ContractAllocator::Leaking => "multiversx_sc_wasm_adapter::allocator!(leaking);",
+            ContractAllocator::Static64k => "multiversx_sc_wasm_adapter::allocator!(static64k);",
+        }
+    }
+}
+
+pub const DEFAULT_OPT_LEVEL: &str = "z";
+const OPT_LEVELS: &[&str] = &["0", "1", "2", "3", "s", "z"];
+
+pub const DEFAULT_WASM_OPT_FLAG: &str = "-Oz";
+
+/// The build settings of a contract, or the overrides of a named profile, after validation.
+///
+/// Only the settings that are present override the previous values,
+/// except for the features, which are added to the existing ones.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct BuildProfile {
+    pub features: Vec<String>,
+    pub panic_message: Option<bool>,
+    pub opt_level: Option<String>,
+    pub wasm_opt_flags: Option<Vec<String>>,
+    pub allocator: Option<ContractAllocator>,
+    pub stack_size: Option<usize>,
+}
+
+impl BuildProfile {
+    pub fn from_serde(profile_serde: &BuildProfileSerde) -> Self {
+        BuildProfile {
+            features: profile_serde.features.clone(),
+            panic_message: profile_serde.panic_message,
+            opt_level: profile_serde.opt_level.as_deref().map(parse_opt_level),
+            wasm_opt_flags: profile_serde.wasm_opt_flags.clone(),
+            allocator: profile_serde.allocator.as_deref().map(parse_allocator),
+            stack_size: profile_serde.stack_size.as_deref().map(parse_stack_size),
+        }
+    }
+
+    pub fn apply(&self, settings: &mut OutputContractSettings) {
+        for feature in &self.features {
+            if !settings.features.contains(feature) {
+                settings.features.push(feature.clone());
+            }
+        }
+        if let Some(panic_message) = self.panic_message {
+            settings.panic_message = panic_message;
+        }
+        if let Some(opt_level) = &self.opt_level {
+            settings.opt_level = opt_level.clone();
+        }
+        if let Some(wasm_opt_flags) = &self.wasm_opt_flags {
+            settings.wasm_opt_flags = wasm_opt_flags.clone();
+        }
+        if let Some(allocator) = self.allocator {
+            settings.allocator = allocator;
+        }
+        if let Some(stack_size) = self.stack_size {
+            settings.stack_size = Some(stack_size);
+        }
+    }
+}
+
+fn parse_opt_level(opt_level: &str) -> String {
+    assert!(
+        OPT_LEVELS.contains(&opt_level),
+        "invalid opt-level '{opt_level}' in multicontract.toml, expected one of {OPT_LEVELS:?}"
+    );
+    opt_level.to_string()
+}
+
+fn parse_allocator(name: &str) -> ContractAllocator {
+    ContractAllocator::from_name(name).unwrap_or_else(|| {
+        panic!("invalid allocator '{name}' in multicontract.toml, expected \"wee-alloc\", \"fail\", \"leaking\" or \"static64k\"")
+    })
+}
+
+/// Accepts a number of bytes, optionally in KiB, e.g. "65536" or "64k".
+fn parse_stack_size(stack_size: &str) -> usize {
+    let trimmed = stack_size.trim();
+    let parsed = if let Some(kib) = trimmed.strip_suffix('k') {
+        kib.trim().parse::<usize>().map(|kib| kib * 1024)
+    } else {
+        trimmed.parse::<usize>()
+    };
+    parsed.unwrap_or_else(|_| {
+        panic!("invalid stack-size '{stack_size}' in multicontract.toml, expected a number of bytes, e.g. \"65536\" or \"64k\"")
+    })
+}
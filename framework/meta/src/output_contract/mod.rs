@@ -1,3 +1,4 @@
+mod build_profile;
 mod multi_contract_serde;
 mod output_contract_builder;
 mod output_contract_model;
@@ -7,6 +8,7 @@ mod wasm_clean;
 mod wasm_crate_gen;
 mod wasm_update;
 
+pub use build_profile::*;
 pub use multi_contract_serde::*;
 pub use output_contract_builder::*;
 pub use output_contract_model::*;
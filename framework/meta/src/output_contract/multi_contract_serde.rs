@@ -1,6 +1,8 @@
 use serde::Deserialize;
 use std::collections::HashMap;
 
+use crate::meta_validate_abi::AbiValidationSettings;
+
 #[derive(Deserialize, Debug)]
 pub struct MultiContractConfigSerde {
     #[serde(default)]
@@ -10,6 +12,13 @@ pub struct MultiContractConfigSerde {
     #[serde(default)]
     #[serde(rename = "labels-for-contracts")]
     pub labels_for_contracts: HashMap<String, Vec<String>>,
+    #[serde(default)]
+    #[serde(rename = "abi-validation")]
+    pub abi_validation: AbiValidationSettings,
+
+    /// Alternate build settings, applied to all contracts by `sc-meta build --profile <name>`.
+    #[serde(default)]
+    pub profiles: HashMap<String, BuildProfileSerde>,
 }
 
 #[derive(Deserialize, Debug)]
@@ -32,9 +41,56 @@ pub struct OutputContractSerde {
     #[serde(rename = "external-view")]
     pub external_view: Option<bool>,
 
+    /// The build fails if the wasm output is larger than this, in bytes.
+    #[serde(default)]
+    #[serde(rename = "max-size")]
+    pub max_size: Option<usize>,
+
+    /// A warning is printed if the wasm output is larger than this, in bytes.
+    #[serde(default)]
+    #[serde(rename = "warn-size")]
+    pub warn_size: Option<usize>,
+
+    /// The VM Executive Interface version to check the imports against, or "ignore".
+    #[serde(default)]
+    pub ei: Option<String>,
+
+    #[serde(flatten)]
+    pub build_profile: BuildProfileSerde,
+}
+
+/// The settings that control how a contract is compiled.
+///
+/// The same keys are used for a contract and for a named profile.
+#[derive(Deserialize, Default, Clone, Debug)]
+pub struct BuildProfileSerde {
+    /// Cargo features to enable.
+    /// Features of the contract crate are given by name, others as `<dependency>/<feature>`.
+    #[serde(default)]
+    pub features: Vec<String>,
+
     #[serde(default)]
     #[serde(rename = "panic-message")]
     pub panic_message: Option<bool>,
+
+    /// The Rust optimization level, "z" by default.
+    #[serde(default)]
+    #[serde(rename = "opt-level")]
+    pub opt_level: Option<String>,
+
+    /// Replaces the default "-Oz" flag passed to wasm-opt.
+    #[serde(default)]
+    #[serde(rename = "wasm-opt-flags")]
+    pub wasm_opt_flags: Option<Vec<String>>,
+
+    /// One of "wee-alloc" (the default), "fail", "leaking" or "static64k".
+    #[serde(default)]
+    pub allocator: Option<String>,
+
+    /// Stack size of the contract, in bytes, e.g. "65536" or "64k".
+    #[serde(default)]
+    #[serde(rename = "stack-size")]
+    pub stack_size: Option<String>,
 }
 
 #[derive(Deserialize, Default, Debug)]
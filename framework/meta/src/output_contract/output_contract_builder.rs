@@ -5,9 +5,11 @@ use std::{
     path::Path,
 };
 
+use crate::{ei::EIVersion, meta_validate_abi::AbiValidationSettings};
+
 use super::{
-    MultiContractConfigSerde, OutputContract, OutputContractConfig, OutputContractSerde,
-    OutputContractSettings,
+    BuildProfile, MultiContractConfigSerde, OutputContract, OutputContractConfig,
+    OutputContractSerde, OutputContractSettings,
 };
 
 /// Temporary structure, to help create instances of `OutputContract`. Not publicly exposed.
@@ -51,10 +53,7 @@ impl OutputContractBuilder {
                 add_labels: cms.add_labels.iter().cloned().collect(),
                 add_endpoints: cms.add_endpoints.iter().cloned().collect(),
                 constructors,
-                settings: OutputContractSettings {
-                    external_view: cms.external_view.unwrap_or_default(),
-                    panic_message: cms.panic_message.unwrap_or_default(),
-                },
+                settings: build_settings(cms),
                 ..Default::default()
             },
         )
@@ -87,6 +86,31 @@ impl OutputContractBuilder {
     }
 }
 
+fn build_settings(cms: &OutputContractSerde) -> OutputContractSettings {
+    let mut settings = OutputContractSettings {
+        external_view: cms.external_view.unwrap_or_default(),
+        check_ei: parse_check_ei(&cms.ei),
+        max_size: cms.max_size,
+        warn_size: cms.warn_size,
+        ..Default::default()
+    };
+    BuildProfile::from_serde(&cms.build_profile).apply(&mut settings);
+    settings
+}
+
+const EI_CHECK_IGNORE: &str = "ignore";
+
+/// The latest version is checked by default.
+fn parse_check_ei(ei: &Option<String>) -> Option<EIVersion> {
+    match ei.as_deref() {
+        None => Some(EIVersion::default()),
+        Some(EI_CHECK_IGNORE) => None,
+        Some(name) => Some(EIVersion::from_name(name).unwrap_or_else(|| {
+            panic!("invalid EI version '{name}' in multicontract.toml, expected \"1.0\", \"1.1\" or \"{EI_CHECK_IGNORE}\"")
+        })),
+    }
+}
+
 fn process_labels_for_contracts(
     contract_builders: &mut HashMap<String, OutputContractBuilder>,
     labels_for_contracts: &HashMap<String, Vec<String>>,
@@ -241,6 +265,14 @@ impl OutputContractConfig {
         OutputContractConfig {
             default_contract_config_name: config.settings.main.clone().unwrap_or_default(),
             contracts,
+            abi_validation: config.abi_validation.clone(),
+            profiles: config
+                .profiles
+                .iter()
+                .map(|(name, profile_serde)| {
+                    (name.clone(), BuildProfile::from_serde(profile_serde))
+                })
+                .collect(),
         }
     }
 
@@ -258,6 +290,8 @@ impl OutputContractConfig {
                 contract_name: default_contract_config_name,
                 abi: original_abi.clone(),
             }],
+            abi_validation: AbiValidationSettings::default(),
+            profiles: HashMap::new(),
         }
     }
 
@@ -1,6 +1,9 @@
+use std::collections::HashMap;
+
 use multiversx_sc::abi::ContractAbi;
 
-use crate::cli_args::BuildArgs;
+use super::{BuildProfile, ContractAllocator, DEFAULT_OPT_LEVEL, DEFAULT_WASM_OPT_FLAG};
+use crate::{cli_args::BuildArgs, ei::EIVersion, meta_validate_abi::AbiValidationSettings};
 
 pub const DEFAULT_LABEL: &str = "default";
 
@@ -8,6 +11,8 @@ pub const DEFAULT_LABEL: &str = "default";
 pub struct OutputContractConfig {
     pub default_contract_config_name: String,
     pub contracts: Vec<OutputContract>,
+    pub abi_validation: AbiValidationSettings,
+    pub profiles: HashMap<String, BuildProfile>,
 }
 
 impl OutputContractConfig {
@@ -52,9 +57,21 @@ impl OutputContractConfig {
             .find(|contract| contract.contract_name == contract_name)
             .unwrap_or_else(|| panic!("output contract {contract_name} not found"))
     }
+
+    /// Overrides the settings of all contracts with the ones from the given profile.
+    pub fn apply_profile(&mut self, profile_name: &str) {
+        let profile = self.profiles.get(profile_name).unwrap_or_else(|| {
+            let mut profile_names: Vec<&String> = self.profiles.keys().collect();
+            profile_names.sort();
+            panic!("Could not find profile '{profile_name}' in multicontract.toml. Available profiles are: {profile_names:?}")
+        });
+        for contract in &mut self.contracts {
+            profile.apply(&mut contract.settings);
+        }
+    }
 }
 
-#[derive(Default, Clone, PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq)]
 pub struct OutputContractSettings {
     /// External view contracts are just readers of data from another contract.
     pub external_view: bool,
@@ -62,6 +79,49 @@ pub struct OutputContractSettings {
     /// Panic messages add a lot of bloat to the final bytecode,
     /// so they should only be used for debugging purposes.
     pub panic_message: bool,
+
+    /// The imports of the compiled contract are checked against this version of the VM Executive Interface.
+    ///
+    /// No check if `None`.
+    pub check_ei: Option<EIVersion>,
+
+    /// Size budget of the wasm output, in bytes. Exceeding it fails the build.
+    pub max_size: Option<usize>,
+
+    /// Exceeding this size only produces a warning.
+    pub warn_size: Option<usize>,
+
+    /// Cargo features enabled in the wasm crate.
+    ///
+    /// Features of the contract crate are given by name, others as `<dependency>/<feature>`.
+    pub features: Vec<String>,
+
+    /// The `opt-level` of the release profile of the wasm crate.
+    pub opt_level: String,
+
+    pub wasm_opt_flags: Vec<String>,
+
+    pub allocator: ContractAllocator,
+
+    /// Stack size of the contract, in bytes. The toolchain default is used if `None`.
+    pub stack_size: Option<usize>,
+}
+
+impl Default for OutputContractSettings {
+    fn default() -> Self {
+        OutputContractSettings {
+            external_view: false,
+            panic_message: false,
+            check_ei: Some(EIVersion::default()),
+            max_size: None,
+            warn_size: None,
+            features: Vec::new(),
+            opt_level: DEFAULT_OPT_LEVEL.to_string(),
+            wasm_opt_flags: vec![DEFAULT_WASM_OPT_FLAG.to_string()],
+            allocator: ContractAllocator::default(),
+            stack_size: None,
+        }
+    }
 }
 
 /// Represents a contract created by the framework when building.
@@ -113,6 +173,10 @@ impl OutputContract {
         format!("{}/Cargo.toml", &self.wasm_crate_path())
     }
 
+    pub fn wasm_src_lib_path(&self) -> String {
+        format!("{}/src/lib.rs", &self.wasm_crate_path())
+    }
+
     /// The name of the wasm crate, as defined in its corresponding `Cargo.toml`.
     ///
     /// Note this does not necessarily have to match the name of the crate directory.
@@ -140,7 +204,11 @@ impl OutputContract {
         format!("{}.abi.json", &self.contract_name)
     }
 
-    fn output_name_base(&self, build_args: &BuildArgs) -> String {
+    pub fn ts_output_name(&self) -> String {
+        format!("{}.ts", &self.contract_name)
+    }
+
+    pub fn output_name_base(&self, build_args: &BuildArgs) -> String {
         if let Some(wasm_name_override) = &build_args.wasm_name_override {
             wasm_name_override.clone()
         } else if let Some(suffix) = &build_args.wasm_name_suffix {
@@ -162,6 +230,14 @@ impl OutputContract {
         format!("{}.imports.json", self.output_name_base(build_args))
     }
 
+    pub fn build_record_output_name(&self, build_args: &BuildArgs) -> String {
+        format!("{}.build.json", self.output_name_base(build_args))
+    }
+
+    pub fn size_report_output_name(&self, build_args: &BuildArgs) -> String {
+        format!("{}.sizes.json", self.output_name_base(build_args))
+    }
+
     pub fn twiggy_top_name(&self, build_args: &BuildArgs) -> String {
         format!("twiggy-top-{}.txt", self.output_name_base(build_args))
     }
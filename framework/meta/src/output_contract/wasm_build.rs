@@ -1,8 +1,15 @@
 use std::{fs, process::Command};
 
+use colored::Colorize;
+
 use super::OutputContract;
 use crate::{
-    cli_args::BuildArgs, meta_wasm_tools, output_contract::print_util::print_build_command,
+    abi_json::{ContractCrateBuildAbiJson, FrameworkBuildAbiJson, RustcAbiJson},
+    cli_args::BuildArgs,
+    meta_wasm_tools,
+    output_contract::print_util::print_build_command,
+    verify::{code_hash_hex, BuildRecordJson},
+    wasm_analysis::{analyze_wasm, report_wasm_analysis, WasmSizeReport},
 };
 
 impl OutputContract {
@@ -33,26 +40,58 @@ impl OutputContract {
         if let Some(target_dir) = &build_args.target_dir {
             command.args(["--target-dir", target_dir]);
         }
-        let rustflags = compose_rustflags(build_args);
+        // also in the generated Cargo.toml, but a build profile can change them
+        let features = self.cargo_features();
+        if !features.is_empty() {
+            command.args(["--features", &features.join(",")]);
+        }
+        command.env("CARGO_PROFILE_RELEASE_OPT_LEVEL", &self.settings.opt_level);
+        let rustflags = self.compose_rustflags(build_args);
         if !rustflags.is_empty() {
             command.env("RUSTFLAGS", rustflags);
         }
         command
     }
-}
 
-fn compose_rustflags(build_args: &BuildArgs) -> String {
-    let mut rustflags = String::new();
-    if !build_args.wasm_symbols {
-        rustflags.push_str("-C link-arg=-s");
+    /// The features, as passed to Cargo when building the wasm crate.
+    fn cargo_features(&self) -> Vec<String> {
+        let contract_crate_name = self.abi.build_info.contract_crate.name;
+        self.settings
+            .features
+            .iter()
+            .map(|feature| {
+                if feature.contains('/') {
+                    feature.clone()
+                } else {
+                    format!("{contract_crate_name}/{feature}")
+                }
+            })
+            .collect()
     }
-    if build_args.emit_mir {
-        if !rustflags.is_empty() {
-            rustflags.push(' ');
+
+    fn compose_rustflags(&self, build_args: &BuildArgs) -> String {
+        let mut rustflags = Vec::new();
+        if !build_args.wasm_symbols {
+            rustflags.push("-C link-arg=-s".to_string());
+        }
+        if let Some(stack_size) = self.settings.stack_size {
+            rustflags.push(format!("-C link-arg=-zstack-size={stack_size}"));
+        }
+        if build_args.emit_mir {
+            rustflags.push("--emit=mir".to_string());
+        }
+        rustflags.join(" ")
+    }
+
+    /// Cargo falls back to the `RUSTFLAGS` of the environment, if none are composed.
+    fn effective_rustflags(&self, build_args: &BuildArgs) -> String {
+        let rustflags = self.compose_rustflags(build_args);
+        if rustflags.is_empty() {
+            std::env::var("RUSTFLAGS").unwrap_or_default()
+        } else {
+            rustflags
         }
-        rustflags.push_str("--emit=mir");
     }
-    rustflags
 }
 
 impl OutputContract {
@@ -60,7 +99,8 @@ impl OutputContract {
         self.copy_contracts_to_output(build_args, output_path);
         self.run_wasm_opt(build_args, output_path);
         self.run_wasm2wat(build_args, output_path);
-        self.extract_imports(build_args, output_path);
+        self.analyze_wasm(build_args, output_path);
+        self.write_build_record(build_args, output_path);
         self.run_twiggy(build_args, output_path);
     }
 
@@ -71,13 +111,49 @@ impl OutputContract {
             .expect("failed to copy compiled contract to output directory");
     }
 
+    /// Allows `sc-meta verify` to reproduce the build later on.
+    fn write_build_record(&self, build_args: &BuildArgs, output_path: &str) {
+        let output_wasm_path = format!("{output_path}/{}", self.wasm_output_name(build_args));
+        let wasm_bytes = fs::read(output_wasm_path).expect("failed to read compiled contract");
+        let (wasm_opt, wasm_opt_flags) = if build_args.wasm_opt {
+            (
+                meta_wasm_tools::wasm_opt_version(),
+                self.settings.wasm_opt_flags.clone(),
+            )
+        } else {
+            (String::new(), Vec::new())
+        };
+        let build_record = BuildRecordJson {
+            contract_name: self.contract_name.clone(),
+            output_name: self.output_name_base(build_args),
+            contract_crate: ContractCrateBuildAbiJson::from(&self.abi.build_info.contract_crate),
+            framework: FrameworkBuildAbiJson::from(&self.abi.build_info.framework),
+            rustc: RustcAbiJson::create(),
+            wasm_opt,
+            build_args: build_args.to_raw_reproducible(),
+            rustflags: self.effective_rustflags(build_args),
+            features: self.cargo_features(),
+            opt_level: self.settings.opt_level.clone(),
+            wasm_opt_flags,
+            code_hash: code_hash_hex(&wasm_bytes),
+        };
+        let output_build_record_path = format!(
+            "{output_path}/{}",
+            self.build_record_output_name(build_args)
+        );
+        build_record.save_to_file(output_build_record_path);
+    }
+
     fn run_wasm_opt(&self, build_args: &BuildArgs, output_path: &str) {
         if !build_args.wasm_opt {
             return;
         }
 
         let output_wasm_path = format!("{output_path}/{}", self.wasm_output_name(build_args));
-        meta_wasm_tools::run_wasm_opt(output_wasm_path.as_str());
+        meta_wasm_tools::run_wasm_opt(
+            output_wasm_path.as_str(),
+            self.settings.wasm_opt_flags.as_slice(),
+        );
     }
 
     fn run_wasm2wat(&self, build_args: &BuildArgs, output_path: &str) {
@@ -90,20 +166,58 @@ impl OutputContract {
         meta_wasm_tools::run_wasm2wat(output_wasm_path.as_str(), output_wat_path.as_str());
     }
 
-    fn extract_imports(&self, build_args: &BuildArgs, output_path: &str) {
-        if !build_args.extract_imports {
+    /// Runs on the final binary, after all optimizations.
+    fn analyze_wasm(&self, build_args: &BuildArgs, output_path: &str) {
+        let output_wasm_path = format!("{output_path}/{}", self.wasm_output_name(build_args));
+        let wasm_bytes = fs::read(output_wasm_path).expect("failed to read compiled contract");
+        let analysis = analyze_wasm(&wasm_bytes, self.settings.check_ei)
+            .expect("failed to parse compiled contract");
+
+        if build_args.extract_imports {
+            let output_imports_json_path = format!(
+                "{}/{}",
+                output_path,
+                self.imports_json_output_name(build_args)
+            );
+            write_imports_output(
+                output_imports_json_path.as_str(),
+                analysis.ei_imports.as_slice(),
+            );
+        }
+
+        if build_args.size_report {
+            let output_size_report_path =
+                format!("{output_path}/{}", self.size_report_output_name(build_args));
+            fs::write(output_size_report_path, analysis.size_report.to_json())
+                .expect("failed to write size report file");
+        }
+
+        report_wasm_analysis(&self.wasm_output_name(build_args), &analysis);
+        self.check_size_budget(build_args, &analysis.size_report);
+    }
+
+    /// Debug symbols add a lot to the size, so the budget only applies to regular builds.
+    fn check_size_budget(&self, build_args: &BuildArgs, size_report: &WasmSizeReport) {
+        if build_args.wasm_symbols {
             return;
         }
 
-        let output_wasm_path = format!("{output_path}/{}", self.wasm_output_name(build_args));
-        let output_imports_json_path = format!(
-            "{}/{}",
-            output_path,
-            self.imports_json_output_name(build_args)
-        );
-        let result = meta_wasm_tools::run_wasm_objdump(output_wasm_path.as_str());
-        let import_names = meta_wasm_tools::parse_imports(result.as_str());
-        write_imports_output(output_imports_json_path.as_str(), import_names.as_slice());
+        let wasm_name = self.wasm_output_name(build_args);
+        let wasm_size = size_report.wasm_size;
+        if let Some(warn_size) = self.settings.warn_size {
+            if wasm_size > warn_size {
+                let message = format!(
+                    "Warning: {wasm_name} is {wasm_size} bytes, above the warning size of {warn_size} bytes."
+                );
+                println!("{}", message.yellow());
+            }
+        }
+        if let Some(max_size) = self.settings.max_size {
+            assert!(
+                wasm_size <= max_size,
+                "{wasm_name} is {wasm_size} bytes, above the maximum size of {max_size} bytes set in multicontract.toml."
+            );
+        }
     }
 }
 
@@ -1,10 +1,12 @@
 use std::{
+    collections::{BTreeMap, BTreeSet},
     fs::{self, File},
     io::Write,
     path::PathBuf,
 };
 
 use super::OutputContract;
+use crate::CargoTomlContents;
 
 const PREFIX_AUTO_GENERATED: &str =
     "// Code generated by the multiversx-sc multi-contract system. DO NOT EDIT.
@@ -22,7 +24,6 @@ const PREFIX_NO_STD: &str = "
 #![no_std]
 #![feature(alloc_error_handler, lang_items)]
 
-multiversx_sc_wasm_adapter::allocator!();
 ";
 
 impl OutputContract {
@@ -47,10 +48,57 @@ impl OutputContract {
         }
     }
 
+    /// Groups the features by dependency. Plain feature names belong to the contract crate.
+    fn features_by_dependency(&self) -> BTreeMap<String, Vec<String>> {
+        let mut result: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for feature in &self.settings.features {
+            let (dep_name, feature_name) = match feature.split_once('/') {
+                Some((dep_name, feature_name)) => (dep_name, feature_name),
+                None => (self.abi.build_info.contract_crate.name, feature.as_str()),
+            };
+            result
+                .entry(dep_name.to_string())
+                .or_default()
+                .push(feature_name.to_string());
+        }
+        result
+    }
+
+    /// The dependencies of the wasm crate that can receive features from this contract's settings.
+    pub fn feature_dependency_names(&self) -> BTreeSet<String> {
+        let mut result: BTreeSet<String> = self.features_by_dependency().into_keys().collect();
+        result.insert(self.abi.build_info.contract_crate.name.to_string());
+        result
+    }
+
+    /// Writes the features and the optimization level into the wasm crate `Cargo.toml`.
+    ///
+    /// The features of all dependencies in `feature_dependency_names` are overwritten,
+    /// so that nothing is inherited from the `Cargo.toml` of another contract.
+    pub fn apply_settings_to_cargo_toml(
+        &self,
+        cargo_toml_contents: &mut CargoTomlContents,
+        feature_dependency_names: &BTreeSet<String>,
+    ) {
+        let features_by_dependency = self.features_by_dependency();
+        for dep_name in feature_dependency_names {
+            let features = features_by_dependency
+                .get(dep_name)
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+            let found = cargo_toml_contents.change_dependency_features(dep_name, features);
+            assert!(
+                found || features.is_empty(),
+                "features specified for dependency {dep_name}, which is not a dependency of the wasm crate of contract {}",
+                self.contract_name
+            );
+        }
+        cargo_toml_contents.change_release_opt_level(&self.settings.opt_level);
+    }
+
     /// Generates the wasm crate lib.rs source, st the given path.
     pub fn generate_wasm_src_lib_file(&self) {
-        let lib_path = format!("{}/src/lib.rs", &self.wasm_crate_path());
-        let mut wasm_lib_file = File::create(lib_path).unwrap();
+        let mut wasm_lib_file = File::create(self.wasm_src_lib_path()).unwrap();
         self.write_wasm_src_lib_contents(&mut wasm_lib_file);
     }
 
@@ -61,6 +109,12 @@ impl OutputContract {
             .unwrap();
         self.write_stat_comments(wasm_lib_file);
         wasm_lib_file.write_all(PREFIX_NO_STD.as_bytes()).unwrap();
+        writeln!(
+            wasm_lib_file,
+            "{}",
+            self.settings.allocator.macro_invocation()
+        )
+        .unwrap();
 
         wasm_lib_file
             .write_all(self.panic_handler_macro_invocation().as_bytes())
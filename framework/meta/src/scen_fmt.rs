@@ -0,0 +1,89 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use colored::Colorize;
+use multiversx_chain_scenario_format::scenario_fmt::{format_scenario_file, is_scenario_json_file};
+
+use crate::cli_args::ScenFmtArgs;
+
+pub fn format_scenarios(args: &ScenFmtArgs) {
+    let path = if let Some(some_path) = &args.path {
+        some_path.as_str()
+    } else {
+        "./"
+    };
+
+    let mut files = Vec::new();
+    find_scenario_files(Path::new(path), args.ignore.as_slice(), &mut files);
+    files.sort();
+
+    let mut num_unformatted = 0;
+    let mut num_errors = 0;
+    for file in &files {
+        match format_scenario_file(file, args.check) {
+            Ok(true) => {
+                num_unformatted += 1;
+                if args.check {
+                    println!("{}", file.display());
+                } else {
+                    println!("Formatted {}", file.display());
+                }
+            },
+            Ok(false) => {},
+            Err(err) => {
+                num_errors += 1;
+                println!("{}", format!("{}: {err}", file.display()).red());
+            },
+        }
+    }
+
+    println!(
+        "{} scenario files, {num_unformatted} {}, {num_errors} errors.",
+        files.len(),
+        if args.check {
+            "unformatted"
+        } else {
+            "formatted"
+        },
+    );
+
+    if num_errors > 0 || (args.check && num_unformatted > 0) {
+        std::process::exit(1);
+    }
+}
+
+fn find_scenario_files(path: &Path, ignore: &[String], result: &mut Vec<PathBuf>) {
+    if path.is_file() {
+        if is_scenario_json_file(path) {
+            result.push(path.to_owned());
+        }
+        return;
+    }
+
+    let read_dir = fs::read_dir(path).expect("error reading directory");
+    for child_result in read_dir {
+        let child_path = child_result.unwrap().path();
+        if child_path.is_file() || can_continue_recursion(&child_path, ignore) {
+            find_scenario_files(&child_path, ignore, result);
+        }
+    }
+}
+
+fn can_continue_recursion(path: &Path, ignore: &[String]) -> bool {
+    if !path.is_dir() {
+        return false;
+    }
+
+    if let Some(dir_name_str) = path.file_name().and_then(|name| name.to_str()) {
+        if ignore.iter().any(|ignored| ignored == dir_name_str) {
+            return false;
+        }
+
+        // do not explore hidden folders
+        !dir_name_str.starts_with('.')
+    } else {
+        false
+    }
+}
@@ -0,0 +1,70 @@
+use std::path::{Path, PathBuf};
+
+use colored::Colorize;
+
+use crate::cli_args::NewArgs;
+
+use super::{default_templates_path, find_templates, is_valid_crate_name};
+
+pub fn create_contract(args: &NewArgs) {
+    if !is_valid_crate_name(&args.name) {
+        exit_with_error(&format!(
+            "Invalid crate name {}: only lowercase letters, digits, '-' and '_' are allowed, starting with a letter.",
+            args.name
+        ));
+    }
+
+    let templates_path = match &args.templates_path {
+        Some(templates_path) => PathBuf::from(templates_path),
+        None => default_templates_path().unwrap_or_else(|| {
+            exit_with_error(
+                "Framework repository not found from the current directory, use --templates-path to specify the templates directory.",
+            )
+        }),
+    };
+    if !templates_path.is_dir() {
+        exit_with_error(&format!(
+            "Templates directory {} not found, use --templates-path to specify one.",
+            templates_path.display()
+        ));
+    }
+
+    let templates = find_templates(&templates_path);
+    let Some(template) = templates
+        .iter()
+        .find(|template| template.name == args.template)
+    else {
+        let template_names: Vec<&str> = templates
+            .iter()
+            .map(|template| template.name.as_str())
+            .collect();
+        exit_with_error(&format!(
+            "Unknown template {}. Available templates: {}.",
+            args.template,
+            template_names.join(", ")
+        ));
+    };
+
+    let target_parent = args.path.as_deref().unwrap_or("./");
+    let target_path = Path::new(target_parent).join(&args.name);
+    if target_path.exists() {
+        exit_with_error(&format!("{} already exists.", target_path.display()));
+    }
+
+    template.instantiate(&target_path, &args.name);
+    println!(
+        "{}",
+        format!(
+            "Created contract crate {} in {}, from template {}.",
+            args.name,
+            target_path.display(),
+            template.name
+        )
+        .green()
+    );
+}
+
+fn exit_with_error(message: &str) -> ! {
+    eprintln!("{}", message.red());
+    std::process::exit(1);
+}
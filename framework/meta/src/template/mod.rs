@@ -0,0 +1,10 @@
+mod contract_creator;
+mod template_copy;
+mod template_list;
+mod template_names;
+
+pub use contract_creator::create_contract;
+pub use template_list::{
+    default_templates_path, find_templates, find_templates_path_from, ContractTemplate,
+};
+pub use template_names::{is_valid_crate_name, CrateNames, CrateNamesReplacer};
@@ -0,0 +1,177 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use toml::Value;
+
+use crate::{
+    cargo_toml_contents::{CARGO_TOML_DEPENDENCIES, CARGO_TOML_DEV_DEPENDENCIES},
+    folder_structure::CARGO_TOML_FILE_NAME,
+    CargoTomlContents,
+};
+
+use super::CrateNamesReplacer;
+
+/// Build outputs, regenerated in the new crate.
+const IGNORED_DIRECTORIES: &[&str] = &["target", "output"];
+const IGNORED_FILES: &[&str] = &["Cargo.lock"];
+
+const RUST_SOURCE_EXTENSION: &str = "rs";
+
+pub(super) fn copy_template(
+    template_path: &Path,
+    target_path: &Path,
+    replacer: &CrateNamesReplacer,
+) {
+    fs::create_dir_all(target_path).expect("failed to create directory");
+    let template_root = fs::canonicalize(template_path).unwrap();
+    let target_root = fs::canonicalize(target_path).unwrap();
+
+    let copier = TemplateCopier {
+        replacer,
+        workspace_path_literal: Some(workspace_relative_path(&template_root))
+            .filter(|old_path| !old_path.is_empty())
+            .map(|old_path| replacer.replace_in_text(&string_literal(&old_path), true)),
+        new_workspace_path_literal: string_literal(&workspace_relative_path(&target_root)),
+    };
+    copier.copy_dir(&template_root, &target_root);
+    adjust_path_dependencies(&template_root, &target_root, &target_root);
+}
+
+struct TemplateCopier<'a> {
+    replacer: &'a CrateNamesReplacer,
+
+    /// The path of the template relative to its workspace, as it appears in the tests, after renaming.
+    workspace_path_literal: Option<String>,
+    new_workspace_path_literal: String,
+}
+
+impl<'a> TemplateCopier<'a> {
+    /// File contents and file names are adjusted, directory names are kept.
+    fn copy_dir(&self, source_dir: &Path, target_dir: &Path) {
+        fs::create_dir_all(target_dir).expect("failed to create directory");
+        let read_dir = fs::read_dir(source_dir).expect("error reading directory");
+        for child_result in read_dir {
+            let child = child_result.unwrap();
+            let file_name = child.file_name().to_string_lossy().to_string();
+            let child_path = child.path();
+            if child.file_type().unwrap().is_dir() {
+                if !IGNORED_DIRECTORIES.contains(&file_name.as_str()) {
+                    self.copy_dir(&child_path, &target_dir.join(&file_name));
+                }
+            } else if !IGNORED_FILES.contains(&file_name.as_str()) {
+                let target_file_path =
+                    target_dir.join(self.replacer.replace_in_file_name(&file_name));
+                self.copy_file(&child_path, &target_file_path);
+            }
+        }
+    }
+
+    /// Binary files are copied as they are.
+    fn copy_file(&self, source_path: &Path, target_path: &Path) {
+        let contents = fs::read(source_path).expect("failed to read template file");
+        let rust_source =
+            source_path.extension().and_then(|ext| ext.to_str()) == Some(RUST_SOURCE_EXTENSION);
+        let new_contents = match String::from_utf8(contents) {
+            Ok(text) => self.adjust_text(&text, rust_source).into_bytes(),
+            Err(err) => err.into_bytes(),
+        };
+        fs::write(target_path, new_contents).expect("failed to write file");
+    }
+
+    fn adjust_text(&self, text: &str, rust_source: bool) -> String {
+        let new_text = self.replacer.replace_in_text(text, rust_source);
+        match &self.workspace_path_literal {
+            Some(old_literal) if rust_source => {
+                new_text.replace(old_literal, &self.new_workspace_path_literal)
+            },
+            _ => new_text,
+        }
+    }
+}
+
+/// The tests locate the contract crate relative to the workspace, e.g. `contracts/examples/adder`.
+///
+/// A crate outside of any workspace is its own workspace root, so the path is empty.
+fn workspace_relative_path(crate_path: &Path) -> String {
+    match crate_path
+        .ancestors()
+        .skip(1)
+        .find(|dir| is_workspace_root(dir))
+    {
+        Some(workspace_root) => crate_path
+            .strip_prefix(workspace_root)
+            .unwrap()
+            .to_string_lossy()
+            .replace('\\', "/"),
+        None => String::new(),
+    }
+}
+
+fn string_literal(contents: &str) -> String {
+    format!("\"{contents}\"")
+}
+
+fn is_workspace_root(dir: &Path) -> bool {
+    let cargo_toml_path = dir.join(CARGO_TOML_FILE_NAME);
+    cargo_toml_path.is_file()
+        && CargoTomlContents::load_from_file(cargo_toml_path)
+            .toml_value
+            .get("workspace")
+            .is_some()
+}
+
+/// Path dependencies outside the template, such as the framework crates,
+/// need to point to the same crates from the new location.
+fn adjust_path_dependencies(template_root: &Path, target_root: &Path, target_dir: &Path) {
+    let cargo_toml_path = target_dir.join(CARGO_TOML_FILE_NAME);
+    if cargo_toml_path.is_file() {
+        let template_dir = template_root.join(target_dir.strip_prefix(target_root).unwrap());
+        let mut cargo_toml_contents = CargoTomlContents::load_from_file(&cargo_toml_path);
+        let mut changed = false;
+        for deps_name in [CARGO_TOML_DEPENDENCIES, CARGO_TOML_DEV_DEPENDENCIES] {
+            if let Some(Value::Table(deps)) = cargo_toml_contents.toml_value.get_mut(deps_name) {
+                for (_, dep) in deps.iter_mut() {
+                    if let Some(Value::String(dep_path)) = dep.get_mut("path") {
+                        if let Some(new_dep_path) = relocated_path(
+                            template_root,
+                            &template_dir,
+                            target_dir,
+                            dep_path.as_str(),
+                        ) {
+                            *dep_path = new_dep_path;
+                            changed = true;
+                        }
+                    }
+                }
+            }
+        }
+        if changed {
+            cargo_toml_contents.save_to_file(&cargo_toml_path);
+        }
+    }
+
+    let read_dir = fs::read_dir(target_dir).expect("error reading directory");
+    for child_result in read_dir {
+        let child = child_result.unwrap();
+        if child.file_type().unwrap().is_dir() {
+            adjust_path_dependencies(template_root, target_root, &child.path());
+        }
+    }
+}
+
+/// Paths within the template stay the same.
+fn relocated_path(
+    template_root: &Path,
+    template_dir: &Path,
+    target_dir: &Path,
+    dep_path: &str,
+) -> Option<String> {
+    let dep_abs_path: PathBuf = fs::canonicalize(template_dir.join(dep_path)).ok()?;
+    if dep_abs_path.starts_with(template_root) {
+        return None;
+    }
+    let new_dep_path = pathdiff::diff_paths(&dep_abs_path, target_dir)?;
+    Some(new_dep_path.to_string_lossy().replace('\\', "/"))
+}
@@ -0,0 +1,73 @@
+use std::path::{Path, PathBuf};
+
+use crate::{folder_structure::RelevantDirectories, CargoTomlContents};
+
+use super::{template_copy::copy_template, CrateNamesReplacer};
+
+/// Directories that never contain contract templates.
+const TEMPLATE_SEARCH_IGNORE: &[&str] = &["target"];
+
+/// Where the templates reside, relative to the framework repository root.
+const TEMPLATES_RELATIVE_PATH: &str = "contracts/examples";
+
+/// Distinguishes the framework repository root from other workspaces.
+const FRAMEWORK_ROOT_MARKER: &str = "framework/meta/Cargo.toml";
+
+/// A contract crate that can be copied into a new contract.
+#[derive(Clone, Debug)]
+pub struct ContractTemplate {
+    /// The name of the template directory, e.g. `adder`.
+    pub name: String,
+    pub path: PathBuf,
+}
+
+impl ContractTemplate {
+    pub fn crate_name(&self) -> String {
+        CargoTomlContents::load_from_file(self.path.join("Cargo.toml")).package_name()
+    }
+
+    /// Copies the template to `target_path`, replacing the template crate name with the new one everywhere.
+    pub fn instantiate(&self, target_path: &Path, new_crate_name: &str) {
+        let replacer = CrateNamesReplacer::new(&self.crate_name(), new_crate_name);
+        copy_template(&self.path, target_path, &replacer);
+    }
+}
+
+/// The examples of the framework repository containing the current directory.
+///
+/// Falls back to the framework checkout sc-meta was built from, if it is still around.
+/// Installed binaries have no such checkout, they only find templates from within the repository.
+pub fn default_templates_path() -> Option<PathBuf> {
+    std::env::current_dir()
+        .ok()
+        .and_then(|current_dir| find_templates_path_from(&current_dir))
+        .or_else(|| find_templates_path_from(Path::new(env!("CARGO_MANIFEST_DIR"))))
+}
+
+/// Looks for the framework repository root in the given directory and its parents.
+pub fn find_templates_path_from(dir: &Path) -> Option<PathBuf> {
+    dir.ancestors()
+        .find(|ancestor| {
+            ancestor.join(FRAMEWORK_ROOT_MARKER).is_file()
+                && ancestor.join(TEMPLATES_RELATIVE_PATH).is_dir()
+        })
+        .map(|root| root.join(TEMPLATES_RELATIVE_PATH))
+}
+
+/// All contract crates under the given path qualify as templates, sorted by name.
+pub fn find_templates(templates_path: &Path) -> Vec<ContractTemplate> {
+    let ignore: Vec<String> = TEMPLATE_SEARCH_IGNORE
+        .iter()
+        .map(|dir_name| dir_name.to_string())
+        .collect();
+    let dirs = RelevantDirectories::find_all(templates_path, &ignore);
+    let mut templates: Vec<ContractTemplate> = dirs
+        .iter_contract_crates()
+        .map(|dir| ContractTemplate {
+            name: dir.path.file_name().unwrap().to_string_lossy().to_string(),
+            path: dir.path.clone(),
+        })
+        .collect();
+    templates.sort_by(|a, b| a.name.cmp(&b.name));
+    templates
+}
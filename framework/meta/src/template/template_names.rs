@@ -0,0 +1,132 @@
+/// The two spellings of a contract crate name:
+/// the package name, e.g. `crowdfunding-esdt`, and the Rust identifier, e.g. `crowdfunding_esdt`.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct CrateNames {
+    pub package: String,
+    pub ident: String,
+}
+
+impl CrateNames {
+    pub fn new(crate_name: &str) -> Self {
+        CrateNames {
+            package: crate_name.to_string(),
+            ident: crate_name.replace('-', "_"),
+        }
+    }
+}
+
+/// Same rules as cargo, minus the uppercase letters, which the contract crates never use.
+pub fn is_valid_crate_name(crate_name: &str) -> bool {
+    let mut chars = crate_name.chars();
+    match chars.next() {
+        Some(first) if first.is_ascii_lowercase() => {},
+        _ => return false,
+    }
+    chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_')
+}
+
+/// Replaces the template crate name with the new crate name, in file contents and file names.
+///
+/// Only whole words are replaced, so `adder` is renamed in `adder::ContractBuilder`,
+/// but not in `ladder` or `adder_contract`. Same for `empty`, which is not renamed in `is_empty`.
+/// File names are the exception, they may start with the crate name, as in `adder_test.rs`.
+pub struct CrateNamesReplacer {
+    old: CrateNames,
+    new: CrateNames,
+}
+
+impl CrateNamesReplacer {
+    pub fn new(old_crate_name: &str, new_crate_name: &str) -> Self {
+        CrateNamesReplacer {
+            old: CrateNames::new(old_crate_name),
+            new: CrateNames::new(new_crate_name),
+        }
+    }
+
+    /// Rust sources get the identifier spelling outside of string literals.
+    pub fn replace_in_text(&self, text: &str, rust_source: bool) -> String {
+        let mut result = String::with_capacity(text.len());
+        let mut index = 0;
+        while index < text.len() {
+            if let Some(old) = self.match_at(text, index) {
+                let new = if self.is_ident_occurrence(text, index, old, rust_source) {
+                    &self.new.ident
+                } else {
+                    &self.new.package
+                };
+                result.push_str(new);
+                index += old.len();
+            } else {
+                let c = text[index..].chars().next().unwrap();
+                result.push(c);
+                index += c.len_utf8();
+            }
+        }
+        result
+    }
+
+    /// Source file names, such as `adder_test.rs`, get the identifier spelling,
+    /// all other files, such as `adder.scen.json`, the package name spelling.
+    pub fn replace_in_file_name(&self, file_name: &str) -> String {
+        match file_name.strip_prefix(self.old.ident.as_str()) {
+            Some(rest) if rest.starts_with('_') => format!("{}{rest}", self.new.ident),
+            _ => self.replace_in_text(file_name, false),
+        }
+    }
+
+    fn match_at(&self, text: &str, index: usize) -> Option<&str> {
+        [&self.old.package, &self.old.ident]
+            .into_iter()
+            .find(|old| {
+                text[index..].starts_with(old.as_str())
+                    && !is_word_char_before(text, index)
+                    && !is_word_char_after(text, index + old.len())
+            })
+            .map(String::as_str)
+    }
+
+    fn is_ident_occurrence(&self, text: &str, index: usize, old: &str, rust_source: bool) -> bool {
+        if self.old.package != self.old.ident {
+            // the spelling found tells which one to use
+            return old == self.old.ident;
+        }
+
+        let before = &text[..index];
+        let after = &text[index + old.len()..];
+        if after.starts_with("::") || after.starts_with(".rs") {
+            return true;
+        }
+        rust_source && !is_in_string_literal(before)
+    }
+}
+
+fn is_word_char_before(text: &str, index: usize) -> bool {
+    matches!(text[..index].chars().next_back(), Some(c) if is_word_char(c))
+}
+
+fn is_word_char_after(text: &str, index: usize) -> bool {
+    matches!(text[index..].chars().next(), Some(c) if is_word_char(c))
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+/// Counts the unescaped quotes on the current line, good enough for the contract sources.
+fn is_in_string_literal(before: &str) -> bool {
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let mut in_string = false;
+    let mut escaped = false;
+    for c in before[line_start..].chars() {
+        match c {
+            '\\' if in_string => {
+                escaped = !escaped;
+                continue;
+            },
+            '"' if !escaped => in_string = !in_string,
+            _ => {},
+        }
+        escaped = false;
+    }
+    in_string
+}
@@ -0,0 +1,7 @@
+mod scenario_test_code;
+mod scenario_test_file;
+mod scenario_test_kind;
+
+pub use scenario_test_code::world_fn_code;
+pub use scenario_test_file::{find_scenario_files, ScenarioTestFile, TestFileItem};
+pub use scenario_test_kind::ScenarioTestKind;
@@ -0,0 +1,53 @@
+/// Same limits as the default rustfmt settings, so that generated files need no reformatting.
+const MAX_WIDTH: usize = 100;
+const FN_CALL_WIDTH: usize = 60;
+
+/// Formats a function call statement the way rustfmt would,
+/// splitting the arguments on separate lines when they don't fit.
+pub fn format_call(indent: usize, callee: &str, args: &[String]) -> String {
+    let indent_str = " ".repeat(indent);
+    let args_str = args.join(", ");
+    let single_line = format!("{indent_str}{callee}({args_str});");
+    if args_str.len() <= FN_CALL_WIDTH && single_line.len() <= MAX_WIDTH {
+        return single_line;
+    }
+
+    let mut multi_line = format!("{indent_str}{callee}(\n");
+    for arg in args {
+        multi_line.push_str(&format!("{indent_str}    {arg},\n"));
+    }
+    multi_line.push_str(&format!("{indent_str});"));
+    multi_line
+}
+
+/// Generates the imports and the `world()` function of a new scenario Rust test file,
+/// registering the contract built by the crate.
+///
+/// The `workspace_relative_path` is the path of the contract crate, relative to the workspace root.
+pub fn world_fn_code(workspace_relative_path: &str, package_name: &str) -> String {
+    let crate_ident = package_name.replace('-', "_");
+    let set_current_dir = format_call(
+        4,
+        "blockchain.set_current_dir_from_workspace",
+        &[format!("\"{workspace_relative_path}\"")],
+    );
+    let register_contract = format_call(
+        4,
+        "blockchain.register_contract",
+        &[
+            format!("\"file:output/{package_name}.wasm\""),
+            format!("{crate_ident}::ContractBuilder"),
+        ],
+    );
+    format!(
+        "use multiversx_sc_scenario::*;
+
+fn world() -> ScenarioWorld {{
+    let mut blockchain = ScenarioWorld::new();
+{set_current_dir}
+
+{register_contract}
+    blockchain
+}}"
+    )
+}
@@ -0,0 +1,198 @@
+use std::{fs, path::Path};
+
+use super::ScenarioTestKind;
+
+pub(super) const SCENARIO_FILE_SUFFIX: &str = ".scen.json";
+const SCENARIO_PATH_PREFIX: &str = "\"scenarios/";
+
+/// Lists the names of the scenario files directly under a contract's `scenarios` directory, sorted.
+///
+/// Files in subdirectories, like `.steps.json` files, are only ever used from other scenarios,
+/// so they don't get tests of their own.
+/// Files starting with `_` are outputs of the Rust testing framework, e.g. `_generated_init.scen.json`,
+/// and are skipped as well.
+pub fn find_scenario_files(scenarios_path: &Path) -> Vec<String> {
+    let Ok(read_dir) = fs::read_dir(scenarios_path) else {
+        return Vec::new();
+    };
+
+    let mut names: Vec<String> = read_dir
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_file())
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .filter(|name| name.ends_with(SCENARIO_FILE_SUFFIX) && !name.starts_with('_'))
+        .collect();
+    names.sort();
+    names
+}
+
+/// A top-level item of a scenario test file, kept verbatim:
+/// a function together with its attributes and comments, or a comment block.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct TestFileItem {
+    pub code: String,
+
+    /// The scenario file run by the item.
+    /// Commented-out tests also count, so that disabled scenarios are not added back.
+    pub scenario: Option<String>,
+}
+
+impl TestFileItem {
+    fn new(code: String) -> Self {
+        let scenario = find_scenario_name(&code);
+        TestFileItem { code, scenario }
+    }
+
+    fn is_test(&self) -> bool {
+        self.scenario.is_some() || self.code.lines().any(|line| line.trim() == "#[test]")
+    }
+}
+
+fn find_scenario_name(code: &str) -> Option<String> {
+    let start = code.find(SCENARIO_PATH_PREFIX)? + SCENARIO_PATH_PREFIX.len();
+    let end = start + code[start..].find('"')?;
+    let name = &code[start..end];
+    if name.ends_with(SCENARIO_FILE_SUFFIX) && !name.contains('/') {
+        Some(name.to_string())
+    } else {
+        None
+    }
+}
+
+/// A scenario test file, split into the part before the first test and the tests themselves.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct ScenarioTestFile {
+    /// Everything before the first test, e.g. the imports and the `world()` function.
+    pub header: String,
+
+    pub items: Vec<TestFileItem>,
+}
+
+impl ScenarioTestFile {
+    /// Splits the file into items, separated by blank lines at the top level.
+    pub fn parse(code: &str) -> Self {
+        let lines: Vec<&str> = code.lines().collect();
+        let mut chunks = Vec::new();
+        let mut chunk_start = None;
+        let mut block_end = None;
+        for (i, line) in lines.iter().enumerate() {
+            if let Some(end) = block_end {
+                if is_block_end(line, end) {
+                    chunks.push((chunk_start.take().unwrap(), i + 1));
+                    block_end = None;
+                }
+                continue;
+            }
+
+            if line.trim().is_empty() {
+                if let Some(start) = chunk_start.take() {
+                    chunks.push((start, i));
+                }
+                continue;
+            }
+
+            let start = *chunk_start.get_or_insert(i);
+            if line.starts_with("/*") {
+                if line.contains("*/") {
+                    chunks.push((start, i + 1));
+                    chunk_start = None;
+                } else {
+                    block_end = Some("*/");
+                }
+            } else if !line.starts_with(char::is_whitespace) && line.trim_end().ends_with('{') {
+                block_end = Some("}");
+            }
+        }
+        if let Some(start) = chunk_start {
+            chunks.push((start, lines.len()));
+        }
+
+        let items: Vec<TestFileItem> = chunks
+            .iter()
+            .map(|&(start, end)| TestFileItem::new(lines[start..end].join("\n")))
+            .collect();
+        let Some(first_test) = items.iter().position(TestFileItem::is_test) else {
+            return ScenarioTestFile {
+                header: code.trim_end().to_string(),
+                items: Vec::new(),
+            };
+        };
+
+        let header_end = chunks[first_test].0;
+        ScenarioTestFile {
+            header: lines[..header_end].join("\n").trim_end().to_string(),
+            items: items[first_test..].to_vec(),
+        }
+    }
+
+    /// Adds tests for the new scenarios, in alphabetical order,
+    /// and removes the tests of scenarios that no longer exist.
+    ///
+    /// Tests that are kept are not changed in any way, so `#[ignore]` annotations,
+    /// comments and custom test bodies are preserved.
+    pub fn sync(&mut self, kind: ScenarioTestKind, scenario_names: &[String]) {
+        self.items.retain(|item| match &item.scenario {
+            Some(scenario) => scenario_names.contains(scenario),
+            None => true,
+        });
+
+        for scenario_name in scenario_names {
+            if self
+                .items
+                .iter()
+                .any(|item| item.scenario.as_ref() == Some(scenario_name))
+            {
+                continue;
+            }
+
+            let position = self.insert_position(scenario_name);
+            self.items.insert(
+                position,
+                TestFileItem {
+                    code: kind.test_fn_code(scenario_name),
+                    scenario: Some(scenario_name.clone()),
+                },
+            );
+        }
+    }
+
+    /// Before the first test of a scenario that comes later alphabetically,
+    /// otherwise after the last scenario test.
+    fn insert_position(&self, scenario_name: &str) -> usize {
+        if let Some(position) = self.items.iter().position(
+            |item| matches!(&item.scenario, Some(existing) if existing.as_str() > scenario_name),
+        ) {
+            return position;
+        }
+
+        self.items
+            .iter()
+            .rposition(|item| item.scenario.is_some())
+            .map(|position| position + 1)
+            .unwrap_or(self.items.len())
+    }
+
+    pub fn to_code(&self) -> String {
+        let mut code = String::new();
+        if !self.header.is_empty() {
+            code.push_str(&self.header);
+            code.push('\n');
+        }
+        for item in &self.items {
+            if !code.is_empty() {
+                code.push('\n');
+            }
+            code.push_str(&item.code);
+            code.push('\n');
+        }
+        code
+    }
+}
+
+fn is_block_end(line: &str, end: &str) -> bool {
+    if end == "}" {
+        line.trim_end() == "}"
+    } else {
+        line.contains(end)
+    }
+}
@@ -0,0 +1,78 @@
+use super::scenario_test_code::format_call;
+
+/// The two flavors of generated scenario tests, each kept in its own test file.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ScenarioTestKind {
+    /// Runs the scenarios on the Rust VM, with the contracts registered in `world()`.
+    Rs,
+
+    /// Runs the scenarios on the Go VM, using the compiled contracts.
+    Go,
+}
+
+impl ScenarioTestKind {
+    pub const ALL: [ScenarioTestKind; 2] = [ScenarioTestKind::Rs, ScenarioTestKind::Go];
+
+    fn name(self) -> &'static str {
+        match self {
+            ScenarioTestKind::Rs => "rs",
+            ScenarioTestKind::Go => "go",
+        }
+    }
+
+    /// Existing test files are recognized by this suffix, e.g. `scenario_rs_test.rs`.
+    pub fn test_file_suffix(self) -> String {
+        format!("scenario_{}_test.rs", self.name())
+    }
+
+    /// The name of newly created test files, e.g. `adder_scenario_rs_test.rs`.
+    pub fn default_test_file_name(self, package_name: &str) -> String {
+        format!(
+            "{}_{}",
+            package_name.replace('-', "_"),
+            self.test_file_suffix()
+        )
+    }
+
+    pub fn is_test_file_name(self, file_name: &str) -> bool {
+        let suffix = self.test_file_suffix();
+        file_name == suffix || file_name.ends_with(&format!("_{suffix}"))
+    }
+
+    /// E.g. `claim_egld_rs` for `claim-egld.scen.json`.
+    pub fn test_fn_name(self, scenario_file_name: &str) -> String {
+        let scenario_name = scenario_file_name
+            .strip_suffix(super::scenario_test_file::SCENARIO_FILE_SUFFIX)
+            .unwrap_or(scenario_file_name);
+        let fn_name: String = scenario_name
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() {
+                    c.to_ascii_lowercase()
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        format!("{fn_name}_{}", self.name())
+    }
+
+    /// The generated test function for a scenario file.
+    pub fn test_fn_code(self, scenario_file_name: &str) -> String {
+        let scenario_path = format!("\"scenarios/{scenario_file_name}\"");
+        let run_call = match self {
+            ScenarioTestKind::Rs => format_call(
+                4,
+                "multiversx_sc_scenario::run_rs",
+                &[scenario_path, "world()".to_string()],
+            ),
+            ScenarioTestKind::Go => {
+                format_call(4, "multiversx_sc_scenario::run_go", &[scenario_path])
+            },
+        };
+        format!(
+            "#[test]\nfn {}() {{\n{run_call}\n}}",
+            self.test_fn_name(scenario_file_name)
+        )
+    }
+}
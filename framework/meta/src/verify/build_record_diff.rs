@@ -0,0 +1,91 @@
+use super::BuildRecordJson;
+
+/// A build setting that has a different value in the rebuilt contract.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct BuildRecordDifference {
+    pub setting: &'static str,
+    pub recorded: String,
+    pub current: String,
+}
+
+fn compare(
+    differences: &mut Vec<BuildRecordDifference>,
+    setting: &'static str,
+    recorded: &str,
+    current: &str,
+) {
+    if recorded != current {
+        differences.push(BuildRecordDifference {
+            setting,
+            recorded: recorded.to_string(),
+            current: current.to_string(),
+        });
+    }
+}
+
+/// Settings missing from the recorded build, because it predates them, are not compared.
+pub fn diff_build_records(
+    recorded: &BuildRecordJson,
+    current: &BuildRecordJson,
+) -> Vec<BuildRecordDifference> {
+    let mut differences = Vec::new();
+    compare(
+        &mut differences,
+        "rustc version",
+        &recorded.rustc.short,
+        &current.rustc.short,
+    );
+    compare(
+        &mut differences,
+        "framework version",
+        &recorded.framework.version,
+        &current.framework.version,
+    );
+    compare(
+        &mut differences,
+        "contract crate version",
+        &recorded.contract_crate.version,
+        &current.contract_crate.version,
+    );
+    if recorded.code_hash.is_empty() {
+        return differences;
+    }
+
+    compare(
+        &mut differences,
+        "wasm-opt version",
+        &recorded.wasm_opt,
+        &current.wasm_opt,
+    );
+    compare(
+        &mut differences,
+        "build arguments",
+        &recorded.build_args.join(" "),
+        &current.build_args.join(" "),
+    );
+    compare(
+        &mut differences,
+        "rustflags",
+        &recorded.rustflags,
+        &current.rustflags,
+    );
+    compare(
+        &mut differences,
+        "features",
+        &recorded.features.join(","),
+        &current.features.join(","),
+    );
+    compare(
+        &mut differences,
+        "opt-level",
+        &recorded.opt_level,
+        &current.opt_level,
+    );
+    compare(
+        &mut differences,
+        "wasm-opt flags",
+        &recorded.wasm_opt_flags.join(" "),
+        &current.wasm_opt_flags.join(" "),
+    );
+    differences
+}
@@ -0,0 +1,101 @@
+use std::{fs, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+use crate::abi_json::{
+    BuildInfoAbiJson, ContractCrateBuildAbiJson, FrameworkBuildAbiJson, RustcAbiJson,
+};
+
+const PROFILE_BUILD_ARG: &str = "--profile";
+
+/// Everything needed to reproduce a contract build, written next to the wasm output.
+///
+/// The ABI only contains the versions, the build arguments and the wasm-opt version are only known when building.
+#[derive(Serialize, Deserialize, Default, Clone, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BuildRecordJson {
+    /// The name of the output contract, from multicontract.toml, e.g. `adder`.
+    #[serde(default)]
+    pub contract_name: String,
+
+    /// The base name of the output files, e.g. `adder-debug` when built with `--profile debug`.
+    #[serde(default)]
+    pub output_name: String,
+
+    pub contract_crate: ContractCrateBuildAbiJson,
+    pub framework: FrameworkBuildAbiJson,
+    pub rustc: RustcAbiJson,
+
+    /// The output of `wasm-opt --version`, empty if wasm-opt was not used.
+    #[serde(default)]
+    pub wasm_opt: String,
+
+    /// The `sc-meta build` arguments, except the ones only affecting the output location.
+    #[serde(default)]
+    pub build_args: Vec<String>,
+
+    /// The effective `RUSTFLAGS`, including the ones inherited from the environment.
+    #[serde(default)]
+    pub rustflags: String,
+
+    /// The Cargo features of the wasm crate build, including the ones from multicontract.toml.
+    #[serde(default)]
+    pub features: Vec<String>,
+
+    /// The `opt-level` of the release profile, passed to Cargo via the environment.
+    #[serde(default)]
+    pub opt_level: String,
+
+    /// Empty if wasm-opt was not used.
+    #[serde(default)]
+    pub wasm_opt_flags: Vec<String>,
+
+    /// Hex-encoded, as computed by the protocol.
+    pub code_hash: String,
+}
+
+impl BuildRecordJson {
+    /// Older builds only have the build info in the ABI.
+    pub fn from_abi_build_info(build_info: BuildInfoAbiJson) -> Self {
+        BuildRecordJson {
+            contract_crate: build_info.contract_crate,
+            framework: build_info.framework,
+            rustc: build_info.rustc,
+            ..Default::default()
+        }
+    }
+
+    /// The base name of the output files when building again with the given suffix.
+    ///
+    /// Records that predate the contract name only have the output name,
+    /// from which the suffix added by a recorded profile is removed.
+    pub fn rebuilt_output_name(&self, output_name: &str, wasm_suffix: &str) -> String {
+        let contract_name = if self.contract_name.is_empty() {
+            self.recorded_profile()
+                .and_then(|profile| output_name.strip_suffix(&format!("-{profile}")))
+                .unwrap_or(output_name)
+        } else {
+            self.contract_name.as_str()
+        };
+        format!("{contract_name}-{wasm_suffix}")
+    }
+
+    fn recorded_profile(&self) -> Option<&str> {
+        self.build_args
+            .iter()
+            .position(|arg| arg == PROFILE_BUILD_ARG)
+            .and_then(|index| self.build_args.get(index + 1))
+            .map(String::as_str)
+    }
+
+    pub fn load_from_file<P: AsRef<Path>>(path: P) -> Result<Self, String> {
+        let contents = fs::read_to_string(path).map_err(|err| err.to_string())?;
+        serde_json::from_str(&contents).map_err(|err| err.to_string())
+    }
+
+    pub fn save_to_file<P: AsRef<Path>>(&self, path: P) {
+        let mut json = serde_json::to_string_pretty(self).unwrap();
+        json.push('\n');
+        fs::write(path, json).expect("failed to write build record file");
+    }
+}
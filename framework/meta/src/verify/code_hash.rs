@@ -0,0 +1,25 @@
+use blake2::{digest::consts::U32, Blake2b, Digest};
+
+const CODE_HASH_LENGTH: usize = 32;
+
+/// The hash under which the protocol stores the code of a contract: Blake2b, with a 32 byte output.
+pub fn code_hash_hex(wasm_bytes: &[u8]) -> String {
+    let mut hasher = Blake2b::<U32>::new();
+    hasher.update(wasm_bytes);
+    hex::encode(hasher.finalize())
+}
+
+/// Accepts both hex, as shown by the explorer, and base64, as returned by the API for an account.
+pub fn parse_code_hash(code_hash: &str) -> Result<String, String> {
+    let code_hash = code_hash.trim();
+    let bytes = hex::decode(code_hash)
+        .or_else(|_| base64::decode(code_hash))
+        .map_err(|_| format!("code hash {code_hash} is neither hex, nor base64"))?;
+    if bytes.len() != CODE_HASH_LENGTH {
+        return Err(format!(
+            "code hash {code_hash} has {} bytes, expected {CODE_HASH_LENGTH}",
+            bytes.len()
+        ));
+    }
+    Ok(hex::encode(bytes))
+}
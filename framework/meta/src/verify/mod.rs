@@ -0,0 +1,13 @@
+mod build_record_diff;
+mod build_record_json;
+mod code_hash;
+mod rust_toolchain;
+mod source_package;
+
+pub use build_record_diff::{diff_build_records, BuildRecordDifference};
+pub use build_record_json::BuildRecordJson;
+pub use code_hash::{code_hash_hex, parse_code_hash};
+pub use rust_toolchain::{
+    find_installed_toolchain, rustc_version_verbose_matches, toolchain_install_command,
+};
+pub use source_package::write_source_package;
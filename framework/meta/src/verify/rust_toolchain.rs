@@ -0,0 +1,62 @@
+use std::process::Command;
+
+use crate::abi_json::RustcAbiJson;
+
+const RUSTUP_NAME: &str = "rustup";
+
+const COMMIT_HASH_PREFIX: &str = "commit-hash: ";
+const RELEASE_PREFIX: &str = "release: ";
+
+/// As recorded in the build info.
+const STABLE_CHANNEL: &str = "Stable";
+
+/// Finds an installed rustup toolchain with exactly the recorded compiler,
+/// so that the rebuild does not depend on which toolchain happens to be the default.
+///
+/// Toolchain names do not reliably identify nightly compilers,
+/// so every installed toolchain is asked for its version.
+pub fn find_installed_toolchain(rustc: &RustcAbiJson) -> Option<String> {
+    let output = Command::new(RUSTUP_NAME)
+        .args(["toolchain", "list"])
+        .output()
+        .ok()?;
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| line.split_whitespace().next())
+        .find(|toolchain| {
+            let Ok(output) = Command::new(RUSTUP_NAME)
+                .args(["run", toolchain, "rustc", "-vV"])
+                .output()
+            else {
+                return false;
+            };
+            rustc_version_verbose_matches(&String::from_utf8_lossy(&output.stdout), rustc)
+        })
+        .map(str::to_string)
+}
+
+/// Checks the output of `rustc -vV` against the recorded compiler.
+///
+/// The commit hash is the most precise, the release is only used if the commit hash was not known.
+pub fn rustc_version_verbose_matches(version_verbose: &str, rustc: &RustcAbiJson) -> bool {
+    let field = |prefix: &str| {
+        version_verbose
+            .lines()
+            .find_map(|line| line.strip_prefix(prefix))
+            .map(str::trim)
+    };
+    if rustc.commit_hash.is_empty() {
+        field(RELEASE_PREFIX) == Some(rustc.version.as_str())
+    } else {
+        field(COMMIT_HASH_PREFIX) == Some(rustc.commit_hash.as_str())
+    }
+}
+
+/// Only stable toolchains can be named for sure from the recorded compiler version.
+pub fn toolchain_install_command(rustc: &RustcAbiJson) -> Option<String> {
+    if rustc.channel == STABLE_CHANNEL {
+        Some(format!("rustup toolchain install {}", rustc.version))
+    } else {
+        None
+    }
+}
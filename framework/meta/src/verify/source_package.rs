@@ -0,0 +1,107 @@
+use std::{
+    collections::BTreeSet,
+    ffi::OsStr,
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use flate2::{write::GzEncoder, Compression};
+
+use crate::{folder_structure::CARGO_TOML_FILE_NAME, CargoTomlContents};
+
+const IGNORED_DIRECTORIES: &[&str] = &["target", ".git"];
+const OUTPUT_DIRECTORY: &str = "output";
+
+/// Of the build outputs, only these describe how the contract was built.
+const PACKAGED_OUTPUT_SUFFIXES: &[&str] = &[".abi.json", ".build.json"];
+
+const PACKAGED_FILE_MODE: u32 = 0o644;
+
+/// Writes a `.tar.gz` with the sources of the contract crate, so that others can rebuild it.
+///
+/// The archive only depends on the file contents, so packaging the same sources twice yields the same archive.
+///
+/// Returns the path dependencies that point outside the crate, since they are not part of the package.
+pub fn write_source_package(crate_path: &Path, package_path: &Path) -> Vec<String> {
+    let crate_root = fs::canonicalize(crate_path).expect("contract crate not found");
+    let crate_dir_name = crate_root
+        .file_name()
+        .expect("invalid contract crate path")
+        .to_string_lossy()
+        .to_string();
+
+    let mut files = Vec::new();
+    collect_package_files(&crate_root, &mut files);
+    files.sort();
+
+    let mut builder = tar::Builder::new(GzEncoder::new(Vec::new(), Compression::default()));
+    for file_path in &files {
+        let contents = fs::read(file_path).expect("failed to read contract file");
+        let relative_path = file_path.strip_prefix(&crate_root).unwrap();
+        let mut header = tar::Header::new_gnu();
+        header.set_size(contents.len() as u64);
+        header.set_mode(PACKAGED_FILE_MODE);
+        header.set_mtime(0);
+        builder
+            .append_data(
+                &mut header,
+                Path::new(&crate_dir_name).join(relative_path),
+                contents.as_slice(),
+            )
+            .expect("failed to add file to source package");
+    }
+    let archive = builder
+        .into_inner()
+        .and_then(|encoder| encoder.finish())
+        .expect("failed to write source package");
+    let mut package_file = fs::File::create(package_path).expect("failed to create source package");
+    package_file
+        .write_all(&archive)
+        .expect("failed to write source package");
+
+    external_path_dependencies(&crate_root, &files)
+}
+
+fn is_packaged_output(file_name: &str) -> bool {
+    PACKAGED_OUTPUT_SUFFIXES
+        .iter()
+        .any(|suffix| file_name.ends_with(suffix))
+}
+
+fn collect_package_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    let in_output = dir.file_name() == Some(OsStr::new(OUTPUT_DIRECTORY));
+    let read_dir = fs::read_dir(dir).expect("error reading directory");
+    for child_result in read_dir {
+        let child = child_result.unwrap();
+        let file_name = child.file_name().to_string_lossy().to_string();
+        if child.file_type().unwrap().is_dir() {
+            if !IGNORED_DIRECTORIES.contains(&file_name.as_str()) {
+                collect_package_files(&child.path(), files);
+            }
+        } else if !in_output || is_packaged_output(&file_name) {
+            files.push(child.path());
+        }
+    }
+}
+
+fn external_path_dependencies(crate_root: &Path, files: &[PathBuf]) -> Vec<String> {
+    let mut result = BTreeSet::new();
+    for cargo_toml_path in files
+        .iter()
+        .filter(|path| path.file_name() == Some(OsStr::new(CARGO_TOML_FILE_NAME)))
+    {
+        let crate_dir = cargo_toml_path.parent().unwrap();
+        let cargo_toml_contents = CargoTomlContents::load_from_file(cargo_toml_path);
+        for dep_path in cargo_toml_contents.local_dependency_paths(&[]) {
+            let in_crate = matches!(
+                fs::canonicalize(crate_dir.join(&dep_path)),
+                Ok(dep_abs_path) if dep_abs_path.starts_with(crate_root)
+            );
+            if !in_crate {
+                result.insert(dep_path);
+            }
+        }
+    }
+    result.into_iter().collect()
+}
@@ -0,0 +1,103 @@
+mod wasm_module_info;
+mod wasm_reachability;
+mod wasm_size_report;
+mod wasm_violation;
+
+pub use wasm_module_info::{WasmFunctionInfo, WasmModuleInfo, EI_IMPORT_MODULE};
+pub use wasm_size_report::{
+    diff_size_reports, WasmFunctionSize, WasmFunctionSizeChange, WasmSizeReport,
+};
+pub use wasm_violation::{report_wasm_analysis, WasmAnalysis, WasmViolation, WasmViolationKind};
+
+use std::collections::BTreeSet;
+
+use wasmparser::BinaryReaderError;
+
+use crate::ei::EIVersion;
+use wasm_violation::WasmViolationCollector;
+
+/// The maximum number of pages the VM allows in a single `memory.grow`.
+pub const MAX_MEMORY_GROW_PAGES: u32 = 10;
+
+/// Checks a compiled contract against the restrictions of the VM.
+///
+/// Each violation comes with the endpoints that can reach it.
+pub fn analyze_wasm(
+    wasm_bytes: &[u8],
+    ei_version: Option<EIVersion>,
+) -> Result<WasmAnalysis, BinaryReaderError> {
+    let info = WasmModuleInfo::parse(wasm_bytes)?;
+    let exports_reaching = wasm_reachability::exports_reaching_functions(&info);
+    let mut collector = WasmViolationCollector::default();
+
+    if let Some(ei_version) = ei_version {
+        check_imports(&info, ei_version, &exports_reaching, &mut collector);
+    }
+
+    for function_index in info.num_imported_functions()..info.num_functions() {
+        let function_info = info.function_info(function_index).unwrap();
+        let function_name = info.function_display_name(function_index);
+        let endpoints = &exports_reaching[function_index as usize];
+
+        for opcode in &function_info.float_opcodes {
+            collector.add(
+                WasmViolationKind::FloatOpcode(opcode.clone()),
+                function_name.clone(),
+                endpoints,
+            );
+        }
+        for opcode in &function_info.bulk_memory_opcodes {
+            collector.add(
+                WasmViolationKind::BulkMemoryOpcode(opcode.clone()),
+                function_name.clone(),
+                endpoints,
+            );
+        }
+        for pages in function_info.memory_grow_pages.iter().flatten() {
+            if *pages > MAX_MEMORY_GROW_PAGES {
+                collector.add(
+                    WasmViolationKind::MemoryGrowAboveLimit(*pages),
+                    function_name.clone(),
+                    endpoints,
+                );
+            }
+        }
+    }
+
+    Ok(WasmAnalysis {
+        ei_imports: info.ei_imports(),
+        size_report: WasmSizeReport::new(wasm_bytes.len(), &info),
+        ei_version,
+        violations: collector.into_violations(),
+    })
+}
+
+/// Imports from modules other than the VM are never resolved, so they are disallowed as well.
+///
+/// The VM rejects the contract even if the import is never called.
+fn check_imports(
+    info: &WasmModuleInfo,
+    ei_version: EIVersion,
+    exports_reaching: &[BTreeSet<String>],
+    collector: &mut WasmViolationCollector,
+) {
+    for (import_index, (module, name)) in info.imported_functions.iter().enumerate() {
+        if module == EI_IMPORT_MODULE && ei_version.contains_ei_function(name) {
+            continue;
+        }
+
+        let kind = WasmViolationKind::DisallowedImport(format!("{module}.{name}"));
+        collector.add_kind(kind.clone());
+        let import_index = import_index as u32;
+        for caller_index in info.num_imported_functions()..info.num_functions() {
+            let caller_info = info.function_info(caller_index).unwrap();
+            if caller_info.calls.contains(&import_index) {
+                collector.add(
+                    kind.clone(),
+                    info.function_display_name(caller_index),
+                    &exports_reaching[import_index as usize],
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,204 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use wasmparser::{
+    BinaryReaderError, ElementItems, ExternalKind, FunctionBody, Name, NameSectionReader, Operator,
+    Parser, Payload, TypeRef,
+};
+
+/// The module from which the VM functions are imported.
+pub const EI_IMPORT_MODULE: &str = "env";
+
+const NAME_SECTION: &str = "name";
+
+/// What the analysis needs to know about a function body.
+#[derive(Default, Debug)]
+pub struct WasmFunctionInfo {
+    /// The size of the function body in the binary, in bytes.
+    pub body_size: usize,
+    pub calls: BTreeSet<u32>,
+    pub has_indirect_calls: bool,
+    pub float_opcodes: BTreeSet<String>,
+    pub bulk_memory_opcodes: BTreeSet<String>,
+
+    /// The number of pages for each `memory.grow`, if it is a constant.
+    pub memory_grow_pages: Vec<Option<u32>>,
+}
+
+/// The contents of a compiled contract that are relevant to the analysis.
+///
+/// Imported functions come first in the function index space, followed by the functions defined in the module.
+#[derive(Default, Debug)]
+pub struct WasmModuleInfo {
+    /// `(module, name)` for each imported function.
+    pub imported_functions: Vec<(String, String)>,
+    pub functions: Vec<WasmFunctionInfo>,
+    pub exported_functions: Vec<(String, u32)>,
+
+    /// Functions placed in tables, the only possible targets of indirect calls.
+    pub table_functions: BTreeSet<u32>,
+
+    /// Only present if the contract was built with debug symbols.
+    pub function_names: BTreeMap<u32, String>,
+
+    pub code_section_size: usize,
+    pub data_section_size: usize,
+}
+
+impl WasmModuleInfo {
+    pub fn parse(wasm_bytes: &[u8]) -> Result<Self, BinaryReaderError> {
+        let mut info = WasmModuleInfo::default();
+        for payload in Parser::new(0).parse_all(wasm_bytes) {
+            match payload? {
+                Payload::ImportSection(reader) => {
+                    for import in reader {
+                        let import = import?;
+                        if let TypeRef::Func(_) = import.ty {
+                            info.imported_functions
+                                .push((import.module.to_string(), import.name.to_string()));
+                        }
+                    }
+                },
+                Payload::ExportSection(reader) => {
+                    for export in reader {
+                        let export = export?;
+                        if export.kind == ExternalKind::Func {
+                            info.exported_functions
+                                .push((export.name.to_string(), export.index));
+                        }
+                    }
+                },
+                Payload::ElementSection(reader) => {
+                    for element in reader {
+                        if let ElementItems::Functions(function_indices) = element?.items {
+                            for function_index in function_indices {
+                                info.table_functions.insert(function_index?);
+                            }
+                        }
+                    }
+                },
+                Payload::DataSection(reader) => {
+                    info.data_section_size = reader.range().len();
+                },
+                Payload::CodeSectionStart { range, .. } => {
+                    info.code_section_size = range.len();
+                },
+                Payload::CodeSectionEntry(body) => {
+                    info.functions.push(parse_function_body(&body)?);
+                },
+                Payload::CustomSection(reader) if reader.name() == NAME_SECTION => {
+                    info.parse_name_section(reader.data(), reader.data_offset())?;
+                },
+                _ => {},
+            }
+        }
+        Ok(info)
+    }
+
+    fn parse_name_section(&mut self, data: &[u8], offset: usize) -> Result<(), BinaryReaderError> {
+        for name in NameSectionReader::new(data, offset) {
+            if let Name::Function(name_map) = name? {
+                for naming in name_map {
+                    let naming = naming?;
+                    self.function_names
+                        .insert(naming.index, naming.name.to_string());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// The names of the functions imported from the VM, in the order they appear in the module.
+    pub fn ei_imports(&self) -> Vec<String> {
+        self.imported_functions
+            .iter()
+            .filter(|(module, _)| module == EI_IMPORT_MODULE)
+            .map(|(_, name)| name.clone())
+            .collect()
+    }
+
+    pub fn num_imported_functions(&self) -> u32 {
+        self.imported_functions.len() as u32
+    }
+
+    pub fn num_functions(&self) -> u32 {
+        self.num_imported_functions() + self.functions.len() as u32
+    }
+
+    /// Yields `None` for imported functions.
+    pub fn function_info(&self, function_index: u32) -> Option<&WasmFunctionInfo> {
+        let defined_index = function_index.checked_sub(self.num_imported_functions())?;
+        self.functions.get(defined_index as usize)
+    }
+
+    /// Uses the debug symbols if available, the function index otherwise.
+    pub fn function_display_name(&self, function_index: u32) -> String {
+        if let Some((module, name)) = self.imported_functions.get(function_index as usize) {
+            return format!("{module}.{name}");
+        }
+        match self.function_names.get(&function_index) {
+            Some(name) => name.clone(),
+            None => format!("func[{function_index}]"),
+        }
+    }
+}
+
+fn parse_function_body(body: &FunctionBody) -> Result<WasmFunctionInfo, BinaryReaderError> {
+    let mut function_info = WasmFunctionInfo {
+        body_size: body.range().len(),
+        ..Default::default()
+    };
+    let mut last_i32_const: Option<i32> = None;
+    let mut reader = body.get_operators_reader()?;
+    while !reader.eof() {
+        let operator = reader.read()?;
+        match &operator {
+            Operator::Call { function_index } => {
+                function_info.calls.insert(*function_index);
+            },
+            Operator::CallIndirect { .. } => {
+                function_info.has_indirect_calls = true;
+            },
+            Operator::MemoryGrow { .. } => {
+                let pages = last_i32_const.and_then(|value| u32::try_from(value).ok());
+                function_info.memory_grow_pages.push(pages);
+            },
+            Operator::MemoryInit { .. }
+            | Operator::DataDrop { .. }
+            | Operator::MemoryCopy { .. }
+            | Operator::MemoryFill { .. }
+            | Operator::TableInit { .. }
+            | Operator::ElemDrop { .. }
+            | Operator::TableCopy { .. } => {
+                function_info
+                    .bulk_memory_opcodes
+                    .insert(operator_name(&operator));
+            },
+            _ => {
+                let name = operator_name(&operator);
+                if is_float_operator_name(&name) {
+                    function_info.float_opcodes.insert(name);
+                }
+            },
+        }
+        last_i32_const = match operator {
+            Operator::I32Const { value } => Some(value),
+            _ => None,
+        };
+    }
+    Ok(function_info)
+}
+
+/// The name of the operator variant, e.g. `F64Add`.
+fn operator_name(operator: &Operator) -> String {
+    let debug = format!("{operator:?}");
+    debug
+        .split(|c: char| !c.is_alphanumeric())
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// Covers arithmetic, loads, stores, constants and conversions from and to floats, including SIMD lanes.
+fn is_float_operator_name(name: &str) -> bool {
+    name.contains("F32") || name.contains("F64")
+}
@@ -0,0 +1,28 @@
+use std::collections::BTreeSet;
+
+use super::WasmModuleInfo;
+
+/// For each function, the exported functions from which it can be called.
+///
+/// Indirect calls can reach any function placed in a table, so they are treated as calls to all of them.
+pub(super) fn exports_reaching_functions(info: &WasmModuleInfo) -> Vec<BTreeSet<String>> {
+    let num_functions = info.num_functions();
+    let mut result = vec![BTreeSet::new(); num_functions as usize];
+    for (export_name, export_index) in &info.exported_functions {
+        let mut visited = BTreeSet::new();
+        let mut stack = vec![*export_index];
+        while let Some(function_index) = stack.pop() {
+            if function_index >= num_functions || !visited.insert(function_index) {
+                continue;
+            }
+            result[function_index as usize].insert(export_name.clone());
+            if let Some(function_info) = info.function_info(function_index) {
+                stack.extend(function_info.calls.iter().copied());
+                if function_info.has_indirect_calls {
+                    stack.extend(info.table_functions.iter().copied());
+                }
+            }
+        }
+    }
+    result
+}
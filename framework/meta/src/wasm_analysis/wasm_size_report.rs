@@ -0,0 +1,109 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::WasmModuleInfo;
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct WasmFunctionSize {
+    pub name: String,
+    pub size: usize,
+}
+
+/// Machine-readable breakdown of the size of a compiled contract, in bytes.
+///
+/// Function names are only meaningful when the contract was built with `--wasm-symbols`,
+/// otherwise they are just indices, which can change from one build to the next.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WasmSizeReport {
+    pub wasm_size: usize,
+    pub code_size: usize,
+    pub data_size: usize,
+    /// Largest first.
+    pub functions: Vec<WasmFunctionSize>,
+}
+
+impl WasmSizeReport {
+    pub fn new(wasm_size: usize, info: &WasmModuleInfo) -> Self {
+        let mut functions: Vec<WasmFunctionSize> = (0..info.functions.len() as u32)
+            .map(|defined_index| {
+                let function_index = info.num_imported_functions() + defined_index;
+                WasmFunctionSize {
+                    name: info.function_display_name(function_index),
+                    size: info.function_info(function_index).unwrap().body_size,
+                }
+            })
+            .collect();
+        functions.sort_by(|a, b| b.size.cmp(&a.size).then_with(|| a.name.cmp(&b.name)));
+        WasmSizeReport {
+            wasm_size,
+            code_size: info.code_section_size,
+            data_size: info.data_section_size,
+            functions,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        let mut json = serde_json::to_string_pretty(self).unwrap();
+        json.push('\n');
+        json
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|err| err.to_string())
+    }
+
+    /// Functions with the same name are counted together.
+    fn sizes_by_name(&self) -> BTreeMap<&str, usize> {
+        let mut result = BTreeMap::new();
+        for function in &self.functions {
+            *result.entry(function.name.as_str()).or_default() += function.size;
+        }
+        result
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct WasmFunctionSizeChange {
+    pub name: String,
+    /// `None` if the function was added.
+    pub before: Option<usize>,
+    /// `None` if the function was removed.
+    pub after: Option<usize>,
+}
+
+impl WasmFunctionSizeChange {
+    pub fn difference(&self) -> i64 {
+        self.after.unwrap_or_default() as i64 - self.before.unwrap_or_default() as i64
+    }
+}
+
+/// The functions whose size changed, biggest changes first.
+pub fn diff_size_reports(
+    baseline: &WasmSizeReport,
+    current: &WasmSizeReport,
+) -> Vec<WasmFunctionSizeChange> {
+    let before = baseline.sizes_by_name();
+    let after = current.sizes_by_name();
+    let mut names: Vec<&str> = before.keys().chain(after.keys()).copied().collect();
+    names.sort_unstable();
+    names.dedup();
+
+    let mut changes: Vec<WasmFunctionSizeChange> = names
+        .into_iter()
+        .map(|name| WasmFunctionSizeChange {
+            name: name.to_string(),
+            before: before.get(name).copied(),
+            after: after.get(name).copied(),
+        })
+        .filter(|change| change.before != change.after)
+        .collect();
+    changes.sort_by(|a, b| {
+        b.difference()
+            .abs()
+            .cmp(&a.difference().abs())
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    changes
+}
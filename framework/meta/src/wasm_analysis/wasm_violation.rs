@@ -0,0 +1,153 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+};
+
+use colored::Colorize;
+
+use crate::ei::EIVersion;
+
+use super::WasmSizeReport;
+
+/// Problems that make the VM reject or fail to run the contract.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum WasmViolationKind {
+    /// A function imported from the VM that is not part of the targeted EI version.
+    DisallowedImport(String),
+    /// The VM does not allow floating point operations, since they are not deterministic.
+    FloatOpcode(String),
+    /// A `memory.grow` by a constant number of pages, larger than the VM allows.
+    MemoryGrowAboveLimit(u32),
+    /// Instructions from the bulk memory proposal, not supported by the VM.
+    BulkMemoryOpcode(String),
+}
+
+impl WasmViolationKind {
+    pub fn rule_name(&self) -> &'static str {
+        match self {
+            WasmViolationKind::DisallowedImport(_) => "disallowed-import",
+            WasmViolationKind::FloatOpcode(_) => "float-opcode",
+            WasmViolationKind::MemoryGrowAboveLimit(_) => "memory-grow-above-limit",
+            WasmViolationKind::BulkMemoryOpcode(_) => "bulk-memory-opcode",
+        }
+    }
+}
+
+impl fmt::Display for WasmViolationKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WasmViolationKind::DisallowedImport(name) => {
+                write!(f, "import `{name}` is not provided by the VM")
+            },
+            WasmViolationKind::FloatOpcode(name) => {
+                write!(f, "floating point instruction `{name}`")
+            },
+            WasmViolationKind::MemoryGrowAboveLimit(pages) => write!(
+                f,
+                "`memory.grow` by {pages} pages, above the limit of {} pages",
+                super::MAX_MEMORY_GROW_PAGES
+            ),
+            WasmViolationKind::BulkMemoryOpcode(name) => {
+                write!(f, "bulk memory instruction `{name}`")
+            },
+        }
+    }
+}
+
+/// All occurrences of a violation, grouped together.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct WasmViolation {
+    pub kind: WasmViolationKind,
+    /// The functions containing the offending instruction, or calling the disallowed import.
+    ///
+    /// Can be empty for imports that are never called.
+    pub functions: BTreeSet<String>,
+    /// The endpoints that can reach any of these functions.
+    pub endpoints: BTreeSet<String>,
+}
+
+#[derive(Default)]
+pub(super) struct WasmViolationCollector(BTreeMap<WasmViolationKind, WasmViolation>);
+
+impl WasmViolationCollector {
+    /// Some violations are not tied to any function.
+    pub(super) fn add_kind(&mut self, kind: WasmViolationKind) -> &mut WasmViolation {
+        self.0.entry(kind.clone()).or_insert_with(|| WasmViolation {
+            kind,
+            functions: BTreeSet::new(),
+            endpoints: BTreeSet::new(),
+        })
+    }
+
+    pub(super) fn add(
+        &mut self,
+        kind: WasmViolationKind,
+        function: String,
+        endpoints: &BTreeSet<String>,
+    ) {
+        let violation = self.add_kind(kind);
+        violation.functions.insert(function);
+        violation.endpoints.extend(endpoints.iter().cloned());
+    }
+
+    pub(super) fn into_violations(self) -> Vec<WasmViolation> {
+        self.0.into_values().collect()
+    }
+}
+
+/// The outcome of analyzing a compiled contract.
+#[derive(Clone, Debug)]
+pub struct WasmAnalysis {
+    /// The functions imported from the VM.
+    pub ei_imports: Vec<String>,
+    /// The imports are only checked when a version is specified.
+    pub ei_version: Option<EIVersion>,
+    pub violations: Vec<WasmViolation>,
+    pub size_report: WasmSizeReport,
+}
+
+impl WasmAnalysis {
+    pub fn has_violations(&self) -> bool {
+        !self.violations.is_empty()
+    }
+}
+
+fn format_names(names: &BTreeSet<String>) -> String {
+    names
+        .iter()
+        .map(|name| format!("`{name}`"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Prints all violations, fails if there are any.
+pub fn report_wasm_analysis(wasm_name: &str, analysis: &WasmAnalysis) {
+    for violation in &analysis.violations {
+        let endpoints = if violation.endpoints.is_empty() {
+            "not reachable from any endpoint".to_string()
+        } else {
+            format!("used by {}", format_names(&violation.endpoints))
+        };
+        let location = if violation.functions.is_empty() {
+            "never called".to_string()
+        } else {
+            format!("in {}", format_names(&violation.functions))
+        };
+        let line = format!(
+            "[{}] {} {location}, {endpoints}",
+            violation.kind.rule_name(),
+            violation.kind,
+        );
+        println!("{} {}", "error:".red(), line.red());
+    }
+
+    let ei_description = match analysis.ei_version {
+        Some(ei_version) => format!("EI {}", ei_version.name()),
+        None => "any EI version".to_string(),
+    };
+    assert!(
+        !analysis.has_violations(),
+        "{wasm_name} cannot run on the VM, targeting {ei_description}: {} violation(s) found.",
+        analysis.violations.len()
+    );
+}
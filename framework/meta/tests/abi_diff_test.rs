@@ -0,0 +1,278 @@
+use multiversx_sc_meta::{
+    abi_diff::{diff_abi, AbiChangeSeverity},
+    abi_json::deserialize_abi_from_json,
+};
+
+const OLD_ABI_JSON: &str = r#"{
+    "name": "Test",
+    "constructor": {
+        "inputs": [{ "name": "initial", "type": "BigUint" }],
+        "outputs": []
+    },
+    "endpoints": [
+        {
+            "name": "deposit",
+            "mutability": "mutable",
+            "payableInTokens": ["*"],
+            "inputs": [{ "name": "to", "type": "Address" }],
+            "outputs": []
+        },
+        {
+            "name": "getSum",
+            "mutability": "readonly",
+            "inputs": [],
+            "outputs": [{ "type": "BigUint" }]
+        },
+        {
+            "name": "getStatus",
+            "mutability": "readonly",
+            "onlyRoles": ["pauser"],
+            "inputs": [],
+            "outputs": [{ "type": "Status" }]
+        }
+    ],
+    "events": [
+        {
+            "identifier": "deposit",
+            "inputs": [
+                { "name": "caller", "type": "Address", "indexed": true },
+                { "name": "amount", "type": "BigUint" }
+            ]
+        }
+    ],
+    "hasCallback": false,
+    "types": {
+        "Status": {
+            "type": "enum",
+            "variants": [
+                { "name": "Inactive", "discriminant": 0 },
+                { "name": "Active", "discriminant": 1 },
+                { "name": "Paused", "discriminant": 2 }
+            ]
+        },
+        "Payment": {
+            "type": "struct",
+            "fields": [
+                { "name": "token", "type": "TokenIdentifier" },
+                { "name": "amount", "type": "BigUint" }
+            ]
+        }
+    }
+}"#;
+
+const NEW_ABI_JSON: &str = r#"{
+    "name": "Test",
+    "constructor": {
+        "inputs": [{ "name": "initial_value", "type": "BigUint" }],
+        "outputs": []
+    },
+    "endpoints": [
+        {
+            "name": "deposit",
+            "mutability": "mutable",
+            "onlyRoles": ["depositor"],
+            "payableInTokens": ["EGLD"],
+            "inputs": [
+                { "name": "to", "type": "Address" },
+                { "name": "memo", "type": "optional<bytes>", "multi_arg": true }
+            ],
+            "outputs": []
+        },
+        {
+            "name": "getTotal",
+            "mutability": "readonly",
+            "inputs": [],
+            "outputs": [{ "type": "BigUint" }]
+        },
+        {
+            "name": "getStatus",
+            "mutability": "mutable",
+            "inputs": [],
+            "outputs": [{ "type": "Status" }]
+        }
+    ],
+    "events": [
+        {
+            "identifier": "deposit",
+            "inputs": [
+                { "name": "caller", "type": "Address", "indexed": true },
+                { "name": "amount", "type": "u64" }
+            ]
+        }
+    ],
+    "hasCallback": false,
+    "types": {
+        "Status": {
+            "type": "enum",
+            "variants": [
+                { "name": "Inactive", "discriminant": 0 },
+                { "name": "Active", "discriminant": 1 },
+                { "name": "Stopped", "discriminant": 3 }
+            ]
+        },
+        "Payment": {
+            "type": "struct",
+            "fields": [
+                { "name": "token_identifier", "type": "TokenIdentifier" },
+                { "name": "amount", "type": "BigUint" }
+            ]
+        }
+    }
+}"#;
+
+fn diff_descriptions() -> Vec<String> {
+    let old = deserialize_abi_from_json(OLD_ABI_JSON).unwrap();
+    let new = deserialize_abi_from_json(NEW_ABI_JSON).unwrap();
+    diff_abi(&old, &new)
+        .iter()
+        .map(ToString::to_string)
+        .collect()
+}
+
+#[test]
+fn test_abi_diff() {
+    assert_eq!(
+        diff_descriptions(),
+        vec![
+            "[breaking] endpoint `deposit`: no longer accepts payments in `*`",
+            "[breaking] endpoint `deposit`: now restricted to role `depositor`",
+            "[breaking] endpoint `getSum`: renamed to `getTotal`",
+            "[breaking] endpoint `getStatus`: mutability changed from Readonly to Mutable",
+            "[breaking] event `deposit`: argument #1 `amount` changed type from `BigUint` to `u64`",
+            "[breaking] type `Status`: variant `Paused` removed",
+            "[compatible] endpoint `deposit`: input #1 `memo` of type `optional<bytes>` added",
+            "[compatible] endpoint `getStatus`: no longer restricted to role `pauser`",
+            "[compatible] endpoint `getTotal`: added",
+            "[compatible] type `Payment`: field #0 renamed from `token` to `token_identifier`",
+            "[compatible] type `Status`: variant `Stopped` added",
+        ]
+    );
+}
+
+const RENAMES_OLD_ABI_JSON: &str = r#"{
+    "name": "Test",
+    "endpoints": [
+        {
+            "name": "pay",
+            "mutability": "mutable",
+            "inputs": [{ "name": "payments", "type": "List<Payment>" }],
+            "outputs": []
+        }
+    ],
+    "events": [
+        {
+            "identifier": "payment",
+            "inputs": [
+                { "name": "caller", "type": "Address", "indexed": true },
+                { "name": "payment", "type": "Payment" }
+            ]
+        },
+        {
+            "identifier": "refund",
+            "inputs": [
+                { "name": "caller", "type": "Address", "indexed": true },
+                { "name": "amount", "type": "BigUint" }
+            ]
+        }
+    ],
+    "hasCallback": false,
+    "types": {
+        "Order": {
+            "type": "struct",
+            "fields": [
+                { "name": "payment", "type": "Payment" },
+                { "name": "count", "type": "u32" }
+            ]
+        },
+        "Payment": {
+            "type": "struct",
+            "fields": [
+                { "name": "token", "type": "TokenIdentifier" },
+                { "name": "amount", "type": "BigUint" }
+            ]
+        }
+    }
+}"#;
+
+const RENAMES_NEW_ABI_JSON: &str = r#"{
+    "name": "Test",
+    "endpoints": [
+        {
+            "name": "pay",
+            "mutability": "mutable",
+            "inputs": [{ "name": "payments", "type": "List<TokenPayment>" }],
+            "outputs": []
+        }
+    ],
+    "events": [
+        {
+            "identifier": "payment",
+            "inputs": [
+                { "name": "from", "type": "Address", "indexed": true },
+                { "name": "payment", "type": "TokenPayment" }
+            ]
+        },
+        {
+            "identifier": "refund",
+            "inputs": [
+                { "name": "caller", "type": "Address" },
+                { "name": "amount", "type": "BigUint", "indexed": true }
+            ]
+        }
+    ],
+    "hasCallback": false,
+    "types": {
+        "TokenOrder": {
+            "type": "struct",
+            "fields": [
+                { "name": "payment", "type": "TokenPayment" },
+                { "name": "count", "type": "u32" }
+            ]
+        },
+        "TokenPayment": {
+            "type": "struct",
+            "fields": [
+                { "name": "token_identifier", "type": "TokenIdentifier" },
+                { "name": "amount", "type": "BigUint" }
+            ]
+        }
+    }
+}"#;
+
+#[test]
+fn test_abi_diff_renames() {
+    let old = deserialize_abi_from_json(RENAMES_OLD_ABI_JSON).unwrap();
+    let new = deserialize_abi_from_json(RENAMES_NEW_ABI_JSON).unwrap();
+    let descriptions: Vec<String> = diff_abi(&old, &new)
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    assert_eq!(
+        descriptions,
+        vec![
+            "[breaking] event `refund`: argument #0 `caller` is no longer indexed",
+            "[breaking] event `refund`: argument #1 `amount` is now indexed",
+            "[compatible] event `payment`: argument #0 renamed from `caller` to `from`",
+            "[compatible] type `Order`: renamed to `TokenOrder`",
+            "[compatible] type `Payment`: renamed to `TokenPayment`",
+            "[compatible] type `Payment`: field #0 renamed from `token` to `token_identifier`",
+        ]
+    );
+}
+
+#[test]
+fn test_abi_diff_same() {
+    let abi = deserialize_abi_from_json(OLD_ABI_JSON).unwrap();
+    assert!(diff_abi(&abi, &abi).is_empty());
+}
+
+#[test]
+fn test_abi_diff_severity() {
+    let old = deserialize_abi_from_json(OLD_ABI_JSON).unwrap();
+    let new = deserialize_abi_from_json(NEW_ABI_JSON).unwrap();
+
+    // downgrading removes the newly added endpoint
+    assert!(diff_abi(&new, &old)
+        .iter()
+        .any(|change| change.severity == AbiChangeSeverity::Breaking));
+}
@@ -0,0 +1,165 @@
+use multiversx_sc::abi::{ContractAbi, EndpointAbi, EndpointMutabilityAbi, InputAbi};
+use multiversx_sc_meta::{
+    meta_validate_abi::{validate_abi_rules, AbiValidationRule, AbiValidationSeverity},
+    output_contract::{MultiContractConfigSerde, OutputContractConfig},
+};
+
+fn get_serialized_toml() -> MultiContractConfigSerde {
+    toml::from_str(
+        r#"
+        [settings]
+        main = "main-contract"
+
+        [contracts.main-contract]
+        add-unlabelled = true
+
+        [contracts.view-contract]
+        external-view = true
+        add-labels = ["view"]
+
+        [abi-validation]
+        payable-view = "deny"
+        undescribed-type = "allow"
+    "#,
+    )
+    .unwrap()
+}
+
+fn endpoint(
+    name: &'static str,
+    rust_method_name: &'static str,
+    labels: &'static [&'static str],
+    mutability: EndpointMutabilityAbi,
+) -> EndpointAbi {
+    EndpointAbi {
+        name,
+        rust_method_name,
+        labels,
+        mutability,
+        ..Default::default()
+    }
+}
+
+fn get_contract_abi() -> ContractAbi {
+    let mut payable_view = endpoint("getSum", "get_sum", &[], EndpointMutabilityAbi::Readonly);
+    payable_view.payable_in_tokens = &["EGLD"];
+
+    let mut undescribed_input = endpoint("pay", "pay", &[], EndpointMutabilityAbi::Mutable);
+    undescribed_input.inputs.push(InputAbi {
+        arg_name: "payments",
+        type_name: "List<Payment>".into(),
+        multi_arg: false,
+    });
+
+    let endpoints = vec![
+        endpoint("deposit", "deposit", &[], EndpointMutabilityAbi::Mutable),
+        // same module, inherited twice
+        endpoint("deposit", "deposit", &[], EndpointMutabilityAbi::Mutable),
+        endpoint(
+            "deposit",
+            "module_deposit",
+            &["view"],
+            EndpointMutabilityAbi::Mutable,
+        ),
+        endpoint(
+            "ESDTTransfer",
+            "esdt_transfer",
+            &[],
+            EndpointMutabilityAbi::Mutable,
+        ),
+        payable_view,
+        undescribed_input,
+        endpoint(
+            "getView",
+            "get_view",
+            &["view"],
+            EndpointMutabilityAbi::Readonly,
+        ),
+    ];
+    ContractAbi::generate_with_endpoints(endpoints)
+}
+
+#[test]
+fn test_abi_validation_rules() {
+    let abi = get_contract_abi();
+    let output_contracts = OutputContractConfig::load_from_config(&get_serialized_toml(), &abi);
+    let issues = validate_abi_rules(&abi, &output_contracts, None);
+
+    let rules_and_severities: Vec<(AbiValidationRule, AbiValidationSeverity)> = issues
+        .iter()
+        .map(|issue| (issue.rule, issue.severity))
+        .collect();
+    assert_eq!(
+        rules_and_severities,
+        vec![
+            (
+                AbiValidationRule::DuplicateEndpointName,
+                AbiValidationSeverity::Deny
+            ),
+            (
+                AbiValidationRule::BuiltinFunctionName,
+                AbiValidationSeverity::Deny
+            ),
+            (AbiValidationRule::PayableView, AbiValidationSeverity::Deny),
+            (
+                AbiValidationRule::ExternalViewMutableEndpoint,
+                AbiValidationSeverity::Warn
+            ),
+        ]
+    );
+    assert_eq!(
+        issues[0].message,
+        "endpoint name `deposit` is used by several methods: `deposit`, `module_deposit` (labels: view)"
+    );
+    assert_eq!(
+        issues[3].message,
+        "external view contract `view-contract` exposes mutable endpoint `deposit`"
+    );
+}
+
+#[test]
+fn test_abi_validation_undescribed_type() {
+    let abi = get_contract_abi();
+    let output_contracts = OutputContractConfig::default_config(&abi);
+    let issues = validate_abi_rules(&abi, &output_contracts, None);
+
+    let undescribed: Vec<&str> = issues
+        .iter()
+        .filter(|issue| issue.rule == AbiValidationRule::UndescribedType)
+        .map(|issue| issue.message.as_str())
+        .collect();
+    assert_eq!(
+        undescribed,
+        vec!["type `Payment`, referenced in endpoint `pay`, has no description"]
+    );
+}
+
+#[test]
+fn test_abi_validation_promise_callback() {
+    let mut abi = get_contract_abi();
+    abi.promise_callbacks.push(endpoint(
+        "retrieve_funds_callback",
+        "retrieve_funds_callback",
+        &[],
+        EndpointMutabilityAbi::Mutable,
+    ));
+    let mut output_contracts = OutputContractConfig::default_config(&abi);
+    assert!(!validate_abi_rules(&abi, &output_contracts, Some(""))
+        .iter()
+        .any(|issue| issue.rule == AbiValidationRule::CallbackWithoutProxyCall));
+
+    // opt-in
+    output_contracts.abi_validation.0.insert(
+        AbiValidationRule::CallbackWithoutProxyCall,
+        AbiValidationSeverity::Warn,
+    );
+    let has_callback_issue = |source: &str| {
+        validate_abi_rules(&abi, &output_contracts, Some(source))
+            .iter()
+            .any(|issue| issue.rule == AbiValidationRule::CallbackWithoutProxyCall)
+    };
+    assert!(has_callback_issue(""));
+    assert!(!has_callback_issue(
+        "self.callbacks().retrieve_funds_callback()"
+    ));
+}
@@ -1,5 +1,7 @@
 use multiversx_sc::abi::{ContractAbi, EndpointAbi};
-use multiversx_sc_meta::output_contract::{MultiContractConfigSerde, OutputContractConfig};
+use multiversx_sc_meta::output_contract::{
+    ContractAllocator, MultiContractConfigSerde, OutputContractConfig,
+};
 
 fn get_serialized_toml() -> MultiContractConfigSerde {
     toml::from_str(
@@ -122,3 +124,69 @@ fn test_output_contract_config() {
         ["endpoint1", "endpoint2", "endpoint3", "endpoint4"]
     );
 }
+
+#[test]
+fn test_build_profiles() {
+    let serde: MultiContractConfigSerde = toml::from_str(
+        r#"
+        [settings]
+        main = "main-contract"
+
+        [contracts.main-contract]
+        add-unlabelled = true
+        features = ["feature1", "multiversx-sc-wasm-adapter/feature2"]
+        opt-level = "s"
+        wasm-opt-flags = ["-O3"]
+        stack-size = "64k"
+
+        [contracts.secondary-contract]
+        add-labels = ["label1"]
+        allocator = "static64k"
+
+        [profiles.debug]
+        panic-message = true
+        features = ["feature3"]
+        allocator = "leaking"
+    "#,
+    )
+    .unwrap();
+    let mut contract_config = OutputContractConfig::load_from_config(&serde, &get_contract_abi());
+
+    let main_settings = &contract_config.main_contract().settings;
+    assert_eq!(
+        main_settings.features,
+        ["feature1", "multiversx-sc-wasm-adapter/feature2"]
+    );
+    assert_eq!(main_settings.opt_level, "s");
+    assert_eq!(main_settings.wasm_opt_flags, ["-O3"]);
+    assert_eq!(main_settings.allocator, ContractAllocator::WeeAlloc);
+    assert_eq!(main_settings.stack_size, Some(65536));
+    assert!(!main_settings.panic_message);
+
+    let secondary_settings = &contract_config.find_contract("secondary-contract").settings;
+    assert!(secondary_settings.features.is_empty());
+    assert_eq!(secondary_settings.opt_level, "z");
+    assert_eq!(secondary_settings.wasm_opt_flags, ["-Oz"]);
+    assert_eq!(secondary_settings.allocator, ContractAllocator::Static64k);
+    assert_eq!(secondary_settings.stack_size, None);
+
+    contract_config.apply_profile("debug");
+
+    let main_settings = &contract_config.main_contract().settings;
+    assert_eq!(
+        main_settings.features,
+        [
+            "feature1",
+            "multiversx-sc-wasm-adapter/feature2",
+            "feature3"
+        ]
+    );
+    assert_eq!(main_settings.opt_level, "s");
+    assert_eq!(main_settings.allocator, ContractAllocator::Leaking);
+    assert!(main_settings.panic_message);
+
+    let secondary_settings = &contract_config.find_contract("secondary-contract").settings;
+    assert_eq!(secondary_settings.features, ["feature3"]);
+    assert_eq!(secondary_settings.allocator, ContractAllocator::Leaking);
+    assert!(secondary_settings.panic_message);
+}
@@ -0,0 +1,182 @@
+use std::{fs, path::Path, process::Command};
+
+use multiversx_sc_meta::{
+    template::{
+        default_templates_path, find_templates, find_templates_path_from, CrateNamesReplacer,
+    },
+    CargoTomlContents,
+};
+
+#[test]
+fn template_names_replace_single_word_test() {
+    let replacer = CrateNamesReplacer::new("adder", "my-adder");
+
+    assert_eq!(
+        replacer.replace_in_text("use adder::*;\nlet adder = adder::contract_obj();", true),
+        "use my_adder::*;\nlet my_adder = my_adder::contract_obj();"
+    );
+    assert_eq!(
+        replacer.replace_in_text(
+            r#"world.register_contract("file:output/adder.wasm", adder::ContractBuilder);"#,
+            true
+        ),
+        r#"world.register_contract("file:output/my-adder.wasm", my_adder::ContractBuilder);"#
+    );
+    assert_eq!(
+        replacer.replace_in_text("name = \"adder-meta\"\npath = \"src/adder.rs\"", false),
+        "name = \"my-adder-meta\"\npath = \"src/my_adder.rs\""
+    );
+    assert_eq!(
+        replacer.replace_in_text("a ladder, some adders", true),
+        "a ladder, some adders"
+    );
+    assert_eq!(
+        replacer.replace_in_text("let adder_contract = my_adder;", true),
+        "let adder_contract = my_adder;"
+    );
+    assert_eq!(
+        replacer.replace_in_file_name("adder_test.rs"),
+        "my_adder_test.rs"
+    );
+    assert_eq!(
+        replacer.replace_in_file_name("adder.scen.json"),
+        "my-adder.scen.json"
+    );
+}
+
+#[test]
+fn template_names_replace_keeps_longer_identifiers_test() {
+    let replacer = CrateNamesReplacer::new("empty", "my-contract");
+
+    assert_eq!(
+        replacer.replace_in_text(
+            "multiversx_sc_wasm_adapter::empty_callback! {}\nif list.is_empty() {}",
+            true
+        ),
+        "multiversx_sc_wasm_adapter::empty_callback! {}\nif list.is_empty() {}"
+    );
+    assert_eq!(
+        replacer.replace_in_file_name("empty_rust_test.rs"),
+        "my_contract_rust_test.rs"
+    );
+}
+
+#[test]
+fn template_names_replace_two_words_test() {
+    let replacer = CrateNamesReplacer::new("crowdfunding-esdt", "my-crowdfunding");
+
+    assert_eq!(
+        replacer.replace_in_text(
+            "multiversx_sc_meta::cli_main::<crowdfunding_esdt::AbiProvider>();",
+            true
+        ),
+        "multiversx_sc_meta::cli_main::<my_crowdfunding::AbiProvider>();"
+    );
+    assert_eq!(
+        replacer.replace_in_text("\"file:output/crowdfunding-esdt.wasm\"", true),
+        "\"file:output/my-crowdfunding.wasm\""
+    );
+    assert_eq!(
+        replacer.replace_in_text("crowdfunding-init.scen.json", false),
+        "crowdfunding-init.scen.json"
+    );
+}
+
+#[test]
+fn template_list_test() {
+    let templates = find_templates(&default_templates_path().unwrap());
+    let template_names: Vec<&str> = templates
+        .iter()
+        .map(|template| template.name.as_str())
+        .collect();
+    assert!(template_names.contains(&"adder"));
+    assert!(template_names.contains(&"crowdfunding-esdt"));
+    assert!(template_names.contains(&"empty"));
+}
+
+#[test]
+fn template_path_test() {
+    let meta_src_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+    let templates_path = find_templates_path_from(&meta_src_path).unwrap();
+    assert!(templates_path.ends_with("contracts/examples"));
+    assert!(templates_path.join("adder").is_dir());
+
+    assert!(find_templates_path_from(&std::env::temp_dir()).is_none());
+}
+
+#[test]
+fn template_instantiate_adder_test() {
+    let target_parent = Path::new(env!("CARGO_TARGET_TMPDIR")).join("template-test");
+    let target_path = target_parent.join("new-adder");
+    if target_path.exists() {
+        fs::remove_dir_all(&target_path).unwrap();
+    }
+
+    let templates = find_templates(&default_templates_path().unwrap());
+    let adder = templates
+        .iter()
+        .find(|template| template.name == "adder")
+        .unwrap();
+    adder.instantiate(&target_path, "new-adder");
+
+    assert!(target_path.join("src/new_adder.rs").is_file());
+    assert!(target_path.join("meta/src/main.rs").is_file());
+    assert!(target_path.join("wasm/src/lib.rs").is_file());
+    assert!(target_path.join("multiversx.json").is_file());
+    assert!(target_path.join("scenarios/new-adder.scen.json").is_file());
+    assert!(target_path
+        .join("tests/new_adder_scenario_rs_test.rs")
+        .is_file());
+    assert!(!target_path.join("src/adder.rs").exists());
+
+    let cargo_toml = CargoTomlContents::load_from_file(target_path.join("Cargo.toml"));
+    assert_eq!(cargo_toml.package_name(), "new-adder");
+    let framework_path = cargo_toml.local_dependency_paths(&[]);
+    assert!(target_path
+        .join(&framework_path[0])
+        .join("Cargo.toml")
+        .is_file());
+
+    let meta_cargo_toml =
+        CargoTomlContents::load_from_file(target_path.join("meta").join("Cargo.toml"));
+    assert_eq!(meta_cargo_toml.package_name(), "new-adder-meta");
+    assert!(meta_cargo_toml.dependency("new-adder").is_some());
+
+    let meta_main = fs::read_to_string(target_path.join("meta/src/main.rs")).unwrap();
+    assert!(meta_main.contains("new_adder::AbiProvider"));
+}
+
+/// The new crate lives outside of the framework workspace, same as the crates created by users.
+#[test]
+fn template_instantiate_and_build_empty_test() {
+    let target_path = std::env::temp_dir()
+        .join("multiversx-template-test")
+        .join("new-empty");
+    if target_path.exists() {
+        fs::remove_dir_all(&target_path).unwrap();
+    }
+
+    let templates = find_templates(&default_templates_path().unwrap());
+    let empty = templates
+        .iter()
+        .find(|template| template.name == "empty")
+        .unwrap();
+    empty.instantiate(&target_path, "new-empty");
+
+    assert!(target_path.join("src/new_empty.rs").is_file());
+    let wasm_lib = fs::read_to_string(target_path.join("wasm/src/lib.rs")).unwrap();
+    assert!(wasm_lib.contains("multiversx_sc_wasm_adapter::empty_callback!"));
+    assert!(wasm_lib.contains("new_empty"));
+
+    let build_target_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("template-build");
+    let exit_status = Command::new("cargo")
+        .args(["build", "--all-targets", "--target-dir"])
+        .arg(&build_target_dir)
+        .current_dir(&target_path)
+        .status()
+        .expect("failed to run cargo build");
+    assert!(
+        exit_status.success(),
+        "the new contract crate does not build"
+    );
+}
@@ -0,0 +1,151 @@
+use std::{fs, path::Path};
+
+use multiversx_sc_meta::{
+    abi_json::RustcAbiJson,
+    verify::{
+        code_hash_hex, diff_build_records, parse_code_hash, rustc_version_verbose_matches,
+        toolchain_install_command, write_source_package, BuildRecordJson,
+    },
+};
+
+const ADDER_PATH: &str = "../../contracts/examples/adder";
+
+/// Blake2b-256 of empty input.
+const EMPTY_CODE_HASH: &str = "0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8";
+
+#[test]
+fn verify_code_hash_test() {
+    assert_eq!(code_hash_hex(&[]), EMPTY_CODE_HASH);
+
+    assert_eq!(parse_code_hash(EMPTY_CODE_HASH).unwrap(), EMPTY_CODE_HASH);
+    assert_eq!(
+        parse_code_hash("DldRwCblQ7Loqy6wYJnaodHl30d3j3eH+qtFzfEv46g=").unwrap(),
+        EMPTY_CODE_HASH
+    );
+    assert!(parse_code_hash("0e5751c0").is_err());
+    assert!(parse_code_hash("not a hash!").is_err());
+}
+
+#[test]
+fn verify_build_record_diff_test() {
+    let mut recorded = BuildRecordJson::default();
+    recorded.rustc.short = "rustc 1.69.0 (84c898d65 2023-04-16)".to_string();
+    recorded.framework.version = "0.39.5".to_string();
+    recorded.wasm_opt = "wasm-opt version 112".to_string();
+    recorded.build_args = vec!["--wat".to_string()];
+    recorded.features = vec!["adder/feature-a".to_string()];
+    recorded.opt_level = "z".to_string();
+    recorded.code_hash = EMPTY_CODE_HASH.to_string();
+
+    let mut current = recorded.clone();
+    assert!(diff_build_records(&recorded, &current).is_empty());
+
+    current.rustc.short = "rustc 1.70.0 (90c541806 2023-05-31)".to_string();
+    current.wasm_opt = "wasm-opt version 113".to_string();
+    current.features = Vec::new();
+    current.opt_level = "1".to_string();
+    let settings: Vec<&str> = diff_build_records(&recorded, &current)
+        .iter()
+        .map(|difference| difference.setting)
+        .collect();
+    assert_eq!(
+        settings,
+        vec!["rustc version", "wasm-opt version", "features", "opt-level"]
+    );
+
+    // only the versions are known from an ABI
+    let abi_only = BuildRecordJson {
+        code_hash: String::new(),
+        wasm_opt: String::new(),
+        ..recorded
+    };
+    let settings: Vec<&str> = diff_build_records(&abi_only, &current)
+        .iter()
+        .map(|difference| difference.setting)
+        .collect();
+    assert_eq!(settings, vec!["rustc version"]);
+}
+
+#[test]
+fn verify_rebuilt_output_name_test() {
+    let mut recorded = BuildRecordJson {
+        contract_name: "adder".to_string(),
+        output_name: "adder-debug".to_string(),
+        build_args: vec!["--profile".to_string(), "debug".to_string()],
+        ..Default::default()
+    };
+    assert_eq!(
+        recorded.rebuilt_output_name("adder-debug", "verify"),
+        "adder-verify"
+    );
+
+    // older records, without the contract name
+    recorded.contract_name = String::new();
+    assert_eq!(
+        recorded.rebuilt_output_name("adder-debug", "verify"),
+        "adder-verify"
+    );
+    recorded.build_args = Vec::new();
+    assert_eq!(
+        recorded.rebuilt_output_name("adder", "verify"),
+        "adder-verify"
+    );
+}
+
+const RUSTC_VERSION_VERBOSE: &str = "rustc 1.69.0 (84c898d65 2023-04-16)
+binary: rustc
+commit-hash: 84c898d65adf2f39a5a98507f1fe0ce10a2b8dbc
+commit-date: 2023-04-16
+host: x86_64-unknown-linux-gnu
+release: 1.69.0
+LLVM version: 15.0.7
+";
+
+#[test]
+fn verify_rust_toolchain_test() {
+    let mut rustc = RustcAbiJson {
+        version: "1.69.0".to_string(),
+        commit_hash: "84c898d65adf2f39a5a98507f1fe0ce10a2b8dbc".to_string(),
+        commit_date: "2023-04-16".to_string(),
+        channel: "Stable".to_string(),
+        short: "rustc 1.69.0 (84c898d65 2023-04-16)".to_string(),
+    };
+    assert!(rustc_version_verbose_matches(RUSTC_VERSION_VERBOSE, &rustc));
+    assert_eq!(
+        toolchain_install_command(&rustc).unwrap(),
+        "rustup toolchain install 1.69.0"
+    );
+
+    rustc.commit_hash = "90c541806f23a127002de5b4038be731ba1458ca".to_string();
+    assert!(!rustc_version_verbose_matches(
+        RUSTC_VERSION_VERBOSE,
+        &rustc
+    ));
+
+    // the release is only compared if the commit hash is unknown
+    rustc.commit_hash = String::new();
+    assert!(rustc_version_verbose_matches(RUSTC_VERSION_VERBOSE, &rustc));
+
+    rustc.channel = "Nightly".to_string();
+    assert!(toolchain_install_command(&rustc).is_none());
+}
+
+#[test]
+fn verify_source_package_test() {
+    let package_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("verify-test");
+    fs::create_dir_all(&package_dir).unwrap();
+    let package_path_1 = package_dir.join("adder-1.tar.gz");
+    let package_path_2 = package_dir.join("adder-2.tar.gz");
+
+    let external_deps = write_source_package(Path::new(ADDER_PATH), &package_path_1);
+    write_source_package(Path::new(ADDER_PATH), &package_path_2);
+
+    // the framework crates are referenced by path in this repository
+    assert!(external_deps
+        .iter()
+        .any(|dep_path| dep_path.ends_with("framework/base")));
+
+    let package_1 = fs::read(&package_path_1).unwrap();
+    assert!(!package_1.is_empty());
+    assert_eq!(package_1, fs::read(&package_path_2).unwrap());
+}
@@ -0,0 +1,215 @@
+use std::collections::BTreeSet;
+
+use multiversx_sc_meta::{
+    ei::EIVersion,
+    wasm_analysis::{
+        analyze_wasm, diff_size_reports, WasmFunctionSize, WasmFunctionSizeChange, WasmSizeReport,
+        WasmViolation, WasmViolationKind,
+    },
+};
+
+const ADDER_WASM_PATH: &str = "../../contracts/examples/multisig/test-contracts/adder.wasm";
+
+fn encode_name(name: &str) -> Vec<u8> {
+    let mut result = vec![name.len() as u8];
+    result.extend_from_slice(name.as_bytes());
+    result
+}
+
+fn encode_section(id: u8, items: &[Vec<u8>]) -> Vec<u8> {
+    let mut contents = vec![items.len() as u8];
+    for item in items {
+        contents.extend_from_slice(item);
+    }
+    let mut result = vec![id, contents.len() as u8];
+    result.extend(contents);
+    result
+}
+
+fn encode_body(code: &[u8]) -> Vec<u8> {
+    let mut body = vec![0x00]; // no locals
+    body.extend_from_slice(code);
+    body.push(0x0b); // end
+    let mut result = vec![body.len() as u8];
+    result.extend(body);
+    result
+}
+
+/// Functions 0-2 are imported, functions 3 and 4 are the endpoints, function 5 is only called by the first endpoint.
+fn test_module() -> Vec<u8> {
+    let mut module = b"\0asm\x01\0\0\0".to_vec();
+    module.extend(encode_section(1, &[vec![0x60, 0x00, 0x00]]));
+    let imports: Vec<Vec<u8>> = ["getNumArguments", "managedBufferToHex", "notAnEiFunction"]
+        .iter()
+        .map(|name| {
+            let mut import = encode_name("env");
+            import.extend(encode_name(name));
+            import.extend([0x00, 0x00]);
+            import
+        })
+        .collect();
+    module.extend(encode_section(2, &imports));
+    module.extend(encode_section(3, &[vec![0x00], vec![0x00], vec![0x00]]));
+    let exports: Vec<Vec<u8>> = [("first", 3u8), ("second", 4u8)]
+        .iter()
+        .map(|(name, function_index)| {
+            let mut export = encode_name(name);
+            export.extend([0x00, *function_index]);
+            export
+        })
+        .collect();
+    module.extend(encode_section(7, &exports));
+    module.extend(encode_section(
+        10,
+        &[
+            // call 0, call 5
+            encode_body(&[0x10, 0x00, 0x10, 0x05]),
+            // memory.grow by 20 pages
+            encode_body(&[0x41, 0x14, 0x40, 0x00, 0x1a]),
+            // call 1, f64.const 1.0, memory.copy
+            encode_body(&[
+                0x10, 0x01, 0x44, 0, 0, 0, 0, 0, 0, 0xf0, 0x3f, 0x1a, 0x41, 0x00, 0x41, 0x00, 0x41,
+                0x00, 0xfc, 0x0a, 0x00, 0x00,
+            ]),
+        ],
+    ));
+    module
+}
+
+fn names(names: &[&str]) -> BTreeSet<String> {
+    names.iter().map(|name| name.to_string()).collect()
+}
+
+fn find_violation<'a>(
+    violations: &'a [WasmViolation],
+    kind: &WasmViolationKind,
+) -> &'a WasmViolation {
+    violations
+        .iter()
+        .find(|violation| &violation.kind == kind)
+        .unwrap_or_else(|| panic!("violation {kind} not found"))
+}
+
+#[test]
+fn wasm_analysis_adder_test() {
+    let wasm_bytes = std::fs::read(ADDER_WASM_PATH).unwrap();
+    let analysis = analyze_wasm(&wasm_bytes, Some(EIVersion::V1_0)).unwrap();
+    assert!(!analysis.has_violations());
+    assert!(analysis.ei_imports.contains(&"getNumArguments".to_string()));
+    assert!(analysis.ei_imports.contains(&"signalError".to_string()));
+}
+
+#[test]
+fn wasm_analysis_violations_test() {
+    let analysis = analyze_wasm(&test_module(), Some(EIVersion::V1_1)).unwrap();
+    assert_eq!(
+        analysis.ei_imports,
+        vec!["getNumArguments", "managedBufferToHex", "notAnEiFunction"]
+    );
+    assert_eq!(analysis.violations.len(), 4);
+
+    let import_violation = find_violation(
+        &analysis.violations,
+        &WasmViolationKind::DisallowedImport("env.notAnEiFunction".to_string()),
+    );
+    assert!(import_violation.functions.is_empty());
+    assert!(import_violation.endpoints.is_empty());
+
+    let float_violation = find_violation(
+        &analysis.violations,
+        &WasmViolationKind::FloatOpcode("F64Const".to_string()),
+    );
+    assert_eq!(float_violation.functions, names(&["func[5]"]));
+    assert_eq!(float_violation.endpoints, names(&["first"]));
+
+    let bulk_memory_violation = find_violation(
+        &analysis.violations,
+        &WasmViolationKind::BulkMemoryOpcode("MemoryCopy".to_string()),
+    );
+    assert_eq!(bulk_memory_violation.endpoints, names(&["first"]));
+
+    let memory_grow_violation = find_violation(
+        &analysis.violations,
+        &WasmViolationKind::MemoryGrowAboveLimit(20),
+    );
+    assert_eq!(memory_grow_violation.functions, names(&["func[4]"]));
+    assert_eq!(memory_grow_violation.endpoints, names(&["second"]));
+}
+
+#[test]
+fn wasm_analysis_ei_version_test() {
+    let analysis = analyze_wasm(&test_module(), Some(EIVersion::V1_0)).unwrap();
+    let import_violation = find_violation(
+        &analysis.violations,
+        &WasmViolationKind::DisallowedImport("env.managedBufferToHex".to_string()),
+    );
+    assert_eq!(import_violation.functions, names(&["func[5]"]));
+    assert_eq!(import_violation.endpoints, names(&["first"]));
+
+    let analysis = analyze_wasm(&test_module(), None).unwrap();
+    assert_eq!(analysis.violations.len(), 3);
+}
+
+#[test]
+fn wasm_size_report_test() {
+    let wasm_bytes = test_module();
+    let size_report = analyze_wasm(&wasm_bytes, None).unwrap().size_report;
+    assert_eq!(size_report.wasm_size, wasm_bytes.len());
+    assert_eq!(size_report.data_size, 0);
+    assert_eq!(
+        size_report.functions,
+        vec![
+            WasmFunctionSize {
+                name: "func[5]".to_string(),
+                size: 24,
+            },
+            WasmFunctionSize {
+                name: "func[4]".to_string(),
+                size: 7,
+            },
+            WasmFunctionSize {
+                name: "func[3]".to_string(),
+                size: 6,
+            },
+        ]
+    );
+    let total_bodies_size: usize = size_report.functions.iter().map(|f| f.size).sum();
+    assert!(size_report.code_size > total_bodies_size);
+
+    let deserialized = WasmSizeReport::from_json(&size_report.to_json()).unwrap();
+    assert_eq!(deserialized, size_report);
+}
+
+#[test]
+fn wasm_size_diff_test() {
+    let baseline = analyze_wasm(&test_module(), None).unwrap().size_report;
+    let mut current = baseline.clone();
+    current.functions[0].size += 10;
+    current.functions.remove(2);
+    current.functions.push(WasmFunctionSize {
+        name: "func[6]".to_string(),
+        size: 3,
+    });
+
+    assert!(diff_size_reports(&baseline, &baseline).is_empty());
+    assert_eq!(
+        diff_size_reports(&baseline, &current),
+        vec![
+            WasmFunctionSizeChange {
+                name: "func[5]".to_string(),
+                before: Some(24),
+                after: Some(34),
+            },
+            WasmFunctionSizeChange {
+                name: "func[3]".to_string(),
+                before: Some(6),
+                after: None,
+            },
+            WasmFunctionSizeChange {
+                name: "func[6]".to_string(),
+                before: None,
+                after: Some(3),
+            },
+        ]
+    );
+}
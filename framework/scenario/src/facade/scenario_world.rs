@@ -1,5 +1,8 @@
 use crate::{
-    multiversx_chain_vm::{world_mock::ContractContainer, BlockchainMock},
+    multiversx_chain_vm::{
+        world_mock::{BlockProduction, ContractContainer},
+        BlockchainMock,
+    },
     multiversx_sc::contract_base::{CallableContractBuilder, ContractAbiProvider},
     scenario_format::interpret_trait::InterpreterContext,
 };
@@ -36,6 +39,42 @@ impl ScenarioWorld {
         &self.blockchain_mock.current_dir
     }
 
+    /// Uses an account snapshot file, as exported from a real network, as the base state of the world.
+    ///
+    /// Accounts not set explicitly are resolved from the snapshot when first used.
+    /// Execution stays fully offline.
+    pub fn fork_from_snapshot<P: AsRef<Path>>(&mut self, relative_path: P) -> &mut Self {
+        let mut absolute_path = self.blockchain_mock.current_dir.clone();
+        absolute_path.push(relative_path);
+        self.blockchain_mock.load_fork_snapshot(absolute_path);
+        self
+    }
+
+    /// Derives the block info from a block production model from here on,
+    /// instead of requiring it to be set explicitly.
+    pub fn set_block_production(&mut self, block_production: BlockProduction) -> &mut Self {
+        self.blockchain_mock.set_block_production(block_production);
+        self
+    }
+
+    /// Produces the given number of blocks.
+    pub fn advance_rounds(&mut self, num_rounds: u64) -> &mut Self {
+        self.blockchain_mock.advance_rounds(num_rounds);
+        self
+    }
+
+    /// Produces as many blocks as needed for at least `secs` seconds to pass.
+    pub fn advance_time(&mut self, secs: u64) -> &mut Self {
+        self.blockchain_mock.advance_time(secs);
+        self
+    }
+
+    /// Produces blocks up to the first round of the given epoch.
+    pub fn advance_to_epoch(&mut self, epoch: u64) -> &mut Self {
+        self.blockchain_mock.advance_to_epoch(epoch);
+        self
+    }
+
     pub fn interpreter_context(&self) -> InterpreterContext {
         self.blockchain_mock.interpreter_context()
     }
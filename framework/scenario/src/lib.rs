@@ -1,4 +1,5 @@
 mod facade;
+mod scenario_dir_runner;
 mod scenario_go_runner;
 mod scenario_rs_runner;
 pub mod whitebox;
@@ -20,7 +21,12 @@ pub use multiversx_chain_vm::scenario::model as scenario_model;
 /// For backwards compatibility, will be removed.
 pub use multiversx_chain_vm::scenario as mandos_system;
 
+pub use multiversx_chain_vm::world_mock::BlockProduction;
+
 pub use facade::ScenarioWorld;
+pub use scenario_dir_runner::{
+    ScenarioDirRunReport, ScenarioDirRunner, ScenarioRunOutcome, ScenarioRunResult,
+};
 pub use scenario_go_runner::run_go;
 pub use scenario_rs_runner::run_rs;
 
@@ -0,0 +1,184 @@
+use colored::Colorize;
+use std::{
+    fmt::Write as _,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+const JUNIT_SUITE_NAME: &str = "scenarios";
+
+#[derive(Debug, Clone)]
+pub enum ScenarioRunOutcome {
+    Passed,
+
+    /// Contains the panic message.
+    Failed(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct ScenarioRunResult {
+    /// Relative to the scenario directory.
+    pub path: PathBuf,
+    pub duration: Duration,
+    pub outcome: ScenarioRunOutcome,
+}
+
+impl ScenarioRunResult {
+    pub fn is_passed(&self) -> bool {
+        matches!(self.outcome, ScenarioRunOutcome::Passed)
+    }
+}
+
+/// The results of a directory run, in the order of the scenario paths.
+#[derive(Debug, Clone)]
+pub struct ScenarioDirRunReport {
+    pub dir_path: PathBuf,
+    pub num_threads: usize,
+    pub duration: Duration,
+    pub results: Vec<ScenarioRunResult>,
+}
+
+impl ScenarioDirRunReport {
+    pub fn num_passed(&self) -> usize {
+        self.results
+            .iter()
+            .filter(|result| result.is_passed())
+            .count()
+    }
+
+    pub fn num_failed(&self) -> usize {
+        self.results.len() - self.num_passed()
+    }
+
+    /// Prints one line per scenario, with its result and duration, followed by the totals.
+    pub fn print_summary(&self) {
+        let name_width = self
+            .results
+            .iter()
+            .map(|result| result.path.display().to_string().len())
+            .chain(std::iter::once("scenario".len()))
+            .max()
+            .unwrap_or_default();
+
+        println!("{:name_width$}  {:6}  {:>10}", "scenario", "result", "time");
+        for result in &self.results {
+            let outcome = match &result.outcome {
+                ScenarioRunOutcome::Passed => format!("{:6}", "ok").green(),
+                ScenarioRunOutcome::Failed(_) => format!("{:6}", "FAILED").red(),
+            };
+            println!(
+                "{:name_width$}  {}  {:>10}",
+                result.path.display(),
+                outcome,
+                format_duration(result.duration),
+            );
+        }
+
+        for result in &self.results {
+            if let ScenarioRunOutcome::Failed(message) = &result.outcome {
+                println!("\n{}\n{message}", result.path.display().to_string().red());
+            }
+        }
+
+        let totals = format!(
+            "\n{} scenarios: {} passed, {} failed; finished in {} on {} thread(s)",
+            self.results.len(),
+            self.num_passed(),
+            self.num_failed(),
+            format_duration(self.duration),
+            self.num_threads,
+        );
+        if self.num_failed() == 0 {
+            println!("{}", totals.green());
+        } else {
+            println!("{}", totals.red());
+        }
+    }
+
+    /// The JUnit XML format, as understood by most CI systems.
+    pub fn to_junit_xml(&self) -> String {
+        let mut xml = String::new();
+        writeln!(xml, r#"<?xml version="1.0" encoding="UTF-8"?>"#).unwrap();
+        writeln!(
+            xml,
+            r#"<testsuites tests="{}" failures="{}" time="{:.3}">"#,
+            self.results.len(),
+            self.num_failed(),
+            self.duration.as_secs_f64(),
+        )
+        .unwrap();
+        writeln!(
+            xml,
+            r#"  <testsuite name="{}" tests="{}" failures="{}" time="{:.3}">"#,
+            JUNIT_SUITE_NAME,
+            self.results.len(),
+            self.num_failed(),
+            self.duration.as_secs_f64(),
+        )
+        .unwrap();
+        for result in &self.results {
+            let name = xml_escape(&result.path.display().to_string());
+            let class_name = xml_escape(&self.dir_path.display().to_string());
+            let time = result.duration.as_secs_f64();
+            match &result.outcome {
+                ScenarioRunOutcome::Passed => {
+                    writeln!(
+                        xml,
+                        r#"    <testcase name="{name}" classname="{class_name}" time="{time:.3}"/>"#,
+                    )
+                    .unwrap();
+                },
+                ScenarioRunOutcome::Failed(message) => {
+                    let message = xml_escape(message);
+                    writeln!(
+                        xml,
+                        r#"    <testcase name="{name}" classname="{class_name}" time="{time:.3}">"#,
+                    )
+                    .unwrap();
+                    writeln!(
+                        xml,
+                        r#"      <failure message="scenario failed">{message}</failure>"#,
+                    )
+                    .unwrap();
+                    writeln!(xml, "    </testcase>").unwrap();
+                },
+            }
+        }
+        writeln!(xml, "  </testsuite>").unwrap();
+        writeln!(xml, "</testsuites>").unwrap();
+        xml
+    }
+
+    /// Creates the parent directories, if needed.
+    pub fn write_junit_xml<P: AsRef<Path>>(&self, file_path: P) {
+        let file_path = file_path.as_ref();
+        if let Some(parent) = file_path.parent() {
+            std::fs::create_dir_all(parent).unwrap();
+        }
+        std::fs::write(file_path, self.to_junit_xml()).unwrap_or_else(|err| {
+            panic!(
+                "cannot write JUnit report to {}: {err}",
+                file_path.display()
+            )
+        });
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{:.3}s", duration.as_secs_f64())
+}
+
+fn xml_escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
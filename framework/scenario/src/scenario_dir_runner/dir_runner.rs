@@ -0,0 +1,269 @@
+use crate::{multiversx_chain_vm::scenario::executor::parse_execute_mandos_steps, ScenarioWorld};
+use glob::{MatchOptions, Pattern};
+use std::{
+    any::Any,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Instant,
+};
+
+use super::{ScenarioDirRunReport, ScenarioRunOutcome, ScenarioRunResult};
+
+const DEFAULT_INCLUDE_PATTERNS: &[&str] = &["**/*.scen.json", "**/*.scen.json5", "**/*.scen.yaml"];
+
+/// Paths are matched relative to the scenario directory, `*` never crosses a `/`.
+const PATTERN_MATCH_OPTIONS: MatchOptions = MatchOptions {
+    case_sensitive: true,
+    require_literal_separator: true,
+    require_literal_leading_dot: false,
+};
+
+/// Runs all the scenarios in a directory using the Rust infrastructure and the debug mode,
+/// spread over several threads.
+///
+/// Every scenario gets its own `ScenarioWorld`, produced by the world factory,
+/// so no state leaks from one scenario to another.
+///
+/// Replaces the long lists of `run_rs` test functions:
+///
+/// ```ignore
+/// #[test]
+/// fn all_scenarios_rs() {
+///     ScenarioDirRunner::new("scenarios")
+///         .exclude("**/*-go.scen.json")
+///         .junit_report("../../target/scenario-reports/adder.xml")
+///         .run(world);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct ScenarioDirRunner {
+    relative_dir_path: PathBuf,
+    include: Vec<String>,
+    exclude: Vec<String>,
+    num_threads: Option<usize>,
+    junit_report_path: Option<PathBuf>,
+}
+
+impl ScenarioDirRunner {
+    /// The directory is relative to the current directory of the worlds produced by the factory.
+    pub fn new<P: AsRef<Path>>(relative_dir_path: P) -> Self {
+        ScenarioDirRunner {
+            relative_dir_path: relative_dir_path.as_ref().to_path_buf(),
+            include: Vec::new(),
+            exclude: Vec::new(),
+            num_threads: None,
+            junit_report_path: None,
+        }
+    }
+
+    /// Only runs the scenarios matching at least one of the include patterns.
+    ///
+    /// If none are given, all `*.scen.json`, `*.scen.json5` and `*.scen.yaml` files
+    /// in the directory and its subdirectories are run.
+    pub fn include(mut self, pattern: &str) -> Self {
+        self.include.push(pattern.to_string());
+        self
+    }
+
+    /// Skips the scenarios matching any of the exclude patterns, even if they were included.
+    pub fn exclude(mut self, pattern: &str) -> Self {
+        self.exclude.push(pattern.to_string());
+        self
+    }
+
+    /// Defaults to the available parallelism of the machine.
+    pub fn threads(mut self, num_threads: usize) -> Self {
+        assert!(num_threads > 0, "at least one thread is required");
+        self.num_threads = Some(num_threads);
+        self
+    }
+
+    /// Also writes the results as a JUnit XML report, at the given path,
+    /// relative to the current directory of the worlds produced by the factory.
+    pub fn junit_report<P: AsRef<Path>>(mut self, report_path: P) -> Self {
+        self.junit_report_path = Some(report_path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Runs all selected scenarios, prints the summary and writes the report, if configured.
+    ///
+    /// Panics if any of the scenarios failed, or if no scenario was selected.
+    pub fn run<F>(self, world_factory: F) -> ScenarioDirRunReport
+    where
+        F: Fn() -> ScenarioWorld + Sync,
+    {
+        let report = self.run_no_panic(world_factory);
+        report.print_summary();
+        assert!(
+            !report.results.is_empty(),
+            "no scenarios found in {}",
+            report.dir_path.display()
+        );
+        assert!(
+            report.num_failed() == 0,
+            "{} of {} scenarios failed",
+            report.num_failed(),
+            report.results.len()
+        );
+        report
+    }
+
+    /// Runs all selected scenarios and writes the report, if configured,
+    /// but leaves it to the caller to interpret the results.
+    pub fn run_no_panic<F>(self, world_factory: F) -> ScenarioDirRunReport
+    where
+        F: Fn() -> ScenarioWorld + Sync,
+    {
+        let current_dir = world_factory().current_dir().clone();
+        let dir_path = current_dir.join(&self.relative_dir_path);
+        let scenario_paths = self.find_scenarios(&dir_path);
+        let num_threads = self
+            .num_threads
+            .unwrap_or_else(default_num_threads)
+            .min(scenario_paths.len())
+            .max(1);
+
+        let start = Instant::now();
+        let results = run_in_parallel(&dir_path, &scenario_paths, num_threads, &world_factory);
+        let report = ScenarioDirRunReport {
+            dir_path,
+            num_threads,
+            duration: start.elapsed(),
+            results,
+        };
+
+        if let Some(junit_report_path) = &self.junit_report_path {
+            report.write_junit_xml(current_dir.join(junit_report_path));
+        }
+
+        report
+    }
+
+    /// Paths are relative to the scenario directory, sorted, so that the reports are stable.
+    fn find_scenarios(&self, dir_path: &Path) -> Vec<PathBuf> {
+        let include = if self.include.is_empty() {
+            let default_patterns: Vec<String> = DEFAULT_INCLUDE_PATTERNS
+                .iter()
+                .map(|pattern| pattern.to_string())
+                .collect();
+            parse_patterns(&default_patterns)
+        } else {
+            parse_patterns(&self.include)
+        };
+        let exclude = parse_patterns(&self.exclude);
+
+        let mut scenario_paths = Vec::new();
+        collect_files(dir_path, Path::new(""), &mut scenario_paths);
+        scenario_paths.retain(|path| matches_any(&include, path) && !matches_any(&exclude, path));
+        scenario_paths.sort();
+        scenario_paths
+    }
+}
+
+fn default_num_threads() -> usize {
+    std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+}
+
+fn parse_patterns(patterns: &[String]) -> Vec<Pattern> {
+    patterns
+        .iter()
+        .map(|pattern| {
+            Pattern::new(pattern).unwrap_or_else(|err| panic!("invalid pattern `{pattern}`: {err}"))
+        })
+        .collect()
+}
+
+fn matches_any(patterns: &[Pattern], path: &Path) -> bool {
+    patterns
+        .iter()
+        .any(|pattern| pattern.matches_path_with(path, PATTERN_MATCH_OPTIONS))
+}
+
+fn collect_files(dir_path: &Path, relative_path: &Path, files: &mut Vec<PathBuf>) {
+    let entries = std::fs::read_dir(dir_path)
+        .unwrap_or_else(|err| panic!("cannot read directory {}: {err}", dir_path.display()));
+    for entry in entries {
+        let entry = entry.unwrap();
+        let entry_relative_path = relative_path.join(entry.file_name());
+        if entry.file_type().unwrap().is_dir() {
+            collect_files(&entry.path(), &entry_relative_path, files);
+        } else {
+            files.push(entry_relative_path);
+        }
+    }
+}
+
+/// The threads pick up the next scenario as soon as they are done with the previous one.
+fn run_in_parallel<F>(
+    dir_path: &Path,
+    scenario_paths: &[PathBuf],
+    num_threads: usize,
+    world_factory: &F,
+) -> Vec<ScenarioRunResult>
+where
+    F: Fn() -> ScenarioWorld + Sync,
+{
+    let next_index = AtomicUsize::new(0);
+    let mut results: Vec<(usize, ScenarioRunResult)> = std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..num_threads)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut thread_results = Vec::new();
+                    loop {
+                        let index = next_index.fetch_add(1, Ordering::Relaxed);
+                        match scenario_paths.get(index) {
+                            Some(scenario_path) => {
+                                let result = run_scenario(dir_path, scenario_path, world_factory);
+                                thread_results.push((index, result));
+                            },
+                            None => break,
+                        }
+                    }
+                    thread_results
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect()
+    });
+
+    results.sort_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+fn run_scenario<F>(dir_path: &Path, scenario_path: &Path, world_factory: &F) -> ScenarioRunResult
+where
+    F: Fn() -> ScenarioWorld + Sync,
+{
+    let start = Instant::now();
+    let absolute_path = dir_path.join(scenario_path);
+    let outcome = panic::catch_unwind(AssertUnwindSafe(|| {
+        let mut world = world_factory();
+        parse_execute_mandos_steps(absolute_path.as_path(), &mut world.blockchain_mock);
+    }));
+
+    ScenarioRunResult {
+        path: scenario_path.to_path_buf(),
+        duration: start.elapsed(),
+        outcome: match outcome {
+            Ok(()) => ScenarioRunOutcome::Passed,
+            Err(panic_payload) => ScenarioRunOutcome::Failed(panic_message(panic_payload)),
+        },
+    }
+}
+
+fn panic_message(panic_payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = panic_payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = panic_payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
@@ -0,0 +1,5 @@
+mod dir_run_report;
+mod dir_runner;
+
+pub use dir_run_report::{ScenarioDirRunReport, ScenarioRunOutcome, ScenarioRunResult};
+pub use dir_runner::ScenarioDirRunner;
@@ -91,7 +91,7 @@ impl BlockchainStateWrapper {
     }
 
     pub fn check_egld_balance(&self, address: &Address, expected_balance: &num_bigint::BigUint) {
-        let actual_balance = match &self.rc_b_mock.accounts.get(address) {
+        let actual_balance = match &self.rc_b_mock.get_account(address) {
             Some(acc) => acc.egld_balance.clone(),
             None => num_bigint::BigUint::zero(),
         };
@@ -111,7 +111,7 @@ impl BlockchainStateWrapper {
         token_id: &[u8],
         expected_balance: &num_bigint::BigUint,
     ) {
-        let actual_balance = match &self.rc_b_mock.accounts.get(address) {
+        let actual_balance = match &self.rc_b_mock.get_account(address) {
             Some(acc) => acc.esdt.get_esdt_balance(token_id, 0),
             None => num_bigint::BigUint::zero(),
         };
@@ -137,7 +137,7 @@ impl BlockchainStateWrapper {
         T: TopEncode + TopDecode + PartialEq + core::fmt::Debug,
     {
         let (actual_balance, actual_attributes_serialized) =
-            match &self.rc_b_mock.accounts.get(address) {
+            match &self.rc_b_mock.get_account(address) {
                 Some(acc) => {
                     let esdt_data = acc.esdt.get_by_identifier_or_default(token_id);
                     let opt_instance = esdt_data.instances.get_by_nonce(nonce);
@@ -315,7 +315,7 @@ impl BlockchainStateWrapper {
         ContractObjBuilder: 'static + Copy + Fn() -> CB,
     {
         let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
-        let deployer_acc = b_mock_ref.accounts.get(deployer).unwrap().clone();
+        let deployer_acc = b_mock_ref.get_account(deployer).unwrap().clone();
 
         let new_sc_address = self.address_factory.new_sc_address();
         b_mock_ref.put_new_address(deployer.clone(), deployer_acc.nonce, new_sc_address.clone());
@@ -339,6 +339,7 @@ impl BlockchainStateWrapper {
 
     pub fn set_egld_balance(&mut self, address: &Address, balance: &num_bigint::BigUint) {
         let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
+        b_mock_ref.resolve_fork_account(address);
         match b_mock_ref.accounts.get_mut(address) {
             Some(acc) => {
                 acc.egld_balance = balance.clone();
@@ -360,6 +361,7 @@ impl BlockchainStateWrapper {
         balance: &num_bigint::BigUint,
     ) {
         let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
+        b_mock_ref.resolve_fork_account(address);
         match b_mock_ref.accounts.get_mut(address) {
             Some(acc) => {
                 acc.esdt.set_esdt_balance(
@@ -406,7 +408,7 @@ impl BlockchainStateWrapper {
         developer_rewards: num_bigint::BigUint,
     ) {
         let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
-
+        b_mock_ref.resolve_fork_account(address);
         match b_mock_ref.accounts.get_mut(address) {
             Some(acc) => {
                 acc.developer_rewards = developer_rewards;
@@ -435,7 +437,7 @@ impl BlockchainStateWrapper {
         uris: &[Vec<u8>],
     ) {
         let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
-
+        b_mock_ref.resolve_fork_account(address);
         match b_mock_ref.accounts.get_mut(address) {
             Some(acc) => {
                 acc.esdt.set_esdt_balance(
@@ -468,6 +470,7 @@ impl BlockchainStateWrapper {
         roles: &[EsdtLocalRole],
     ) {
         let b_mock_ref = Rc::get_mut(&mut self.rc_b_mock).unwrap();
+        b_mock_ref.resolve_fork_account(address);
         match b_mock_ref.accounts.get_mut(address) {
             Some(acc) => {
                 let mut roles_raw = Vec::new();
@@ -604,7 +607,7 @@ impl BlockchainStateWrapper {
     }
 
     pub fn add_mandos_set_account(&mut self, address: &Address) {
-        if let Some(acc) = self.rc_b_mock.accounts.get(address) {
+        if let Some(acc) = self.rc_b_mock.get_account(address) {
             let opt_contract_path = self.address_to_code_path.get(address);
             self.scenario_generator
                 .set_account(acc, opt_contract_path.cloned());
@@ -612,7 +615,7 @@ impl BlockchainStateWrapper {
     }
 
     pub fn add_mandos_check_account(&mut self, address: &Address) {
-        if let Some(acc) = self.rc_b_mock.accounts.get(address) {
+        if let Some(acc) = self.rc_b_mock.get_account(address) {
             self.scenario_generator.check_account(acc);
         }
     }
@@ -748,7 +751,7 @@ impl BlockchainStateWrapper {
 
         let api_after_exec = Rc::try_unwrap(TxContextStack::static_pop()).unwrap();
         let updates = api_after_exec.into_blockchain_updates();
-        let tx_result = match exec_result {
+        let mut tx_result = match exec_result {
             Ok(()) => TxResult::empty(),
             Err(panic_any) => interpret_panic_as_tx_result(panic_any, false),
         };
@@ -770,6 +773,7 @@ impl BlockchainStateWrapper {
                 });
             }
         }
+        tx_result.reentrant_calls = TxContextStack::static_take_reentrant_calls();
 
         tx_result
     }
@@ -785,7 +789,7 @@ impl BlockchainStateWrapper {
 
 impl BlockchainStateWrapper {
     pub fn get_egld_balance(&self, address: &Address) -> num_bigint::BigUint {
-        match self.rc_b_mock.accounts.get(address) {
+        match self.rc_b_mock.get_account(address) {
             Some(acc) => acc.egld_balance.clone(),
             None => panic!(
                 "get_egld_balance: Account {:?} does not exist",
@@ -800,7 +804,7 @@ impl BlockchainStateWrapper {
         token_id: &[u8],
         token_nonce: u64,
     ) -> num_bigint::BigUint {
-        match self.rc_b_mock.accounts.get(address) {
+        match self.rc_b_mock.get_account(address) {
             Some(acc) => acc.esdt.get_esdt_balance(token_id, token_nonce),
             None => panic!(
                 "get_esdt_balance: Account {:?} does not exist",
@@ -815,7 +819,7 @@ impl BlockchainStateWrapper {
         token_id: &[u8],
         token_nonce: u64,
     ) -> Option<T> {
-        match self.rc_b_mock.accounts.get(address) {
+        match self.rc_b_mock.get_account(address) {
             Some(acc) => match acc.esdt.get_by_identifier(token_id) {
                 Some(esdt_data) => esdt_data
                     .instances
@@ -848,7 +852,7 @@ impl BlockchainStateWrapper {
         &self,
         address: &Address,
     ) {
-        let account = match self.rc_b_mock.accounts.get(address) {
+        let account = match self.rc_b_mock.get_account(address) {
             Some(acc) => acc,
             None => panic!(
                 "dump_state_for_account: Account {:?} does not exist",
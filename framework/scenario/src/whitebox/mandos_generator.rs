@@ -18,6 +18,7 @@ impl MandosGenerator {
                 check_gas: None,
                 comment: None,
                 gas_schedule: None,
+                abi: None,
                 name: None,
                 steps: Vec::new(),
             },
@@ -177,6 +177,7 @@ pub(crate) fn tx_expect_as_raw(tx_expect: &TxExpectMandos) -> TxExpectRaw {
         logs: CheckLogsRaw::Star,
         gas: CheckBytesValueRaw::Star,
         refund: CheckBytesValueRaw::Star,
+        reentrant_calls: CheckBytesValueRaw::Unspecified,
     }
 }
 
@@ -0,0 +1,143 @@
+use multiversx_sc_scenario::{scenario_model::*, *};
+
+#[test]
+fn block_production_advance_time() {
+    let mut world = ScenarioWorld::new();
+    world.set_block_production(
+        BlockProduction::new()
+            .round_duration_secs(6)
+            .rounds_per_epoch(100),
+    );
+    world.set_state_step(SetStateStep::new().block_timestamp(1_000u64));
+
+    world.advance_time(60);
+    let block_info = &world.blockchain_mock.current_block_info;
+    assert_eq!(block_info.block_round, 10);
+    assert_eq!(block_info.block_nonce, 10);
+    assert_eq!(block_info.block_timestamp, 1_060);
+    assert_eq!(block_info.block_epoch, 0);
+    assert_eq!(world.blockchain_mock.previous_block_info.block_round, 9);
+
+    // partial rounds count as full rounds
+    world.advance_time(1);
+    assert_eq!(world.blockchain_mock.current_block_info.block_round, 11);
+
+    world.advance_to_epoch(3);
+    let block_info = &world.blockchain_mock.current_block_info;
+    assert_eq!(block_info.block_round, 300);
+    assert_eq!(block_info.block_epoch, 3);
+    assert_eq!(block_info.block_timestamp, 2_800);
+}
+
+#[test]
+fn block_production_continues_from_set_state() {
+    let mut world = ScenarioWorld::new();
+    world.set_state_step(
+        SetStateStep::new()
+            .block_timestamp(500_000u64)
+            .block_nonce(40u64)
+            .block_round(50u64)
+            .block_epoch(7u64),
+    );
+
+    // the default model only applies to new blocks
+    world.advance_rounds(1);
+    let block_info = &world.blockchain_mock.current_block_info;
+    assert_eq!(block_info.block_timestamp, 500_006);
+    assert_eq!(block_info.block_nonce, 41);
+    assert_eq!(block_info.block_round, 51);
+    assert_eq!(block_info.block_epoch, 7);
+    let previous_block_info = &world.blockchain_mock.previous_block_info;
+    assert_eq!(previous_block_info.block_timestamp, 500_000);
+    assert_eq!(previous_block_info.block_round, 50);
+
+    world.set_block_production(BlockProduction::new().rounds_per_epoch(100));
+    assert_eq!(
+        world.blockchain_mock.current_block_info.block_timestamp,
+        500_006
+    );
+
+    world.advance_rounds(49);
+    let block_info = &world.blockchain_mock.current_block_info;
+    assert_eq!(block_info.block_round, 100);
+    assert_eq!(block_info.block_timestamp, 500_300);
+    assert_eq!(block_info.block_epoch, 8);
+}
+
+#[test]
+fn block_production_advance_to_epoch_after_set_state() {
+    let mut world = ScenarioWorld::new();
+    world.set_block_production(BlockProduction::new().rounds_per_epoch(100));
+
+    // round already past the start of epoch 3, while the epoch is lower
+    world.set_state_step(
+        SetStateStep::new()
+            .block_timestamp(10_000u64)
+            .block_round(450u64)
+            .block_epoch(2u64),
+    );
+
+    world.advance_to_epoch(3);
+    let block_info = &world.blockchain_mock.current_block_info;
+    assert_eq!(block_info.block_epoch, 3);
+    assert_eq!(block_info.block_round, 500);
+    assert_eq!(block_info.block_timestamp, 10_300);
+
+    world.advance_to_epoch(5);
+    let block_info = &world.blockchain_mock.current_block_info;
+    assert_eq!(block_info.block_epoch, 5);
+    assert_eq!(block_info.block_round, 700);
+}
+
+#[test]
+fn block_production_deterministic_random_seed() {
+    let mut world_1 = ScenarioWorld::new();
+    world_1.set_block_production(BlockProduction::new().world_seed(42));
+    world_1.advance_rounds(5);
+
+    let mut world_2 = ScenarioWorld::new();
+    world_2.set_block_production(BlockProduction::new().world_seed(42));
+    world_2.advance_rounds(2).advance_rounds(3);
+
+    let mut world_3 = ScenarioWorld::new();
+    world_3.set_block_production(BlockProduction::new().world_seed(43));
+    world_3.advance_rounds(5);
+
+    let seed_1 = &world_1.blockchain_mock.current_block_info.block_random_seed;
+    let seed_2 = &world_2.blockchain_mock.current_block_info.block_random_seed;
+    let seed_3 = &world_3.blockchain_mock.current_block_info.block_random_seed;
+    assert_eq!(seed_1, seed_2);
+    assert_ne!(seed_1, seed_3);
+}
+
+#[test]
+fn block_production_advance_per_tx() {
+    let mut world = ScenarioWorld::new();
+    world.set_block_production(BlockProduction::new().advance_per_tx(true));
+    world.set_state_step(
+        SetStateStep::new()
+            .put_account("address:a", Account::new().balance(100u64))
+            .put_account("address:b", Account::new()),
+    );
+
+    for _ in 0..3 {
+        world.transfer_step(
+            TransferStep::new()
+                .from("address:a")
+                .to("address:b")
+                .egld_value(10u64),
+        );
+    }
+
+    let block_info = &world.blockchain_mock.current_block_info;
+    assert_eq!(block_info.block_round, 3);
+    assert_eq!(block_info.block_timestamp, 18);
+}
+
+#[test]
+#[should_panic(expected = "cannot go back to epoch 1, already in epoch 2")]
+fn block_production_cannot_go_back() {
+    let mut world = ScenarioWorld::new();
+    world.advance_to_epoch(2);
+    world.advance_to_epoch(1);
+}
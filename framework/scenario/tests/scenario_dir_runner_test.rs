@@ -0,0 +1,60 @@
+use multiversx_sc_scenario::*;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("framework/scenario");
+    blockchain
+}
+
+#[test]
+fn dir_runner_set_check_rs() {
+    let report = ScenarioDirRunner::new("tests/scenarios-self/set-check")
+        .threads(4)
+        .run(world);
+
+    assert_eq!(report.num_passed(), 7);
+    assert!(
+        report
+            .results
+            .iter()
+            .any(|result| result.path.as_path()
+                == std::path::Path::new("set-check-storage.scen.json"))
+    );
+}
+
+#[test]
+fn dir_runner_include_exclude_rs() {
+    let report = ScenarioDirRunner::new("tests/scenarios-self")
+        .include("*-check-err.scen.json")
+        .include("transfer-*.scen.json")
+        .exclude("transfer-esdt.scen.json")
+        .run_no_panic(world);
+
+    let paths: Vec<String> = report
+        .results
+        .iter()
+        .map(|result| result.path.display().to_string())
+        .collect();
+    assert_eq!(
+        paths,
+        vec![
+            "esdt-non-zero-balance-check-err.scen.json",
+            "esdt-zero-balance-check-err.scen.json",
+            "transfer-egld.scen.json",
+        ]
+    );
+    assert_eq!(report.num_passed(), 1);
+    assert_eq!(report.num_failed(), 2);
+
+    let junit_xml = report.to_junit_xml();
+    assert!(junit_xml.contains(r#"<testsuites tests="3" failures="2""#));
+    assert!(junit_xml.contains(r#"<testcase name="transfer-egld.scen.json""#));
+}
+
+#[test]
+#[should_panic(expected = "2 of 2 scenarios failed")]
+fn dir_runner_failed_rs() {
+    ScenarioDirRunner::new("tests/scenarios-self")
+        .include("*-check-err.scen.json")
+        .run(world);
+}
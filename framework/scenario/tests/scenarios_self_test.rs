@@ -79,6 +79,15 @@ fn set_check_balance_rs() {
     );
 }
 
+#[test]
+#[should_panic(expected = "checkState failed: 4 mismatch(es) in 2 account(s)")]
+fn set_check_multiple_err_rs() {
+    multiversx_sc_scenario::run_rs(
+        "tests/scenarios-self/set-check/set-check-multiple.err.json",
+        world(),
+    );
+}
+
 #[test]
 #[should_panic]
 fn set_check_code_err_rs() {
@@ -208,6 +217,70 @@ fn builtin_func_esdt_transfer() {
     );
 }
 
+#[test]
+fn builtin_func_esdt_burn_role_for_all_rs() {
+    multiversx_sc_scenario::run_rs(
+        "tests/scenarios-self/builtin-func-esdt-burn-role-for-all.scen.json",
+        world(),
+    );
+}
+
+#[test]
+fn builtin_func_esdt_freeze_wipe_rs() {
+    multiversx_sc_scenario::run_rs(
+        "tests/scenarios-self/builtin-func-esdt-freeze-wipe.scen.json",
+        world(),
+    );
+}
+
+#[test]
+fn builtin_func_esdt_limited_transfer_rs() {
+    multiversx_sc_scenario::run_rs(
+        "tests/scenarios-self/builtin-func-esdt-limited-transfer.scen.json",
+        world(),
+    );
+}
+
+#[test]
+fn builtin_func_esdt_modify_royalties_rs() {
+    multiversx_sc_scenario::run_rs(
+        "tests/scenarios-self/builtin-func-esdt-modify-royalties.scen.json",
+        world(),
+    );
+}
+
+#[test]
+fn builtin_func_esdt_nft_create_role_transfer_rs() {
+    multiversx_sc_scenario::run_rs(
+        "tests/scenarios-self/builtin-func-esdt-nft-create-role-transfer.scen.json",
+        world(),
+    );
+}
+
+#[test]
+fn builtin_func_esdt_set_new_uris_rs() {
+    multiversx_sc_scenario::run_rs(
+        "tests/scenarios-self/builtin-func-esdt-set-new-uris.scen.json",
+        world(),
+    );
+}
+
+#[test]
+fn builtin_func_multi_transfer_egld_rs() {
+    multiversx_sc_scenario::run_rs(
+        "tests/scenarios-self/builtin-func-multi-transfer-egld.scen.json",
+        world(),
+    );
+}
+
+#[test]
+fn builtin_func_save_key_value_rs() {
+    multiversx_sc_scenario::run_rs(
+        "tests/scenarios-self/builtin-func-save-key-value.scen.json",
+        world(),
+    );
+}
+
 #[test]
 #[should_panic]
 fn esdt_non_zero_balance_check_err_rs() {
@@ -239,6 +312,16 @@ fn transfer_egld_rs() {
     multiversx_sc_scenario::run_rs("tests/scenarios-self/transfer-egld.scen.json", world());
 }
 
+#[test]
+fn transfer_egld_yaml_rs() {
+    multiversx_sc_scenario::run_rs("tests/scenarios-self/transfer-egld.scen.yaml", world());
+}
+
+#[test]
+fn transfer_egld_json5_rs() {
+    multiversx_sc_scenario::run_rs("tests/scenarios-self/transfer-egld.scen.json5", world());
+}
+
 #[test]
 fn transfer_esdt_rs() {
     multiversx_sc_scenario::run_rs("tests/scenarios-self/transfer-esdt.scen.json", world());
@@ -248,3 +331,10 @@ fn transfer_esdt_rs() {
 fn validator_reward_rs() {
     multiversx_sc_scenario::run_rs("tests/scenarios-self/validatorReward.scen.json", world());
 }
+
+#[test]
+fn fork_snapshot_rs() {
+    let mut world = world();
+    world.fork_from_snapshot("tests/scenarios-self/fork/account-snapshot.json");
+    multiversx_sc_scenario::run_rs("tests/scenarios-self/fork/fork-transfer.steps.json", world);
+}
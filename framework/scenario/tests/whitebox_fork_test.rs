@@ -0,0 +1,24 @@
+use std::rc::Rc;
+
+use multiversx_sc_scenario::{
+    num_bigint::BigUint, scenario_model::AddressValue, whitebox::BlockchainStateWrapper,
+};
+
+const SNAPSHOT_PATH: &str = "tests/scenarios-self/fork/account-snapshot.json";
+
+/// Setters act on the forked account, instead of failing or replacing it.
+#[test]
+fn whitebox_set_balances_on_fork_account() {
+    let mut b_mock = BlockchainStateWrapper::new();
+    Rc::get_mut(b_mock.get_mut_state())
+        .unwrap()
+        .load_fork_snapshot(SNAPSHOT_PATH);
+
+    let holder = AddressValue::from("address:holder").to_address();
+    b_mock.set_egld_balance(&holder, &BigUint::from(2_000u32));
+    b_mock.set_esdt_balance(&holder, b"OTHER-123456", &BigUint::from(10u32));
+
+    b_mock.check_egld_balance(&holder, &BigUint::from(2_000u32));
+    b_mock.check_esdt_balance(&holder, b"TOKEN-123456", &BigUint::from(500u32));
+    b_mock.check_esdt_balance(&holder, b"OTHER-123456", &BigUint::from(10u32));
+}
@@ -0,0 +1,193 @@
+use crate::{address_h256_to_erdrs, Interactor};
+use log::info;
+use multiversx_sc_scenario::{
+    multiversx_chain_vm::world_mock::{decode_esdt_roles, is_protected_storage_key},
+    multiversx_sc::{
+        storage::protected_keys::{ELROND_ESDT_LOCAL_ROLES_KEY, ELROND_ESDT_NFT_LAST_NONCE_KEY},
+        types::Address,
+    },
+    scenario_format::interpret_trait::IntoRaw,
+    scenario_model::{
+        Account, AddressValue, BigUintValue, BytesKey, BytesValue, Scenario, SetStateStep, Step,
+        U64Value,
+    },
+};
+use multiversx_sdk::data::{address::Address as ErdrsAddress, esdt::EsdtBalance};
+use std::path::Path;
+
+impl Interactor {
+    /// Exports the current on-chain state of the given accounts into a snapshot file:
+    /// nonce, EGLD balance, ESDT balances and metadata, storage, code, username and developer rewards.
+    ///
+    /// The snapshot is a scenario file with a single `setState` step,
+    /// to be used as a fork base via `ScenarioWorld::fork_from_snapshot`.
+    ///
+    /// ESDT roles and last NFT nonces are read from their protected storage keys into the ESDT fields.
+    /// Other protocol-managed storage is not exported.
+    pub async fn export_account_snapshot<P: AsRef<Path>>(
+        &mut self,
+        addresses: &[Address],
+        file_path: P,
+    ) {
+        let mut set_state_step = SetStateStep::new();
+        for address in addresses {
+            let account = self.retrieve_account_snapshot(address).await;
+            set_state_step = set_state_step.put_account(address, account);
+        }
+
+        let scenario = Scenario {
+            name: None,
+            comment: Some("account snapshot".to_string()),
+            check_gas: None,
+            abi: None,
+            steps: vec![Step::SetState(set_state_step)],
+        };
+        scenario.into_raw().save_to_file(file_path.as_ref());
+        info!(
+            "exported {} account(s) to {}",
+            addresses.len(),
+            file_path.as_ref().display()
+        );
+    }
+
+    async fn retrieve_account_snapshot(&self, address: &Address) -> Account {
+        let erdrs_address = address_h256_to_erdrs(address);
+        let on_chain_account = self
+            .proxy
+            .get_account(&erdrs_address)
+            .await
+            .expect("error retrieving account");
+        let esdt_balances = self
+            .proxy
+            .get_account_esdt_tokens(&erdrs_address)
+            .await
+            .expect("error retrieving account ESDT tokens");
+        let storage_pairs = self
+            .proxy
+            .get_account_storage_keys(&erdrs_address)
+            .await
+            .expect("error retrieving account storage");
+
+        let mut account = Account::new()
+            .nonce(on_chain_account.nonce)
+            .balance(on_chain_account.balance.as_str());
+
+        if !on_chain_account.code.is_empty() {
+            account.code = Some(BytesValue::from(format!("0x{}", on_chain_account.code)));
+        }
+        if let Some(owner_address) = &on_chain_account.owner_address {
+            if !owner_address.is_empty() {
+                let owner_expr = format!("bech32:{owner_address}");
+                account.owner = Some(AddressValue::from(owner_expr.as_str()));
+            }
+        }
+        if let Some(username) = &on_chain_account.username {
+            if !username.is_empty() {
+                account.username = Some(BytesValue::from(format!("str:{username}")));
+            }
+        }
+        if let Some(developer_reward) = &on_chain_account.developer_reward {
+            if !developer_reward.is_empty() && developer_reward != "0" {
+                account.developer_rewards = Some(BigUintValue::from(developer_reward.as_str()));
+            }
+        }
+
+        let mut esdt_balances: Vec<&EsdtBalance> = esdt_balances.values().collect();
+        esdt_balances.sort_by(|a, b| a.token_identifier.cmp(&b.token_identifier));
+        for esdt_balance in esdt_balances {
+            account = add_esdt_balance(account, esdt_balance);
+        }
+
+        for (key_hex, value_hex) in storage_pairs {
+            let key = hex::decode(&key_hex).expect("error hex-decoding storage key");
+            if let Some(token_identifier) = key.strip_prefix(ELROND_ESDT_LOCAL_ROLES_KEY) {
+                let value = hex::decode(&value_hex).expect("error hex-decoding ESDT roles");
+                account = add_esdt_roles(account, token_identifier, &value);
+                continue;
+            }
+            if let Some(token_identifier) = key.strip_prefix(ELROND_ESDT_NFT_LAST_NONCE_KEY) {
+                account = account.esdt_nft_last_nonce(
+                    token_identifier_expr(token_identifier).as_str(),
+                    format!("0x{value_hex}").as_str(),
+                );
+                continue;
+            }
+            if is_protected_storage_key(&key) {
+                continue;
+            }
+            account.storage.insert(
+                BytesKey::from(format!("0x{key_hex}").as_str()),
+                BytesValue::from(format!("0x{value_hex}")),
+            );
+        }
+
+        account
+    }
+}
+
+/// NFT/SFT identifiers returned by the gateway include the nonce, as hex, after a second dash.
+fn add_esdt_balance(account: Account, esdt_balance: &EsdtBalance) -> Account {
+    let balance = esdt_balance.balance.as_str();
+    if esdt_balance.nonce == 0 {
+        let token_id_expr = format!("str:{}", esdt_balance.token_identifier);
+        return account.esdt_balance(token_id_expr.as_str(), balance);
+    }
+
+    let token_identifier = esdt_balance
+        .token_identifier
+        .rsplit_once('-')
+        .map_or(esdt_balance.token_identifier.as_str(), |(prefix, _)| prefix);
+    let token_id_expr = format!("str:{token_identifier}");
+    let attributes = esdt_balance.attributes.as_deref().map(base64_decode);
+    let mut account = account.esdt_nft_balance(
+        token_id_expr.as_str(),
+        esdt_balance.nonce,
+        balance,
+        attributes,
+    );
+
+    let instance = account
+        .esdt
+        .get_mut(&BytesKey::from(token_id_expr.as_str()))
+        .unwrap()
+        .get_mut_esdt_object()
+        .get_or_insert_instance_for_nonce(esdt_balance.nonce);
+    if let Some(creator) = &esdt_balance.creator {
+        let creator_address =
+            ErdrsAddress::from_bech32_string(creator).expect("invalid ESDT creator address");
+        instance.creator = Some(BytesValue::from(creator_address.to_bytes().to_vec()));
+    }
+    if let Some(royalties) = &esdt_balance.royalties {
+        instance.royalties = Some(U64Value::from(royalties.as_str()));
+    }
+    if let Some(hash) = &esdt_balance.hash {
+        instance.hash = Some(BytesValue::from(base64_decode(hash)));
+    }
+    if let Some(uris) = &esdt_balance.uris {
+        instance.uri = uris
+            .iter()
+            .map(|uri| BytesValue::from(base64_decode(uri)))
+            .collect();
+    }
+
+    account
+}
+
+fn add_esdt_roles(account: Account, token_identifier: &[u8], encoded_roles: &[u8]) -> Account {
+    let roles = decode_esdt_roles(encoded_roles)
+        .into_iter()
+        .map(|role| String::from_utf8(role).expect("invalid ESDT role name"))
+        .collect();
+    account.esdt_roles(token_identifier_expr(token_identifier).as_str(), roles)
+}
+
+fn token_identifier_expr(token_identifier: &[u8]) -> String {
+    format!(
+        "str:{}",
+        std::str::from_utf8(token_identifier).expect("invalid token identifier")
+    )
+}
+
+fn base64_decode(encoded: &str) -> Vec<u8> {
+    base64::decode(encoded).expect("error base64-decoding ESDT metadata")
+}
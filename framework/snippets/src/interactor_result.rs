@@ -4,7 +4,7 @@ use log::info;
 use multiversx_sc_scenario::{
     bech32,
     multiversx_sc::{
-        codec::{PanicErrorHandler, TopDecodeMulti},
+        codec::{PanicErrorHandler, TopDecode, TopDecodeMulti},
         types::Address,
     },
 };
@@ -49,6 +49,55 @@ impl<T: TopDecodeMulti> InteractorResult<T> {
         }
     }
 
+    /// Finds the first log emitted by an `#[event]` method with the given event identifier.
+    ///
+    /// Not to be confused with the log identifier, which for these events is the name of the endpoint emitting them.
+    pub fn find_event(&self, event_identifier: &str) -> Option<&Events> {
+        if let Some(logs) = &self.logs {
+            logs.events.iter().find(|event| {
+                event
+                    .topics
+                    .as_ref()
+                    .and_then(|topics| topics.get(0))
+                    .map(|first_topic| decode_base64_or_panic(first_topic))
+                    .as_deref()
+                    == Some(event_identifier.as_bytes())
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Decodes the indexed arguments of the first event with the given identifier.
+    pub fn decode_event_indexed_args<E: TopDecodeMulti>(&self, event_identifier: &str) -> E {
+        let event = self
+            .find_event(event_identifier)
+            .unwrap_or_else(|| panic!("event `{event_identifier}` not found"));
+        let mut raw_topics: Vec<Vec<u8>> = event
+            .topics
+            .iter()
+            .flatten()
+            .skip(1)
+            .map(|topic| decode_base64_or_panic(topic))
+            .collect();
+        E::multi_decode_or_handle_err(&mut raw_topics, PanicErrorHandler).unwrap()
+    }
+
+    /// Decodes the non-indexed arguments of the first event with the given identifier.
+    ///
+    /// Events with several non-indexed arguments can be decoded as a tuple of them, or as an equivalent struct.
+    pub fn decode_event_data<D: TopDecode>(&self, event_identifier: &str) -> D {
+        let event = self
+            .find_event(event_identifier)
+            .unwrap_or_else(|| panic!("event `{event_identifier}` not found"));
+        let raw_data = event
+            .data
+            .as_deref()
+            .map(decode_base64_or_panic)
+            .unwrap_or_default();
+        D::top_decode_or_handle_err(raw_data, PanicErrorHandler).unwrap()
+    }
+
     pub fn new_deployed_address(&self) -> Address {
         let event = self
             .find_log(LOG_IDENTIFIER_SC_DEPLOY)
@@ -78,6 +127,10 @@ impl<T: TopDecodeMulti> InteractorResult<T> {
     }
 }
 
+fn decode_base64_or_panic(encoded: &str) -> Vec<u8> {
+    base64::decode(encoded).expect("error base64-decoding event log")
+}
+
 fn decode_scr_data_or_panic(data: &str) -> Vec<Vec<u8>> {
     let mut split = data.split('@');
     let _ = split.next().expect("SCR data should start with '@'");
@@ -1,4 +1,5 @@
 mod interactor;
+mod interactor_account_snapshot;
 mod interactor_dns;
 mod interactor_result;
 mod interactor_retrieve;
@@ -9,5 +9,6 @@ pub use multiversx_sc;
 
 pub mod api;
 pub mod error_hook;
+pub mod wasm_alloc;
 pub mod wasm_deps;
 mod wasm_macros;
@@ -0,0 +1,14 @@
+use core::alloc::{GlobalAlloc, Layout};
+
+/// Signals an error on any attempt to allocate.
+///
+/// Useful for contracts that only work with managed types, to make sure that no heap allocation slips in.
+pub struct FailAllocator;
+
+unsafe impl GlobalAlloc for FailAllocator {
+    unsafe fn alloc(&self, _layout: Layout) -> *mut u8 {
+        crate::error_hook::signal_error(&b"memory allocation forbidden"[..])
+    }
+
+    unsafe fn dealloc(&self, _ptr: *mut u8, _layout: Layout) {}
+}
@@ -0,0 +1,66 @@
+use core::{
+    alloc::{GlobalAlloc, Layout},
+    arch::wasm32,
+    cell::UnsafeCell,
+    ptr,
+};
+
+use super::align_up;
+
+const PAGE_SIZE: usize = 65536;
+
+/// Grows the memory as needed and never frees anything.
+///
+/// Contract calls are short-lived, so this is both smaller and faster than a real allocator,
+/// as long as the contract does not allocate too much.
+pub struct LeakingAllocator {
+    next: UnsafeCell<usize>,
+    end: UnsafeCell<usize>,
+}
+
+/// Contracts are single-threaded.
+unsafe impl Sync for LeakingAllocator {}
+
+impl LeakingAllocator {
+    pub const fn new() -> Self {
+        LeakingAllocator {
+            next: UnsafeCell::new(0),
+            end: UnsafeCell::new(0),
+        }
+    }
+}
+
+impl Default for LeakingAllocator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+unsafe impl GlobalAlloc for LeakingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let next = &mut *self.next.get();
+        let end = &mut *self.end.get();
+
+        let mut start = align_up(*next, layout.align());
+        if start + layout.size() > *end {
+            let num_pages = (layout.size() + layout.align() + PAGE_SIZE - 1) / PAGE_SIZE;
+            let previous_num_pages = wasm32::memory_grow::<0>(num_pages);
+            if previous_num_pages == usize::MAX {
+                return ptr::null_mut();
+            }
+
+            let grown_start = previous_num_pages * PAGE_SIZE;
+            if grown_start != *end {
+                // the new pages do not continue the current region
+                *next = grown_start;
+            }
+            *end = grown_start + num_pages * PAGE_SIZE;
+            start = align_up(*next, layout.align());
+        }
+
+        *next = start + layout.size();
+        start as *mut u8
+    }
+
+    unsafe fn dealloc(&self, _ptr: *mut u8, _layout: Layout) {}
+}
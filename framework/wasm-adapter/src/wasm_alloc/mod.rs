@@ -0,0 +1,15 @@
+//! Alternatives to the default `wee_alloc` allocator, selected via the `allocator` setting in `multicontract.toml`.
+
+mod fail_allocator;
+#[cfg(target_arch = "wasm32")]
+mod leaking_allocator;
+mod static_allocator;
+
+pub use fail_allocator::FailAllocator;
+#[cfg(target_arch = "wasm32")]
+pub use leaking_allocator::LeakingAllocator;
+pub use static_allocator::{StaticAllocator64K, STATIC_ALLOCATOR_SIZE};
+
+fn align_up(address: usize, align: usize) -> usize {
+    (address + align - 1) & !(align - 1)
+}
@@ -0,0 +1,53 @@
+use core::{
+    alloc::{GlobalAlloc, Layout},
+    cell::UnsafeCell,
+    ptr,
+};
+
+use super::align_up;
+
+pub const STATIC_ALLOCATOR_SIZE: usize = 64 * 1024;
+
+/// Allocates from a fixed 64 KiB buffer and never frees anything.
+///
+/// Running out of space fails the allocation.
+pub struct StaticAllocator64K {
+    arena: UnsafeCell<[u8; STATIC_ALLOCATOR_SIZE]>,
+    used: UnsafeCell<usize>,
+}
+
+/// Contracts are single-threaded.
+unsafe impl Sync for StaticAllocator64K {}
+
+impl StaticAllocator64K {
+    pub const fn new() -> Self {
+        StaticAllocator64K {
+            arena: UnsafeCell::new([0; STATIC_ALLOCATOR_SIZE]),
+            used: UnsafeCell::new(0),
+        }
+    }
+}
+
+impl Default for StaticAllocator64K {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+unsafe impl GlobalAlloc for StaticAllocator64K {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let arena_start = self.arena.get() as usize;
+        let used = &mut *self.used.get();
+
+        let start = align_up(arena_start + *used, layout.align());
+        let new_used = start + layout.size() - arena_start;
+        if new_used > STATIC_ALLOCATOR_SIZE {
+            return ptr::null_mut();
+        }
+
+        *used = new_used;
+        start as *mut u8
+    }
+
+    unsafe fn dealloc(&self, _ptr: *mut u8, _layout: Layout) {}
+}
@@ -5,6 +5,21 @@ macro_rules! allocator {
         static ALLOC: multiversx_sc_wasm_adapter::wasm_deps::WeeAlloc =
             multiversx_sc_wasm_adapter::wasm_deps::WeeAlloc::INIT;
     };
+    (fail) => {
+        #[global_allocator]
+        static ALLOC: multiversx_sc_wasm_adapter::wasm_alloc::FailAllocator =
+            multiversx_sc_wasm_adapter::wasm_alloc::FailAllocator;
+    };
+    (leaking) => {
+        #[global_allocator]
+        static ALLOC: multiversx_sc_wasm_adapter::wasm_alloc::LeakingAllocator =
+            multiversx_sc_wasm_adapter::wasm_alloc::LeakingAllocator::new();
+    };
+    (static64k) => {
+        #[global_allocator]
+        static ALLOC: multiversx_sc_wasm_adapter::wasm_alloc::StaticAllocator64K =
+            multiversx_sc_wasm_adapter::wasm_alloc::StaticAllocator64K::new();
+    };
 }
 
 #[macro_export]
@@ -0,0 +1,17 @@
+use multiversx_sdk::{
+    blockchain::{CommunicationProxy, DEVNET_GATEWAY},
+    data::address::Address,
+};
+
+#[tokio::main]
+async fn main() {
+    let addr = Address::from_bech32_string(
+        "erd1qqqqqqqqqqqqqpgqfzydqmdw7m2vazsp6u5p95yxz76t2p9rd8ss0zp9ts",
+    )
+    .unwrap();
+
+    let blockchain = CommunicationProxy::new(DEVNET_GATEWAY.to_string());
+    let storage = blockchain.get_account_storage_keys(&addr).await.unwrap();
+
+    println!("{storage:#?}");
+}
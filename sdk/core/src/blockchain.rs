@@ -2,6 +2,7 @@ use std::collections::HashMap;
 
 use crate::data::{
     account::{Account, AccountResponse},
+    account_storage::AccountStorageResponse,
     address::Address,
     esdt::{EsdtBalance, EsdtBalanceResponse},
     hyperblock::{HyperBlock, HyperBlockResponse},
@@ -207,6 +208,31 @@ impl CommunicationProxy {
         }
     }
 
+    // get_account_storage_keys retrieves all the storage key-value pairs of an account from the network, hex encoded
+    pub async fn get_account_storage_keys(
+        &self,
+        address: &Address,
+    ) -> Result<HashMap<String, String>> {
+        if !address.is_valid() {
+            return Err(anyhow!("invalid address"));
+        }
+
+        let endpoint = ACCOUNT_ENDPOINT.to_string() + address.to_string().as_str() + "/keys";
+        let endpoint = self.get_endpoint(endpoint.as_str());
+        let resp = self
+            .client
+            .get(endpoint)
+            .send()
+            .await?
+            .json::<AccountStorageResponse>()
+            .await?;
+
+        match resp.data {
+            None => Err(anyhow!("{}", resp.error)),
+            Some(b) => Ok(b.pairs),
+        }
+    }
+
     async fn get_transaction_info_internal(
         &self,
         hash: &str,
@@ -11,6 +11,9 @@ pub struct Account {
     pub code: String,
     pub code_hash: Option<Vec<u8>>,
     pub root_hash: Option<String>,
+    pub owner_address: Option<String>,
+    pub developer_reward: Option<String>,
+    pub username: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+// AccountStorageData holds the storage of an account, as hex encoded key-value pairs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountStorageData {
+    pub pairs: HashMap<String, String>,
+}
+
+// AccountStorageResponse holds the account storage endpoint response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountStorageResponse {
+    pub data: Option<AccountStorageData>,
+    pub error: String,
+    pub code: String,
+}
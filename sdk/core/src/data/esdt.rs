@@ -7,6 +7,13 @@ use std::collections::HashMap;
 pub struct EsdtBalance {
     pub token_identifier: String,
     pub balance: String,
+    #[serde(default)]
+    pub nonce: u64,
+    pub creator: Option<String>,
+    pub royalties: Option<String>,
+    pub hash: Option<String>,
+    pub uris: Option<Vec<String>>,
+    pub attributes: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -0,0 +1,310 @@
+use crate::{interpret_trait::InterpreterContext, value_interpreter::interpret_string};
+
+use super::{
+    abi_literal::AbiLiteral,
+    abi_type_name::{classify_type_name, AbiTypeKind},
+    AbiEnumVariantDescription, AbiFieldDescription, AbiTypeDescription, AbiTypes,
+};
+
+impl AbiTypes {
+    /// Top-encodes the literal as the given type, same as the contract would top-encode it.
+    pub fn encode_top(
+        &self,
+        type_name: &str,
+        literal: &AbiLiteral,
+        context: &InterpreterContext,
+    ) -> Vec<u8> {
+        match classify_type_name(type_name) {
+            AbiTypeKind::Unsigned(_) | AbiTypeKind::BigUint => {
+                strip_unsigned(interpret_leaf(type_name, literal, context))
+            },
+            AbiTypeKind::Signed(_) | AbiTypeKind::BigInt => {
+                strip_signed(interpret_signed_leaf(type_name, literal, context))
+            },
+            AbiTypeKind::Bool => encode_bool(type_name, literal, context, false),
+            AbiTypeKind::Bytes => interpret_leaf(type_name, literal, context),
+            AbiTypeKind::Option(_) if literal.is_null() => Vec::new(),
+            AbiTypeKind::List(item_type) => {
+                let mut result = Vec::new();
+                for item in expect_list(type_name, literal) {
+                    self.encode_nested(item_type, item, context, &mut result);
+                }
+                result
+            },
+            AbiTypeKind::Custom(name) => match self.type_description(name) {
+                Some(AbiTypeDescription::Enum { variants }) => {
+                    let (variant, fields_literal) = find_variant(name, variants, literal);
+                    if variant.fields.is_empty() {
+                        strip_unsigned(vec![variant.discriminant])
+                    } else {
+                        let mut result = vec![variant.discriminant];
+                        self.encode_fields(
+                            name,
+                            &variant.fields,
+                            fields_literal,
+                            context,
+                            &mut result,
+                        );
+                        result
+                    }
+                },
+                _ => self.encode_nested_to_vec(type_name, literal, context),
+            },
+            _ => self.encode_nested_to_vec(type_name, literal, context),
+        }
+    }
+
+    fn encode_nested_to_vec(
+        &self,
+        type_name: &str,
+        literal: &AbiLiteral,
+        context: &InterpreterContext,
+    ) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.encode_nested(type_name, literal, context, &mut result);
+        result
+    }
+
+    /// Nested-encodes the literal as the given type, appending to `dest`.
+    pub fn encode_nested(
+        &self,
+        type_name: &str,
+        literal: &AbiLiteral,
+        context: &InterpreterContext,
+        dest: &mut Vec<u8>,
+    ) {
+        match classify_type_name(type_name) {
+            AbiTypeKind::Unsigned(width) => {
+                let bytes = strip_unsigned(interpret_leaf(type_name, literal, context));
+                dest.extend_from_slice(&pad_to_width(type_name, bytes, width, 0u8));
+            },
+            AbiTypeKind::Signed(width) => {
+                let bytes = strip_signed(interpret_signed_leaf(type_name, literal, context));
+                let padding = if bytes.first().map_or(false, |b| b >> 7 == 1) {
+                    0xffu8
+                } else {
+                    0u8
+                };
+                dest.extend_from_slice(&pad_to_width(type_name, bytes, width, padding));
+            },
+            AbiTypeKind::BigUint => {
+                let bytes = strip_unsigned(interpret_leaf(type_name, literal, context));
+                push_length_prefixed(dest, &bytes);
+            },
+            AbiTypeKind::BigInt => {
+                let bytes = strip_signed(interpret_signed_leaf(type_name, literal, context));
+                push_length_prefixed(dest, &bytes);
+            },
+            AbiTypeKind::Bool => {
+                dest.extend_from_slice(&encode_bool(type_name, literal, context, true));
+            },
+            AbiTypeKind::Bytes => {
+                let bytes = interpret_leaf(type_name, literal, context);
+                push_length_prefixed(dest, &bytes);
+            },
+            AbiTypeKind::FixedBytes(len) => {
+                let bytes = interpret_leaf(type_name, literal, context);
+                assert!(
+                    bytes.len() == len,
+                    "{type_name} value {literal} should be {len} bytes long, not {}",
+                    bytes.len()
+                );
+                dest.extend_from_slice(&bytes);
+            },
+            AbiTypeKind::Option(item_type) => {
+                if literal.is_null() {
+                    dest.push(0);
+                } else {
+                    dest.push(1);
+                    self.encode_nested(item_type, literal, context, dest);
+                }
+            },
+            AbiTypeKind::List(item_type) => {
+                let items = expect_list(type_name, literal);
+                dest.extend_from_slice(&(items.len() as u32).to_be_bytes());
+                for item in items {
+                    self.encode_nested(item_type, item, context, dest);
+                }
+            },
+            AbiTypeKind::Array(len, item_type) => {
+                let items = expect_list(type_name, literal);
+                assert!(
+                    items.len() == len,
+                    "{type_name} expects {len} items, {} provided",
+                    items.len()
+                );
+                for item in items {
+                    self.encode_nested(item_type, item, context, dest);
+                }
+            },
+            AbiTypeKind::Tuple(item_types) => {
+                let items = expect_list(type_name, literal);
+                assert!(
+                    items.len() == item_types.len(),
+                    "{type_name} expects {} items, {} provided",
+                    item_types.len(),
+                    items.len()
+                );
+                for (item_type, item) in item_types.iter().zip(items.iter()) {
+                    self.encode_nested(item_type, item, context, dest);
+                }
+            },
+            AbiTypeKind::Custom(name) => match self.type_description(name) {
+                Some(AbiTypeDescription::Struct { fields }) => {
+                    self.encode_fields(name, fields, literal, context, dest);
+                },
+                Some(AbiTypeDescription::Enum { variants }) => {
+                    let (variant, fields_literal) = find_variant(name, variants, literal);
+                    dest.push(variant.discriminant);
+                    if !variant.fields.is_empty() {
+                        self.encode_fields(name, &variant.fields, fields_literal, context, dest);
+                    }
+                },
+                None => panic!("unknown ABI type: {name}"),
+            },
+        }
+    }
+
+    /// Fields are given either by name, or as a list, in order.
+    fn encode_fields(
+        &self,
+        type_name: &str,
+        fields: &[AbiFieldDescription],
+        literal: &AbiLiteral,
+        context: &InterpreterContext,
+        dest: &mut Vec<u8>,
+    ) {
+        match literal {
+            AbiLiteral::Map(entries) => {
+                for (key, _) in entries {
+                    assert!(
+                        fields.iter().any(|field| &field.name == key),
+                        "{type_name} has no field named {key}"
+                    );
+                }
+                for field in fields {
+                    let (_, value) = entries
+                        .iter()
+                        .find(|(key, _)| key == &field.name)
+                        .unwrap_or_else(|| panic!("missing field {} of {type_name}", field.name));
+                    self.encode_nested(&field.field_type, value, context, dest);
+                }
+            },
+            AbiLiteral::List(items) => {
+                assert!(
+                    items.len() == fields.len(),
+                    "{type_name} has {} fields, {} provided",
+                    fields.len(),
+                    items.len()
+                );
+                for (field, item) in fields.iter().zip(items.iter()) {
+                    self.encode_nested(&field.field_type, item, context, dest);
+                }
+            },
+            _ => panic!("expected the fields of {type_name}, found {literal}"),
+        }
+    }
+}
+
+fn interpret_leaf(type_name: &str, literal: &AbiLiteral, context: &InterpreterContext) -> Vec<u8> {
+    match literal {
+        AbiLiteral::Leaf(expr) => interpret_string(expr, context),
+        _ => panic!("expected a single value for {type_name}, found {literal}"),
+    }
+}
+
+/// Plain numbers are positive, so they must not be read as two's complement.
+fn interpret_signed_leaf(
+    type_name: &str,
+    literal: &AbiLiteral,
+    context: &InterpreterContext,
+) -> Vec<u8> {
+    match literal {
+        AbiLiteral::Leaf(expr) if expr.starts_with(|c: char| c.is_ascii_digit()) => {
+            interpret_string(&format!("+{expr}"), context)
+        },
+        _ => interpret_leaf(type_name, literal, context),
+    }
+}
+
+fn encode_bool(
+    type_name: &str,
+    literal: &AbiLiteral,
+    context: &InterpreterContext,
+    nested: bool,
+) -> Vec<u8> {
+    match strip_unsigned(interpret_leaf(type_name, literal, context)).as_slice() {
+        [] if nested => vec![0],
+        [] => Vec::new(),
+        [1] => vec![1],
+        _ => panic!("invalid bool value: {literal}"),
+    }
+}
+
+fn expect_list<'a>(type_name: &str, literal: &'a AbiLiteral) -> &'a [AbiLiteral] {
+    match literal {
+        AbiLiteral::List(items) => items.as_slice(),
+        _ => panic!("expected a list for {type_name}, found {literal}"),
+    }
+}
+
+fn find_variant<'a>(
+    type_name: &str,
+    variants: &'a [AbiEnumVariantDescription],
+    literal: &'a AbiLiteral,
+) -> (&'a AbiEnumVariantDescription, &'a AbiLiteral) {
+    let (variant_name, fields_literal) = match literal {
+        AbiLiteral::Leaf(name) => (name, literal),
+        AbiLiteral::Tagged(name, fields_literal) => (name, fields_literal.as_ref()),
+        _ => panic!("expected a variant of {type_name}, found {literal}"),
+    };
+    let variant = variants
+        .iter()
+        .find(|variant| &variant.name == variant_name)
+        .unwrap_or_else(|| panic!("{type_name} has no variant named {variant_name}"));
+    if !variant.fields.is_empty() {
+        assert!(
+            matches!(literal, AbiLiteral::Tagged(..)),
+            "variant {variant_name} of {type_name} has fields, but none were provided"
+        );
+    }
+    (variant, fields_literal)
+}
+
+fn push_length_prefixed(dest: &mut Vec<u8>, bytes: &[u8]) {
+    dest.extend_from_slice(&(bytes.len() as u32).to_be_bytes());
+    dest.extend_from_slice(bytes);
+}
+
+fn pad_to_width(type_name: &str, bytes: Vec<u8>, width: usize, padding: u8) -> Vec<u8> {
+    assert!(
+        bytes.len() <= width,
+        "value does not fit in {type_name}, which is {width} bytes wide"
+    );
+    let mut result = vec![padding; width - bytes.len()];
+    result.extend_from_slice(&bytes);
+    result
+}
+
+/// Removes leading zeroes, which might come from explicit fixed width expressions, e.g. `u64:5`.
+fn strip_unsigned(bytes: Vec<u8>) -> Vec<u8> {
+    let first_non_zero = bytes.iter().position(|b| *b != 0).unwrap_or(bytes.len());
+    bytes[first_non_zero..].to_vec()
+}
+
+/// Removes redundant leading sign bytes, keeping the two's complement representation valid.
+fn strip_signed(bytes: Vec<u8>) -> Vec<u8> {
+    let mut start = 0;
+    while start < bytes.len() {
+        let redundant = match bytes[start] {
+            0x00 => bytes.get(start + 1).map_or(true, |next| next >> 7 == 0),
+            0xff => bytes.get(start + 1).map_or(false, |next| next >> 7 == 1),
+            _ => false,
+        };
+        if !redundant {
+            break;
+        }
+        start += 1;
+    }
+    bytes[start..].to_vec()
+}
@@ -0,0 +1,217 @@
+use std::fmt;
+
+/// The JSON-like literal following the type name in an `abi:` expression.
+///
+/// Syntax:
+/// - `{field: value, ...}` - struct fields, by name;
+/// - `[value, ...]` - lists, arrays, tuples, or struct fields in order;
+/// - `Variant`, `Variant{field: value, ...}`, `Variant[value, ...]` - enum variants;
+/// - `null` - an empty `Option`;
+/// - anything else is a regular value expression, e.g. `5`, `str:abc` or `address:owner`.
+///   It needs to be quoted if it contains any of `,{}[]"`, e.g. `"str:a, b"`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AbiLiteral {
+    Leaf(String),
+    List(Vec<AbiLiteral>),
+    Map(Vec<(String, AbiLiteral)>),
+    Tagged(String, Box<AbiLiteral>),
+}
+
+pub const NULL_LITERAL: &str = "null";
+
+const DELIMITERS: &[char] = &[',', '{', '}', '[', ']', '"'];
+
+impl AbiLiteral {
+    pub fn parse(s: &str) -> Self {
+        let mut parser = LiteralParser {
+            chars: s.chars().collect(),
+            pos: 0,
+        };
+        let literal = parser.parse_value();
+        parser.skip_whitespace();
+        assert!(
+            parser.pos == parser.chars.len(),
+            "unexpected characters after ABI literal: {s}"
+        );
+        literal
+    }
+
+    pub fn is_null(&self) -> bool {
+        matches!(self, AbiLiteral::Leaf(s) if s == NULL_LITERAL)
+    }
+}
+
+struct LiteralParser {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl LiteralParser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().map_or(false, char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, expected: char) {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(c) if c == expected => self.pos += 1,
+            Some(c) => panic!("invalid ABI literal: expected '{expected}', found '{c}'"),
+            None => panic!("invalid ABI literal: expected '{expected}', found end of input"),
+        }
+    }
+
+    /// Consumes the closing delimiter, if found.
+    fn is_at_end_of_sequence(&mut self, closing: char) -> bool {
+        self.skip_whitespace();
+        if self.peek() == Some(closing) {
+            self.pos += 1;
+            return true;
+        }
+        false
+    }
+
+    fn parse_value(&mut self) -> AbiLiteral {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('{') => self.parse_map(),
+            Some('[') => self.parse_list(),
+            Some('"') => AbiLiteral::Leaf(self.parse_quoted()),
+            _ => {
+                let raw = self.read_until(DELIMITERS);
+                let raw = raw.trim();
+                match self.peek() {
+                    Some('{') | Some('[') if !raw.is_empty() => {
+                        AbiLiteral::Tagged(raw.to_string(), Box::new(self.parse_value()))
+                    },
+                    _ => AbiLiteral::Leaf(raw.to_string()),
+                }
+            },
+        }
+    }
+
+    fn parse_map(&mut self) -> AbiLiteral {
+        self.expect('{');
+        let mut entries = Vec::new();
+        while !self.is_at_end_of_sequence('}') {
+            if !entries.is_empty() {
+                self.expect(',');
+                if self.is_at_end_of_sequence('}') {
+                    break;
+                }
+            }
+            self.skip_whitespace();
+            let key = if self.peek() == Some('"') {
+                self.parse_quoted()
+            } else {
+                self.read_until(&[':']).trim().to_string()
+            };
+            self.expect(':');
+            let value = self.parse_value();
+            entries.push((key, value));
+        }
+        AbiLiteral::Map(entries)
+    }
+
+    fn parse_list(&mut self) -> AbiLiteral {
+        self.expect('[');
+        let mut items = Vec::new();
+        while !self.is_at_end_of_sequence(']') {
+            if !items.is_empty() {
+                self.expect(',');
+                if self.is_at_end_of_sequence(']') {
+                    break;
+                }
+            }
+            items.push(self.parse_value());
+        }
+        AbiLiteral::List(items)
+    }
+
+    fn parse_quoted(&mut self) -> String {
+        self.expect('"');
+        let mut result = String::new();
+        loop {
+            match self.peek() {
+                Some('"') => {
+                    self.pos += 1;
+                    return result;
+                },
+                Some('\\') => {
+                    self.pos += 1;
+                    let escaped = self
+                        .peek()
+                        .expect("invalid ABI literal: unterminated string");
+                    result.push(escaped);
+                    self.pos += 1;
+                },
+                Some(c) => {
+                    result.push(c);
+                    self.pos += 1;
+                },
+                None => panic!("invalid ABI literal: unterminated string"),
+            }
+        }
+    }
+
+    fn read_until(&mut self, delimiters: &[char]) -> String {
+        let start = self.pos;
+        while let Some(c) = self.peek() {
+            if delimiters.contains(&c) {
+                break;
+            }
+            self.pos += 1;
+        }
+        self.chars[start..self.pos].iter().collect()
+    }
+}
+
+impl fmt::Display for AbiLiteral {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AbiLiteral::Leaf(s) => write_leaf(f, s),
+            AbiLiteral::List(items) => {
+                f.write_str("[")?;
+                for (index, item) in items.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                f.write_str("]")
+            },
+            AbiLiteral::Map(entries) => {
+                f.write_str("{")?;
+                for (index, (key, value)) in entries.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{key}: {value}")?;
+                }
+                f.write_str("}")
+            },
+            AbiLiteral::Tagged(tag, value) => write!(f, "{tag}{value}"),
+        }
+    }
+}
+
+fn write_leaf(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    let needs_quotes = s.is_empty() || s.trim() != s || s.contains(DELIMITERS);
+    if !needs_quotes {
+        return f.write_str(s);
+    }
+
+    f.write_str("\"")?;
+    for c in s.chars() {
+        if c == '"' || c == '\\' {
+            f.write_str("\\")?;
+        }
+        write!(f, "{c}")?;
+    }
+    f.write_str("\"")
+}
@@ -0,0 +1,232 @@
+use num_bigint::{BigInt, BigUint};
+
+use super::{
+    abi_literal::{AbiLiteral, NULL_LITERAL},
+    abi_type_name::{classify_type_name, AbiTypeKind},
+    AbiEnumVariantDescription, AbiFieldDescription, AbiTypeDescription, AbiTypes, ABI_PREFIX,
+};
+
+type DecodeResult = Result<AbiLiteral, String>;
+
+impl AbiTypes {
+    /// The reverse of the `abi:` expression: decodes a top-encoded value
+    /// into an expression that encodes back to the same bytes.
+    ///
+    /// Falls back to a hex expression if the value cannot be decoded as the given type.
+    pub fn pretty_print(&self, type_name: &str, top_encoded: &[u8]) -> String {
+        match self.decode_top(type_name, top_encoded) {
+            Ok(literal) => format_abi_expression(type_name, &literal),
+            Err(_) => format!("0x{}", hex::encode(top_encoded)),
+        }
+    }
+
+    pub fn decode_top(&self, type_name: &str, bytes: &[u8]) -> DecodeResult {
+        match classify_type_name(type_name) {
+            AbiTypeKind::Unsigned(width) | AbiTypeKind::Signed(width) if bytes.len() > width => {
+                Err(format!("too many bytes for {type_name}"))
+            },
+            AbiTypeKind::Unsigned(_) | AbiTypeKind::BigUint => Ok(unsigned_leaf(bytes)),
+            AbiTypeKind::Signed(_) | AbiTypeKind::BigInt => Ok(signed_leaf(bytes)),
+            AbiTypeKind::Bool => bool_leaf(bytes),
+            AbiTypeKind::Bytes => Ok(bytes_leaf(bytes)),
+            AbiTypeKind::Option(_) if bytes.is_empty() => {
+                Ok(AbiLiteral::Leaf(NULL_LITERAL.to_string()))
+            },
+            AbiTypeKind::List(item_type) => {
+                let mut input = NestedInput::new(bytes);
+                let mut items = Vec::new();
+                while !input.is_depleted() {
+                    items.push(self.decode_nested(item_type, &mut input)?);
+                }
+                Ok(AbiLiteral::List(items))
+            },
+            AbiTypeKind::Custom(name) if bytes.len() <= 1 => match self.type_description(name) {
+                Some(AbiTypeDescription::Enum { variants }) => {
+                    let discriminant = bytes.first().copied().unwrap_or_default();
+                    let variant = find_variant(name, variants, discriminant)?;
+                    if !variant.fields.is_empty() {
+                        return Err(format!("missing fields of {name}::{}", variant.name));
+                    }
+                    Ok(AbiLiteral::Leaf(variant.name.clone()))
+                },
+                _ => self.decode_all_nested(type_name, bytes),
+            },
+            _ => self.decode_all_nested(type_name, bytes),
+        }
+    }
+
+    fn decode_all_nested(&self, type_name: &str, bytes: &[u8]) -> DecodeResult {
+        let mut input = NestedInput::new(bytes);
+        let literal = self.decode_nested(type_name, &mut input)?;
+        if !input.is_depleted() {
+            return Err(format!("unexpected bytes after {type_name}"));
+        }
+        Ok(literal)
+    }
+
+    fn decode_nested(&self, type_name: &str, input: &mut NestedInput) -> DecodeResult {
+        match classify_type_name(type_name) {
+            AbiTypeKind::Unsigned(width) => Ok(unsigned_leaf(input.read(width)?)),
+            AbiTypeKind::Signed(width) => Ok(signed_leaf(input.read(width)?)),
+            AbiTypeKind::BigUint => {
+                let len = input.read_len()?;
+                Ok(unsigned_leaf(input.read(len)?))
+            },
+            AbiTypeKind::BigInt => {
+                let len = input.read_len()?;
+                Ok(signed_leaf(input.read(len)?))
+            },
+            AbiTypeKind::Bool => bool_leaf(input.read(1)?),
+            AbiTypeKind::Bytes => {
+                let len = input.read_len()?;
+                Ok(bytes_leaf(input.read(len)?))
+            },
+            AbiTypeKind::FixedBytes(len) => Ok(hex_leaf(input.read(len)?)),
+            AbiTypeKind::Option(item_type) => match input.read(1)? {
+                [0] => Ok(AbiLiteral::Leaf(NULL_LITERAL.to_string())),
+                [1] => self.decode_nested(item_type, input),
+                _ => Err(format!("invalid {type_name}")),
+            },
+            AbiTypeKind::List(item_type) => {
+                let len = input.read_len()?;
+                let items = (0..len)
+                    .map(|_| self.decode_nested(item_type, input))
+                    .collect::<Result<_, _>>()?;
+                Ok(AbiLiteral::List(items))
+            },
+            AbiTypeKind::Array(len, item_type) => {
+                let items = (0..len)
+                    .map(|_| self.decode_nested(item_type, input))
+                    .collect::<Result<_, _>>()?;
+                Ok(AbiLiteral::List(items))
+            },
+            AbiTypeKind::Tuple(item_types) => {
+                let items = item_types
+                    .iter()
+                    .map(|item_type| self.decode_nested(item_type, input))
+                    .collect::<Result<_, _>>()?;
+                Ok(AbiLiteral::List(items))
+            },
+            AbiTypeKind::Custom(name) => match self.type_description(name) {
+                Some(AbiTypeDescription::Struct { fields }) => {
+                    Ok(AbiLiteral::Map(self.decode_fields(fields, input)?))
+                },
+                Some(AbiTypeDescription::Enum { variants }) => {
+                    let variant = find_variant(name, variants, input.read(1)?[0])?;
+                    if variant.fields.is_empty() {
+                        return Ok(AbiLiteral::Leaf(variant.name.clone()));
+                    }
+                    let fields = self.decode_fields(&variant.fields, input)?;
+                    let fields_literal = if variant.is_tuple_variant() {
+                        AbiLiteral::List(fields.into_iter().map(|(_, value)| value).collect())
+                    } else {
+                        AbiLiteral::Map(fields)
+                    };
+                    Ok(AbiLiteral::Tagged(
+                        variant.name.clone(),
+                        Box::new(fields_literal),
+                    ))
+                },
+                None => Err(format!("unknown ABI type: {name}")),
+            },
+        }
+    }
+
+    fn decode_fields(
+        &self,
+        fields: &[AbiFieldDescription],
+        input: &mut NestedInput,
+    ) -> Result<Vec<(String, AbiLiteral)>, String> {
+        fields
+            .iter()
+            .map(|field| {
+                let value = self.decode_nested(&field.field_type, input)?;
+                Ok((field.name.clone(), value))
+            })
+            .collect()
+    }
+}
+
+/// Formats a literal as a full `abi:` expression.
+pub fn format_abi_expression(type_name: &str, literal: &AbiLiteral) -> String {
+    match literal {
+        AbiLiteral::List(_) | AbiLiteral::Map(_) => format!("{ABI_PREFIX}{type_name}{literal}"),
+        _ => format!("{ABI_PREFIX}{type_name}:{literal}"),
+    }
+}
+
+fn find_variant<'a>(
+    type_name: &str,
+    variants: &'a [AbiEnumVariantDescription],
+    discriminant: u8,
+) -> Result<&'a AbiEnumVariantDescription, String> {
+    variants
+        .iter()
+        .find(|variant| variant.discriminant == discriminant)
+        .ok_or_else(|| format!("invalid {type_name} discriminant: {discriminant}"))
+}
+
+fn unsigned_leaf(bytes: &[u8]) -> AbiLiteral {
+    AbiLiteral::Leaf(BigUint::from_bytes_be(bytes).to_string())
+}
+
+fn signed_leaf(bytes: &[u8]) -> AbiLiteral {
+    AbiLiteral::Leaf(BigInt::from_signed_bytes_be(bytes).to_string())
+}
+
+fn bool_leaf(bytes: &[u8]) -> DecodeResult {
+    match bytes {
+        [] | [0] => Ok(AbiLiteral::Leaf("false".to_string())),
+        [1] => Ok(AbiLiteral::Leaf("true".to_string())),
+        _ => Err("invalid bool".to_string()),
+    }
+}
+
+/// Readable strings are shown as such, everything else as hex.
+///
+/// Strings containing `|` would be split by the interpreter, so they are also shown as hex.
+fn bytes_leaf(bytes: &[u8]) -> AbiLiteral {
+    if bytes.is_empty() {
+        return AbiLiteral::Leaf(String::new());
+    }
+    match std::str::from_utf8(bytes) {
+        Ok(s) if !s.contains('|') && !s.chars().any(char::is_control) => {
+            AbiLiteral::Leaf(format!("str:{s}"))
+        },
+        _ => hex_leaf(bytes),
+    }
+}
+
+fn hex_leaf(bytes: &[u8]) -> AbiLiteral {
+    AbiLiteral::Leaf(format!("0x{}", hex::encode(bytes)))
+}
+
+struct NestedInput<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> NestedInput<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        NestedInput { bytes, pos: 0 }
+    }
+
+    fn is_depleted(&self) -> bool {
+        self.pos == self.bytes.len()
+    }
+
+    fn read(&mut self, len: usize) -> Result<&'a [u8], String> {
+        let end = self.pos + len;
+        if end > self.bytes.len() {
+            return Err("input too short".to_string());
+        }
+        let result = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(result)
+    }
+
+    fn read_len(&mut self) -> Result<usize, String> {
+        let len_bytes = self.read(4)?;
+        Ok(u32::from_be_bytes(len_bytes.try_into().unwrap()) as usize)
+    }
+}
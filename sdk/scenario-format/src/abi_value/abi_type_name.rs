@@ -0,0 +1,89 @@
+/// How a type name from the ABI gets encoded.
+pub(crate) enum AbiTypeKind<'a> {
+    /// Fixed width when nested, width in bytes.
+    Unsigned(usize),
+
+    /// Fixed width when nested, width in bytes.
+    Signed(usize),
+
+    BigUint,
+    BigInt,
+    Bool,
+
+    /// Length-prefixed when nested.
+    Bytes,
+
+    /// Same length in all cases, e.g. addresses.
+    FixedBytes(usize),
+
+    Option(&'a str),
+    List(&'a str),
+    Array(usize, &'a str),
+    Tuple(Vec<&'a str>),
+
+    /// Structs and enums, described in the `"types"` section of the ABI.
+    Custom(&'a str),
+}
+
+pub(crate) fn classify_type_name(type_name: &str) -> AbiTypeKind<'_> {
+    match type_name {
+        "u8" => return AbiTypeKind::Unsigned(1),
+        "u16" => return AbiTypeKind::Unsigned(2),
+        "u32" | "usize" => return AbiTypeKind::Unsigned(4),
+        "u64" => return AbiTypeKind::Unsigned(8),
+        "i8" => return AbiTypeKind::Signed(1),
+        "i16" => return AbiTypeKind::Signed(2),
+        "i32" | "isize" => return AbiTypeKind::Signed(4),
+        "i64" => return AbiTypeKind::Signed(8),
+        "BigUint" => return AbiTypeKind::BigUint,
+        "BigInt" => return AbiTypeKind::BigInt,
+        "bool" => return AbiTypeKind::Bool,
+        "bytes" | "utf-8 string" | "TokenIdentifier" | "EgldOrEsdtTokenIdentifier" => {
+            return AbiTypeKind::Bytes
+        },
+        "Address" | "H256" => return AbiTypeKind::FixedBytes(32),
+        "CodeMetadata" => return AbiTypeKind::FixedBytes(2),
+        _ => {},
+    }
+
+    if let Some((base, args)) = split_generic(type_name) {
+        match (base, args.as_slice()) {
+            ("Option", [item]) => return AbiTypeKind::Option(item),
+            ("List", [item]) => return AbiTypeKind::List(item),
+            ("tuple", _) => return AbiTypeKind::Tuple(args),
+            _ => {},
+        }
+
+        if let Some(len) = base.strip_prefix("array") {
+            if let (Ok(len), [item]) = (len.parse::<usize>(), args.as_slice()) {
+                return AbiTypeKind::Array(len, item);
+            }
+        }
+    }
+
+    AbiTypeKind::Custom(type_name)
+}
+
+/// Splits `Base<A,B<C,D>>` into `Base` and `[A, B<C,D>]`.
+fn split_generic(type_name: &str) -> Option<(&str, Vec<&str>)> {
+    let open = type_name.find('<')?;
+    let inner = type_name.strip_suffix('>')?.get(open + 1..)?;
+
+    let mut args = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (index, c) in inner.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                args.push(inner[start..index].trim());
+                start = index + 1;
+            },
+            _ => {},
+        }
+    }
+    args.push(inner[start..].trim());
+
+    Some((&type_name[..open], args))
+}
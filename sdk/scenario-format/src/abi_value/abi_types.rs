@@ -0,0 +1,76 @@
+use std::{collections::BTreeMap, fs, path::Path};
+
+use serde::Deserialize;
+
+/// The custom type descriptions from a contract ABI JSON, i.e. its `"types"` section.
+///
+/// Everything else in the ABI is ignored.
+#[derive(Clone, Debug, Default)]
+pub struct AbiTypes {
+    pub types: BTreeMap<String, AbiTypeDescription>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum AbiTypeDescription {
+    Struct {
+        #[serde(default)]
+        fields: Vec<AbiFieldDescription>,
+    },
+    Enum {
+        #[serde(default)]
+        variants: Vec<AbiEnumVariantDescription>,
+    },
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct AbiFieldDescription {
+    pub name: String,
+
+    #[serde(rename = "type")]
+    pub field_type: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct AbiEnumVariantDescription {
+    pub name: String,
+    pub discriminant: u8,
+
+    #[serde(default)]
+    pub fields: Vec<AbiFieldDescription>,
+}
+
+impl AbiEnumVariantDescription {
+    /// Tuple variants have their fields named "0", "1", etc.
+    pub fn is_tuple_variant(&self) -> bool {
+        self.fields
+            .iter()
+            .enumerate()
+            .all(|(index, field)| field.name == index.to_string())
+    }
+}
+
+#[derive(Deserialize)]
+struct AbiJsonTypesRaw {
+    #[serde(default)]
+    types: BTreeMap<String, AbiTypeDescription>,
+}
+
+impl AbiTypes {
+    pub fn from_abi_json_str(s: &str) -> Self {
+        let raw: AbiJsonTypesRaw =
+            serde_json::from_str(s).unwrap_or_else(|err| panic!("invalid ABI JSON: {err}"));
+        AbiTypes { types: raw.types }
+    }
+
+    pub fn load_from_abi_file<P: AsRef<Path>>(path: P) -> Self {
+        let contents = fs::read_to_string(path.as_ref()).unwrap_or_else(|err| {
+            panic!("cannot read ABI file {}: {err}", path.as_ref().display())
+        });
+        Self::from_abi_json_str(contents.as_str())
+    }
+
+    pub fn type_description(&self, type_name: &str) -> Option<&AbiTypeDescription> {
+        self.types.get(type_name)
+    }
+}
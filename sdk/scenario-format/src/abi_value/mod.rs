@@ -0,0 +1,42 @@
+mod abi_encode;
+mod abi_literal;
+mod abi_pretty;
+mod abi_type_name;
+mod abi_types;
+
+pub use abi_literal::AbiLiteral;
+pub use abi_pretty::format_abi_expression;
+pub use abi_types::*;
+
+use crate::interpret_trait::InterpreterContext;
+
+pub const ABI_PREFIX: &str = "abi:";
+
+/// Interprets an expression of the form `abi:<type><literal>`, e.g. `abi:MyStruct{a: 1, b: str:x}`,
+/// by top-encoding the literal as the given type, just like the contract would.
+///
+/// The literal can also be separated from the type by a colon, e.g. `abi:Option<u32>:5`, `abi:MyEnum:Variant`.
+///
+/// Requires the ABI types to be set in the interpreter context.
+pub fn interpret_abi_expression(s: &str, context: &InterpreterContext) -> Vec<u8> {
+    let abi_types = context.abi_types.as_ref().unwrap_or_else(|| {
+        panic!("cannot interpret {ABI_PREFIX}{s}, no contract ABI was provided")
+    });
+    let (type_name, literal) =
+        split_type_and_literal(s).unwrap_or_else(|| panic!("missing value in {ABI_PREFIX}{s}"));
+    abi_types.encode_top(type_name, &AbiLiteral::parse(literal), context)
+}
+
+/// The type name of an `abi:` expression, `None` for other expressions.
+pub fn abi_expression_type_name(expr: &str) -> Option<&str> {
+    let (type_name, _) = split_type_and_literal(expr.strip_prefix(ABI_PREFIX)?)?;
+    Some(type_name)
+}
+
+fn split_type_and_literal(s: &str) -> Option<(&str, &str)> {
+    let literal_start = s.find(&[':', '{', '['][..])?;
+    let type_name = s[..literal_start].trim();
+    let literal = &s[literal_start..];
+    let literal = literal.strip_prefix(':').unwrap_or(literal);
+    Some((type_name, literal))
+}
@@ -1,13 +1,26 @@
-use std::path::PathBuf;
+use std::{path::PathBuf, sync::Arc};
+
+use crate::abi_value::AbiTypes;
 
 #[derive(Default)]
 pub struct InterpreterContext {
     pub context_path: PathBuf,
+
+    /// Needed for interpreting `abi:` expressions.
+    pub abi_types: Option<Arc<AbiTypes>>,
 }
 
 impl InterpreterContext {
     pub fn new(context_path: PathBuf) -> Self {
-        InterpreterContext { context_path }
+        InterpreterContext {
+            context_path,
+            abi_types: None,
+        }
+    }
+
+    pub fn with_abi_types(mut self, abi_types: AbiTypes) -> Self {
+        self.abi_types = Some(Arc::new(abi_types));
+        self
     }
 }
 
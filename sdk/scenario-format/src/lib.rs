@@ -1,3 +1,5 @@
+pub mod abi_value;
 pub mod interpret_trait;
+pub mod scenario_fmt;
 pub mod serde_raw;
 pub mod value_interpreter;
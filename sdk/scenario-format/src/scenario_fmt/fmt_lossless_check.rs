@@ -0,0 +1,68 @@
+use serde_json::Value;
+
+/// Fields unknown to the raw model are silently dropped when deserializing.
+///
+/// Empty values are allowed to disappear, e.g. `"storage": {}` in `setState`.
+pub(super) fn check_nothing_lost(original: &Value, round_tripped: &Value) -> Result<(), String> {
+    let mut lost_paths = Vec::new();
+    collect_lost_paths(original, round_tripped, "", &mut lost_paths);
+    if lost_paths.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "formatting would lose the following fields: {}",
+            lost_paths.join(", ")
+        ))
+    }
+}
+
+fn collect_lost_paths(
+    original: &Value,
+    round_tripped: &Value,
+    path: &str,
+    lost_paths: &mut Vec<String>,
+) {
+    match (original, round_tripped) {
+        (Value::Object(original_map), Value::Object(round_tripped_map)) => {
+            for (key, original_value) in original_map {
+                let child_path = format!("{path}/{key}");
+                match round_tripped_map.get(key) {
+                    Some(round_tripped_value) => collect_lost_paths(
+                        original_value,
+                        round_tripped_value,
+                        &child_path,
+                        lost_paths,
+                    ),
+                    None if !is_empty_value(original_value) => lost_paths.push(child_path),
+                    None => {},
+                }
+            }
+        },
+        (Value::Array(original_items), Value::Array(round_tripped_items)) => {
+            for (index, original_item) in original_items.iter().enumerate() {
+                let child_path = format!("{path}/{index}");
+                match round_tripped_items.get(index) {
+                    Some(round_tripped_item) => collect_lost_paths(
+                        original_item,
+                        round_tripped_item,
+                        &child_path,
+                        lost_paths,
+                    ),
+                    None if !is_empty_value(original_item) => lost_paths.push(child_path),
+                    None => {},
+                }
+            }
+        },
+        _ => {},
+    }
+}
+
+fn is_empty_value(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::String(s) => s.is_empty(),
+        Value::Array(items) => items.is_empty(),
+        Value::Object(map) => map.is_empty(),
+        _ => false,
+    }
+}
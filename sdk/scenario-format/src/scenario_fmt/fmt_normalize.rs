@@ -0,0 +1,125 @@
+use std::collections::BTreeMap;
+
+use crate::serde_raw::{
+    CheckBytesValueRaw, CheckLogsRaw, CheckStorageRaw, CheckValueListRaw, ScenarioRaw, StepRaw,
+    TxExpectRaw, ValueSubTree,
+};
+
+/// Shorter hex values are more likely to be numbers than text.
+const MIN_PRINTABLE_LEN: usize = 3;
+
+/// Only touches values that are usually bytes:
+/// storage keys and values, arguments, expected outputs and logs.
+pub(super) fn normalize_scenario(scenario: &mut ScenarioRaw) {
+    for step in scenario.steps.iter_mut() {
+        normalize_step(step);
+    }
+}
+
+fn normalize_step(step: &mut StepRaw) {
+    match step {
+        StepRaw::SetState { accounts, .. } => {
+            for account in accounts.values_mut() {
+                account.storage = normalize_keys(std::mem::take(&mut account.storage));
+                account.storage.values_mut().for_each(normalize_value);
+            }
+        },
+        StepRaw::ScCall { tx, expect, .. } => {
+            tx.arguments.iter_mut().for_each(normalize_value);
+            normalize_expect(expect);
+        },
+        StepRaw::ScQuery { tx, expect, .. } => {
+            tx.arguments.iter_mut().for_each(normalize_value);
+            normalize_expect(expect);
+        },
+        StepRaw::ScDeploy { tx, expect, .. } => {
+            tx.arguments.iter_mut().for_each(normalize_value);
+            normalize_expect(expect);
+        },
+        StepRaw::CheckState { accounts, .. } => {
+            for account in accounts.accounts.values_mut() {
+                if let CheckStorageRaw::Equal(details) = &mut account.storage {
+                    details.storages = normalize_keys(std::mem::take(&mut details.storages));
+                    details
+                        .storages
+                        .values_mut()
+                        .for_each(normalize_check_value);
+                }
+            }
+        },
+        _ => {},
+    }
+}
+
+fn normalize_expect(expect: &mut Option<TxExpectRaw>) {
+    if let Some(expect) = expect {
+        normalize_check_list(&mut expect.out);
+        if let CheckLogsRaw::List(log_list) = &mut expect.logs {
+            for log in log_list.list.iter_mut() {
+                normalize_check_list(&mut log.topics);
+                normalize_check_value(&mut log.data);
+            }
+        }
+    }
+}
+
+/// A key is only renamed if no other key would end up with the same name.
+fn normalize_keys<V>(map: BTreeMap<String, V>) -> BTreeMap<String, V> {
+    let mut result = BTreeMap::new();
+    let mut renamed = Vec::new();
+    for (key, value) in map {
+        match normalize_expr(&key) {
+            Some(normalized_key) => renamed.push((key, normalized_key, value)),
+            None => {
+                result.insert(key, value);
+            },
+        }
+    }
+    for (key, normalized_key, value) in renamed {
+        if result.contains_key(&normalized_key) {
+            result.insert(key, value);
+        } else {
+            result.insert(normalized_key, value);
+        }
+    }
+    result
+}
+
+fn normalize_check_list(check_list: &mut CheckValueListRaw) {
+    if let CheckValueListRaw::CheckList(list) = check_list {
+        list.iter_mut().for_each(normalize_check_value);
+    }
+}
+
+fn normalize_check_value(check_value: &mut CheckBytesValueRaw) {
+    if let CheckBytesValueRaw::Equal(value) = check_value {
+        normalize_value(value);
+    }
+}
+
+fn normalize_value(value: &mut ValueSubTree) {
+    match value {
+        ValueSubTree::Str(expr) => {
+            if let Some(normalized) = normalize_expr(expr) {
+                *expr = normalized;
+            }
+        },
+        ValueSubTree::List(items) => items.iter_mut().for_each(normalize_value),
+        ValueSubTree::Map(map) => map.values_mut().for_each(normalize_value),
+    }
+}
+
+/// `0x` followed by the hex of printable ASCII becomes `str:`.
+///
+/// `|` is excluded, since it would split the value.
+fn normalize_expr(expr: &str) -> Option<String> {
+    let bytes = hex::decode(expr.strip_prefix("0x")?).ok()?;
+    if bytes.len() < MIN_PRINTABLE_LEN
+        || !bytes
+            .iter()
+            .all(|b| (0x20..=0x7e).contains(b) && *b != b'|')
+    {
+        return None;
+    }
+    Some(format!("str:{}", String::from_utf8(bytes).unwrap()))
+}
@@ -0,0 +1,61 @@
+mod fmt_lossless_check;
+mod fmt_normalize;
+
+use std::{fs, path::Path};
+
+use crate::serde_raw::ScenarioRaw;
+
+use fmt_lossless_check::check_nothing_lost;
+use fmt_normalize::normalize_scenario;
+
+/// File name suffixes of the JSON scenario files that get formatted.
+pub const SCENARIO_JSON_FILE_SUFFIXES: &[&str] = &[".scen.json", ".step.json", ".steps.json"];
+
+/// Formats the contents of a JSON scenario file, by round-tripping it through the raw model.
+///
+/// Comments and step order are kept, maps get sorted by key
+/// and printable hex values in storage, arguments, outputs and logs are converted to `str:`.
+///
+/// Refuses to format files containing fields unknown to the model, since they would get lost.
+pub fn format_scenario_json(contents: &str) -> Result<String, String> {
+    let original: serde_json::Value =
+        serde_json::from_str(contents).map_err(|err| format!("invalid JSON: {err}"))?;
+    let mut scenario: ScenarioRaw =
+        serde_json::from_str(contents).map_err(|err| format!("invalid scenario: {err}"))?;
+
+    let round_tripped = serde_json::to_value(&scenario).unwrap();
+    check_nothing_lost(&original, &round_tripped)?;
+
+    normalize_scenario(&mut scenario);
+    Ok(scenario.to_json_string())
+}
+
+/// Formats a JSON scenario file in place, unless in check mode.
+///
+/// Returns whether the file is (or was) not formatted.
+pub fn format_scenario_file<P: AsRef<Path>>(path: P, check_only: bool) -> Result<bool, String> {
+    let path = path.as_ref();
+    let contents =
+        fs::read_to_string(path).map_err(|err| format!("cannot read {}: {err}", path.display()))?;
+    let formatted = format_scenario_json(contents.as_str())?;
+    if formatted == contents {
+        return Ok(false);
+    }
+
+    if !check_only {
+        fs::write(path, formatted)
+            .map_err(|err| format!("cannot write {}: {err}", path.display()))?;
+    }
+    Ok(true)
+}
+
+pub fn is_scenario_json_file<P: AsRef<Path>>(path: P) -> bool {
+    path.as_ref()
+        .file_name()
+        .and_then(|file_name| file_name.to_str())
+        .map_or(false, |file_name| {
+            SCENARIO_JSON_FILE_SUFFIXES
+                .iter()
+                .any(|suffix| file_name.ends_with(suffix))
+        })
+}
@@ -1,9 +1,33 @@
-use std::{fs, io::Write, path::Path};
+use std::{ffi::OsStr, fs, io::Write, path::Path};
 
 use serde::{Deserialize, Serialize};
 
 use crate::serde_raw::StepRaw;
 
+/// The formats scenarios can be authored in. They all map onto the same raw model.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScenarioFileFormat {
+    Json,
+
+    /// JSON with comments, trailing commas, unquoted keys, etc.
+    Json5,
+
+    /// Numbers should be quoted when their exact representation matters, e.g. `"0x0001"`,
+    /// since unquoted YAML numbers get normalized to decimal.
+    Yaml,
+}
+
+impl ScenarioFileFormat {
+    /// Decided by the file extension, JSON if not recognized.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Self {
+        match path.as_ref().extension().and_then(OsStr::to_str) {
+            Some("json5") => ScenarioFileFormat::Json5,
+            Some("yaml") | Some("yml") => ScenarioFileFormat::Yaml,
+            _ => ScenarioFileFormat::Json,
+        }
+    }
+}
+
 /// Mapped 1-on-1 with the JSON. No complex logic here, just a basic interface with the JSON.
 /// The conversion to `Scenario` adds all additional functionality.
 #[derive(Serialize, Deserialize)]
@@ -25,6 +49,11 @@ pub struct ScenarioRaw {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub gas_schedule: Option<String>,
 
+    /// Path to the contract ABI, relative to the scenario file, needed for `abi:` expressions.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub abi: Option<String>,
+
     pub steps: Vec<StepRaw>,
 }
 
@@ -33,12 +62,29 @@ impl ScenarioRaw {
         serde_json::from_str(s).unwrap()
     }
 
+    pub fn from_json5_str(s: &str) -> Self {
+        json5::from_str(s).unwrap()
+    }
+
+    pub fn from_yaml_str(s: &str) -> Self {
+        serde_yaml::from_str(s).unwrap()
+    }
+
+    pub fn from_str_with_format(s: &str, format: ScenarioFileFormat) -> Self {
+        match format {
+            ScenarioFileFormat::Json => Self::from_json_str(s),
+            ScenarioFileFormat::Json5 => Self::from_json5_str(s),
+            ScenarioFileFormat::Yaml => Self::from_yaml_str(s),
+        }
+    }
+
+    /// The format is decided by the file extension.
     pub fn load_from_file<P>(path: P) -> Self
     where
         P: AsRef<Path>,
     {
-        let contents = fs::read_to_string(path).unwrap();
-        Self::from_json_str(contents.as_str())
+        let contents = fs::read_to_string(path.as_ref()).unwrap();
+        Self::from_str_with_format(contents.as_str(), ScenarioFileFormat::from_path(path))
     }
 
     pub fn to_json_string(&self) -> String {
@@ -51,14 +97,38 @@ impl ScenarioRaw {
         serialized
     }
 
+    pub fn to_yaml_string(&self) -> String {
+        serde_yaml::to_string(self).unwrap()
+    }
+
+    /// Comments in JSON5 sources are not part of the model, so JSON5 is written as plain JSON,
+    /// which is also valid JSON5.
+    pub fn to_string_with_format(&self, format: ScenarioFileFormat) -> String {
+        match format {
+            ScenarioFileFormat::Json | ScenarioFileFormat::Json5 => self.to_json_string(),
+            ScenarioFileFormat::Yaml => self.to_yaml_string(),
+        }
+    }
+
+    /// The format is decided by the file extension.
     pub fn save_to_file<P>(&self, path: P)
     where
         P: AsRef<Path>,
     {
-        let json_string = self.to_json_string();
+        let contents = self.to_string_with_format(ScenarioFileFormat::from_path(path.as_ref()));
         let path_parent = path.as_ref().parent().unwrap();
         fs::create_dir_all(path_parent).unwrap();
         let mut file = fs::File::create(path).unwrap();
-        file.write_all(json_string.as_bytes()).unwrap();
+        file.write_all(contents.as_bytes()).unwrap();
+    }
+
+    /// Converts a scenario file from one format to another, based on the file extensions,
+    /// e.g. from `.scen.json` to `.scen.yaml`.
+    pub fn convert_file<P, Q>(input_path: P, output_path: Q)
+    where
+        P: AsRef<Path>,
+        Q: AsRef<Path>,
+    {
+        Self::load_from_file(input_path).save_to_file(output_path);
     }
 }
@@ -16,7 +16,8 @@ impl Serialize for CheckStorageDetailsRaw {
     where
         S: Serializer,
     {
-        let mut map = serializer.serialize_map(Some(self.storages.len()))?;
+        let len = self.storages.len() + usize::from(self.other_storages_allowed);
+        let mut map = serializer.serialize_map(Some(len))?;
         for (k, v) in self.storages.iter() {
             map.serialize_entry(k, v)?;
         }
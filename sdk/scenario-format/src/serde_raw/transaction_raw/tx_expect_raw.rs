@@ -27,4 +27,8 @@ pub struct TxExpectRaw {
     #[serde(default)]
     #[serde(skip_serializing_if = "CheckBytesValueRaw::is_unspecified")]
     pub refund: CheckBytesValueRaw,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "CheckBytesValueRaw::is_unspecified")]
+    pub reentrant_calls: CheckBytesValueRaw,
 }
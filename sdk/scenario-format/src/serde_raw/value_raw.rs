@@ -97,6 +97,28 @@ impl<'de> Visitor<'de> for ValueSubTreeVisitor {
         Ok(ValueSubTree::Str(String::from(value)))
     }
 
+    /// Unquoted numbers and booleans are possible in YAML and JSON5.
+    fn visit_bool<E>(self, value: bool) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(ValueSubTree::Str(value.to_string()))
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(ValueSubTree::Str(value.to_string()))
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(ValueSubTree::Str(value.to_string()))
+    }
+
     fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
     where
         A: SeqAccess<'de>,
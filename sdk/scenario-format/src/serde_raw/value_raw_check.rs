@@ -62,6 +62,30 @@ impl<'de> Visitor<'de> for CheckBytesValueRawVisitor {
         }
     }
 
+    fn visit_bool<E>(self, value: bool) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        let vst = ValueSubTreeVisitor.visit_bool(value)?;
+        Ok(CheckBytesValueRaw::Equal(vst))
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        let vst = ValueSubTreeVisitor.visit_i64(value)?;
+        Ok(CheckBytesValueRaw::Equal(vst))
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        let vst = ValueSubTreeVisitor.visit_u64(value)?;
+        Ok(CheckBytesValueRaw::Equal(vst))
+    }
+
     fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
     where
         A: SeqAccess<'de>,
@@ -1,4 +1,8 @@
-use crate::{interpret_trait::InterpreterContext, serde_raw::ValueSubTree};
+use crate::{
+    abi_value::{interpret_abi_expression, ABI_PREFIX},
+    interpret_trait::InterpreterContext,
+    serde_raw::ValueSubTree,
+};
 
 use super::{file_loader::load_file, functions::*, parse_num::*, prefixes::*};
 
@@ -27,6 +31,11 @@ pub fn interpret_string(s: &str, context: &InterpreterContext) -> Vec<u8> {
         return Vec::new();
     }
 
+    // checked before splitting, since ABI literals can contain any value expression
+    if let Some(stripped) = s.strip_prefix(ABI_PREFIX) {
+        return interpret_abi_expression(stripped, context);
+    }
+
     // concatenate values of different formats
     let split_parts: Vec<_> = s.split('|').collect();
     if split_parts.len() > 1 {
@@ -0,0 +1,176 @@
+use multiversx_chain_scenario_format::{
+    abi_value::{abi_expression_type_name, AbiTypes},
+    interpret_trait::InterpreterContext,
+    value_interpreter::interpret_string,
+};
+
+const ABI_JSON: &str = r#"{
+    "name": "Test",
+    "endpoints": [],
+    "types": {
+        "Payment": {
+            "type": "struct",
+            "fields": [
+                { "name": "token_identifier", "type": "TokenIdentifier" },
+                { "name": "token_nonce", "type": "u64" },
+                { "name": "amount", "type": "BigUint" }
+            ]
+        },
+        "Status": {
+            "type": "enum",
+            "variants": [
+                { "name": "Inactive", "discriminant": 0 },
+                { "name": "Active", "discriminant": 1 }
+            ]
+        },
+        "Action": {
+            "type": "enum",
+            "variants": [
+                { "name": "Nothing", "discriminant": 0 },
+                {
+                    "name": "Delta",
+                    "discriminant": 1,
+                    "fields": [{ "name": "0", "type": "i32" }]
+                },
+                {
+                    "name": "Pay",
+                    "discriminant": 2,
+                    "fields": [
+                        { "name": "to", "type": "Address" },
+                        { "name": "payments", "type": "List<Payment>" }
+                    ]
+                }
+            ]
+        }
+    }
+}"#;
+
+fn abi_context() -> InterpreterContext {
+    InterpreterContext::default().with_abi_types(AbiTypes::from_abi_json_str(ABI_JSON))
+}
+
+#[test]
+fn test_abi_struct() {
+    let context = &abi_context();
+    let expected = interpret_string(
+        "nested:str:TOK-123456|u64:5|biguint:1000",
+        &InterpreterContext::default(),
+    );
+
+    assert_eq!(
+        expected,
+        interpret_string(
+            "abi:Payment{token_identifier: str:TOK-123456, token_nonce: 5, amount: 1000}",
+            context
+        )
+    );
+
+    // fields in order
+    assert_eq!(
+        expected,
+        interpret_string("abi:Payment[str:TOK-123456, 5, 1000]", context)
+    );
+}
+
+#[test]
+fn test_abi_enum() {
+    let context = &abi_context();
+
+    assert_eq!(
+        Vec::<u8>::new(),
+        interpret_string("abi:Status:Inactive", context)
+    );
+    assert_eq!(vec![1], interpret_string("abi:Status:Active", context));
+    assert_eq!(
+        vec![0],
+        interpret_string("abi:List<Status>[Inactive]", context)
+    );
+    assert_eq!(
+        vec![1, 0xff, 0xff, 0xff, 0xfe],
+        interpret_string("abi:Action:Delta[-2]", context)
+    );
+}
+
+#[test]
+fn test_abi_generics() {
+    let context = &abi_context();
+
+    assert_eq!(
+        Vec::<u8>::new(),
+        interpret_string("abi:Option<u32>:null", context)
+    );
+    assert_eq!(
+        vec![1, 0, 0, 0, 5],
+        interpret_string("abi:Option<u32>:5", context)
+    );
+    assert_eq!(
+        vec![0, 1, 0, 0, 0, 1, b'a'],
+        interpret_string("abi:tuple<u16,bytes>[1, str:a]", context)
+    );
+    assert_eq!(
+        vec![1, 2, 3],
+        interpret_string("abi:array3<u8>[1, 2, 3]", context)
+    );
+    assert_eq!(
+        vec![0, 0, 0, 1, b',', 0, 0, 0, 0],
+        interpret_string(r#"abi:List<bytes>["str:,", ""]"#, context)
+    );
+}
+
+#[test]
+fn test_abi_code_metadata() {
+    let context = &abi_context();
+
+    assert_eq!(
+        vec![0x05, 0x06],
+        interpret_string("abi:CodeMetadata:0x0506", context)
+    );
+    assert_eq!(
+        vec![1, 0x01, 0x00, 0, 0, 0, 1],
+        interpret_string("abi:tuple<Option<CodeMetadata>,u32>[0x0100, 1]", context)
+    );
+}
+
+#[test]
+fn test_abi_pretty_print_round_trip() {
+    let context = &abi_context();
+    let abi_types = context.abi_types.as_ref().unwrap();
+
+    let expressions = [
+        "abi:Payment{token_identifier: str:TOK-123456, token_nonce: 5, amount: 1000}",
+        "abi:Action:Nothing",
+        "abi:Action:Delta[-2]",
+        "abi:Action:Pay{to: 0x0000000000000000000000000000000000000000000000000000000000000001, payments: [{token_identifier: str:A-000000, token_nonce: 0, amount: 0}]}",
+        "abi:Option<Status>:null",
+        "abi:Option<Status>:Active",
+        "abi:CodeMetadata:0x0102",
+        r#"abi:List<bytes>["str:a, b", "", 0x00ff]"#,
+    ];
+
+    for expression in expressions {
+        let type_name = abi_expression_type_name(expression).unwrap();
+        let encoded = interpret_string(expression, context);
+        assert_eq!(expression, abi_types.pretty_print(type_name, &encoded));
+    }
+}
+
+#[test]
+fn test_abi_pretty_print_invalid() {
+    let abi_types = AbiTypes::from_abi_json_str(ABI_JSON);
+    assert_eq!("0x05", abi_types.pretty_print("Status", &[5]));
+}
+
+#[test]
+#[should_panic(expected = "Payment has no field named amout")]
+fn test_abi_unknown_field() {
+    interpret_string(
+        "abi:Payment{token_identifier: str:TOK-123456, token_nonce: 5, amout: 1000}",
+        &abi_context(),
+    );
+}
+
+#[test]
+#[should_panic(expected = "no contract ABI was provided")]
+fn test_abi_missing() {
+    interpret_string("abi:u32:5", &InterpreterContext::default());
+}
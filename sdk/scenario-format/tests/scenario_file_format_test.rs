@@ -0,0 +1,122 @@
+use multiversx_chain_scenario_format::serde_raw::{ScenarioFileFormat, ScenarioRaw};
+
+const SCENARIO_JSON: &str = r#"{
+    "comment": "simple EGLD transfer",
+    "steps": [
+        {
+            "step": "setState",
+            "accounts": {
+                "address:A": {
+                    "nonce": "0",
+                    "balance": "150"
+                }
+            }
+        },
+        {
+            "step": "transfer",
+            "id": "1",
+            "tx": {
+                "from": "address:A",
+                "to": "address:B",
+                "egldValue": "100"
+            }
+        }
+    ]
+}
+"#;
+
+const SCENARIO_JSON5: &str = r#"
+// comments are allowed
+{
+    comment: "simple EGLD transfer",
+    steps: [
+        {
+            step: "setState",
+            accounts: {
+                "address:A": {
+                    nonce: "0",
+                    balance: "150", // trailing commas too
+                },
+            },
+        },
+        {
+            step: "transfer",
+            id: "1",
+            tx: {
+                from: "address:A",
+                to: "address:B",
+                egldValue: "100",
+            },
+        },
+    ],
+}
+"#;
+
+const SCENARIO_YAML: &str = r#"
+# comments are allowed
+comment: simple EGLD transfer
+steps:
+  - step: setState
+    accounts:
+      address:A:
+        nonce: 0
+        balance: 150
+  - step: transfer
+    id: "1"
+    tx:
+      from: address:A
+      to: address:B
+      egldValue: 100
+"#;
+
+#[test]
+fn test_format_from_path() {
+    assert_eq!(
+        ScenarioFileFormat::Json,
+        ScenarioFileFormat::from_path("a/b.scen.json")
+    );
+    assert_eq!(
+        ScenarioFileFormat::Json5,
+        ScenarioFileFormat::from_path("a/b.scen.json5")
+    );
+    assert_eq!(
+        ScenarioFileFormat::Yaml,
+        ScenarioFileFormat::from_path("a/b.scen.yaml")
+    );
+    assert_eq!(
+        ScenarioFileFormat::Yaml,
+        ScenarioFileFormat::from_path("a/b.steps.yml")
+    );
+}
+
+#[test]
+fn test_json5_to_json() {
+    let scenario = ScenarioRaw::from_json5_str(SCENARIO_JSON5);
+    assert_eq!(SCENARIO_JSON, scenario.to_json_string());
+}
+
+#[test]
+fn test_yaml_to_json() {
+    let scenario = ScenarioRaw::from_yaml_str(SCENARIO_YAML);
+    assert_eq!(SCENARIO_JSON, scenario.to_json_string());
+}
+
+#[test]
+fn test_convert_file() {
+    let dir = std::env::temp_dir().join("scenario-format-convert-test");
+    let json_path = dir.join("transfer.scen.json");
+    let yaml_path = dir.join("transfer.scen.yaml");
+
+    ScenarioRaw::from_json_str(SCENARIO_JSON).save_to_file(&json_path);
+    ScenarioRaw::convert_file(&json_path, &yaml_path);
+    let yaml = std::fs::read_to_string(&yaml_path).unwrap();
+    assert_eq!(
+        SCENARIO_JSON,
+        ScenarioRaw::from_yaml_str(&yaml).to_json_string()
+    );
+
+    ScenarioRaw::convert_file(&yaml_path, &json_path);
+    assert_eq!(SCENARIO_JSON, std::fs::read_to_string(&json_path).unwrap());
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,115 @@
+use multiversx_chain_scenario_format::scenario_fmt::{format_scenario_file, format_scenario_json};
+
+const UNFORMATTED: &str = r#"{
+  "name": "fmt",
+  "comment": "steps keep their order",
+  "steps": [
+    { "step": "setState",
+      "accounts": {
+        "address:owner": { "nonce": "0", "balance": "0" },
+        "address:a": { "nonce": "0", "balance": "0",
+          "storage": { "0x68656c6c6f": "0x776f726c64", "0x0102": "0x010203" } }
+      }
+    },
+    { "step": "scCall", "id": "1",
+      "tx": { "from": "address:owner", "to": "address:a", "function": "f",
+              "arguments": ["0x616263", "0x05"], "gasLimit": "5,000,000", "gasPrice": "0" },
+      "expect": { "out": ["0x6f6b"], "status": "0" }
+    },
+    { "step": "checkState",
+      "accounts": { "address:a": { "storage": { "+": "" }, "code": "*" }, "+": "" }
+    }
+  ]
+}"#;
+
+const FORMATTED: &str = r#"{
+    "name": "fmt",
+    "comment": "steps keep their order",
+    "steps": [
+        {
+            "step": "setState",
+            "accounts": {
+                "address:a": {
+                    "nonce": "0",
+                    "balance": "0",
+                    "storage": {
+                        "0x0102": "0x010203",
+                        "str:hello": "str:world"
+                    }
+                },
+                "address:owner": {
+                    "nonce": "0",
+                    "balance": "0"
+                }
+            }
+        },
+        {
+            "step": "scCall",
+            "id": "1",
+            "tx": {
+                "from": "address:owner",
+                "to": "address:a",
+                "function": "f",
+                "arguments": [
+                    "str:abc",
+                    "0x05"
+                ],
+                "gasLimit": "5,000,000",
+                "gasPrice": "0"
+            },
+            "expect": {
+                "out": [
+                    "0x6f6b"
+                ],
+                "status": "0"
+            }
+        },
+        {
+            "step": "checkState",
+            "accounts": {
+                "address:a": {
+                    "storage": {
+                        "+": ""
+                    },
+                    "code": "*"
+                },
+                "+": ""
+            }
+        }
+    ]
+}
+"#;
+
+#[test]
+fn test_format_scenario() {
+    assert_eq!(FORMATTED, format_scenario_json(UNFORMATTED).unwrap());
+}
+
+#[test]
+fn test_format_idempotent() {
+    assert_eq!(FORMATTED, format_scenario_json(FORMATTED).unwrap());
+}
+
+#[test]
+fn test_format_unknown_field() {
+    let err = format_scenario_json(r#"{ "steps": [], "traceGas": true }"#).unwrap_err();
+    assert!(err.contains("/traceGas"), "{err}");
+}
+
+#[test]
+fn test_format_file_check() {
+    let dir = std::env::temp_dir().join("scenario-format-fmt-test");
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("fmt.scen.json");
+    std::fs::write(&path, UNFORMATTED).unwrap();
+
+    // check mode leaves the file alone
+    assert!(format_scenario_file(&path, true).unwrap());
+    assert_eq!(UNFORMATTED, std::fs::read_to_string(&path).unwrap());
+
+    assert!(format_scenario_file(&path, false).unwrap());
+    assert_eq!(FORMATTED, std::fs::read_to_string(&path).unwrap());
+    assert!(!format_scenario_file(&path, true).unwrap());
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
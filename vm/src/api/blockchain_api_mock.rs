@@ -1,5 +1,6 @@
 use crate::{
     num_bigint,
+    tx_mock::EsdtGlobalSetting,
     world_mock::{is_smart_contract_address, EsdtData, EsdtInstance},
     DebugApi,
 };
@@ -225,8 +226,12 @@ impl BlockchainApiImpl for DebugApi {
         false
     }
 
-    fn check_esdt_limited_transfer(&self, _token_id_handle: Self::ManagedBufferHandle) -> bool {
-        false
+    fn check_esdt_limited_transfer(&self, token_id_handle: Self::ManagedBufferHandle) -> bool {
+        let token_identifier_value = self.mb_to_boxed_bytes(token_id_handle);
+        self.blockchain_cache().is_esdt_global_setting_set(
+            token_identifier_value.as_slice(),
+            EsdtGlobalSetting::LimitedTransfer,
+        )
     }
 
     fn load_esdt_local_roles(
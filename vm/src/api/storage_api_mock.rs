@@ -1,6 +1,7 @@
 use crate::{
     num_bigint::{BigInt, Sign},
     tx_mock::TxPanic,
+    world_mock::is_protected_storage_key,
     DebugApi,
 };
 use alloc::vec::Vec;
@@ -19,10 +20,7 @@ impl StorageReadApi for DebugApi {
 
 impl DebugApi {
     fn storage_load_vec_u8(&self, key: &[u8]) -> Vec<u8> {
-        self.with_contract_account(|account| match account.storage.get(&key.to_vec()) {
-            None => Vec::with_capacity(0),
-            Some(value) => value.clone(),
-        })
+        self.with_contract_account(|account| account.read_storage(key))
     }
 }
 
@@ -62,10 +60,7 @@ impl StorageReadApiImpl for DebugApi {
         );
         let key_bytes = self.mb_to_boxed_bytes(key_handle);
         self.with_account(&address, |account| {
-            match account.storage.get(key_bytes.as_slice()) {
-                None => self.mb_overwrite(dest, &[]),
-                Some(value) => self.mb_overwrite(dest, value.as_slice()),
-            }
+            self.mb_overwrite(dest, account.read_storage(key_bytes.as_slice()).as_slice());
         })
     }
 }
@@ -80,8 +75,7 @@ impl StorageWriteApi for DebugApi {
 
 impl StorageWriteApiImpl for DebugApi {
     fn storage_store_slice_u8(&self, key: &[u8], value: &[u8]) {
-        // TODO: extract magic strings somewhere
-        if key.starts_with(&b"ELROND"[..]) {
+        if is_protected_storage_key(key) {
             std::panic::panic_any(TxPanic {
                 status: 10,
                 message: "cannot write to storage under Elrond reserved key".to_string(),
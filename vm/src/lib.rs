@@ -8,6 +8,8 @@ mod managed_test_util;
 pub mod scenario;
 pub mod tx_execution;
 pub mod tx_mock;
+#[cfg(feature = "wasm-engine")]
+pub mod wasm_engine;
 pub mod world_mock;
 
 pub use crate::scenario::executor::*;
@@ -1,14 +1,17 @@
 use crate::scenario::model::{
-    AddressKey, BytesValue, CheckAccounts, CheckEsdt, CheckEsdtData, CheckEsdtInstance,
-    CheckEsdtInstances, CheckEsdtMap, CheckStateStep, CheckStorage, CheckValue, Checkable, Step,
+    AddressKey, BytesValue, CheckAccount, CheckAccounts, CheckEsdt, CheckEsdtData,
+    CheckEsdtInstance, CheckEsdtInstances, CheckEsdtMap, CheckStateStep, CheckStorage, CheckValue,
+    Checkable, Step,
 };
 use num_traits::Zero;
 
 use crate::{
-    bytes_to_string, verbose_hex, verbose_hex_list,
-    world_mock::{AccountEsdt, BlockchainMock, EsdtData, EsdtInstance, EsdtInstances},
+    bytes_to_string,
+    world_mock::{AccountData, AccountEsdt, BlockchainMock, EsdtData, EsdtInstance, EsdtInstances},
 };
 
+use super::check_state_report::CheckStateReport;
+
 impl BlockchainMock {
     pub fn perform_check_state(&mut self, check_state_step: CheckStateStep) -> &mut Self {
         execute(self, &check_state_step.accounts);
@@ -24,142 +27,154 @@ impl BlockchainMock {
     }
 }
 
+/// Checks all accounts before failing, so that all differences get reported together.
 fn execute(state: &BlockchainMock, accounts: &CheckAccounts) {
+    let mut report = CheckStateReport::new(&state.addr_to_pretty_string_map);
     for (expected_address, expected_account) in accounts.accounts.iter() {
-        if let Some(account) = state.accounts.get(&expected_address.value) {
-            assert!(
-                expected_account.nonce.check(account.nonce),
-                "bad account nonce. Address: {}. Want: {}. Have: {}",
-                expected_address,
-                expected_account.nonce,
-                account.nonce
-            );
+        if let Some(account) = state.get_account(&expected_address.value) {
+            check_account(&mut report, expected_address, expected_account, account);
+        } else if !accounts.other_accounts_allowed {
+            report.add_mismatch(expected_address, "account", &"present", "not found");
+        }
+    }
+    report.assert_no_mismatches();
+}
+
+fn check_account(
+    report: &mut CheckStateReport,
+    address: &AddressKey,
+    expected_account: &CheckAccount,
+    account: &AccountData,
+) {
+    if !expected_account.nonce.check(account.nonce) {
+        report.add_mismatch(
+            address,
+            "nonce",
+            &expected_account.nonce,
+            account.nonce.to_string(),
+        );
+    }
 
-            assert!(
-                expected_account.balance.check(&account.egld_balance),
-                "bad account balance. Address: {}. Want: {}. Have: {}",
-                expected_address,
-                expected_account.balance,
-                account.egld_balance
-            );
+    if !expected_account.balance.check(&account.egld_balance) {
+        report.add_mismatch(
+            address,
+            "balance",
+            &expected_account.balance,
+            account.egld_balance.to_string(),
+        );
+    }
 
-            assert!(
-                expected_account.username.check(&account.username),
-                "bad account username. Address: {}. Want: {}. Have: {}",
-                expected_address,
-                expected_account.username,
-                std::str::from_utf8(account.username.as_slice()).unwrap()
-            );
-            let default_value = &Vec::new();
-            let actual_code = account.contract_path.as_ref().unwrap_or(default_value);
-            assert!(
-                expected_account.code.check(actual_code),
-                "bad account code. Address: {}. Want: {}. Have: {}",
-                expected_address,
-                expected_account.code,
-                std::str::from_utf8(actual_code.as_slice()).unwrap()
-            );
+    if !expected_account.username.check(&account.username) {
+        report.add_mismatch(
+            address,
+            "username",
+            &expected_account.username,
+            report.pretty_value(&account.username),
+        );
+    }
 
-            assert!(
-                expected_account
-                    .developer_rewards
-                    .check(&account.developer_rewards),
-                "bad account developerRewards. Address: {}. Want: {}. Have: {}",
-                expected_address,
-                expected_account.developer_rewards,
-                account.developer_rewards
-            );
+    let default_value = &Vec::new();
+    let actual_code = account.contract_path.as_ref().unwrap_or(default_value);
+    if !expected_account.code.check(actual_code) {
+        report.add_mismatch(
+            address,
+            "code",
+            &expected_account.code,
+            report.pretty_code(actual_code),
+        );
+    }
 
-            if let CheckStorage::Equal(eq) = &expected_account.storage {
-                let default_value = &Vec::new();
-                for (expected_key, expected_value) in eq.storages.iter() {
-                    let actual_value = account
-                        .storage
-                        .get(&expected_key.value)
-                        .unwrap_or(default_value);
-                    assert!(
-                        expected_value.check(actual_value),
-                        "bad storage value. Address: {}. Key: {}. Want: {}. Have: {}",
-                        expected_address,
-                        expected_key,
-                        expected_value,
-                        verbose_hex(actual_value)
-                    );
-                }
+    if !expected_account
+        .developer_rewards
+        .check(&account.developer_rewards)
+    {
+        report.add_mismatch(
+            address,
+            "developerRewards",
+            &expected_account.developer_rewards,
+            account.developer_rewards.to_string(),
+        );
+    }
 
-                let default_check_value = CheckValue::Equal(BytesValue::empty());
-                for (actual_key, actual_value) in account.storage.iter() {
-                    let expected_value = eq
-                        .storages
-                        .get(&actual_key.clone().into())
-                        .unwrap_or(&default_check_value);
-                    if expected_value.to_string() == default_check_value.to_string()
-                        && !eq.other_storages_allowed
-                    {
-                        assert!(
-                            expected_value.check(actual_value),
-                            "bad storage value. Address: {}. Key: {}. Want: {}. Have: {}",
-                            expected_address,
-                            verbose_hex(actual_key),
-                            expected_value,
-                            verbose_hex(actual_value)
-                        );
-                    }
-                }
+    if let CheckStorage::Equal(eq) = &expected_account.storage {
+        let default_value = &Vec::new();
+        for (expected_key, expected_value) in eq.storages.iter() {
+            let actual_value = account
+                .storage
+                .get(&expected_key.value)
+                .unwrap_or(default_value);
+            if !expected_value.check(actual_value) {
+                report.add_mismatch(
+                    address,
+                    format!("storage {expected_key}"),
+                    expected_value,
+                    report.pretty_value(actual_value),
+                );
+            }
+        }
+
+        let default_check_value = CheckValue::Equal(BytesValue::empty());
+        let mut actual_keys: Vec<&Vec<u8>> = account.storage.keys().collect();
+        actual_keys.sort();
+        for actual_key in actual_keys {
+            let actual_value = &account.storage[actual_key];
+            let expected_value = eq
+                .storages
+                .get(&actual_key.clone().into())
+                .unwrap_or(&default_check_value);
+            if expected_value.to_string() == default_check_value.to_string()
+                && !eq.other_storages_allowed
+                && !expected_value.check(actual_value)
+            {
+                report.add_mismatch(
+                    address,
+                    format!("storage {}", report.pretty_key(actual_key)),
+                    &"\"\"",
+                    report.pretty_value(actual_value),
+                );
             }
-            check_account_esdt(expected_address, &expected_account.esdt, &account.esdt);
-        } else {
-            assert!(
-                accounts.other_accounts_allowed,
-                "Expected account not found"
-            );
         }
     }
+
+    check_account_esdt(report, address, &expected_account.esdt, &account.esdt);
 }
 
-pub fn check_account_esdt(address: &AddressKey, expected: &CheckEsdtMap, actual: &AccountEsdt) {
+fn check_account_esdt(
+    report: &mut CheckStateReport,
+    address: &AddressKey,
+    expected: &CheckEsdtMap,
+    actual: &AccountEsdt,
+) {
     match expected {
         CheckEsdtMap::Star => {},
         CheckEsdtMap::Equal(contents) => {
             for (key, expected_value) in contents.contents.iter() {
+                let token = bytes_to_string(key.value.as_slice());
                 let actual_value = actual.get_by_identifier_or_default(key.value.as_slice());
                 match expected_value {
                     CheckEsdt::Short(expected_balance) => {
-                        if expected_balance.value.is_zero() {
-                            assert!(
-                                actual_value.is_empty(),
-                                "No balance expected for ESDT token address: {}. token name: {}. nonce: {}.",
-                                address,
-                                bytes_to_string(key.value.as_slice()),
-                                0
-                            );
+                        let is_expected_fungible_balance = if expected_balance.value.is_zero() {
+                            actual_value.is_empty()
                         } else {
-                            assert!(
-                                actual_value.instances.len() == 1,
-                                "One ESDT instance expected, with nonce 0 for address: {}. token name: {}.",
-                                address,
-                                bytes_to_string(key.value.as_slice()),
-                            );
-                            let single_instance = actual_value
-                                .instances
-                                .get_by_nonce(0)
-                                .unwrap_or_else(|| panic!("Expected fungible ESDT with none 0"));
-                            assert_eq!(
-                                single_instance.balance,
-                                expected_balance.value,
-                                "Unexpected fungible token balance for address: {}. token name: {}.",
+                            actual_value.instances.len() == 1
+                                && actual_value
+                                    .instances
+                                    .get_by_nonce(0)
+                                    .map_or(false, |instance| {
+                                        instance.balance == expected_balance.value
+                                    })
+                        };
+                        if !is_expected_fungible_balance {
+                            report.add_mismatch(
                                 address,
-                                bytes_to_string(key.value.as_slice()),
+                                format!("esdt {token}"),
+                                expected_balance,
+                                report.esdt_summary(&actual_value),
                             );
                         }
                     },
                     CheckEsdt::Full(expected_esdt) => {
-                        check_esdt_data(
-                            address,
-                            bytes_to_string(key.value.as_slice()),
-                            expected_esdt,
-                            &actual_value,
-                        );
+                        check_esdt_data(report, address, &token, expected_esdt, &actual_value);
                     },
                 }
             }
@@ -170,8 +185,9 @@ pub fn check_account_esdt(address: &AddressKey, expected: &CheckEsdtMap, actual:
                         continue;
                     }
                     check_esdt_data(
+                        report,
                         address,
-                        bytes_to_string(token_identifier),
+                        &bytes_to_string(token_identifier),
                         &CheckEsdtData::default(),
                         actual_value,
                     );
@@ -181,8 +197,9 @@ pub fn check_account_esdt(address: &AddressKey, expected: &CheckEsdtMap, actual:
         CheckEsdtMap::Unspecified => {
             for (token_identifier, actual_value) in actual.iter() {
                 check_esdt_data(
+                    report,
                     address,
-                    bytes_to_string(token_identifier),
+                    &bytes_to_string(token_identifier),
                     &CheckEsdtData::default(),
                     actual_value,
                 );
@@ -191,61 +208,63 @@ pub fn check_account_esdt(address: &AddressKey, expected: &CheckEsdtMap, actual:
     }
 }
 
-pub fn check_esdt_data(
+fn check_esdt_data(
+    report: &mut CheckStateReport,
     address: &AddressKey,
-    token: String,
+    token: &str,
     expected: &CheckEsdtData,
     actual: &EsdtData,
 ) {
-    let mut errors: Vec<String> = vec!["".to_string()];
     check_token_instances(
+        report,
         address,
-        token.clone(),
+        token,
         &expected.instances,
         &actual.instances,
-        &mut errors,
     );
+
     if !expected.last_nonce.check(actual.last_nonce) {
-        errors.push(format!(
-            "bad last nonce. Address: {}. Token Name: {}. Want: {}. Have: {}\n",
-            address, token, expected.last_nonce, &actual.last_nonce
-        ));
+        report.add_mismatch(
+            address,
+            format!("esdt {token} lastNonce"),
+            &expected.last_nonce,
+            actual.last_nonce.to_string(),
+        );
     }
 
     if !expected.frozen.check(u64::from(actual.frozen)) {
-        errors.push(format!(
-            "bad last nonce. Address: {}. Token Name: {}. Want: {}. Have: {}\n",
-            address, token, expected.frozen, &actual.frozen
-        ));
+        report.add_mismatch(
+            address,
+            format!("esdt {token} frozen"),
+            &expected.frozen,
+            actual.frozen.to_string(),
+        );
     }
-
-    errors.push("".to_string());
-    assert!(errors.len() == 2, "{}", errors.join("\n"));
 }
 
-pub fn check_token_instances(
+fn check_token_instances(
+    report: &mut CheckStateReport,
     address: &AddressKey,
-    token: String,
+    token: &str,
     expected: &CheckEsdtInstances,
     actual: &EsdtInstances,
-    errors: &mut Vec<String>,
 ) {
     match expected {
         CheckEsdtInstances::Equal(eq) => {
             for expected_value in eq.iter() {
                 let actual_value = actual.get_by_nonce_or_default(expected_value.nonce.value);
-                check_token_instance(address, &token, expected_value, &actual_value, errors);
+                check_token_instance(report, address, token, expected_value, &actual_value);
             }
 
             let default_expected_value = CheckEsdtInstance::default();
             for (actual_key, actual_value) in actual.get_instances().iter() {
                 if !expected.contains_nonce(*actual_key) {
                     check_token_instance(
+                        report,
                         address,
-                        &token,
+                        token,
                         &default_expected_value,
                         actual_value,
-                        errors,
                     );
                 }
             }
@@ -256,93 +275,77 @@ pub fn check_token_instances(
     }
 }
 
-pub fn check_token_instance(
+fn check_token_instance(
+    report: &mut CheckStateReport,
     address: &AddressKey,
     token: &str,
     expected_value: &CheckEsdtInstance,
     actual_value: &EsdtInstance,
-    errors: &mut Vec<String>,
 ) {
-    if !expected_value.balance.check(&actual_value.balance) {
-        errors.push(format!(
-            "bad esdt balance. Address: {}. Token {}. Nonce {}. Want: {}. Have: {}",
-            address,
-            token,
-            expected_value.nonce.value,
-            expected_value.balance,
-            &actual_value.balance,
-        ))
-    }
+    let field_prefix = format!("esdt {token} nonce {}", actual_value.nonce);
 
     if !expected_value.balance.check(&actual_value.balance) {
-        errors.push(format!(
-            "bad esdt balance. Address: {}. Token {}. Nonce {}. Want: {}. Have: {}",
+        report.add_mismatch(
             address,
-            token,
-            expected_value.nonce.value,
-            expected_value.balance,
-            &actual_value.balance,
-        ))
+            format!("{field_prefix} balance"),
+            &expected_value.balance,
+            actual_value.balance.to_string(),
+        );
     }
+
     let actual_creator = if let Some(creator) = &actual_value.metadata.creator {
         creator.as_ref()
     } else {
         &[]
     };
     if !expected_value.creator.check(actual_creator) {
-        errors.push(format!(
-            "bad esdt creator. Address: {}. Token {}. Nonce {}. Want: {}. Have: {}",
+        report.add_mismatch(
             address,
-            token,
-            expected_value.nonce.value,
-            expected_value.creator,
-            verbose_hex(actual_creator),
-        ))
+            format!("{field_prefix} creator"),
+            &expected_value.creator,
+            report.pretty_value(actual_creator),
+        );
     }
 
     let actual_royalties = actual_value.metadata.royalties;
     if !expected_value.royalties.check(actual_royalties) {
-        errors.push(format!(
-            "bad esdt royalties. Address: {}. Token {}. Nonce {}. Want: {}. Have: {}",
-            address, token, expected_value.nonce.value, expected_value.royalties, actual_royalties
-        ))
+        report.add_mismatch(
+            address,
+            format!("{field_prefix} royalties"),
+            &expected_value.royalties,
+            actual_royalties.to_string(),
+        );
     }
 
     let actual_hash = actual_value.metadata.hash.clone().unwrap_or_default();
     if !expected_value.hash.check(&actual_hash) {
-        errors.push(format!(
-            "bad esdt hash. Address: {}. Token {}. Nonce {}. Want: {}. Have: {}",
+        report.add_mismatch(
             address,
-            token,
-            expected_value.nonce.value,
-            expected_value.hash,
-            verbose_hex(&actual_hash),
-        ))
+            format!("{field_prefix} hash"),
+            &expected_value.hash,
+            report.pretty_value(&actual_hash),
+        );
     }
 
     let actual_uri = actual_value.metadata.uri.as_slice();
     if !expected_value.uri.check(actual_uri) {
-        errors.push(format!(
-            "bad esdt uri. Address: {}. Token {}. Nonce {}. Want: {}. Have: {}",
+        report.add_mismatch(
             address,
-            token,
-            expected_value.nonce.value,
-            expected_value.uri.pretty_str(),
-            verbose_hex_list(actual_uri),
-        ))
+            format!("{field_prefix} uri"),
+            &expected_value.uri.pretty_str(),
+            report.pretty_value_list(actual_uri),
+        );
     }
 
     if !expected_value
         .attributes
         .check(&actual_value.metadata.attributes)
     {
-        errors.push(format!(
-            "bad esdt attributes. Address: {}. Token {}. Nonce {}. Want: {}. Have: {}",
+        report.add_mismatch(
             address,
-            token,
-            expected_value.nonce.value,
-            expected_value.attributes,
-            verbose_hex(&actual_value.metadata.attributes),
-        ))
+            format!("{field_prefix} attributes"),
+            &expected_value.attributes,
+            report.pretty_value(&actual_value.metadata.attributes),
+        );
     }
 }
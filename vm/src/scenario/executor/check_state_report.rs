@@ -0,0 +1,212 @@
+use std::{collections::HashMap, fmt::Display};
+
+use multiversx_sc::types::heap::Address;
+
+use crate::{num_bigint::BigUint, verbose_hex, world_mock::EsdtData};
+
+/// Values up to this length that are not readable strings are shown as numbers.
+const MAX_NUMBER_BYTES_LEN: usize = 8;
+
+/// Shorter values are more likely to be numbers, even if all their bytes are printable.
+const MIN_READABLE_STR_LEN: usize = 3;
+
+/// Longer code is only summarized by its length, since it would not fit in the report.
+const MAX_CODE_DISPLAY_LEN: usize = 64;
+
+const COLUMN_SEPARATOR: &str = " | ";
+
+/// Collects all differences between the expected and the actual state of a `checkState` step,
+/// so that they can all be reported at once, grouped by account.
+pub(super) struct CheckStateReport<'a> {
+    addr_to_pretty_string_map: &'a HashMap<Address, String>,
+    mismatches: Vec<Mismatch>,
+}
+
+struct Mismatch {
+    account: String,
+    field: String,
+    want: String,
+    have: String,
+}
+
+impl<'a> CheckStateReport<'a> {
+    pub fn new(addr_to_pretty_string_map: &'a HashMap<Address, String>) -> Self {
+        CheckStateReport {
+            addr_to_pretty_string_map,
+            mismatches: Vec::new(),
+        }
+    }
+
+    pub fn add_mismatch(
+        &mut self,
+        account: &impl Display,
+        field: impl Into<String>,
+        want: &impl Display,
+        have: impl Into<String>,
+    ) {
+        self.mismatches.push(Mismatch {
+            account: account.to_string(),
+            field: field.into(),
+            want: unquote(want.to_string()),
+            have: have.into(),
+        });
+    }
+
+    /// Displays a value from the actual state in the scenario syntax, as closely as possible.
+    ///
+    /// Known addresses are shown by their scenario names, readable text as `str:`, short values as numbers.
+    pub fn pretty_value(&self, value: &[u8]) -> String {
+        if value.is_empty() {
+            return "\"\"".to_string();
+        }
+        if let Some(pretty_address) = self.pretty_address(value) {
+            return pretty_address;
+        }
+        if let Some(text) = readable_str(value) {
+            return format!("str:{text}");
+        }
+        if value.len() <= MAX_NUMBER_BYTES_LEN {
+            return BigUint::from_bytes_be(value).to_string();
+        }
+        verbose_hex(value)
+    }
+
+    /// Storage keys are usually a readable name, followed by some encoded arguments.
+    /// The two parts are displayed separately, concatenated with `|`, as in the scenario syntax.
+    pub fn pretty_key(&self, key: &[u8]) -> String {
+        let name_len = key
+            .iter()
+            .position(|&c| !is_readable_char(c))
+            .unwrap_or(key.len());
+        if name_len == 0 {
+            return self.pretty_value(key);
+        }
+        if name_len == key.len() {
+            return format!("str:{}", String::from_utf8_lossy(key));
+        }
+
+        let (name, arguments) = key.split_at(name_len);
+        format!(
+            "str:{}|{}",
+            String::from_utf8_lossy(name),
+            self.pretty_value(arguments)
+        )
+    }
+
+    pub fn pretty_code(&self, code: &[u8]) -> String {
+        if code.is_empty() {
+            return "\"\"".to_string();
+        }
+        match readable_str(code) {
+            Some(text) if code.len() <= MAX_CODE_DISPLAY_LEN => text.to_string(),
+            _ => format!("<{} bytes>", code.len()),
+        }
+    }
+
+    pub fn pretty_value_list(&self, values: &[Vec<u8>]) -> String {
+        let pretty_values: Vec<String> = values.iter().map(|v| self.pretty_value(v)).collect();
+        format!("[{}]", pretty_values.join(", "))
+    }
+
+    pub fn esdt_summary(&self, esdt_data: &EsdtData) -> String {
+        let mut parts: Vec<String> = esdt_data
+            .instances
+            .get_instances()
+            .values()
+            .map(|instance| format!("nonce {}: {}", instance.nonce, instance.balance))
+            .collect();
+        if esdt_data.last_nonce != 0 {
+            parts.push(format!("lastNonce: {}", esdt_data.last_nonce));
+        }
+        let roles = esdt_data.get_roles();
+        if !roles.is_empty() {
+            parts.push(format!("roles: {}", self.pretty_value_list(&roles)));
+        }
+        if esdt_data.frozen {
+            parts.push("frozen".to_string());
+        }
+        if parts.is_empty() {
+            "empty".to_string()
+        } else {
+            parts.join(", ")
+        }
+    }
+
+    fn pretty_address(&self, value: &[u8]) -> Option<String> {
+        if value.len() != Address::len_bytes() {
+            return None;
+        }
+        self.addr_to_pretty_string_map
+            .get(&Address::from_slice(value))
+            .cloned()
+    }
+
+    /// Panics with the full report, if there were any differences.
+    pub fn assert_no_mismatches(self) {
+        if self.mismatches.is_empty() {
+            return;
+        }
+
+        let field_width = column_width("field", self.mismatches.iter().map(|m| &m.field));
+        let want_width = column_width("want", self.mismatches.iter().map(|m| &m.want));
+
+        let mut accounts: Vec<&str> = Vec::new();
+        for mismatch in &self.mismatches {
+            if !accounts.contains(&mismatch.account.as_str()) {
+                accounts.push(mismatch.account.as_str());
+            }
+        }
+
+        let mut report = format!(
+            "checkState failed: {} mismatch(es) in {} account(s)\n",
+            self.mismatches.len(),
+            accounts.len()
+        );
+        for account in accounts {
+            report.push_str(&format!(
+                "\n  {account}\n    {:field_width$}{COLUMN_SEPARATOR}{:want_width$}{COLUMN_SEPARATOR}have\n",
+                "field", "want",
+            ));
+            for mismatch in self.mismatches.iter().filter(|m| m.account == account) {
+                report.push_str(&format!(
+                    "    {:field_width$}{COLUMN_SEPARATOR}{:want_width$}{COLUMN_SEPARATOR}{}\n",
+                    mismatch.field, mismatch.want, mismatch.have,
+                ));
+            }
+        }
+
+        panic!("{report}");
+    }
+}
+
+/// Expected values are displayed as they appear in the scenario, but without the JSON quotes,
+/// to match the actual values next to them.
+fn unquote(value: String) -> String {
+    match value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+        Some(unquoted) if !unquoted.is_empty() && !unquoted.contains('"') => unquoted.to_string(),
+        _ => value,
+    }
+}
+
+fn column_width<'b>(header: &str, values: impl Iterator<Item = &'b String>) -> usize {
+    values
+        .map(|value| value.chars().count())
+        .chain(std::iter::once(header.len()))
+        .max()
+        .unwrap_or_default()
+}
+
+fn is_readable_char(c: u8) -> bool {
+    c.is_ascii_graphic() || c == b' '
+}
+
+fn readable_str(value: &[u8]) -> Option<&str> {
+    if value.len() >= MIN_READABLE_STR_LEN
+        && value.iter().all(|&c| is_readable_char(c))
+        && value.iter().any(u8::is_ascii_alphabetic)
+    {
+        std::str::from_utf8(value).ok()
+    } else {
+        None
+    }
+}
@@ -0,0 +1,44 @@
+use std::path::Path;
+
+use crate::{scenario::model::Step, world_mock::BlockchainMock};
+
+use super::set_state::{convert_scenario_account_to_world_mock, update_block_info};
+
+impl BlockchainMock {
+    /// Uses an account snapshot, typically exported from a real network, as the base of the state.
+    ///
+    /// The snapshot is a scenario file, containing only `setState` steps.
+    /// Its accounts are only brought into the state when first used,
+    /// while its block info and new addresses are applied right away.
+    pub fn load_fork_snapshot<P: AsRef<Path>>(&mut self, snapshot_path: P) -> &mut Self {
+        let snapshot = crate::scenario::parse_scenario(snapshot_path.as_ref());
+        for step in snapshot.steps.iter() {
+            let set_state_step = if let Step::SetState(set_state_step) = step {
+                set_state_step
+            } else {
+                panic!(
+                    "fork snapshot {} can only contain setState steps",
+                    snapshot_path.as_ref().display()
+                );
+            };
+
+            for (address, account) in set_state_step.accounts.iter() {
+                self.add_fork_account(convert_scenario_account_to_world_mock(address, account));
+            }
+            for new_address in set_state_step.new_addresses.iter() {
+                self.put_new_address(
+                    new_address.creator_address.value.clone(),
+                    new_address.creator_nonce.value,
+                    new_address.new_address.value.clone(),
+                );
+            }
+            if let Some(block_info_obj) = &*set_state_step.previous_block_info {
+                update_block_info(&mut self.previous_block_info, block_info_obj);
+            }
+            if let Some(block_info_obj) = &*set_state_step.current_block_info {
+                update_block_info(&mut self.current_block_info, block_info_obj);
+            }
+        }
+        self
+    }
+}
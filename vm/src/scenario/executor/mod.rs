@@ -1,6 +1,8 @@
 mod all_steps;
 mod check_state;
+mod check_state_report;
 pub mod contract_info;
+mod fork_snapshot;
 pub mod sc_call;
 pub mod sc_deploy;
 pub mod sc_query;
@@ -18,6 +18,7 @@ use super::check_tx_output;
 impl BlockchainMock {
     /// Adds a SC deploy step, as specified in the `sc_deploy_step` argument, then executes it.
     pub fn perform_sc_deploy(&mut self, sc_deploy_step: ScDeployStep) -> &mut Self {
+        self.advance_round_for_tx();
         self.with_borrowed(|state| {
             let (_, _, state) = execute_and_check(state, &sc_deploy_step);
             ((), state)
@@ -43,6 +44,7 @@ impl BlockchainMock {
         RequestedResult: CodecFrom<OriginalResult>,
     {
         let sc_deploy_step: ScDeployStep = typed_sc_deploy.into();
+        self.advance_round_for_tx();
         let (tx_result, new_address) = self.with_borrowed(|state| {
             let (tx_result, new_address, state) = execute(state, &sc_deploy_step);
             ((tx_result, new_address), state)
@@ -1,4 +1,4 @@
-use crate::scenario::model::{SetStateStep, Step};
+use crate::scenario::model::{Account, AddressKey, SetStateStep, Step};
 use multiversx_sc::types::heap::Address;
 
 use crate::world_mock::{
@@ -18,52 +18,7 @@ impl BlockchainMock {
 
 fn execute(state: &mut BlockchainMock, set_state_step: &SetStateStep) {
     for (address, account) in set_state_step.accounts.iter() {
-        let storage = account
-            .storage
-            .iter()
-            .map(|(k, v)| (k.value.clone(), v.value.clone()))
-            .collect();
-        let esdt = AccountEsdt::new_from_raw_map(
-            account
-                .esdt
-                .iter()
-                .map(|(k, v)| (k.value.clone(), convert_mandos_esdt_to_world_mock(v)))
-                .collect(),
-        );
-
-        state.validate_and_add_account(AccountData {
-            address: address.to_address(),
-            nonce: account
-                .nonce
-                .as_ref()
-                .map(|nonce| nonce.value)
-                .unwrap_or_default(),
-            egld_balance: account
-                .balance
-                .as_ref()
-                .map(|balance| balance.value.clone())
-                .unwrap_or_default(),
-            esdt,
-            username: account
-                .username
-                .as_ref()
-                .map(|bytes_value| bytes_value.value.clone())
-                .unwrap_or_default(),
-            storage,
-            contract_path: account
-                .code
-                .as_ref()
-                .map(|bytes_value| bytes_value.value.clone()),
-            contract_owner: account
-                .owner
-                .as_ref()
-                .map(|address_value| address_value.value.clone()),
-            developer_rewards: account
-                .developer_rewards
-                .as_ref()
-                .map(|rewards| rewards.value.clone())
-                .unwrap_or_default(),
-        });
+        state.validate_and_add_account(convert_scenario_account_to_world_mock(address, account));
     }
     for new_address in set_state_step.new_addresses.iter() {
         assert!(
@@ -84,6 +39,58 @@ fn execute(state: &mut BlockchainMock, set_state_step: &SetStateStep) {
     }
 }
 
+pub(crate) fn convert_scenario_account_to_world_mock(
+    address: &AddressKey,
+    account: &Account,
+) -> AccountData {
+    let storage = account
+        .storage
+        .iter()
+        .map(|(k, v)| (k.value.clone(), v.value.clone()))
+        .collect();
+    let esdt = AccountEsdt::new_from_raw_map(
+        account
+            .esdt
+            .iter()
+            .map(|(k, v)| (k.value.clone(), convert_mandos_esdt_to_world_mock(v)))
+            .collect(),
+    );
+
+    AccountData {
+        address: address.to_address(),
+        nonce: account
+            .nonce
+            .as_ref()
+            .map(|nonce| nonce.value)
+            .unwrap_or_default(),
+        egld_balance: account
+            .balance
+            .as_ref()
+            .map(|balance| balance.value.clone())
+            .unwrap_or_default(),
+        esdt,
+        username: account
+            .username
+            .as_ref()
+            .map(|bytes_value| bytes_value.value.clone())
+            .unwrap_or_default(),
+        storage,
+        contract_path: account
+            .code
+            .as_ref()
+            .map(|bytes_value| bytes_value.value.clone()),
+        contract_owner: account
+            .owner
+            .as_ref()
+            .map(|address_value| address_value.value.clone()),
+        developer_rewards: account
+            .developer_rewards
+            .as_ref()
+            .map(|rewards| rewards.value.clone())
+            .unwrap_or_default(),
+    }
+}
+
 fn convert_mandos_esdt_to_world_mock(mandos_esdt: &crate::scenario::model::Esdt) -> EsdtData {
     match mandos_esdt {
         crate::scenario::model::Esdt::Short(short_esdt) => {
@@ -165,7 +172,7 @@ fn convert_scenario_esdt_instance_to_world_mock(
     }
 }
 
-fn update_block_info(
+pub(crate) fn update_block_info(
     block_info: &mut CrateBlockInfo,
     scenario_block_info: &crate::scenario::model::BlockInfo,
 ) {
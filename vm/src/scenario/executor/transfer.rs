@@ -10,6 +10,7 @@ use crate::{
 
 impl BlockchainMock {
     pub fn perform_transfer(&mut self, transfer_step: TransferStep) -> &mut Self {
+        self.advance_round_for_tx();
         self.with_borrowed(|state| ((), execute(state, &transfer_step.tx)));
         self.scenario_trace
             .steps
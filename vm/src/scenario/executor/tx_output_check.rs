@@ -15,10 +15,14 @@ pub fn check_tx_output(tx_id: &str, tx_expect: &TxExpect, tx_result: &TxResult)
 
     assert!(
         tx_expect.out.check(tx_result.result_values.as_slice()),
-        "bad out value. Tx id: '{}'. Want: [{}]. Have: [{}]",
+        "bad out value. Tx id: '{}'. Want: [{}]. Have: [{}]{}",
         tx_id,
         tx_expect.out_to_string(),
-        tx_result.result_values_to_string()
+        tx_result.result_values_to_string(),
+        tx_expect
+            .decoded_out_to_string(tx_result.result_values.as_slice())
+            .map(|decoded| format!(". Have (decoded): [{decoded}]"))
+            .unwrap_or_default(),
     );
 
     assert!(
@@ -29,6 +33,14 @@ pub fn check_tx_output(tx_id: &str, tx_expect: &TxExpect, tx_result: &TxResult)
         have_str,
     );
 
+    assert!(
+        tx_expect.reentrant_calls.check(tx_result.reentrant_calls),
+        "reentrant calls mismatch. Tx id: '{}'. Want: {}. Have: {}",
+        tx_id,
+        tx_expect.reentrant_calls,
+        tx_result.reentrant_calls,
+    );
+
     match &tx_expect.logs {
         CheckLogs::Star => {},
         CheckLogs::List(expected_logs) => {
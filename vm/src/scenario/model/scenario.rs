@@ -1,4 +1,5 @@
 use crate::scenario_format::{
+    abi_value::AbiTypes,
     interpret_trait::{InterpretableFrom, InterpreterContext, IntoRaw},
     serde_raw::ScenarioRaw,
 };
@@ -10,15 +11,30 @@ pub struct Scenario {
     pub name: Option<String>,
     pub comment: Option<String>,
     pub check_gas: Option<bool>,
+
+    /// Path to the contract ABI, relative to the scenario file.
+    pub abi: Option<String>,
+
     pub steps: Vec<Step>,
 }
 
 impl InterpretableFrom<ScenarioRaw> for Scenario {
     fn interpret_from(from: ScenarioRaw, context: &InterpreterContext) -> Self {
+        let abi_context;
+        let context = if let Some(abi_path) = &from.abi {
+            let abi_types = AbiTypes::load_from_abi_file(context.context_path.join(abi_path));
+            abi_context =
+                InterpreterContext::new(context.context_path.clone()).with_abi_types(abi_types);
+            &abi_context
+        } else {
+            context
+        };
+
         Scenario {
             name: from.name,
             comment: from.comment,
             check_gas: from.check_gas,
+            abi: from.abi,
             steps: from
                 .steps
                 .into_iter()
@@ -35,6 +51,7 @@ impl IntoRaw<ScenarioRaw> for Scenario {
             comment: self.comment,
             check_gas: self.check_gas,
             gas_schedule: None,
+            abi: self.abi,
             steps: self.steps.into_iter().map(Step::into_raw).collect(),
         }
     }
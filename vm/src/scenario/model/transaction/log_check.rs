@@ -1,3 +1,5 @@
+use multiversx_sc::codec::{top_encode_to_vec_u8_or_panic, TopEncode, TopEncodeMulti};
+
 use crate::{
     scenario::model::{BytesValue, CheckValue, CheckValueList},
     scenario_format::{
@@ -14,6 +16,33 @@ pub struct CheckLog {
     pub data: CheckValue<BytesValue>,
 }
 
+impl CheckLog {
+    /// Expects a log emitted by an `#[event]` method, from any address and endpoint.
+    ///
+    /// The topics are the event identifier, followed by the indexed arguments.
+    /// The data is the non-indexed argument, or a tuple of them, if the event has several.
+    pub fn event<T, D>(identifier: &str, indexed_args: T, data: D) -> Self
+    where
+        T: TopEncodeMulti,
+        D: TopEncode,
+    {
+        let mut topics = vec![identifier.as_bytes().to_vec()];
+        indexed_args.multi_encode(&mut topics).unwrap();
+
+        CheckLog {
+            address: CheckValue::Star,
+            endpoint: CheckValue::Star,
+            topics: CheckValue::Equal(
+                topics
+                    .into_iter()
+                    .map(|topic| CheckValue::Equal(BytesValue::from(topic)))
+                    .collect(),
+            ),
+            data: CheckValue::Equal(BytesValue::from(top_encode_to_vec_u8_or_panic(&data))),
+        }
+    }
+}
+
 impl InterpretableFrom<CheckLogRaw> for CheckLog {
     fn interpret_from(from: CheckLogRaw, context: &InterpreterContext) -> Self {
         CheckLog {
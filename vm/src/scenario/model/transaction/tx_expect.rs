@@ -1,10 +1,14 @@
 use crate::{
-    scenario::model::{BytesValue, CheckLogs, CheckValue, CheckValueList, U64Value},
+    scenario::model::{
+        BytesValue, CheckLog, CheckLogList, CheckLogs, CheckValue, CheckValueList, U64Value,
+    },
     scenario_format::{
+        abi_value::{abi_expression_type_name, AbiTypes},
         interpret_trait::{InterpretableFrom, InterpreterContext, IntoRaw},
-        serde_raw::TxExpectRaw,
+        serde_raw::{TxExpectRaw, ValueSubTree},
     },
 };
+use std::sync::Arc;
 
 #[derive(Debug)]
 pub struct TxExpect {
@@ -14,6 +18,13 @@ pub struct TxExpect {
     pub logs: CheckLogs,
     pub gas: CheckValue<U64Value>,
     pub refund: CheckValue<U64Value>,
+
+    /// Only checked by the debugger, see `TxResult::reentrant_calls`.
+    pub reentrant_calls: CheckValue<U64Value>,
+
+    /// Taken from the interpreter context, used to decode the actual results
+    /// the same way as the expected `abi:` results, when they do not match.
+    pub abi_types: Option<Arc<AbiTypes>>,
 }
 
 impl TxExpect {
@@ -25,6 +36,8 @@ impl TxExpect {
             logs: CheckLogs::Star,
             gas: CheckValue::Star,
             refund: CheckValue::Star,
+            reentrant_calls: CheckValue::Star,
+            abi_types: None,
         }
     }
 
@@ -43,6 +56,8 @@ impl TxExpect {
             logs: CheckLogs::Star,
             gas: CheckValue::Star,
             refund: CheckValue::Star,
+            reentrant_calls: CheckValue::Star,
+            abi_types: None,
         }
     }
 
@@ -63,6 +78,25 @@ impl TxExpect {
         self.out = CheckValue::Equal(check_results);
         self
     }
+
+    /// Adds a log to the expected ones. The expected logs then need to match the actual logs exactly.
+    pub fn log(mut self, check_log: CheckLog) -> Self {
+        match &mut self.logs {
+            CheckLogs::Star => {
+                self.logs = CheckLogs::List(CheckLogList {
+                    list: vec![check_log],
+                    more_allowed_at_end: false,
+                });
+            },
+            CheckLogs::List(check_log_list) => check_log_list.list.push(check_log),
+        }
+        self
+    }
+
+    pub fn reentrant_calls(mut self, count: u64) -> Self {
+        self.reentrant_calls = CheckValue::Equal(U64Value::from(count));
+        self
+    }
 }
 
 impl InterpretableFrom<TxExpectRaw> for TxExpect {
@@ -74,6 +108,8 @@ impl InterpretableFrom<TxExpectRaw> for TxExpect {
             message: CheckValue::<BytesValue>::interpret_from(from.message, context),
             gas: CheckValue::<U64Value>::interpret_from(from.gas, context),
             refund: CheckValue::<U64Value>::interpret_from(from.refund, context),
+            reentrant_calls: CheckValue::<U64Value>::interpret_from(from.reentrant_calls, context),
+            abi_types: context.abi_types.clone(),
         }
     }
 }
@@ -87,6 +123,7 @@ impl IntoRaw<TxExpectRaw> for TxExpect {
             logs: self.logs.into_raw(),
             gas: self.gas.into_raw(),
             refund: self.refund.into_raw(),
+            reentrant_calls: self.reentrant_calls.into_raw(),
         }
     }
 }
@@ -100,4 +137,39 @@ impl TxExpect {
             },
         }
     }
+
+    /// Decodes each actual result as the type of the corresponding expected `abi:` result.
+    ///
+    /// Returns `None` if there are no such expected results to go by.
+    pub fn decoded_out_to_string(&self, result_values: &[Vec<u8>]) -> Option<String> {
+        let abi_types = self.abi_types.as_ref()?;
+        let expected_list = match &self.out {
+            CheckValue::Star => return None,
+            CheckValue::Equal(list) => list,
+        };
+
+        let type_names: Vec<Option<&str>> = expected_list
+            .iter()
+            .map(|expected| match expected {
+                CheckValue::Equal(BytesValue {
+                    original: ValueSubTree::Str(expr),
+                    ..
+                }) => abi_expression_type_name(expr),
+                _ => None,
+            })
+            .collect();
+        if type_names.iter().all(Option::is_none) {
+            return None;
+        }
+
+        Some(itertools::join(
+            result_values.iter().enumerate().map(|(i, value)| {
+                match type_names.get(i).copied().flatten() {
+                    Some(type_name) => abi_types.pretty_print(type_name, value),
+                    None => format!("0x{}", hex::encode(value)),
+                }
+            }),
+            ", ",
+        ))
+    }
 }
@@ -2,7 +2,7 @@ use crate::{
     scenario::model::Scenario,
     scenario_format::{
         interpret_trait::{InterpretableFrom, InterpreterContext},
-        serde_raw::ScenarioRaw,
+        serde_raw::{ScenarioFileFormat, ScenarioRaw},
     },
 };
 
@@ -11,7 +11,7 @@ use std::{fs, path::Path};
 pub fn parse_scenario_raw<P: AsRef<Path>>(path: P) -> ScenarioRaw {
     let contents = fs::read_to_string(path.as_ref())
         .unwrap_or_else(|e| panic!("not found: {} {:?}", e, path.as_ref()));
-    serde_json::from_str(contents.as_str()).unwrap()
+    ScenarioRaw::from_str_with_format(contents.as_str(), ScenarioFileFormat::from_path(path))
 }
 
 pub fn parse_scenario<P: AsRef<Path>>(path: P) -> Scenario {
@@ -10,10 +10,13 @@ use super::{
     builtin_func_role_check_wrapper::BuiltinFunctionRoleCheckWrapper,
     builtin_func_trait::BuiltinFunction,
     esdt_nft::{
-        ESDTLocalBurn, ESDTLocalMint, ESDTNftAddQuantity, ESDTNftAddUri, ESDTNftBurn,
-        ESDTNftCreate, ESDTNftUpdateAttributes,
+        ESDTLocalBurn, ESDTLocalMint, ESDTModifyRoyalties, ESDTNftAddQuantity, ESDTNftAddUri,
+        ESDTNftBurn, ESDTNftCreate, ESDTNftUpdateAttributes, ESDTSetNewUris,
     },
-    general::{ChangeOwner, ClaimDeveloperRewards, SetUsername, UpgradeContract},
+    esdt_system::{
+        ESDTFreeze, ESDTGlobalSettingToggle, ESDTNftCreateRoleTransfer, ESDTUnFreeze, ESDTWipe,
+    },
+    general::{ChangeOwner, ClaimDeveloperRewards, SaveKeyValue, SetUsername, UpgradeContract},
     transfer::{ESDTMultiTransfer, ESDTNftTransfer, ESDTTransfer},
 };
 
@@ -24,6 +27,8 @@ const ESDT_ROLE_NFT_ADD_QUANTITY: &str = "ESDTRoleNFTAddQuantity";
 const ESDT_ROLE_NFT_BURN: &str = "ESDTRoleNFTBurn";
 const ESDT_ROLE_NFT_ADD_URI: &str = "ESDTRoleNFTAddURI";
 const ESDT_ROLE_NFT_UPDATE_ATTRIBUTES: &str = "ESDTRoleNFTUpdateAttributes";
+const ESDT_ROLE_MODIFY_ROYALTIES: &str = "ESDTRoleModifyRoyalties";
+const ESDT_ROLE_SET_NEW_URI: &str = "ESDTRoleSetNewURI";
 
 fn builtin_function_impls() -> Vec<Box<dyn BuiltinFunction>> {
     vec![
@@ -55,11 +60,28 @@ fn builtin_function_impls() -> Vec<Box<dyn BuiltinFunction>> {
             ESDT_ROLE_NFT_UPDATE_ATTRIBUTES,
             Box::new(ESDTNftUpdateAttributes),
         )),
+        Box::new(BuiltinFunctionRoleCheckWrapper::new(
+            ESDT_ROLE_MODIFY_ROYALTIES,
+            Box::new(ESDTModifyRoyalties),
+        )),
+        Box::new(BuiltinFunctionRoleCheckWrapper::new(
+            ESDT_ROLE_SET_NEW_URI,
+            Box::new(ESDTSetNewUris),
+        )),
+        Box::new(ESDTNftCreateRoleTransfer),
+        Box::new(ESDTFreeze),
+        Box::new(ESDTUnFreeze),
+        Box::new(ESDTWipe),
+        Box::new(ESDTGlobalSettingToggle::set_limited_transfer()),
+        Box::new(ESDTGlobalSettingToggle::unset_limited_transfer()),
+        Box::new(ESDTGlobalSettingToggle::set_burn_role_for_all()),
+        Box::new(ESDTGlobalSettingToggle::unset_burn_role_for_all()),
         Box::new(ESDTMultiTransfer),
         Box::new(ESDTNftTransfer),
         Box::new(ESDTTransfer),
         Box::new(ChangeOwner),
         Box::new(ClaimDeveloperRewards),
+        Box::new(SaveKeyValue),
         Box::new(SetUsername),
         Box::new(UpgradeContract),
     ]
@@ -1,7 +1,10 @@
-use crate::tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult};
+use crate::tx_mock::{BlockchainUpdate, EsdtGlobalSetting, TxCache, TxInput, TxResult};
 
 use super::builtin_func_trait::{BuiltinFunction, BuiltinFunctionEsdtTransferInfo};
 
+/// Roles that every account has, when the token has the burn role for all setting.
+const ESDT_BURN_ROLES: &[&str] = &["ESDTRoleLocalBurn", "ESDTRoleNFTBurn"];
+
 /// Checks that user has appropriate role before calling the builtin function.
 pub struct BuiltinFunctionRoleCheckWrapper {
     role_name: &'static str,
@@ -40,6 +43,11 @@ impl BuiltinFunction for BuiltinFunctionRoleCheckWrapper {
 
 pub fn check_allowed_to_execute(role_name: &str, tx_input: &TxInput, tx_cache: &TxCache) -> bool {
     let token_identifier = tx_input.args[0].clone();
+    if ESDT_BURN_ROLES.contains(&role_name)
+        && tx_cache.is_esdt_global_setting_set(&token_identifier, EsdtGlobalSetting::BurnRoleForAll)
+    {
+        return true;
+    }
     let available_roles = tx_cache.with_account_mut(&tx_input.to, |account| {
         account.esdt.get_roles(&token_identifier)
     });
@@ -0,0 +1,68 @@
+use multiversx_sc::{
+    api::ESDT_MODIFY_ROYALTIES_FUNC_NAME,
+    codec::{top_encode_to_vec_u8, TopDecode},
+};
+
+use crate::tx_mock::{BlockchainUpdate, TxCache, TxInput, TxLog, TxResult};
+
+use super::super::builtin_func_trait::BuiltinFunction;
+
+/// Royalties are expressed in hundredths of a percent, so this is 100%.
+const MAX_ROYALTIES: u64 = 10_000;
+
+pub struct ESDTModifyRoyalties;
+
+impl BuiltinFunction for ESDTModifyRoyalties {
+    fn name(&self) -> &str {
+        ESDT_MODIFY_ROYALTIES_FUNC_NAME
+    }
+
+    fn execute(&self, tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+        if tx_input.args.len() != 3 {
+            let err_result =
+                TxResult::from_vm_error("invalid arguments to process built-in function");
+            return (err_result, BlockchainUpdate::empty());
+        }
+
+        let token_identifier = tx_input.args[0].as_slice();
+        let Ok(nonce) = u64::top_decode(tx_input.args[1].as_slice()) else {
+            let err_result =
+                TxResult::from_vm_error("invalid arguments to process built-in function");
+            return (err_result, BlockchainUpdate::empty());
+        };
+        let royalties = u64::top_decode(tx_input.args[2].as_slice()).unwrap_or(u64::MAX);
+        if royalties > MAX_ROYALTIES {
+            let err_result = TxResult::from_vm_error("invalid max royalty value");
+            return (err_result, BlockchainUpdate::empty());
+        }
+
+        let set_result = tx_cache.with_account_mut(&tx_input.from, |account| {
+            account
+                .esdt
+                .set_royalties(token_identifier, nonce, royalties)
+        });
+        if let Err(message) = set_result {
+            return (TxResult::from_vm_error(message), BlockchainUpdate::empty());
+        }
+
+        let esdt_modify_royalties_log = TxLog {
+            address: tx_input.from,
+            endpoint: ESDT_MODIFY_ROYALTIES_FUNC_NAME.into(),
+            topics: vec![
+                token_identifier.to_vec(),
+                top_encode_to_vec_u8(&nonce).unwrap(),
+                Vec::new(), // value = 0
+                top_encode_to_vec_u8(&royalties).unwrap(),
+            ],
+            data: vec![],
+        };
+
+        let tx_result = TxResult {
+            result_status: 0,
+            result_logs: vec![esdt_modify_royalties_log],
+            ..Default::default()
+        };
+
+        (tx_result, tx_cache.into_blockchain_updates())
+    }
+}
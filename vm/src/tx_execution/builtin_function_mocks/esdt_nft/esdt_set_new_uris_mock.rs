@@ -0,0 +1,63 @@
+use multiversx_sc::{
+    api::ESDT_SET_NEW_URIS_FUNC_NAME,
+    codec::{top_encode_to_vec_u8, TopDecode},
+};
+
+use crate::tx_mock::{BlockchainUpdate, TxCache, TxInput, TxLog, TxResult};
+
+use super::super::builtin_func_trait::BuiltinFunction;
+
+/// Replaces all URIs of an NFT, as opposed to `ESDTNFTAddURI`, which appends to them.
+pub struct ESDTSetNewUris;
+
+impl BuiltinFunction for ESDTSetNewUris {
+    fn name(&self) -> &str {
+        ESDT_SET_NEW_URIS_FUNC_NAME
+    }
+
+    fn execute(&self, tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+        if tx_input.args.len() < 3 {
+            let err_result =
+                TxResult::from_vm_error("invalid arguments to process built-in function");
+            return (err_result, BlockchainUpdate::empty());
+        }
+
+        let token_identifier = tx_input.args[0].clone();
+        let Ok(nonce) = u64::top_decode(tx_input.args[1].as_slice()) else {
+            let err_result =
+                TxResult::from_vm_error("invalid arguments to process built-in function");
+            return (err_result, BlockchainUpdate::empty());
+        };
+        let mut new_uris = tx_input.args[2..].to_vec();
+
+        let set_result = tx_cache.with_account_mut(&tx_input.from, |account| {
+            account
+                .esdt
+                .set_uris(token_identifier.as_slice(), nonce, new_uris.clone())
+        });
+        if let Err(message) = set_result {
+            return (TxResult::from_vm_error(message), BlockchainUpdate::empty());
+        }
+
+        let mut topics = vec![
+            token_identifier,
+            top_encode_to_vec_u8(&nonce).unwrap(),
+            Vec::new(), // value = 0
+        ];
+        topics.append(&mut new_uris);
+        let esdt_set_new_uris_log = TxLog {
+            address: tx_input.from,
+            endpoint: ESDT_SET_NEW_URIS_FUNC_NAME.into(),
+            topics,
+            data: vec![],
+        };
+
+        let tx_result = TxResult {
+            result_status: 0,
+            result_logs: vec![esdt_set_new_uris_log],
+            ..Default::default()
+        };
+
+        (tx_result, tx_cache.into_blockchain_updates())
+    }
+}
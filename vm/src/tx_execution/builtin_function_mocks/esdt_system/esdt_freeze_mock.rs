@@ -0,0 +1,69 @@
+use multiversx_sc::api::{ESDT_FREEZE_FUNC_NAME, ESDT_UNFREEZE_FUNC_NAME};
+
+use crate::tx_mock::{BlockchainUpdate, TxCache, TxInput, TxLog, TxResult};
+
+use super::{
+    super::builtin_func_trait::BuiltinFunction,
+    esdt_system_common::{check_esdt_system_sc_call, value_topic},
+};
+
+pub struct ESDTFreeze;
+
+impl BuiltinFunction for ESDTFreeze {
+    fn name(&self) -> &str {
+        ESDT_FREEZE_FUNC_NAME
+    }
+
+    fn execute(&self, tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+        execute_toggle_freeze(self.name(), true, tx_input, tx_cache)
+    }
+}
+
+pub struct ESDTUnFreeze;
+
+impl BuiltinFunction for ESDTUnFreeze {
+    fn name(&self) -> &str {
+        ESDT_UNFREEZE_FUNC_NAME
+    }
+
+    fn execute(&self, tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+        execute_toggle_freeze(self.name(), false, tx_input, tx_cache)
+    }
+}
+
+fn execute_toggle_freeze(
+    builtin_function_name: &str,
+    frozen: bool,
+    tx_input: TxInput,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    if let Err(message) = check_esdt_system_sc_call(&tx_input, 1) {
+        return (TxResult::from_vm_error(message), BlockchainUpdate::empty());
+    }
+
+    let token_identifier = tx_input.args[0].clone();
+    let balance = tx_cache.with_account_mut(&tx_input.to, |account| {
+        account.esdt.set_frozen(token_identifier.clone(), frozen);
+        account.esdt.get_esdt_balance(&token_identifier, 0)
+    });
+
+    let freeze_log = TxLog {
+        address: tx_input.from,
+        endpoint: builtin_function_name.into(),
+        topics: vec![
+            token_identifier,
+            Vec::new(),
+            value_topic(&balance),
+            tx_input.to.to_vec(),
+        ],
+        data: vec![],
+    };
+
+    let tx_result = TxResult {
+        result_status: 0,
+        result_logs: vec![freeze_log],
+        ..Default::default()
+    };
+
+    (tx_result, tx_cache.into_blockchain_updates())
+}
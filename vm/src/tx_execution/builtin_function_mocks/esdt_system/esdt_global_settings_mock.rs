@@ -0,0 +1,67 @@
+use multiversx_sc::api::{
+    ESDT_SET_BURN_ROLE_FOR_ALL_FUNC_NAME, ESDT_SET_LIMITED_TRANSFER_FUNC_NAME,
+    ESDT_UNSET_BURN_ROLE_FOR_ALL_FUNC_NAME, ESDT_UNSET_LIMITED_TRANSFER_FUNC_NAME,
+};
+
+use crate::tx_mock::{BlockchainUpdate, EsdtGlobalSetting, TxCache, TxInput, TxResult};
+
+use super::{
+    super::builtin_func_trait::BuiltinFunction, esdt_system_common::check_esdt_system_sc_call,
+};
+
+/// Sets or clears one of the settings that apply to a token on all accounts.
+pub struct ESDTGlobalSettingToggle {
+    name: &'static str,
+    setting: EsdtGlobalSetting,
+    value: bool,
+}
+
+impl ESDTGlobalSettingToggle {
+    pub fn set_limited_transfer() -> Self {
+        ESDTGlobalSettingToggle {
+            name: ESDT_SET_LIMITED_TRANSFER_FUNC_NAME,
+            setting: EsdtGlobalSetting::LimitedTransfer,
+            value: true,
+        }
+    }
+
+    pub fn unset_limited_transfer() -> Self {
+        ESDTGlobalSettingToggle {
+            name: ESDT_UNSET_LIMITED_TRANSFER_FUNC_NAME,
+            setting: EsdtGlobalSetting::LimitedTransfer,
+            value: false,
+        }
+    }
+
+    pub fn set_burn_role_for_all() -> Self {
+        ESDTGlobalSettingToggle {
+            name: ESDT_SET_BURN_ROLE_FOR_ALL_FUNC_NAME,
+            setting: EsdtGlobalSetting::BurnRoleForAll,
+            value: true,
+        }
+    }
+
+    pub fn unset_burn_role_for_all() -> Self {
+        ESDTGlobalSettingToggle {
+            name: ESDT_UNSET_BURN_ROLE_FOR_ALL_FUNC_NAME,
+            setting: EsdtGlobalSetting::BurnRoleForAll,
+            value: false,
+        }
+    }
+}
+
+impl BuiltinFunction for ESDTGlobalSettingToggle {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn execute(&self, tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+        if let Err(message) = check_esdt_system_sc_call(&tx_input, 1) {
+            return (TxResult::from_vm_error(message), BlockchainUpdate::empty());
+        }
+
+        tx_cache.set_esdt_global_setting(tx_input.args[0].as_slice(), self.setting, self.value);
+
+        (TxResult::empty(), tx_cache.into_blockchain_updates())
+    }
+}
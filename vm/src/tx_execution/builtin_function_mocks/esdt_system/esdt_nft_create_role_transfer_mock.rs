@@ -0,0 +1,66 @@
+use multiversx_sc::{
+    api::ESDT_NFT_CREATE_ROLE_TRANSFER_FUNC_NAME, codec::TopDecode, types::heap::Address,
+};
+
+use crate::tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult};
+
+use super::{
+    super::builtin_func_trait::BuiltinFunction,
+    esdt_system_common::{check_esdt_system_sc_call, ERR_INVALID_ARGUMENTS},
+};
+
+const ESDT_ROLE_NFT_CREATE: &[u8] = b"ESDTRoleNFTCreate";
+
+/// Moves the NFT create role from one account to another, together with the last created nonce.
+///
+/// On the real blockchain this happens in two steps, one for each shard involved.
+/// Since the mock only has one shard, both of them are performed here.
+pub struct ESDTNftCreateRoleTransfer;
+
+impl BuiltinFunction for ESDTNftCreateRoleTransfer {
+    fn name(&self) -> &str {
+        ESDT_NFT_CREATE_ROLE_TRANSFER_FUNC_NAME
+    }
+
+    fn execute(&self, tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+        if let Err(message) = check_esdt_system_sc_call(&tx_input, 2) {
+            return (TxResult::from_vm_error(message), BlockchainUpdate::empty());
+        }
+        let Ok(destination) = Address::top_decode(tx_input.args[1].as_slice()) else {
+            let err_result = TxResult::from_vm_error(ERR_INVALID_ARGUMENTS);
+            return (err_result, BlockchainUpdate::empty());
+        };
+
+        let token_identifier = tx_input.args[0].clone();
+        let last_nonce = tx_cache.with_account_mut(&tx_input.to, |account| {
+            let mut roles = account.esdt.get_roles(&token_identifier);
+            roles.retain(|role| role.as_slice() != ESDT_ROLE_NFT_CREATE);
+            account.esdt.set_roles(token_identifier.clone(), roles);
+
+            let esdt_data = account
+                .esdt
+                .get_mut_by_identifier(&token_identifier)
+                .unwrap();
+            std::mem::take(&mut esdt_data.last_nonce)
+        });
+
+        tx_cache.with_account_mut(&destination, |account| {
+            let mut roles = account.esdt.get_roles(&token_identifier);
+            if !roles
+                .iter()
+                .any(|role| role.as_slice() == ESDT_ROLE_NFT_CREATE)
+            {
+                roles.push(ESDT_ROLE_NFT_CREATE.to_vec());
+            }
+            account.esdt.set_roles(token_identifier.clone(), roles);
+
+            let esdt_data = account
+                .esdt
+                .get_mut_by_identifier(&token_identifier)
+                .unwrap();
+            esdt_data.last_nonce = esdt_data.last_nonce.max(last_nonce);
+        });
+
+        (TxResult::empty(), tx_cache.into_blockchain_updates())
+    }
+}
@@ -0,0 +1,34 @@
+use crate::num_bigint::BigUint;
+use multiversx_sc::esdt::ESDT_SYSTEM_SC_ADDRESS_ARRAY;
+use num_traits::Zero;
+
+use crate::tx_mock::TxInput;
+
+pub(super) const ERR_INVALID_ARGUMENTS: &str = "invalid arguments to process built-in function";
+
+/// The builtin functions in this module can only be called by the ESDT system smart contract,
+/// which is how token managers reach them.
+pub(super) fn check_esdt_system_sc_call(
+    tx_input: &TxInput,
+    expected_num_args: usize,
+) -> Result<(), &'static str> {
+    if !tx_input.egld_value.is_zero() {
+        return Err("built in function called with tx value is not allowed");
+    }
+    if tx_input.args.len() != expected_num_args {
+        return Err(ERR_INVALID_ARGUMENTS);
+    }
+    if tx_input.from.as_array() != &ESDT_SYSTEM_SC_ADDRESS_ARRAY {
+        return Err("address is not ESDT system SC");
+    }
+    Ok(())
+}
+
+/// Log topics encode zero values as empty bytes.
+pub(super) fn value_topic(value: &BigUint) -> Vec<u8> {
+    if value.is_zero() {
+        Vec::new()
+    } else {
+        value.to_bytes_be()
+    }
+}
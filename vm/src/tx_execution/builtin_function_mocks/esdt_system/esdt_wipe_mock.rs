@@ -0,0 +1,59 @@
+use multiversx_sc::{api::ESDT_WIPE_FUNC_NAME, codec::top_encode_to_vec_u8};
+
+use crate::{
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxLog, TxResult},
+    world_mock::split_esdt_token_key,
+};
+
+use super::{
+    super::builtin_func_trait::BuiltinFunction,
+    esdt_system_common::{check_esdt_system_sc_call, value_topic},
+};
+
+pub struct ESDTWipe;
+
+impl BuiltinFunction for ESDTWipe {
+    fn name(&self) -> &str {
+        ESDT_WIPE_FUNC_NAME
+    }
+
+    fn execute(&self, tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+        if let Err(message) = check_esdt_system_sc_call(&tx_input, 1) {
+            return (TxResult::from_vm_error(message), BlockchainUpdate::empty());
+        }
+
+        let (token_identifier, nonce) = split_esdt_token_key(tx_input.args[0].as_slice());
+        let wiped_value = tx_cache.with_account_mut(&tx_input.to, |account| {
+            if !account.esdt.is_frozen(token_identifier) {
+                return None;
+            }
+            Some(account.esdt.wipe(token_identifier, nonce))
+        });
+        let Some(wiped_value) = wiped_value else {
+            let err_result = TxResult::from_vm_error(
+                "cannot wipe because the account is not frozen for this esdt token",
+            );
+            return (err_result, BlockchainUpdate::empty());
+        };
+
+        let wipe_log = TxLog {
+            address: tx_input.from,
+            endpoint: ESDT_WIPE_FUNC_NAME.into(),
+            topics: vec![
+                token_identifier.to_vec(),
+                top_encode_to_vec_u8(&nonce).unwrap(),
+                value_topic(&wiped_value),
+                tx_input.to.to_vec(),
+            ],
+            data: vec![],
+        };
+
+        let tx_result = TxResult {
+            result_status: 0,
+            result_logs: vec![wipe_log],
+            ..Default::default()
+        };
+
+        (tx_result, tx_cache.into_blockchain_updates())
+    }
+}
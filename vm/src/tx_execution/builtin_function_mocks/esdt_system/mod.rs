@@ -0,0 +1,10 @@
+mod esdt_freeze_mock;
+mod esdt_global_settings_mock;
+mod esdt_nft_create_role_transfer_mock;
+mod esdt_system_common;
+mod esdt_wipe_mock;
+
+pub use esdt_freeze_mock::*;
+pub use esdt_global_settings_mock::*;
+pub use esdt_nft_create_role_transfer_mock::*;
+pub use esdt_wipe_mock::*;
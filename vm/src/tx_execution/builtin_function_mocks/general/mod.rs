@@ -1,10 +1,12 @@
 mod change_owner_mock;
 mod claim_developer_rewards_mock;
 mod migrate_username_mock;
+mod save_key_value_mock;
 mod set_username_mock;
 mod upgrade_contract;
 
 pub use change_owner_mock::*;
 pub use claim_developer_rewards_mock::*;
+pub use save_key_value_mock::*;
 pub use set_username_mock::*;
 pub use upgrade_contract::*;
@@ -0,0 +1,49 @@
+use multiversx_sc::api::SAVE_KEY_VALUE_FUNC_NAME;
+
+use crate::{
+    tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
+    world_mock::is_protected_storage_key,
+};
+
+use super::super::builtin_func_trait::BuiltinFunction;
+
+/// Lets user accounts write to their own storage, in key-value pairs.
+pub struct SaveKeyValue;
+
+impl BuiltinFunction for SaveKeyValue {
+    fn name(&self) -> &str {
+        SAVE_KEY_VALUE_FUNC_NAME
+    }
+
+    fn execute(&self, tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+        if tx_input.args.is_empty() || tx_input.args.len() % 2 != 0 {
+            return (
+                TxResult::from_vm_error("invalid arguments to process built-in function"),
+                BlockchainUpdate::empty(),
+            );
+        }
+        if tx_input.from != tx_input.to
+            || tx_input
+                .args
+                .chunks(2)
+                .any(|pair| is_protected_storage_key(&pair[0]))
+        {
+            return (
+                TxResult::from_vm_error("operation not permitted"),
+                BlockchainUpdate::empty(),
+            );
+        }
+
+        tx_cache.with_account_mut(&tx_input.to, |account| {
+            for pair in tx_input.args.chunks(2) {
+                if pair[1].is_empty() {
+                    account.storage.remove(&pair[0]);
+                } else {
+                    account.storage.insert(pair[0].clone(), pair[1].clone());
+                }
+            }
+        });
+
+        (TxResult::empty(), tx_cache.into_blockchain_updates())
+    }
+}
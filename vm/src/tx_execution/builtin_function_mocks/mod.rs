@@ -3,6 +3,7 @@ mod builtin_func_map;
 mod builtin_func_role_check_wrapper;
 mod builtin_func_trait;
 mod esdt_nft;
+mod esdt_system;
 mod general;
 mod transfer;
 
@@ -7,7 +7,8 @@ use num_traits::Zero;
 use crate::{
     tx_mock::{
         async_call_tx_input, async_callback_tx_input, async_promise_tx_input, merge_results,
-        AsyncCallTxData, Promise, TxCache, TxContext, TxInput, TxResult, TxResultCalls,
+        AsyncCallTxData, BlockchainUpdate, Promise, TxCache, TxContext, TxContextStack, TxInput,
+        TxPanic, TxResult, TxResultCalls,
     },
     world_mock::{AccountData, AccountEsdt, BlockchainMock},
 };
@@ -18,16 +19,41 @@ pub fn execute_sc_query(tx_input: TxInput, state: BlockchainMock) -> (TxResult,
     let state_rc = Rc::new(state);
     let tx_cache = TxCache::new(state_rc.clone());
     let tx_context = TxContext::new(tx_input, tx_cache);
-    let (_, tx_result) = execute_tx_context(tx_context);
+    let (_, mut tx_result) = execute_tx_context(tx_context);
+    tx_result.reentrant_calls = TxContextStack::static_take_reentrant_calls();
     (tx_result, Rc::try_unwrap(state_rc).unwrap())
 }
 
-pub fn execute_sc_call(tx_input: TxInput, mut state: BlockchainMock) -> (TxResult, BlockchainMock) {
+pub fn execute_sc_call(tx_input: TxInput, state: BlockchainMock) -> (TxResult, BlockchainMock) {
+    let (mut tx_result, state) = execute_sc_call_uncounted(tx_input, state);
+    tx_result.reentrant_calls = TxContextStack::static_take_reentrant_calls();
+    (tx_result, state)
+}
+
+/// Leaves the reentrant calls counter untouched,
+/// so that async calls and callbacks count towards the transaction that started them.
+fn execute_sc_call_uncounted(
+    tx_input: TxInput,
+    mut state: BlockchainMock,
+) -> (TxResult, BlockchainMock) {
     state.subtract_tx_gas(&tx_input.from, tx_input.gas_limit, tx_input.gas_price);
 
     let state_rc = Rc::new(state);
     let tx_cache = TxCache::new(state_rc.clone());
-    let (tx_result, blockchain_updates) = execute_builtin_function_or_default(tx_input, tx_cache);
+    let (tx_result, blockchain_updates) =
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            execute_builtin_function_or_default(tx_input, tx_cache)
+        }))
+        .unwrap_or_else(|panic_any| {
+            // failed transfers outside of contract execution, e.g. from frozen accounts
+            match panic_any.downcast::<TxPanic>() {
+                Ok(panic_obj) => (
+                    TxResult::from_panic_obj(&panic_obj),
+                    BlockchainUpdate::empty(),
+                ),
+                Err(panic_any) => std::panic::resume_unwind(panic_any),
+            }
+        });
 
     let mut state = Rc::try_unwrap(state_rc).unwrap();
     if tx_result.result_status == 0 {
@@ -41,14 +67,14 @@ pub fn execute_async_call_and_callback(
     async_data: AsyncCallTxData,
     state: BlockchainMock,
 ) -> (TxResult, TxResult, BlockchainMock) {
-    if state.accounts.contains_key(&async_data.to) {
+    if state.account_exists(&async_data.to) {
         let async_input = async_call_tx_input(&async_data);
 
-        let (async_result, state) = sc_call_with_async_and_callback(async_input, state);
+        let (async_result, state) = sc_call_with_async_and_callback_uncounted(async_input, state);
 
         let callback_input =
             async_callback_tx_input(&async_data, &async_result, &state.builtin_functions);
-        let (callback_result, state) = execute_sc_call(callback_input, state);
+        let (callback_result, state) = execute_sc_call_uncounted(callback_input, state);
         assert!(
             callback_result.pending_calls.async_call.is_none(),
             "successive asyncs currently not supported"
@@ -77,13 +103,22 @@ pub fn execute_async_call_and_callback(
     }
 }
 
-// TODO: refactor
 pub fn sc_call_with_async_and_callback(
     tx_input: TxInput,
     state: BlockchainMock,
+) -> (TxResult, BlockchainMock) {
+    let (mut tx_result, state) = sc_call_with_async_and_callback_uncounted(tx_input, state);
+    tx_result.reentrant_calls = TxContextStack::static_take_reentrant_calls();
+    (tx_result, state)
+}
+
+// TODO: refactor
+fn sc_call_with_async_and_callback_uncounted(
+    tx_input: TxInput,
+    state: BlockchainMock,
 ) -> (TxResult, BlockchainMock) {
     let contract_address = tx_input.to.clone();
-    let (mut tx_result, mut state) = execute_sc_call(tx_input, state);
+    let (mut tx_result, mut state) = execute_sc_call_uncounted(tx_input, state);
 
     // take & clear pending calls
     let pending_calls = std::mem::replace(&mut tx_result.pending_calls, TxResultCalls::empty());
@@ -122,12 +157,12 @@ pub fn execute_promise_call_and_callback(
     promise: &Promise,
     state: BlockchainMock,
 ) -> (TxResult, TxResult, BlockchainMock) {
-    if state.accounts.contains_key(&promise.call.to) {
+    if state.account_exists(&promise.call.to) {
         let async_input = async_call_tx_input(&promise.call);
-        let (async_result, state) = sc_call_with_async_and_callback(async_input, state);
+        let (async_result, state) = sc_call_with_async_and_callback_uncounted(async_input, state);
 
         let callback_input = async_promise_tx_input(address, promise, &async_result);
-        let (callback_result, state) = execute_sc_call(callback_input, state);
+        let (callback_result, state) = execute_sc_call_uncounted(callback_input, state);
         assert!(
             callback_result.pending_calls.promises.is_empty(),
             "successive promises currently not supported"
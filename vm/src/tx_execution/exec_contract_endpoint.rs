@@ -35,8 +35,9 @@ fn execute_tx_context_rc(tx_context_rc: Rc<TxContext>) -> (Rc<TxContext>, TxResu
     let contract_container =
         contract_map.get_contract(contract_identifier.as_slice(), tx_context_ref.clone());
 
+    TxContextStack::static_check_reentrancy(&tx_context_ref.tx_input_box.to);
     TxContextStack::static_push(tx_context_rc.clone());
-    let tx_result = execute_contract_instance_endpoint(contract_container, func_name);
+    let tx_result = execute_contract_instance_endpoint(&contract_container, func_name);
 
     let tx_context_rc = TxContextStack::static_pop();
     (tx_context_rc, tx_result)
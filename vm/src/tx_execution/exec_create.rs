@@ -3,7 +3,7 @@ use std::rc::Rc;
 use multiversx_sc::types::Address;
 
 use crate::{
-    tx_mock::{TxCache, TxInput, TxResult},
+    tx_mock::{TxCache, TxContextStack, TxInput, TxResult},
     world_mock::BlockchainMock,
 };
 
@@ -21,8 +21,9 @@ pub fn sc_create(
 
     let state_rc = Rc::new(state);
     let tx_cache = TxCache::new(state_rc.clone());
-    let (tx_result, new_address, blockchain_updates) =
+    let (mut tx_result, new_address, blockchain_updates) =
         deploy_contract(tx_input, contract_path.to_vec(), tx_cache);
+    tx_result.reentrant_calls = TxContextStack::static_take_reentrant_calls();
     let mut state = Rc::try_unwrap(state_rc).unwrap();
 
     blockchain_updates.apply(&mut state);
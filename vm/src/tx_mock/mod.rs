@@ -5,6 +5,7 @@ mod tx_async_call_data;
 mod tx_async_promise;
 mod tx_cache;
 mod tx_cache_balance_util;
+mod tx_cache_esdt_global_settings;
 mod tx_cache_source;
 mod tx_context;
 mod tx_context_ref;
@@ -22,6 +23,7 @@ pub use blockchain_rng::*;
 pub use tx_async_call_data::*;
 pub use tx_async_promise::*;
 pub use tx_cache::*;
+pub use tx_cache_esdt_global_settings::*;
 pub use tx_cache_source::*;
 pub use tx_context::*;
 pub use tx_context_ref::*;
@@ -39,7 +39,7 @@ impl TxCache {
         self.source_ref.blockchain_ref()
     }
 
-    fn load_account_if_necessary(&self, address: &Address) {
+    pub(super) fn load_account_if_necessary(&self, address: &Address) {
         let mut accounts_mut = self.accounts.borrow_mut();
         if !accounts_mut.contains_key(address) {
             if let Some(blockchain_account) = self.source_ref.load_account(address) {
@@ -1,9 +1,11 @@
 use crate::num_bigint::BigUint;
-use multiversx_sc::types::heap::Address;
+use multiversx_sc::{esdt::ESDT_SYSTEM_SC_ADDRESS_ARRAY, types::heap::Address};
 
 use crate::{tx_mock::TxPanic, world_mock::EsdtInstanceMetadata};
 
-use super::TxCache;
+use super::{EsdtGlobalSetting, TxCache, EGLD_000000_TOKEN_IDENTIFIER};
+
+const ESDT_ROLE_TRANSFER: &[u8] = b"ESDTTransferRole";
 
 impl TxCache {
     pub fn subtract_egld_balance(&self, address: &Address, call_value: &BigUint) {
@@ -48,6 +50,9 @@ impl TxCache {
             let esdt_data = esdt_data_map
                 .get_mut_by_identifier(esdt_token_identifier)
                 .unwrap_or_else(|| panic_insufficient_funds());
+            if esdt_data.frozen {
+                panic_frozen();
+            }
 
             let esdt_instances = &mut esdt_data.instances;
             let esdt_instance = esdt_instances
@@ -73,6 +78,9 @@ impl TxCache {
         esdt_metadata: EsdtInstanceMetadata,
     ) {
         self.with_account_mut(address, |account| {
+            if account.esdt.is_frozen(esdt_token_identifier) {
+                panic_frozen();
+            }
             account.esdt.increase_balance(
                 esdt_token_identifier.to_vec(),
                 nonce,
@@ -90,12 +98,55 @@ impl TxCache {
         nonce: u64,
         value: &BigUint,
     ) {
+        if esdt_token_identifier == EGLD_000000_TOKEN_IDENTIFIER {
+            self.subtract_egld_balance(from, value);
+            self.increase_egld_balance(to, value);
+            return;
+        }
+
+        self.check_limited_transfer(from, to, esdt_token_identifier);
+
         let metadata = self.subtract_esdt_balance(from, esdt_token_identifier, nonce, value);
 
         self.increase_esdt_balance(to, esdt_token_identifier, nonce, value, metadata);
     }
 }
 
+impl TxCache {
+    /// Tokens with limited transfer can only be sent by or to accounts that have the transfer role.
+    fn check_limited_transfer(&self, from: &Address, to: &Address, esdt_token_identifier: &[u8]) {
+        if !self
+            .is_esdt_global_setting_set(esdt_token_identifier, EsdtGlobalSetting::LimitedTransfer)
+            || from.as_array() == &ESDT_SYSTEM_SC_ADDRESS_ARRAY
+        {
+            return;
+        }
+
+        let has_transfer_role = |address: &Address| {
+            self.with_account(address, |account| {
+                account
+                    .esdt
+                    .get_roles(esdt_token_identifier)
+                    .iter()
+                    .any(|role| role.as_slice() == ESDT_ROLE_TRANSFER)
+            })
+        };
+        if !has_transfer_role(from) && !has_transfer_role(to) {
+            std::panic::panic_any(TxPanic {
+                status: 10,
+                message: "action is not allowed".to_string(),
+            });
+        }
+    }
+}
+
+fn panic_frozen() -> ! {
+    std::panic::panic_any(TxPanic {
+        status: 10,
+        message: "account is frozen for this esdt token".to_string(),
+    });
+}
+
 fn panic_insufficient_funds() -> ! {
     std::panic::panic_any(TxPanic {
         status: 10,
@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+
+use crate::num_bigint::BigUint;
+use multiversx_sc::{storage::protected_keys::ELROND_ESDT_KEY, types::heap::Address};
+use num_traits::Zero;
+
+use crate::world_mock::{AccountData, AccountEsdt};
+
+use super::TxCache;
+
+/// Account that holds the settings that apply to a token on all accounts, same as on the real blockchain.
+pub const ESDT_SYSTEM_ACCOUNT_ADDRESS: [u8; 32] = [0xff; 32];
+
+/// The token settings that are not tied to a specific account.
+///
+/// They are stored as bit flags, in the first byte of the global metadata of the token.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EsdtGlobalSetting {
+    LimitedTransfer,
+    BurnRoleForAll,
+}
+
+impl EsdtGlobalSetting {
+    fn flag(self) -> u8 {
+        match self {
+            EsdtGlobalSetting::LimitedTransfer => 2,
+            EsdtGlobalSetting::BurnRoleForAll => 4,
+        }
+    }
+}
+
+fn global_settings_key(token_identifier: &[u8]) -> Vec<u8> {
+    [ELROND_ESDT_KEY, token_identifier].concat()
+}
+
+impl TxCache {
+    pub fn is_esdt_global_setting_set(
+        &self,
+        token_identifier: &[u8],
+        setting: EsdtGlobalSetting,
+    ) -> bool {
+        let system_account_address = Address::from(ESDT_SYSTEM_ACCOUNT_ADDRESS);
+        self.load_account_if_necessary(&system_account_address);
+        let accounts = self.accounts.borrow();
+        let Some(system_account) = accounts.get(&system_account_address) else {
+            return false;
+        };
+        system_account
+            .storage
+            .get(&global_settings_key(token_identifier))
+            .and_then(|metadata| metadata.first())
+            .map_or(false, |flags| flags & setting.flag() != 0)
+    }
+
+    pub fn set_esdt_global_setting(
+        &self,
+        token_identifier: &[u8],
+        setting: EsdtGlobalSetting,
+        value: bool,
+    ) {
+        let system_account_address = Address::from(ESDT_SYSTEM_ACCOUNT_ADDRESS);
+        self.load_account_if_necessary(&system_account_address);
+        let mut accounts = self.accounts.borrow_mut();
+        let system_account = accounts
+            .entry(system_account_address.clone())
+            .or_insert_with(|| AccountData {
+                address: system_account_address,
+                nonce: 0,
+                egld_balance: BigUint::zero(),
+                esdt: AccountEsdt::default(),
+                username: Vec::new(),
+                storage: HashMap::new(),
+                contract_path: None,
+                contract_owner: None,
+                developer_rewards: BigUint::zero(),
+            });
+
+        let metadata = system_account
+            .storage
+            .entry(global_settings_key(token_identifier))
+            .or_insert_with(|| vec![0u8; 2]);
+        if value {
+            metadata[0] |= setting.flag();
+        } else {
+            metadata[0] &= !setting.flag();
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use super::TxContext;
+use multiversx_sc::types::Address;
 
 use std::{cell::RefCell, rc::Rc};
 
@@ -7,27 +8,58 @@ thread_local!(
 );
 
 #[derive(Debug, Default)]
-pub struct TxContextStack(Vec<Rc<TxContext>>);
+pub struct TxContextStack {
+    contexts: Vec<Rc<TxContext>>,
+
+    /// Counts how many times a contract was entered while it was already executing further down the stack.
+    ///
+    /// Is reset whenever it is retrieved.
+    reentrant_calls: u64,
+}
 
 impl TxContextStack {
     pub fn static_peek() -> Rc<TxContext> {
         API_STACK.with(|cell| {
             let stack = cell.borrow();
-            stack.0.last().unwrap().clone()
+            stack.contexts.last().unwrap().clone()
         })
     }
 
     pub fn static_push(tx_context_rc: Rc<TxContext>) {
         API_STACK.with(|cell| {
             let mut stack = cell.borrow_mut();
-            stack.0.push(tx_context_rc);
+            stack.contexts.push(tx_context_rc);
         })
     }
 
     pub fn static_pop() -> Rc<TxContext> {
         API_STACK.with(|cell| {
             let mut stack = cell.borrow_mut();
-            stack.0.pop().unwrap()
+            stack.contexts.pop().unwrap()
+        })
+    }
+
+    /// Records a reentrant call if the contract at the given address is already being executed.
+    ///
+    /// Must be called before pushing the context of the new execution.
+    pub fn static_check_reentrancy(address: &Address) {
+        API_STACK.with(|cell| {
+            let mut stack = cell.borrow_mut();
+            if stack
+                .contexts
+                .iter()
+                .any(|tx_context| &tx_context.tx_input_box.to == address)
+            {
+                stack.reentrant_calls += 1;
+            }
+        })
+    }
+
+    /// Retrieves the number of reentrant calls recorded since the last call, and resets it.
+    pub fn static_take_reentrant_calls() -> u64 {
+        API_STACK.with(|cell| {
+            let mut stack = cell.borrow_mut();
+            core::mem::take(&mut stack.reentrant_calls)
         })
     }
 }
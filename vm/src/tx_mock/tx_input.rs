@@ -65,6 +65,9 @@ impl TxInput {
     }
 }
 
+/// Token identifier that signals an EGLD transfer within a multi-ESDT transfer.
+pub const EGLD_000000_TOKEN_IDENTIFIER: &[u8] = b"EGLD-000000";
+
 /// Models ESDT transfers between accounts.
 #[derive(Clone, Debug)]
 pub struct TxTokenTransfer {
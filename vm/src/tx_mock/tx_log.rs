@@ -1,6 +1,9 @@
 use crate::scenario::model::Checkable;
 use alloc::vec::Vec;
-use multiversx_sc::types::heap::Address;
+use multiversx_sc::{
+    codec::{TopDecode, TopDecodeMulti},
+    types::heap::Address,
+};
 
 use super::TxFunctionName;
 
@@ -13,6 +16,24 @@ pub struct TxLog {
 }
 
 impl TxLog {
+    /// The first topic of logs emitted by `#[event]` methods.
+    pub fn event_identifier(&self) -> &[u8] {
+        self.topics.first().map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Decodes the indexed arguments of an event, i.e. all topics except the event identifier.
+    pub fn decode_event_indexed_args<T: TopDecodeMulti>(&self) -> T {
+        let mut topics = self.topics.iter().skip(1).cloned().collect::<Vec<_>>();
+        T::multi_decode(&mut topics).unwrap()
+    }
+
+    /// Decodes the non-indexed arguments of an event.
+    ///
+    /// Events with several non-indexed arguments can be decoded as a tuple of them, or as an equivalent struct.
+    pub fn decode_event_data<D: TopDecode>(&self) -> D {
+        D::top_decode(self.data.as_slice()).unwrap()
+    }
+
     pub fn scenario_check(&self, check_log: &crate::scenario::model::CheckLog) -> bool {
         check_log.address.check(self.address.as_bytes())
             && check_log.endpoint.check(&self.endpoint)
@@ -21,6 +21,11 @@ pub struct TxResult {
     ///
     /// Is never cleared of its contents.
     pub all_calls: Vec<AsyncCallTxData>,
+
+    /// How many times a contract was called while it was already executing, during the tx.
+    ///
+    /// Recorded by the debugger, irrespective of whether the reentrant calls succeeded or not.
+    pub reentrant_calls: u64,
 }
 
 impl Default for TxResult {
@@ -32,6 +37,7 @@ impl Default for TxResult {
             result_logs: Vec::new(),
             pending_calls: TxResultCalls::empty(),
             all_calls: Vec::new(),
+            reentrant_calls: 0,
         }
     }
 }
@@ -0,0 +1,5 @@
+mod vm_hooks;
+mod wasm_contract;
+mod wasm_memory;
+
+pub use wasm_contract::{is_wasm_code, WasmContract};
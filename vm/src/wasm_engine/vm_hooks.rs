@@ -0,0 +1,996 @@
+use core::cmp::Ordering;
+
+use multiversx_sc::{
+    api::{
+        BigIntApi, BlockchainApiImpl, CallValueApiImpl, CryptoApiImpl, EndpointArgumentApiImpl,
+        EndpointFinishApiImpl, ErrorApiImpl, HandleConstraints, LogApiImpl, ManagedBufferApi,
+        ManagedTypeApiImpl, SendApiImpl, Sign, StorageReadApiImpl, StorageWriteApiImpl,
+    },
+    types::{
+        heap::{Address, ArgBuffer},
+        BigUint, CodeMetadata, EsdtTokenPayment, ManagedAddress, ManagedArgBuffer, ManagedBuffer,
+        ManagedType, ManagedVec,
+    },
+};
+use wasmi::{errors::LinkerError, Caller, Linker};
+
+use crate::{api::DebugHandle, DebugApi};
+
+use super::wasm_memory::{load_bytes, store_bytes, vm_error};
+
+const ENV: &str = "env";
+
+const ADDRESS_LENGTH: i32 = 32;
+const LEGACY_TOPIC_LENGTH: usize = 32;
+
+fn api() -> DebugApi {
+    DebugApi::new_from_static()
+}
+
+fn handle(raw_handle: i32) -> DebugHandle {
+    DebugHandle::from(raw_handle)
+}
+
+fn load_address(caller: &Caller<'_, ()>, offset: i32) -> Address {
+    Address::from_slice(&load_bytes(caller, offset, ADDRESS_LENGTH))
+}
+
+/// The VM writes results into handles provided by the contract, instead of creating new ones.
+fn copy_to_handle<T: ManagedType<DebugApi>>(dest: i32, value: &T) {
+    let api = api();
+    let bytes = api.mb_to_boxed_bytes(handle(value.get_raw_handle()));
+    api.mb_overwrite(handle(dest), bytes.as_slice());
+}
+
+fn load_code_metadata(code_metadata: i32) -> CodeMetadata {
+    let mut bytes = [0u8; 2];
+    api().mb_copy_to_slice_pad_right(handle(code_metadata), &mut bytes);
+    CodeMetadata::from(bytes)
+}
+
+/// Callback names are static in the framework API, since they normally come from the contract code.
+fn load_callback_name(caller: &Caller<'_, ()>, offset: i32, length: i32) -> &'static str {
+    let name = String::from_utf8(load_bytes(caller, offset, length))
+        .unwrap_or_else(|_| vm_error("invalid callback name"));
+    Box::leak(name.into_boxed_str())
+}
+
+fn status_code<E>(result: Result<(), E>) -> i32 {
+    match result {
+        Ok(()) => 0,
+        Err(_) => 1,
+    }
+}
+
+/// Registers all VM hooks implemented by the engine, under the `env` module, as the contracts expect them.
+///
+/// Each hook forwards to the `DebugApi` of the current transaction context,
+/// which also makes it possible to mix wasm contracts and Rust contracts in the same scenario.
+pub(super) fn define_vm_hooks(linker: &mut Linker<()>) -> Result<(), LinkerError> {
+    define_argument_hooks(linker)?;
+    define_finish_hooks(linker)?;
+    define_error_hooks(linker)?;
+    define_storage_hooks(linker)?;
+    define_managed_buffer_hooks(linker)?;
+    define_big_int_hooks(linker)?;
+    define_blockchain_hooks(linker)?;
+    define_call_value_hooks(linker)?;
+    define_send_hooks(linker)?;
+    define_contract_call_hooks(linker)?;
+    define_deploy_hooks(linker)?;
+    define_log_hooks(linker)?;
+    define_crypto_hooks(linker)?;
+    Ok(())
+}
+
+fn define_argument_hooks(linker: &mut Linker<()>) -> Result<(), LinkerError> {
+    linker.func_wrap(ENV, "getNumArguments", || api().get_num_arguments())?;
+    linker.func_wrap(ENV, "getArgumentLength", |arg_index: i32| {
+        api().get_argument_len(arg_index) as i32
+    })?;
+    linker.func_wrap(
+        ENV,
+        "getArgument",
+        |mut caller: Caller<'_, ()>, arg_index: i32, dest_offset: i32| {
+            let arg = api().get_argument_boxed_bytes(arg_index);
+            store_bytes(&mut caller, dest_offset, arg.as_slice());
+            arg.len() as i32
+        },
+    )?;
+    linker.func_wrap(ENV, "mBufferGetArgument", |arg_index: i32, dest: i32| {
+        api().load_argument_managed_buffer(arg_index, handle(dest));
+        0
+    })?;
+    linker.func_wrap(
+        ENV,
+        "bigIntGetUnsignedArgument",
+        |arg_index: i32, dest: i32| {
+            api().load_argument_big_int_unsigned(arg_index, handle(dest));
+        },
+    )?;
+    linker.func_wrap(
+        ENV,
+        "bigIntGetSignedArgument",
+        |arg_index: i32, dest: i32| {
+            api().load_argument_big_int_signed(arg_index, handle(dest));
+        },
+    )?;
+    linker.func_wrap(ENV, "smallIntGetUnsignedArgument", |arg_index: i32| {
+        api().get_argument_u64(arg_index) as i64
+    })?;
+    linker.func_wrap(ENV, "smallIntGetSignedArgument", |arg_index: i32| {
+        api().get_argument_i64(arg_index)
+    })?;
+    Ok(())
+}
+
+fn define_finish_hooks(linker: &mut Linker<()>) -> Result<(), LinkerError> {
+    linker.func_wrap(
+        ENV,
+        "finish",
+        |caller: Caller<'_, ()>, data_offset: i32, length: i32| {
+            api().finish_slice_u8(&load_bytes(&caller, data_offset, length));
+        },
+    )?;
+    linker.func_wrap(ENV, "bigIntFinishUnsigned", |source: i32| {
+        api().finish_big_uint_raw(handle(source));
+    })?;
+    linker.func_wrap(ENV, "bigIntFinishSigned", |source: i32| {
+        api().finish_big_int_raw(handle(source));
+    })?;
+    linker.func_wrap(ENV, "smallIntFinishUnsigned", |value: i64| {
+        api().finish_u64(value as u64);
+    })?;
+    linker.func_wrap(ENV, "smallIntFinishSigned", |value: i64| {
+        api().finish_i64(value);
+    })?;
+    linker.func_wrap(ENV, "mBufferFinish", |source: i32| {
+        api().finish_managed_buffer_raw(handle(source));
+        0
+    })?;
+    Ok(())
+}
+
+fn define_error_hooks(linker: &mut Linker<()>) -> Result<(), LinkerError> {
+    linker.func_wrap(
+        ENV,
+        "signalError",
+        |caller: Caller<'_, ()>, message_offset: i32, message_length: i32| {
+            api().signal_error(&load_bytes(&caller, message_offset, message_length));
+        },
+    )?;
+    linker.func_wrap(ENV, "managedSignalError", |message_handle: i32| {
+        api().signal_error_from_buffer(handle(message_handle));
+    })?;
+    Ok(())
+}
+
+fn define_storage_hooks(linker: &mut Linker<()>) -> Result<(), LinkerError> {
+    linker.func_wrap(
+        ENV,
+        "storageStore",
+        |caller: Caller<'_, ()>,
+         key_offset: i32,
+         key_length: i32,
+         data_offset: i32,
+         data_length: i32| {
+            let key = load_bytes(&caller, key_offset, key_length);
+            let value = load_bytes(&caller, data_offset, data_length);
+            api().storage_store_slice_u8(&key, &value);
+            0
+        },
+    )?;
+    linker.func_wrap(
+        ENV,
+        "storageLoadLength",
+        |caller: Caller<'_, ()>, key_offset: i32, key_length: i32| {
+            let key = load_bytes(&caller, key_offset, key_length);
+            api().storage_load_len(&key) as i32
+        },
+    )?;
+    linker.func_wrap(
+        ENV,
+        "storageLoad",
+        |mut caller: Caller<'_, ()>, key_offset: i32, key_length: i32, dest_offset: i32| {
+            let key = load_bytes(&caller, key_offset, key_length);
+            let value = api().storage_load_to_heap(&key);
+            store_bytes(&mut caller, dest_offset, &value);
+            value.len() as i32
+        },
+    )?;
+    linker.func_wrap(
+        ENV,
+        "bigIntStorageStoreUnsigned",
+        |caller: Caller<'_, ()>, key_offset: i32, key_length: i32, source: i32| {
+            let key = load_bytes(&caller, key_offset, key_length);
+            api().storage_store_big_uint_raw(&key, handle(source));
+            0
+        },
+    )?;
+    linker.func_wrap(
+        ENV,
+        "bigIntStorageLoadUnsigned",
+        |caller: Caller<'_, ()>, key_offset: i32, key_length: i32, dest: i32| {
+            let key = load_bytes(&caller, key_offset, key_length);
+            let api = api();
+            api.storage_load_big_uint_raw(&key, handle(dest));
+            api.bi_unsigned_byte_length(handle(dest)) as i32
+        },
+    )?;
+    linker.func_wrap(ENV, "mBufferStorageStore", |key: i32, source: i32| {
+        api().storage_store_managed_buffer_raw(handle(key), handle(source));
+        0
+    })?;
+    linker.func_wrap(ENV, "mBufferStorageLoad", |key: i32, dest: i32| {
+        api().storage_load_managed_buffer_raw(handle(key), handle(dest));
+        0
+    })?;
+    linker.func_wrap(
+        ENV,
+        "mBufferStorageLoadFromAddress",
+        |address: i32, key: i32, dest: i32| {
+            api().storage_load_from_address(handle(address), handle(key), handle(dest));
+        },
+    )?;
+    Ok(())
+}
+
+fn define_managed_buffer_hooks(linker: &mut Linker<()>) -> Result<(), LinkerError> {
+    linker.func_wrap(ENV, "mBufferNew", || api().mb_new_empty().get_raw_handle())?;
+    linker.func_wrap(
+        ENV,
+        "mBufferNewFromBytes",
+        |caller: Caller<'_, ()>, data_offset: i32, length: i32| {
+            api()
+                .mb_new_from_bytes(&load_bytes(&caller, data_offset, length))
+                .get_raw_handle()
+        },
+    )?;
+    linker.func_wrap(ENV, "mBufferGetLength", |source: i32| {
+        api().mb_len(handle(source)) as i32
+    })?;
+    linker.func_wrap(
+        ENV,
+        "mBufferGetBytes",
+        |mut caller: Caller<'_, ()>, source: i32, dest_offset: i32| {
+            let bytes = api().mb_to_boxed_bytes(handle(source));
+            store_bytes(&mut caller, dest_offset, bytes.as_slice());
+            0
+        },
+    )?;
+    linker.func_wrap(
+        ENV,
+        "mBufferGetByteSlice",
+        |mut caller: Caller<'_, ()>,
+         source: i32,
+         starting_position: i32,
+         slice_length: i32,
+         dest_offset: i32| {
+            if starting_position < 0 || slice_length < 0 {
+                return 1;
+            }
+            let mut slice = vec![0u8; slice_length as usize];
+            let result =
+                api().mb_load_slice(handle(source), starting_position as usize, &mut slice);
+            if result.is_ok() {
+                store_bytes(&mut caller, dest_offset, &slice);
+            }
+            status_code(result)
+        },
+    )?;
+    linker.func_wrap(
+        ENV,
+        "mBufferCopyByteSlice",
+        |source: i32, starting_position: i32, slice_length: i32, dest: i32| {
+            if starting_position < 0 || slice_length < 0 {
+                return 1;
+            }
+            status_code(api().mb_copy_slice(
+                handle(source),
+                starting_position as usize,
+                slice_length as usize,
+                handle(dest),
+            ))
+        },
+    )?;
+    linker.func_wrap(ENV, "mBufferEq", |handle_1: i32, handle_2: i32| {
+        api().mb_eq(handle(handle_1), handle(handle_2)) as i32
+    })?;
+    linker.func_wrap(
+        ENV,
+        "mBufferSetBytes",
+        |caller: Caller<'_, ()>, dest: i32, data_offset: i32, length: i32| {
+            api().mb_overwrite(handle(dest), &load_bytes(&caller, data_offset, length));
+            0
+        },
+    )?;
+    linker.func_wrap(
+        ENV,
+        "mBufferSetByteSlice",
+        |caller: Caller<'_, ()>,
+         dest: i32,
+         starting_position: i32,
+         data_length: i32,
+         data_offset: i32| {
+            if starting_position < 0 {
+                return 1;
+            }
+            let data = load_bytes(&caller, data_offset, data_length);
+            status_code(api().mb_set_slice(handle(dest), starting_position as usize, &data))
+        },
+    )?;
+    linker.func_wrap(ENV, "mBufferSetRandom", |dest: i32, length: i32| {
+        api().mb_set_random(handle(dest), length as usize);
+        0
+    })?;
+    linker.func_wrap(ENV, "mBufferAppend", |accumulator: i32, data: i32| {
+        api().mb_append(handle(accumulator), handle(data));
+        0
+    })?;
+    linker.func_wrap(
+        ENV,
+        "mBufferAppendBytes",
+        |caller: Caller<'_, ()>, accumulator: i32, data_offset: i32, length: i32| {
+            api().mb_append_bytes(
+                handle(accumulator),
+                &load_bytes(&caller, data_offset, length),
+            );
+            0
+        },
+    )?;
+    linker.func_wrap(ENV, "managedBufferToHex", |source: i32, dest: i32| {
+        api().mb_to_hex(handle(source), handle(dest));
+    })?;
+    linker.func_wrap(
+        ENV,
+        "mBufferToBigIntUnsigned",
+        |source: i32, dest_big_int: i32| {
+            api().mb_to_big_int_unsigned(handle(source), handle(dest_big_int));
+            0
+        },
+    )?;
+    linker.func_wrap(
+        ENV,
+        "mBufferToBigIntSigned",
+        |source: i32, dest_big_int: i32| {
+            api().mb_to_big_int_signed(handle(source), handle(dest_big_int));
+            0
+        },
+    )?;
+    linker.func_wrap(
+        ENV,
+        "mBufferFromBigIntUnsigned",
+        |dest: i32, source_big_int: i32| {
+            api().mb_from_big_int_unsigned(handle(source_big_int), handle(dest));
+            0
+        },
+    )?;
+    linker.func_wrap(
+        ENV,
+        "mBufferFromBigIntSigned",
+        |dest: i32, source_big_int: i32| {
+            api().mb_from_big_int_signed(handle(source_big_int), handle(dest));
+            0
+        },
+    )?;
+    linker.func_wrap(ENV, "validateTokenIdentifier", |token_id: i32| {
+        api().validate_token_identifier(handle(token_id)) as i32
+    })?;
+    Ok(())
+}
+
+macro_rules! define_big_int_binary_op {
+    ($linker:expr, $hook_name:literal, $method_name:ident) => {
+        $linker.func_wrap(ENV, $hook_name, |dest: i32, x: i32, y: i32| {
+            api().$method_name(handle(dest), handle(x), handle(y));
+        })?;
+    };
+}
+
+macro_rules! define_big_int_unary_op {
+    ($linker:expr, $hook_name:literal, $method_name:ident) => {
+        $linker.func_wrap(ENV, $hook_name, |dest: i32, x: i32| {
+            api().$method_name(handle(dest), handle(x));
+        })?;
+    };
+}
+
+fn define_big_int_hooks(linker: &mut Linker<()>) -> Result<(), LinkerError> {
+    linker.func_wrap(ENV, "bigIntNew", |value: i64| {
+        api().bi_new(value).get_raw_handle()
+    })?;
+    linker.func_wrap(ENV, "bigIntSetInt64", |dest: i32, value: i64| {
+        api().bi_set_int64(handle(dest), value);
+    })?;
+    linker.func_wrap(ENV, "bigIntIsInt64", |source: i32| {
+        api().bi_to_i64(handle(source)).is_some() as i32
+    })?;
+    linker.func_wrap(ENV, "bigIntGetInt64", |source: i32| {
+        api()
+            .bi_to_i64(handle(source))
+            .unwrap_or_else(|| vm_error("big int cannot be represented as int64"))
+    })?;
+
+    linker.func_wrap(ENV, "bigIntUnsignedByteLength", |source: i32| {
+        api().bi_unsigned_byte_length(handle(source)) as i32
+    })?;
+    linker.func_wrap(
+        ENV,
+        "bigIntGetUnsignedBytes",
+        |mut caller: Caller<'_, ()>, source: i32, dest_offset: i32| {
+            let bytes = api().bi_get_unsigned_bytes(handle(source));
+            store_bytes(&mut caller, dest_offset, bytes.as_slice());
+            bytes.len() as i32
+        },
+    )?;
+    linker.func_wrap(
+        ENV,
+        "bigIntSetUnsignedBytes",
+        |caller: Caller<'_, ()>, dest: i32, data_offset: i32, length: i32| {
+            api().bi_set_unsigned_bytes(handle(dest), &load_bytes(&caller, data_offset, length));
+        },
+    )?;
+    linker.func_wrap(ENV, "bigIntSignedByteLength", |source: i32| {
+        api().bi_signed_byte_length(handle(source)) as i32
+    })?;
+    linker.func_wrap(
+        ENV,
+        "bigIntGetSignedBytes",
+        |mut caller: Caller<'_, ()>, source: i32, dest_offset: i32| {
+            let bytes = api().bi_get_signed_bytes(handle(source));
+            store_bytes(&mut caller, dest_offset, bytes.as_slice());
+            bytes.len() as i32
+        },
+    )?;
+    linker.func_wrap(
+        ENV,
+        "bigIntSetSignedBytes",
+        |caller: Caller<'_, ()>, dest: i32, data_offset: i32, length: i32| {
+            api().bi_set_signed_bytes(handle(dest), &load_bytes(&caller, data_offset, length));
+        },
+    )?;
+
+    define_big_int_binary_op!(linker, "bigIntAdd", bi_add);
+    define_big_int_binary_op!(linker, "bigIntSub", bi_sub);
+    define_big_int_binary_op!(linker, "bigIntMul", bi_mul);
+    define_big_int_binary_op!(linker, "bigIntTDiv", bi_t_div);
+    define_big_int_binary_op!(linker, "bigIntTMod", bi_t_mod);
+    define_big_int_binary_op!(linker, "bigIntPow", bi_pow);
+    define_big_int_binary_op!(linker, "bigIntAnd", bi_and);
+    define_big_int_binary_op!(linker, "bigIntOr", bi_or);
+    define_big_int_binary_op!(linker, "bigIntXor", bi_xor);
+    define_big_int_unary_op!(linker, "bigIntAbs", bi_abs);
+    define_big_int_unary_op!(linker, "bigIntNeg", bi_neg);
+    define_big_int_unary_op!(linker, "bigIntSqrt", bi_sqrt);
+
+    linker.func_wrap(ENV, "bigIntShr", |dest: i32, x: i32, bits: i32| {
+        api().bi_shr(handle(dest), handle(x), bits as usize);
+    })?;
+    linker.func_wrap(ENV, "bigIntShl", |dest: i32, x: i32, bits: i32| {
+        api().bi_shl(handle(dest), handle(x), bits as usize);
+    })?;
+    linker.func_wrap(ENV, "bigIntSign", |x: i32| match api().bi_sign(handle(x)) {
+        Sign::Plus => 1,
+        Sign::NoSign => 0,
+        Sign::Minus => -1,
+    })?;
+    linker.func_wrap(ENV, "bigIntCmp", |x: i32, y: i32| {
+        match api().bi_cmp(handle(x), handle(y)) {
+            Ordering::Greater => 1,
+            Ordering::Equal => 0,
+            Ordering::Less => -1,
+        }
+    })?;
+    linker.func_wrap(ENV, "bigIntLog2", |x: i32| api().bi_log2(handle(x)) as i32)?;
+    linker.func_wrap(ENV, "bigIntToString", |source: i32, dest: i32| {
+        api().bi_to_string(handle(source), handle(dest));
+    })?;
+    Ok(())
+}
+
+fn define_blockchain_hooks(linker: &mut Linker<()>) -> Result<(), LinkerError> {
+    linker.func_wrap(
+        ENV,
+        "getSCAddress",
+        |mut caller: Caller<'_, ()>, dest_offset: i32| {
+            let address = api().get_sc_address_legacy();
+            store_bytes(&mut caller, dest_offset, address.as_bytes());
+        },
+    )?;
+    linker.func_wrap(
+        ENV,
+        "getOwnerAddress",
+        |mut caller: Caller<'_, ()>, dest_offset: i32| {
+            let address = api().get_owner_address_legacy();
+            store_bytes(&mut caller, dest_offset, address.as_bytes());
+        },
+    )?;
+    linker.func_wrap(
+        ENV,
+        "getCaller",
+        |mut caller: Caller<'_, ()>, dest_offset: i32| {
+            let address = api().get_caller_legacy();
+            store_bytes(&mut caller, dest_offset, address.as_bytes());
+        },
+    )?;
+    linker.func_wrap(ENV, "managedSCAddress", |dest: i32| {
+        api().load_sc_address_managed(handle(dest));
+    })?;
+    linker.func_wrap(ENV, "managedOwnerAddress", |dest: i32| {
+        api().load_owner_address_managed(handle(dest));
+    })?;
+    linker.func_wrap(ENV, "managedCaller", |dest: i32| {
+        api().load_caller_managed(handle(dest));
+    })?;
+    linker.func_wrap(
+        ENV,
+        "getShardOfAddress",
+        |caller: Caller<'_, ()>, address_offset: i32| {
+            let address = load_address(&caller, address_offset);
+            api().get_shard_of_address_legacy(&address) as i32
+        },
+    )?;
+    linker.func_wrap(
+        ENV,
+        "isSmartContract",
+        |caller: Caller<'_, ()>, address_offset: i32| {
+            let address = load_address(&caller, address_offset);
+            api().is_smart_contract_legacy(&address) as i32
+        },
+    )?;
+    linker.func_wrap(
+        ENV,
+        "bigIntGetExternalBalance",
+        |caller: Caller<'_, ()>, address_offset: i32, dest: i32| {
+            let address = load_address(&caller, address_offset);
+            api().load_balance_legacy(handle(dest), &address);
+        },
+    )?;
+    linker.func_wrap(
+        ENV,
+        "bigIntGetESDTExternalBalance",
+        |caller: Caller<'_, ()>,
+         address_offset: i32,
+         token_id_offset: i32,
+         token_id_length: i32,
+         nonce: i64,
+         dest: i32| {
+            let address = load_address(&caller, address_offset);
+            let token_id = load_bytes(&caller, token_id_offset, token_id_length);
+            let api = api();
+            let address_handle = api.mb_new_from_bytes(address.as_bytes());
+            let token_id_handle = api.mb_new_from_bytes(&token_id);
+            api.load_esdt_balance(address_handle, token_id_handle, nonce as u64, handle(dest));
+        },
+    )?;
+    linker.func_wrap(
+        ENV,
+        "getCurrentESDTNFTNonce",
+        |caller: Caller<'_, ()>,
+         address_offset: i32,
+         token_id_offset: i32,
+         token_id_length: i32| {
+            let address = load_address(&caller, address_offset);
+            let token_id = load_bytes(&caller, token_id_offset, token_id_length);
+            let api = api();
+            let address_handle = api.mb_new_from_bytes(address.as_bytes());
+            let token_id_handle = api.mb_new_from_bytes(&token_id);
+            api.get_current_esdt_nft_nonce(address_handle, token_id_handle) as i64
+        },
+    )?;
+
+    linker.func_wrap(ENV, "getGasLeft", || api().get_gas_left() as i64)?;
+    linker.func_wrap(ENV, "getBlockTimestamp", || {
+        api().get_block_timestamp() as i64
+    })?;
+    linker.func_wrap(ENV, "getBlockNonce", || api().get_block_nonce() as i64)?;
+    linker.func_wrap(ENV, "getBlockRound", || api().get_block_round() as i64)?;
+    linker.func_wrap(ENV, "getBlockEpoch", || api().get_block_epoch() as i64)?;
+    linker.func_wrap(ENV, "getPrevBlockTimestamp", || {
+        api().get_prev_block_timestamp() as i64
+    })?;
+    linker.func_wrap(ENV, "getPrevBlockNonce", || {
+        api().get_prev_block_nonce() as i64
+    })?;
+    linker.func_wrap(ENV, "getPrevBlockRound", || {
+        api().get_prev_block_round() as i64
+    })?;
+    linker.func_wrap(ENV, "getPrevBlockEpoch", || {
+        api().get_prev_block_epoch() as i64
+    })?;
+    linker.func_wrap(
+        ENV,
+        "getBlockRandomSeed",
+        |mut caller: Caller<'_, ()>, dest_offset: i32| {
+            let seed = api().get_block_random_seed_legacy();
+            store_bytes(&mut caller, dest_offset, &seed[..]);
+        },
+    )?;
+    linker.func_wrap(
+        ENV,
+        "getPrevBlockRandomSeed",
+        |mut caller: Caller<'_, ()>, dest_offset: i32| {
+            let seed = api().get_prev_block_random_seed_legacy();
+            store_bytes(&mut caller, dest_offset, &seed[..]);
+        },
+    )?;
+    linker.func_wrap(ENV, "managedGetBlockRandomSeed", |dest: i32| {
+        api().load_block_random_seed_managed(handle(dest));
+    })?;
+    linker.func_wrap(ENV, "managedGetPrevBlockRandomSeed", |dest: i32| {
+        api().load_prev_block_random_seed_managed(handle(dest));
+    })?;
+    linker.func_wrap(
+        ENV,
+        "getOriginalTxHash",
+        |mut caller: Caller<'_, ()>, dest_offset: i32| {
+            let tx_hash = api().get_tx_hash_legacy();
+            store_bytes(&mut caller, dest_offset, tx_hash.as_bytes());
+        },
+    )?;
+    linker.func_wrap(ENV, "managedGetOriginalTxHash", |dest: i32| {
+        api().load_tx_hash_managed(handle(dest));
+    })?;
+    Ok(())
+}
+
+fn define_call_value_hooks(linker: &mut Linker<()>) -> Result<(), LinkerError> {
+    linker.func_wrap(ENV, "checkNoPayment", || api().check_not_payable())?;
+    linker.func_wrap(ENV, "bigIntGetCallValue", |dest: i32| {
+        api().load_egld_value(handle(dest));
+    })?;
+    linker.func_wrap(ENV, "managedGetMultiESDTCallValue", |dest: i32| {
+        api().load_all_esdt_transfers(handle(dest));
+    })?;
+    linker.func_wrap(ENV, "getNumESDTTransfers", || {
+        api().esdt_num_transfers() as i32
+    })?;
+
+    linker.func_wrap(ENV, "bigIntGetESDTCallValue", |dest: i32| {
+        api().load_single_esdt_value(handle(dest));
+    })?;
+    linker.func_wrap(
+        ENV,
+        "getESDTTokenName",
+        |mut caller: Caller<'_, ()>, dest_offset: i32| {
+            let api = api();
+            let Some(token_id_handle) = api.token() else {
+                return 0;
+            };
+            let token_id = api.mb_to_boxed_bytes(token_id_handle);
+            store_bytes(&mut caller, dest_offset, token_id.as_slice());
+            token_id.len() as i32
+        },
+    )?;
+    linker.func_wrap(ENV, "getESDTTokenNonce", || api().esdt_token_nonce() as i64)?;
+    linker.func_wrap(ENV, "getESDTTokenType", || {
+        api().esdt_token_type().as_u8() as i32
+    })?;
+
+    linker.func_wrap(
+        ENV,
+        "bigIntGetESDTCallValueByIndex",
+        |dest: i32, index: i32| {
+            let api = api();
+            let value_handle = api.esdt_value_by_index(index as usize);
+            let value = api.bi_get_unsigned_bytes(value_handle);
+            api.bi_set_unsigned_bytes(handle(dest), value.as_slice());
+        },
+    )?;
+    linker.func_wrap(
+        ENV,
+        "getESDTTokenNameByIndex",
+        |mut caller: Caller<'_, ()>, dest_offset: i32, index: i32| {
+            let api = api();
+            let token_id = api.mb_to_boxed_bytes(api.token_by_index(index as usize));
+            store_bytes(&mut caller, dest_offset, token_id.as_slice());
+            token_id.len() as i32
+        },
+    )?;
+    linker.func_wrap(ENV, "getESDTTokenNonceByIndex", |index: i32| {
+        api().esdt_token_nonce_by_index(index as usize) as i64
+    })?;
+    linker.func_wrap(ENV, "getESDTTokenTypeByIndex", |index: i32| {
+        api().esdt_token_type_by_index(index as usize).as_u8() as i32
+    })?;
+    Ok(())
+}
+
+fn define_send_hooks(linker: &mut Linker<()>) -> Result<(), LinkerError> {
+    linker.func_wrap(
+        ENV,
+        "managedTransferValueExecute",
+        |to: i32, amount: i32, gas_limit: i64, endpoint_name: i32, arguments: i32| {
+            status_code(api().transfer_value_execute(
+                &ManagedAddress::<DebugApi>::from_handle(handle(to)),
+                &BigUint::from_handle(handle(amount)),
+                gas_limit as u64,
+                &ManagedBuffer::from_handle(handle(endpoint_name)),
+                &ManagedArgBuffer::from_handle(handle(arguments)),
+            ))
+        },
+    )?;
+    linker.func_wrap(
+        ENV,
+        "managedMultiTransferESDTNFTExecute",
+        |to: i32, payments: i32, gas_limit: i64, endpoint_name: i32, arguments: i32| {
+            let api = api();
+            let to = ManagedAddress::<DebugApi>::from_handle(handle(to));
+            let payments =
+                ManagedVec::<DebugApi, EsdtTokenPayment<DebugApi>>::from_handle(handle(payments));
+            let endpoint_name = ManagedBuffer::from_handle(handle(endpoint_name));
+            let arguments = ManagedArgBuffer::from_handle(handle(arguments));
+
+            // same as the VM, single transfers are performed via the single transfer built-in functions
+            if payments.len() != 1 {
+                return status_code(api.multi_transfer_esdt_nft_execute(
+                    &to,
+                    &payments,
+                    gas_limit as u64,
+                    &endpoint_name,
+                    &arguments,
+                ));
+            }
+
+            let payment = payments.get(0);
+            let result = if payment.token_nonce == 0 {
+                api.transfer_esdt_execute(
+                    &to,
+                    &payment.token_identifier,
+                    &payment.amount,
+                    gas_limit as u64,
+                    &endpoint_name,
+                    &arguments,
+                )
+            } else {
+                api.transfer_esdt_nft_execute(
+                    &to,
+                    &payment.token_identifier,
+                    payment.token_nonce,
+                    &payment.amount,
+                    gas_limit as u64,
+                    &endpoint_name,
+                    &arguments,
+                )
+            };
+            status_code(result)
+        },
+    )?;
+    Ok(())
+}
+
+/// Sync calls, async calls and promises run the called contract in the same VM,
+/// whether it is a wasm contract or a contract compiled to Rust.
+fn define_contract_call_hooks(linker: &mut Linker<()>) -> Result<(), LinkerError> {
+    linker.func_wrap(
+        ENV,
+        "managedExecuteOnDestContext",
+        |gas: i64, address: i32, value: i32, function: i32, arguments: i32, result: i32| {
+            let results = api().execute_on_dest_context_raw(
+                gas as u64,
+                &ManagedAddress::<DebugApi>::from_handle(handle(address)),
+                &BigUint::from_handle(handle(value)),
+                &ManagedBuffer::from_handle(handle(function)),
+                &ManagedArgBuffer::from_handle(handle(arguments)),
+            );
+            copy_to_handle(result, &results);
+            0
+        },
+    )?;
+    linker.func_wrap(
+        ENV,
+        "managedAsyncCall",
+        |destination: i32, value: i32, function: i32, arguments: i32| {
+            api().async_call_raw(
+                &ManagedAddress::<DebugApi>::from_handle(handle(destination)),
+                &BigUint::from_handle(handle(value)),
+                &ManagedBuffer::from_handle(handle(function)),
+                &ManagedArgBuffer::from_handle(handle(arguments)),
+            );
+        },
+    )?;
+    linker.func_wrap(
+        ENV,
+        "managedCreateAsyncCall",
+        |caller: Caller<'_, ()>,
+         destination: i32,
+         value: i32,
+         function: i32,
+         arguments: i32,
+         success_offset: i32,
+         success_length: i32,
+         error_offset: i32,
+         error_length: i32,
+         gas: i64,
+         extra_gas_for_callback: i64,
+         callback_closure: i32| {
+            let success_callback = load_callback_name(&caller, success_offset, success_length);
+            let error_callback = load_callback_name(&caller, error_offset, error_length);
+            api().create_async_call_raw(
+                handle(destination),
+                handle(value),
+                handle(function),
+                handle(arguments),
+                success_callback,
+                error_callback,
+                gas as u64,
+                extra_gas_for_callback as u64,
+                handle(callback_closure),
+            );
+            0
+        },
+    )?;
+    linker.func_wrap(ENV, "cleanReturnData", || api().clean_return_data())?;
+    linker.func_wrap(ENV, "deleteFromReturnData", |result_index: i32| {
+        api().delete_from_return_data(result_index as usize);
+    })?;
+    Ok(())
+}
+
+fn define_deploy_hooks(linker: &mut Linker<()>) -> Result<(), LinkerError> {
+    linker.func_wrap(
+        ENV,
+        "managedCreateContract",
+        |gas: i64,
+         value: i32,
+         code: i32,
+         code_metadata: i32,
+         arguments: i32,
+         result_address: i32,
+         result: i32| {
+            let (new_address, results) = api().deploy_contract(
+                gas as u64,
+                &BigUint::<DebugApi>::from_handle(handle(value)),
+                &ManagedBuffer::from_handle(handle(code)),
+                load_code_metadata(code_metadata),
+                &ManagedArgBuffer::from_handle(handle(arguments)),
+            );
+            copy_to_handle(result_address, &new_address);
+            copy_to_handle(result, &results);
+            0
+        },
+    )?;
+    linker.func_wrap(
+        ENV,
+        "managedDeployFromSourceContract",
+        |gas: i64,
+         value: i32,
+         source_address: i32,
+         code_metadata: i32,
+         arguments: i32,
+         result_address: i32,
+         result: i32| {
+            let (new_address, results) = api().deploy_from_source_contract(
+                gas as u64,
+                &BigUint::<DebugApi>::from_handle(handle(value)),
+                &ManagedAddress::from_handle(handle(source_address)),
+                load_code_metadata(code_metadata),
+                &ManagedArgBuffer::from_handle(handle(arguments)),
+            );
+            copy_to_handle(result_address, &new_address);
+            copy_to_handle(result, &results);
+            0
+        },
+    )?;
+    linker.func_wrap(
+        ENV,
+        "managedUpgradeContract",
+        |destination: i32,
+         gas: i64,
+         value: i32,
+         code: i32,
+         code_metadata: i32,
+         arguments: i32,
+         _result: i32| {
+            api().upgrade_contract(
+                &ManagedAddress::<DebugApi>::from_handle(handle(destination)),
+                gas as u64,
+                &BigUint::from_handle(handle(value)),
+                &ManagedBuffer::from_handle(handle(code)),
+                load_code_metadata(code_metadata),
+                &ManagedArgBuffer::from_handle(handle(arguments)),
+            );
+        },
+    )?;
+    linker.func_wrap(
+        ENV,
+        "managedUpgradeFromSourceContract",
+        |destination: i32,
+         gas: i64,
+         value: i32,
+         source_address: i32,
+         code_metadata: i32,
+         arguments: i32,
+         _result: i32| {
+            api().upgrade_from_source_contract(
+                &ManagedAddress::<DebugApi>::from_handle(handle(destination)),
+                gas as u64,
+                &BigUint::from_handle(handle(value)),
+                &ManagedAddress::from_handle(handle(source_address)),
+                load_code_metadata(code_metadata),
+                &ManagedArgBuffer::from_handle(handle(arguments)),
+            );
+        },
+    )?;
+    Ok(())
+}
+
+fn define_log_hooks(linker: &mut Linker<()>) -> Result<(), LinkerError> {
+    linker.func_wrap(
+        ENV,
+        "writeEventLog",
+        |caller: Caller<'_, ()>,
+         num_topics: i32,
+         topic_lengths_offset: i32,
+         topic_offset: i32,
+         data_offset: i32,
+         data_length: i32| {
+            // topic lengths are 32-bit little endian integers in the contract memory
+            let topic_lengths = load_bytes(&caller, topic_lengths_offset, num_topics * 4);
+            let mut topics = ArgBuffer::new();
+            let mut current_offset = topic_offset;
+            for topic_length_bytes in topic_lengths.chunks(4) {
+                let topic_length = i32::from_le_bytes(topic_length_bytes.try_into().unwrap());
+                topics.push_argument_bytes(&load_bytes(&caller, current_offset, topic_length));
+                current_offset += topic_length;
+            }
+            let data = load_bytes(&caller, data_offset, data_length);
+            api().write_event_log(&topics, &data);
+        },
+    )?;
+    linker.func_wrap(
+        ENV,
+        "writeLog",
+        |caller: Caller<'_, ()>,
+         data_offset: i32,
+         data_length: i32,
+         topic_offset: i32,
+         num_topics: i32| {
+            let topics_raw = load_bytes(
+                &caller,
+                topic_offset,
+                num_topics * LEGACY_TOPIC_LENGTH as i32,
+            );
+            let topics: Vec<[u8; LEGACY_TOPIC_LENGTH]> = topics_raw
+                .chunks(LEGACY_TOPIC_LENGTH)
+                .map(|topic| topic.try_into().unwrap())
+                .collect();
+            let data = load_bytes(&caller, data_offset, data_length);
+            api().write_legacy_log(&topics, &data);
+        },
+    )?;
+    linker.func_wrap(ENV, "managedWriteLog", |topics: i32, data: i32| {
+        api().managed_write_log(handle(topics), handle(data));
+    })?;
+    Ok(())
+}
+
+fn define_crypto_hooks(linker: &mut Linker<()>) -> Result<(), LinkerError> {
+    linker.func_wrap(
+        ENV,
+        "sha256",
+        |mut caller: Caller<'_, ()>, data_offset: i32, length: i32, result_offset: i32| {
+            let hash = api().sha256_legacy(&load_bytes(&caller, data_offset, length));
+            store_bytes(&mut caller, result_offset, &hash);
+            0
+        },
+    )?;
+    linker.func_wrap(ENV, "managedSha256", |input: i32, output: i32| {
+        api().sha256_managed(handle(output), handle(input));
+        0
+    })?;
+    linker.func_wrap(
+        ENV,
+        "keccak256",
+        |mut caller: Caller<'_, ()>, data_offset: i32, length: i32, result_offset: i32| {
+            let hash = api().keccak256_legacy(&load_bytes(&caller, data_offset, length));
+            store_bytes(&mut caller, result_offset, &hash);
+            0
+        },
+    )?;
+    linker.func_wrap(ENV, "managedKeccak256", |input: i32, output: i32| {
+        api().keccak256_managed(handle(output), handle(input));
+        0
+    })?;
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use multiversx_sc::contract_base::CallableContract;
+use wasmi::{errors::LinkerError, Engine, ExternType, Linker, Module, Store};
+
+use super::{vm_hooks::define_vm_hooks, wasm_memory::vm_error};
+
+const WASM_MAGIC: &[u8] = b"\0asm";
+
+/// Checks whether the contract code is a WebAssembly binary, rather than a contract path that has no binary behind it.
+pub fn is_wasm_code(contract_bytes: &[u8]) -> bool {
+    contract_bytes.starts_with(WASM_MAGIC)
+}
+
+/// Runs a compiled contract, using an embedded WebAssembly interpreter (wasmi).
+///
+/// The VM hooks imported by the contract are implemented on top of the `DebugApi` mocks,
+/// so they operate on the same transaction context as the contracts compiled to Rust.
+///
+/// An interpreter is used instead of a JIT engine because all errors in the debugger are panics,
+/// which need to unwind through the contract code to reach the executor.
+///
+/// Only part of the VM hooks are currently supported: arguments, results, storage, managed types,
+/// call value, logs, as well as the managed versions of sync calls, async calls, promises, deploys and upgrades.
+/// Same-context and read-only calls, and the legacy (non-managed) call and deploy hooks are not supported.
+/// Calling any of the unsupported hooks fails the transaction.
+pub struct WasmContract {
+    engine: Engine,
+    module: Module,
+    linker: Linker<()>,
+}
+
+impl WasmContract {
+    pub fn new(contract_bytes: &[u8]) -> Self {
+        let engine = Engine::default();
+        let module = Module::new(&engine, contract_bytes)
+            .unwrap_or_else(|err| panic!("invalid wasm contract: {err}"));
+
+        let mut linker = Linker::new(&engine);
+        define_vm_hooks(&mut linker).expect("VM hooks defined twice");
+        define_unsupported_hooks(&mut linker, &module);
+
+        WasmContract {
+            engine,
+            module,
+            linker,
+        }
+    }
+}
+
+/// Imports that the engine does not implement are only reported when actually called,
+/// to allow testing the rest of the endpoints.
+fn define_unsupported_hooks(linker: &mut Linker<()>, module: &Module) {
+    for import in module.imports() {
+        let ExternType::Func(func_type) = import.ty() else {
+            continue;
+        };
+        let message = format!(
+            "VM hook not supported by the wasm engine: {}",
+            import.name()
+        );
+        let result = linker.func_new(
+            import.module(),
+            import.name(),
+            func_type.clone(),
+            move |_, _, _| vm_error(&message),
+        );
+        match result {
+            Ok(_) | Err(LinkerError::DuplicateDefinition { .. }) => {},
+            Err(err) => panic!("could not link wasm contract: {err}"),
+        }
+    }
+}
+
+impl CallableContract for WasmContract {
+    fn call(&self, fn_name: &str) -> bool {
+        let mut store = Store::new(&self.engine, ());
+        let instance = self
+            .linker
+            .instantiate(&mut store, &self.module)
+            .and_then(|instance_pre| instance_pre.start(&mut store))
+            .unwrap_or_else(|err| panic!("could not instantiate wasm contract: {err}"));
+
+        let Ok(func) = instance.get_typed_func::<(), ()>(&store, fn_name) else {
+            return false;
+        };
+
+        if func.call(&mut store, ()).is_err() {
+            vm_error("execution failed");
+        }
+        true
+    }
+}
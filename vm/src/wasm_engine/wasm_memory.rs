@@ -0,0 +1,38 @@
+use wasmi::{Caller, Extern, Memory};
+
+use crate::tx_mock::TxPanic;
+
+fn contract_memory(caller: &Caller<'_, ()>) -> Memory {
+    caller
+        .get_export("memory")
+        .and_then(Extern::into_memory)
+        .unwrap_or_else(|| vm_error("contract does not export its memory"))
+}
+
+/// Copies `length` bytes from the contract memory, starting at `offset`.
+pub(super) fn load_bytes(caller: &Caller<'_, ()>, offset: i32, length: i32) -> Vec<u8> {
+    if length < 0 {
+        vm_error("mem load: negative length");
+    }
+    let mut buffer = vec![0u8; length as usize];
+    contract_memory(caller)
+        .read(caller, offset as u32 as usize, &mut buffer)
+        .unwrap_or_else(|_| vm_error("mem load: bad bounds"));
+    buffer
+}
+
+/// Copies the given bytes into the contract memory, starting at `offset`.
+pub(super) fn store_bytes(caller: &mut Caller<'_, ()>, offset: i32, bytes: &[u8]) {
+    let memory = contract_memory(caller);
+    memory
+        .write(caller, offset as u32 as usize, bytes)
+        .unwrap_or_else(|_| vm_error("mem store: bad bounds"));
+}
+
+/// Signals a failure of the VM itself, as opposed to an error signalled by the contract.
+pub(super) fn vm_error(message: &str) -> ! {
+    std::panic::panic_any(TxPanic {
+        status: 10,
+        message: message.to_string(),
+    })
+}
@@ -0,0 +1,205 @@
+use crate::num_bigint::BigUint;
+use multiversx_sc::{
+    codec::{top_encode_to_vec_u8, TopDecode},
+    storage::protected_keys::*,
+};
+use num_traits::Zero;
+
+use super::{AccountData, EsdtData, EsdtInstance};
+
+/// Length of the random part of a token identifier, after the dash.
+const TOKEN_IDENTIFIER_RANDOM_LEN: usize = 6;
+
+/// Token types, as stored by the protocol.
+const ESDT_TYPE_FUNGIBLE: u64 = 0;
+const ESDT_TYPE_NON_FUNGIBLE: u64 = 1;
+
+/// Flag in the token properties of an account.
+const ESDT_PROPERTY_FROZEN: u8 = 1;
+
+pub fn is_protected_storage_key(key: &[u8]) -> bool {
+    key.starts_with(ELROND_RESERVED_PREFIX)
+}
+
+/// Splits a storage key suffix into the token identifier and the nonce that might follow it.
+pub fn split_esdt_token_key(token_key: &[u8]) -> (&[u8], u64) {
+    let identifier_len = token_key
+        .iter()
+        .position(|&c| c == b'-')
+        .map_or(token_key.len(), |dash_pos| {
+            (dash_pos + 1 + TOKEN_IDENTIFIER_RANDOM_LEN).min(token_key.len())
+        });
+    let (token_identifier, nonce_bytes) = token_key.split_at(identifier_len);
+    (
+        token_identifier,
+        u64::top_decode(nonce_bytes).unwrap_or_default(),
+    )
+}
+
+impl AccountData {
+    /// Reads storage the way contracts see it.
+    ///
+    /// Besides the regular storage, this includes the data that the protocol keeps under protected keys,
+    /// which the mock models separately: ESDT balances, roles and last NFT nonces.
+    pub fn read_storage(&self, key: &[u8]) -> Vec<u8> {
+        if let Some(value) = self.storage.get(key) {
+            return value.clone();
+        }
+
+        if let Some(token_identifier) = key.strip_prefix(ELROND_ESDT_LOCAL_ROLES_KEY) {
+            return self
+                .esdt
+                .get_by_identifier(token_identifier)
+                .map(encode_esdt_roles)
+                .unwrap_or_default();
+        }
+
+        if let Some(token_identifier) = key.strip_prefix(ELROND_ESDT_NFT_LAST_NONCE_KEY) {
+            return self
+                .esdt
+                .get_by_identifier(token_identifier)
+                .map(|esdt_data| top_encode_to_vec_u8(&esdt_data.last_nonce).unwrap())
+                .unwrap_or_default();
+        }
+
+        if let Some(token_key) = key.strip_prefix(ELROND_ESDT_KEY) {
+            let (token_identifier, nonce) = split_esdt_token_key(token_key);
+            return self
+                .esdt
+                .get_by_identifier(token_identifier)
+                .and_then(|esdt_data| {
+                    let instance = esdt_data.instances.get_by_nonce(nonce)?;
+                    if instance.balance.is_zero() && !esdt_data.frozen {
+                        return None;
+                    }
+                    Some(encode_esdt_token(esdt_data, instance))
+                })
+                .unwrap_or_default();
+        }
+
+        Vec::new()
+    }
+}
+
+/// Same encoding as the protocol, which serializes `ESDTRoles { repeated bytes Roles = 1; }` with protobuf.
+fn encode_esdt_roles(esdt_data: &EsdtData) -> Vec<u8> {
+    let mut encoded = Vec::new();
+    for role in esdt_data.get_roles() {
+        protobuf_bytes_field(&mut encoded, 1, &role);
+    }
+    encoded
+}
+
+/// Inverse of the roles encoding, used when importing the storage of real accounts.
+///
+/// Fields other than the role list are skipped, so is anything after a malformed field.
+pub fn decode_esdt_roles(encoded: &[u8]) -> Vec<Vec<u8>> {
+    let mut roles = Vec::new();
+    let mut remaining = encoded;
+    while let Some(tag) = protobuf_read_varint(&mut remaining) {
+        match tag & 7 {
+            0 => {
+                if protobuf_read_varint(&mut remaining).is_none() {
+                    break;
+                }
+            },
+            2 => {
+                let Some(len) = protobuf_read_varint(&mut remaining) else {
+                    break;
+                };
+                let len = len as usize;
+                if len > remaining.len() {
+                    break;
+                }
+                let (value, rest) = remaining.split_at(len);
+                if tag >> 3 == 1 {
+                    roles.push(value.to_vec());
+                }
+                remaining = rest;
+            },
+            _ => break,
+        }
+    }
+    roles
+}
+
+/// Same encoding as the protocol, which serializes the `ESDigitalToken` structure with protobuf.
+///
+/// The mock does not keep track of the token type, so all tokens with nonce are reported as non-fungible.
+fn encode_esdt_token(esdt_data: &EsdtData, instance: &EsdtInstance) -> Vec<u8> {
+    let mut encoded = Vec::new();
+    let esdt_type = if instance.nonce == 0 {
+        ESDT_TYPE_FUNGIBLE
+    } else {
+        ESDT_TYPE_NON_FUNGIBLE
+    };
+    protobuf_varint_field(&mut encoded, 1, esdt_type);
+    protobuf_bytes_field(&mut encoded, 2, &gob_encode_big_uint(&instance.balance));
+    if esdt_data.frozen {
+        protobuf_bytes_field(&mut encoded, 3, &[ESDT_PROPERTY_FROZEN, 0]);
+    }
+    if instance.nonce != 0 {
+        let metadata = &instance.metadata;
+        let mut encoded_metadata = Vec::new();
+        protobuf_varint_field(&mut encoded_metadata, 1, instance.nonce);
+        protobuf_bytes_field(&mut encoded_metadata, 2, &metadata.name);
+        if let Some(creator) = &metadata.creator {
+            protobuf_bytes_field(&mut encoded_metadata, 3, creator.as_bytes());
+        }
+        protobuf_varint_field(&mut encoded_metadata, 4, metadata.royalties);
+        if let Some(hash) = &metadata.hash {
+            protobuf_bytes_field(&mut encoded_metadata, 5, hash);
+        }
+        for uri in &metadata.uri {
+            protobuf_bytes_field(&mut encoded_metadata, 6, uri);
+        }
+        protobuf_bytes_field(&mut encoded_metadata, 7, &metadata.attributes);
+        protobuf_bytes_field(&mut encoded, 4, &encoded_metadata);
+    }
+    encoded
+}
+
+/// The protocol serializes big integers with the Go `gob` format: a version and sign byte, followed by the magnitude.
+fn gob_encode_big_uint(value: &BigUint) -> Vec<u8> {
+    let mut encoded = vec![2u8];
+    if !value.is_zero() {
+        encoded.extend_from_slice(&value.to_bytes_be());
+    }
+    encoded
+}
+
+fn protobuf_varint(encoded: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        encoded.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    encoded.push(value as u8);
+}
+
+fn protobuf_read_varint(remaining: &mut &[u8]) -> Option<u64> {
+    let mut value = 0u64;
+    for (i, &byte) in remaining.iter().enumerate().take(10) {
+        value |= u64::from(byte & 0x7f) << (7 * i);
+        if byte & 0x80 == 0 {
+            *remaining = &remaining[i + 1..];
+            return Some(value);
+        }
+    }
+    None
+}
+
+/// Fields with default values are not serialized.
+fn protobuf_varint_field(encoded: &mut Vec<u8>, field_number: u64, value: u64) {
+    if value != 0 {
+        protobuf_varint(encoded, field_number << 3);
+        protobuf_varint(encoded, value);
+    }
+}
+
+fn protobuf_bytes_field(encoded: &mut Vec<u8>, field_number: u64, value: &[u8]) {
+    if !value.is_empty() {
+        protobuf_varint(encoded, (field_number << 3) | 2);
+        protobuf_varint(encoded, value.len() as u64);
+        encoded.extend_from_slice(value);
+    }
+}
@@ -0,0 +1,209 @@
+use sha2::{Digest, Sha512};
+
+use crate::scenario::model::{BlockInfo as ScenarioBlockInfo, BytesValue, SetStateStep, Step};
+
+use super::{BlockInfo, BlockchainMock};
+
+/// Same as on mainnet.
+const DEFAULT_ROUND_DURATION_SECS: u64 = 6;
+
+/// One day of 6 second rounds, same as on mainnet.
+const DEFAULT_ROUNDS_PER_EPOCH: u64 = 14_400;
+
+const RANDOM_SEED_LEN: usize = 48;
+
+/// Simulates block production, so that tests can move through long timelines
+/// without setting the block info by hand.
+///
+/// Every round produces exactly one block, so the block nonce advances with the round.
+/// New blocks are always produced on top of the current block info, even if set via setState:
+/// the timestamp advances by the round duration and the epoch changes
+/// whenever the round crosses a multiple of the rounds per epoch.
+/// The random seed is derived from the world seed and the round.
+#[derive(Clone, Debug)]
+pub struct BlockProduction {
+    pub round_duration_secs: u64,
+    pub rounds_per_epoch: u64,
+    pub world_seed: u64,
+
+    /// If set, every transaction step is executed in a new block.
+    pub advance_per_tx: bool,
+}
+
+impl Default for BlockProduction {
+    fn default() -> Self {
+        BlockProduction {
+            round_duration_secs: DEFAULT_ROUND_DURATION_SECS,
+            rounds_per_epoch: DEFAULT_ROUNDS_PER_EPOCH,
+            world_seed: 0,
+            advance_per_tx: false,
+        }
+    }
+}
+
+impl BlockProduction {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn round_duration_secs(mut self, round_duration_secs: u64) -> Self {
+        assert!(round_duration_secs > 0, "round duration cannot be zero");
+        self.round_duration_secs = round_duration_secs;
+        self
+    }
+
+    pub fn rounds_per_epoch(mut self, rounds_per_epoch: u64) -> Self {
+        assert!(rounds_per_epoch > 0, "rounds per epoch cannot be zero");
+        self.rounds_per_epoch = rounds_per_epoch;
+        self
+    }
+
+    pub fn world_seed(mut self, world_seed: u64) -> Self {
+        self.world_seed = world_seed;
+        self
+    }
+
+    pub fn advance_per_tx(mut self, advance_per_tx: bool) -> Self {
+        self.advance_per_tx = advance_per_tx;
+        self
+    }
+
+    /// Block info of the block produced the given number of rounds after the given block.
+    pub fn next_block_info(&self, block_info: &BlockInfo, num_rounds: u64) -> BlockInfo {
+        let round = block_info
+            .block_round
+            .checked_add(num_rounds)
+            .expect("block round overflow");
+        let elapsed_secs = num_rounds
+            .checked_mul(self.round_duration_secs)
+            .expect("block timestamp overflow");
+        let epochs_crossed =
+            round / self.rounds_per_epoch - block_info.block_round / self.rounds_per_epoch;
+        BlockInfo {
+            block_timestamp: block_info
+                .block_timestamp
+                .checked_add(elapsed_secs)
+                .expect("block timestamp overflow"),
+            block_nonce: block_info.block_nonce + num_rounds,
+            block_round: round,
+            block_epoch: block_info.block_epoch + epochs_crossed,
+            block_random_seed: self.random_seed_for_round(round),
+        }
+    }
+
+    /// Number of rounds until the first round of the given epoch,
+    /// starting from the given block.
+    pub fn rounds_until_epoch(&self, block_info: &BlockInfo, epoch: u64) -> u64 {
+        let epochs_to_go = epoch.saturating_sub(block_info.block_epoch);
+        if epochs_to_go == 0 {
+            return 0;
+        }
+
+        let current_epoch_start_round =
+            block_info.block_round - block_info.block_round % self.rounds_per_epoch;
+        let target_round = epochs_to_go
+            .checked_mul(self.rounds_per_epoch)
+            .and_then(|rounds| rounds.checked_add(current_epoch_start_round))
+            .expect("block round overflow");
+        target_round - block_info.block_round
+    }
+
+    fn random_seed_for_round(&self, round: u64) -> Box<[u8; RANDOM_SEED_LEN]> {
+        let mut hasher = Sha512::new();
+        hasher.update(self.world_seed.to_be_bytes());
+        hasher.update(round.to_be_bytes());
+        let hash = hasher.finalize();
+
+        let mut seed = [0u8; RANDOM_SEED_LEN];
+        seed.copy_from_slice(&hash[..RANDOM_SEED_LEN]);
+        Box::new(seed)
+    }
+}
+
+impl BlockchainMock {
+    /// From here on, new blocks are produced according to the block production model.
+    ///
+    /// The current block info is kept as it is, the model only applies to future blocks.
+    pub fn set_block_production(&mut self, block_production: BlockProduction) -> &mut Self {
+        self.block_production = Some(block_production);
+        self
+    }
+
+    /// Produces the given number of blocks, on top of the current block.
+    ///
+    /// The default block production model is used, if none was set.
+    pub fn advance_rounds(&mut self, num_rounds: u64) -> &mut Self {
+        if num_rounds == 0 {
+            return self;
+        }
+
+        let block_production = self.block_production.get_or_insert_with(Default::default);
+        let next_block_info =
+            block_production.next_block_info(&self.current_block_info, num_rounds);
+        self.previous_block_info = if num_rounds == 1 {
+            self.current_block_info.clone()
+        } else {
+            block_production.next_block_info(&self.current_block_info, num_rounds - 1)
+        };
+        self.current_block_info = next_block_info;
+        self.record_block_info();
+        self
+    }
+
+    /// Produces as many blocks as needed for at least the given time to pass.
+    pub fn advance_time(&mut self, secs: u64) -> &mut Self {
+        let round_duration_secs = self
+            .block_production
+            .get_or_insert_with(Default::default)
+            .round_duration_secs;
+        self.advance_rounds((secs + round_duration_secs - 1) / round_duration_secs)
+    }
+
+    /// Produces blocks up to the first round of the given epoch.
+    pub fn advance_to_epoch(&mut self, epoch: u64) -> &mut Self {
+        let current_epoch = self.current_block_info.block_epoch;
+        assert!(
+            epoch >= current_epoch,
+            "cannot go back to epoch {epoch}, already in epoch {current_epoch}"
+        );
+
+        let num_rounds = self
+            .block_production
+            .get_or_insert_with(Default::default)
+            .rounds_until_epoch(&self.current_block_info, epoch);
+        self.advance_rounds(num_rounds)
+    }
+
+    /// Called before each transaction step, has no effect unless configured.
+    pub(crate) fn advance_round_for_tx(&mut self) {
+        let advance_per_tx = self
+            .block_production
+            .as_ref()
+            .map_or(false, |block_production| block_production.advance_per_tx);
+        if advance_per_tx {
+            self.advance_rounds(1);
+        }
+    }
+
+    /// Keeps the scenario trace replayable, even if the block info was never set explicitly.
+    fn record_block_info(&mut self) {
+        let set_state_step = SetStateStep {
+            previous_block_info: Box::new(Some(block_info_as_scenario(&self.previous_block_info))),
+            current_block_info: Box::new(Some(block_info_as_scenario(&self.current_block_info))),
+            ..Default::default()
+        };
+        self.scenario_trace
+            .steps
+            .push(Step::SetState(set_state_step));
+    }
+}
+
+fn block_info_as_scenario(block_info: &BlockInfo) -> ScenarioBlockInfo {
+    ScenarioBlockInfo {
+        block_timestamp: Some(block_info.block_timestamp.into()),
+        block_nonce: Some(block_info.block_nonce.into()),
+        block_round: Some(block_info.block_round.into()),
+        block_epoch: Some(block_info.block_epoch.into()),
+        block_random_seed: Some(BytesValue::from(block_info.block_random_seed.to_vec())),
+    }
+}
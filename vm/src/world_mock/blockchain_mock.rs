@@ -5,22 +5,22 @@ use crate::{
     tx_execution::{init_builtin_functions, BuiltinFunctionMap},
     tx_mock::BlockchainUpdate,
 };
-use multiversx_sc::types::heap::Address;
+use multiversx_sc::{storage::protected_keys::ELROND_REWARD_KEY, types::heap::Address};
 use num_traits::Zero;
 use std::{collections::HashMap, path::PathBuf, rc::Rc};
 
-use super::{AccountData, BlockInfo, ContractMap};
-
-const ELROND_REWARD_KEY: &[u8] = b"ELRONDreward";
+use super::{AccountData, BlockInfo, BlockProduction, ContractMap};
 
 #[derive(Debug)]
 pub struct BlockchainMock {
     pub accounts: HashMap<Address, AccountData>,
+    pub fork_accounts: HashMap<Address, AccountData>,
     pub builtin_functions: Rc<BuiltinFunctionMap>,
     pub addr_to_pretty_string_map: HashMap<Address, String>,
     pub new_addresses: HashMap<(Address, u64), Address>,
     pub previous_block_info: BlockInfo,
     pub current_block_info: BlockInfo,
+    pub block_production: Option<BlockProduction>,
     pub contract_map: ContractMap,
     pub current_dir: PathBuf,
     pub scenario_trace: Scenario,
@@ -30,11 +30,13 @@ impl BlockchainMock {
     pub fn new() -> Self {
         BlockchainMock {
             accounts: HashMap::new(),
+            fork_accounts: HashMap::new(),
             builtin_functions: Rc::new(init_builtin_functions()),
             addr_to_pretty_string_map: HashMap::new(),
             new_addresses: HashMap::new(),
             previous_block_info: BlockInfo::new(),
             current_block_info: BlockInfo::new(),
+            block_production: None,
             contract_map: ContractMap::default(),
             current_dir: std::env::current_dir().unwrap(),
             scenario_trace: Scenario::default(),
@@ -50,7 +52,7 @@ impl Default for BlockchainMock {
 
 impl BlockchainMock {
     pub fn account_exists(&self, address: &Address) -> bool {
-        self.accounts.contains_key(address)
+        self.get_account(address).is_some()
     }
 
     pub fn contains_contract(&self, contract_path_expr: &str) -> bool {
@@ -67,6 +69,7 @@ impl BlockchainMock {
     }
 
     pub fn increase_account_nonce(&mut self, address: &Address) {
+        self.resolve_fork_account(address);
         let account = self.accounts.get_mut(address).unwrap_or_else(|| {
             panic!(
                 "Account not found: {}",
@@ -77,6 +80,7 @@ impl BlockchainMock {
     }
 
     pub fn subtract_tx_gas(&mut self, address: &Address, gas_limit: u64, gas_price: u64) {
+        self.resolve_fork_account(address);
         let account = self.accounts.get_mut(address).unwrap_or_else(|| {
             panic!(
                 "Account not found: {}",
@@ -92,6 +96,7 @@ impl BlockchainMock {
     }
 
     pub fn increase_validator_reward(&mut self, address: &Address, amount: &BigUint) {
+        self.resolve_fork_account(address);
         let account = self.accounts.get_mut(address).unwrap_or_else(|| {
             panic!(
                 "Account not found: {}",
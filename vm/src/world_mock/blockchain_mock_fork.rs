@@ -0,0 +1,34 @@
+use multiversx_sc::types::heap::Address;
+
+use super::{AccountData, BlockchainMock};
+
+/// Accounts from a fork snapshot only get copied into the world state when first used,
+/// so that large snapshots can be loaded cheaply.
+///
+/// Accounts set explicitly, or modified by transactions, always take precedence over the fork.
+impl BlockchainMock {
+    pub fn add_fork_account(&mut self, account: AccountData) {
+        self.validate_account(&account);
+        self.add_addr_scenario_string(account.address.clone());
+        self.fork_accounts.insert(account.address.clone(), account);
+    }
+
+    pub fn is_fork_account(&self, address: &Address) -> bool {
+        !self.accounts.contains_key(address) && self.fork_accounts.contains_key(address)
+    }
+
+    /// Looks the account up in the current state, then in the fork snapshot.
+    pub fn get_account(&self, address: &Address) -> Option<&AccountData> {
+        self.accounts
+            .get(address)
+            .or_else(|| self.fork_accounts.get(address))
+    }
+
+    /// Copies the account from the fork snapshot into the current state, if it is not already there.
+    pub fn resolve_fork_account(&mut self, address: &Address) {
+        if self.is_fork_account(address) {
+            let account = self.fork_accounts[address].clone();
+            self.accounts.insert(address.clone(), account);
+        }
+    }
+}
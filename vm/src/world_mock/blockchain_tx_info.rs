@@ -17,7 +17,7 @@ pub struct BlockchainTxInfo {
 
 impl BlockchainMock {
     pub fn create_tx_info(&self, contract_address: &Address) -> BlockchainTxInfo {
-        if let Some(contract) = self.accounts.get(contract_address) {
+        if let Some(contract) = self.get_account(contract_address) {
             BlockchainTxInfo {
                 previous_block_info: self.previous_block_info.clone(),
                 current_block_info: self.current_block_info.clone(),
@@ -3,10 +3,10 @@ use crate::DebugApi;
 use super::*;
 
 use alloc::vec::Vec;
-use std::{collections::HashMap, fmt};
+use std::{cell::RefCell, collections::HashMap, fmt, rc::Rc};
 
 pub struct ContractMap {
-    contract_objs: HashMap<Vec<u8>, ContractContainer>,
+    contract_objs: RefCell<HashMap<Vec<u8>, Rc<ContractContainer>>>,
 }
 
 impl fmt::Debug for ContractMap {
@@ -18,20 +18,37 @@ impl fmt::Debug for ContractMap {
 impl ContractMap {
     pub fn new() -> Self {
         ContractMap {
-            contract_objs: HashMap::new(),
+            contract_objs: RefCell::new(HashMap::new()),
         }
     }
 
+    /// Retrieves the implementation linked to the contract code.
+    ///
+    /// With the `wasm-engine` feature, code that was not linked to any implementation,
+    /// but is an actual wasm binary, is loaded into the embedded engine.
     pub fn get_contract(
         &self,
         contract_identifier: &[u8],
         _debug_api: DebugApi,
-    ) -> &ContractContainer {
-        if let Some(contract_contatiner) = self.contract_objs.get(contract_identifier) {
-            contract_contatiner
-        } else {
-            unknown_contract_panic(contract_identifier)
+    ) -> Rc<ContractContainer> {
+        if let Some(contract_contatiner) = self.contract_objs.borrow().get(contract_identifier) {
+            return contract_contatiner.clone();
         }
+
+        #[cfg(feature = "wasm-engine")]
+        if crate::wasm_engine::is_wasm_code(contract_identifier) {
+            let contract_container = Rc::new(ContractContainer::new(
+                Box::new(crate::wasm_engine::WasmContract::new(contract_identifier)),
+                None,
+                false,
+            ));
+            self.contract_objs
+                .borrow_mut()
+                .insert(contract_identifier.to_vec(), contract_container.clone());
+            return contract_container;
+        }
+
+        unknown_contract_panic(contract_identifier)
     }
 
     pub fn register_contract(
@@ -41,12 +58,13 @@ impl ContractMap {
     ) {
         let previous_entry = self
             .contract_objs
-            .insert(contract_bytes, contract_container);
+            .get_mut()
+            .insert(contract_bytes, Rc::new(contract_container));
         assert!(previous_entry.is_none(), "contract inserted twice");
     }
 
     pub fn contains_contract(&self, contract_bytes: &[u8]) -> bool {
-        self.contract_objs.contains_key(contract_bytes)
+        self.contract_objs.borrow().contains_key(contract_bytes)
     }
 }
 
@@ -146,6 +146,66 @@ impl AccountEsdt {
             .attributes = new_attribute_bytes;
     }
 
+    pub fn set_frozen(&mut self, token_identifier: Vec<u8>, frozen: bool) {
+        let esdt_data = self.0.entry(token_identifier).or_insert_with(|| EsdtData {
+            instances: EsdtInstances::new(),
+            last_nonce: 0,
+            roles: EsdtRoles::default(),
+            frozen: false,
+        });
+        esdt_data.frozen = frozen;
+    }
+
+    pub fn is_frozen(&self, token_identifier: &[u8]) -> bool {
+        self.get_by_identifier(token_identifier)
+            .map_or(false, |esdt_data| esdt_data.frozen)
+    }
+
+    /// Clears the balance of a token instance, returns the amount that was removed.
+    pub fn wipe(&mut self, token_identifier: &[u8], nonce: u64) -> BigUint {
+        self.0
+            .get_mut(token_identifier)
+            .and_then(|esdt_data| esdt_data.instances.get_mut_by_nonce(nonce))
+            .map(|instance| std::mem::take(&mut instance.balance))
+            .unwrap_or_default()
+    }
+
+    /// Fails, without changing anything, if the token instance does not exist.
+    pub fn set_uris(
+        &mut self,
+        token_identifier: &[u8],
+        nonce: u64,
+        new_uris: Vec<Vec<u8>>,
+    ) -> Result<(), &'static str> {
+        let instance = self
+            .0
+            .get_mut(token_identifier)
+            .ok_or("invalid token")?
+            .instances
+            .get_mut_by_nonce(nonce)
+            .ok_or("invalid token nonce")?;
+        instance.metadata.uri = new_uris;
+        Ok(())
+    }
+
+    /// Fails, without changing anything, if the token instance does not exist.
+    pub fn set_royalties(
+        &mut self,
+        token_identifier: &[u8],
+        nonce: u64,
+        royalties: u64,
+    ) -> Result<(), &'static str> {
+        let instance = self
+            .0
+            .get_mut(token_identifier)
+            .ok_or("invalid token")?
+            .instances
+            .get_mut_by_nonce(nonce)
+            .ok_or("invalid token nonce")?;
+        instance.metadata.royalties = royalties;
+        Ok(())
+    }
+
     pub fn iter(&self) -> Iter<Vec<u8>, EsdtData> {
         self.0.iter()
     }
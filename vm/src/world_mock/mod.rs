@@ -1,7 +1,10 @@
 mod account_data;
+mod account_data_protected_storage;
 mod block_info;
+mod block_production;
 mod blockchain_mock;
 mod blockchain_mock_account_util;
+mod blockchain_mock_fork;
 mod blockchain_mock_init;
 mod blockchain_mock_scenario_trace;
 mod blockchain_tx_info;
@@ -14,7 +17,9 @@ mod esdt_instances;
 mod esdt_roles;
 
 pub use account_data::*;
+pub use account_data_protected_storage::*;
 pub use block_info::*;
+pub use block_production::*;
 pub use blockchain_mock::*;
 pub use blockchain_mock_account_util::is_smart_contract_address;
 pub use blockchain_mock_scenario_trace::*;
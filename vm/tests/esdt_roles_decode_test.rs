@@ -0,0 +1,32 @@
+use multiversx_chain_vm::world_mock::decode_esdt_roles;
+
+#[test]
+fn test_decode_esdt_roles() {
+    let mut encoded = vec![0x0a, 17];
+    encoded.extend_from_slice(b"ESDTRoleNFTCreate");
+    encoded.extend_from_slice(&[0x0a, 15]);
+    encoded.extend_from_slice(b"ESDTRoleNFTBurn");
+
+    assert_eq!(
+        decode_esdt_roles(&encoded),
+        vec![b"ESDTRoleNFTCreate".to_vec(), b"ESDTRoleNFTBurn".to_vec()]
+    );
+}
+
+#[test]
+fn test_decode_esdt_roles_skips_other_fields() {
+    // field 2, varint 150
+    let mut encoded = vec![0x10, 0x96, 0x01, 0x0a, 17];
+    encoded.extend_from_slice(b"ESDTRoleLocalMint");
+
+    assert_eq!(
+        decode_esdt_roles(&encoded),
+        vec![b"ESDTRoleLocalMint".to_vec()]
+    );
+}
+
+#[test]
+fn test_decode_esdt_roles_truncated() {
+    assert!(decode_esdt_roles(&[0x0a, 20, b'E', b'S']).is_empty());
+    assert!(decode_esdt_roles(&[]).is_empty());
+}
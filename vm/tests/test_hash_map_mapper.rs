@@ -1,7 +1,10 @@
 use multiversx_chain_vm::DebugApi;
-use multiversx_sc::storage::{
-    mappers::{MapMapper, StorageClearable, StorageMapper},
-    StorageKey,
+use multiversx_sc::{
+    codec::multi_types::MultiValue2,
+    storage::{
+        mappers::{MapMapper, StorageClearable, StorageMapper, StoragePaginated},
+        StorageKey,
+    },
 };
 
 fn create_map() -> MapMapper<DebugApi, u64, u64> {
@@ -45,3 +48,37 @@ fn test_map_clear() {
     assert_eq!(map.len(), 0);
     assert!(map.is_empty());
 }
+
+#[test]
+fn test_map_iter_from() {
+    let mut map = create_map();
+    map.insert(42, 142);
+    map.insert(43, 143);
+    map.insert(44, 144);
+    let keys: Vec<u64> = map.keys_from(&43).collect();
+    assert_eq!(keys, vec![43, 44]);
+    let entries: Vec<(u64, u64)> = map.iter_from(&42).take(2).collect();
+    assert_eq!(entries, vec![(42, 142), (43, 143)]);
+    assert_eq!(map.iter_from(&50).next(), None);
+}
+
+#[test]
+fn test_map_page() {
+    let mut map = create_map();
+    map.insert(42, 142);
+    map.insert(43, 143);
+    map.insert(44, 144);
+    let first_page: Vec<(u64, u64)> = map
+        .page(None, 2)
+        .into_iter()
+        .map(MultiValue2::into_tuple)
+        .collect();
+    assert_eq!(first_page, vec![(42, 142), (43, 143)]);
+    let second_page: Vec<(u64, u64)> = map
+        .page(Some(43), 2)
+        .into_iter()
+        .map(MultiValue2::into_tuple)
+        .collect();
+    assert_eq!(second_page, vec![(44, 144)]);
+    assert!(map.page(Some(44), 2).is_empty());
+}
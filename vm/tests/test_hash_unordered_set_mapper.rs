@@ -1,6 +1,6 @@
 use multiversx_chain_vm::DebugApi;
 use multiversx_sc::storage::{
-    mappers::{StorageClearable, StorageMapper, UnorderedSetMapper},
+    mappers::{StorageClearable, StorageMapper, StoragePaginated, UnorderedSetMapper},
     StorageKey,
 };
 
@@ -79,3 +79,16 @@ fn test_set_clear() {
     assert_eq!(set.len(), 0);
     assert!(set.is_empty());
 }
+
+#[test]
+fn test_set_iter_from() {
+    let mut set = create_set();
+    set.insert(42);
+    set.insert(43);
+    set.insert(44);
+    set.insert(45);
+    let page: Vec<u64> = set.iter_from(2).take(2).collect();
+    assert_eq!(page, vec![43, 44]);
+    let page: Vec<u64> = set.page(3, 5).into_iter().collect();
+    assert_eq!(page, vec![44, 45]);
+}
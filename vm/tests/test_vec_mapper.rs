@@ -1,6 +1,6 @@
 use multiversx_chain_vm::DebugApi;
 use multiversx_sc::storage::{
-    mappers::{StorageMapper, VecMapper},
+    mappers::{StorageMapper, StoragePaginated, VecMapper},
     StorageKey,
 };
 
@@ -103,3 +103,29 @@ fn test_vec_clear() {
     assert_eq!(vect.len(), 0);
     assert!(vect.is_empty());
 }
+
+#[test]
+fn test_vec_iter_from() {
+    let mut vect = create_vec();
+    vect.extend_from_slice(&[42, 43, 44, 45, 46]);
+    let from_3: Vec<u64> = vect.iter_from(3).collect();
+    assert_eq!(from_3, vec![44, 45, 46]);
+    let page: Vec<u64> = vect.iter_from(2).take(2).collect();
+    assert_eq!(page, vec![43, 44]);
+    let from_0: Vec<u64> = vect.iter_from(0).collect();
+    assert_eq!(from_0, vec![42, 43, 44, 45, 46]);
+    assert_eq!(vect.iter_from(6).next(), None);
+}
+
+#[test]
+fn test_vec_page() {
+    let mut vect = create_vec();
+    vect.extend_from_slice(&[42, 43, 44, 45, 46]);
+    let page: Vec<u64> = vect.page(1, 2).into_iter().collect();
+    assert_eq!(page, vec![42, 43]);
+    let page: Vec<u64> = vect.page(3, 2).into_iter().collect();
+    assert_eq!(page, vec![44, 45]);
+    let page: Vec<u64> = vect.page(5, 2).into_iter().collect();
+    assert_eq!(page, vec![46]);
+    assert!(vect.page(7, 2).is_empty());
+}
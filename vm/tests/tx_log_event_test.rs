@@ -0,0 +1,65 @@
+use multiversx_chain_vm::{
+    scenario::model::{CheckLog, CheckLogs, TxExpect},
+    tx_mock::TxLog,
+};
+use multiversx_sc::{
+    codec::multi_types::MultiValue2,
+    types::heap::{Address, BoxedBytes},
+};
+
+/// Log as emitted by an event with 2 indexed arguments and 2 data fields.
+fn event_log() -> TxLog {
+    TxLog {
+        address: Address::zero(),
+        endpoint: "logEvent".into(),
+        topics: vec![b"my_event".to_vec(), vec![5], b"abc".to_vec()],
+        data: vec![0, 0, 0, 2, 0x03, 0xe8, 0, 0, 0, 0, 0, 0, 0, 7],
+    }
+}
+
+#[test]
+fn test_tx_log_decode_event() {
+    let log = event_log();
+    assert_eq!(log.event_identifier(), b"my_event");
+
+    let indexed_args: MultiValue2<u32, BoxedBytes> = log.decode_event_indexed_args();
+    let (arg_1, arg_2) = indexed_args.into_tuple();
+    assert_eq!(arg_1, 5);
+    assert_eq!(arg_2, BoxedBytes::from(&b"abc"[..]));
+
+    let data: (num_bigint::BigUint, u64) = log.decode_event_data();
+    assert_eq!(data, (num_bigint::BigUint::from(1000u32), 7));
+}
+
+#[test]
+fn test_check_log_event() {
+    let log = event_log();
+
+    let check_log = CheckLog::event(
+        "my_event",
+        MultiValue2::from((5u32, &b"abc"[..])),
+        (num_bigint::BigUint::from(1000u32), 7u64),
+    );
+    assert!(log.scenario_check(&check_log));
+
+    let check_log = CheckLog::event(
+        "my_event",
+        MultiValue2::from((5u32, &b"abc"[..])),
+        (num_bigint::BigUint::from(1000u32), 8u64),
+    );
+    assert!(!log.scenario_check(&check_log));
+}
+
+#[test]
+fn test_tx_expect_log() {
+    let tx_expect = TxExpect::ok()
+        .log(CheckLog::event("event_a", (), 1u32))
+        .log(CheckLog::event("event_b", (), &b""[..]));
+    match tx_expect.logs {
+        CheckLogs::List(check_logs) => {
+            assert_eq!(check_logs.list.len(), 2);
+            assert!(!check_logs.more_allowed_at_end);
+        },
+        CheckLogs::Star => panic!("logs expected to be checked"),
+    }
+}